@@ -24,15 +24,26 @@ mod renderer;
 mod util;
 mod video;
 
+#[cfg(test)]
+mod from_component_test;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod from_register_output_test;
 #[cfg(test)]
 mod from_util_test;
 
+pub use component::AudioMeter;
+pub use component::ChromaKey;
+pub use component::ColorCorrection;
 pub use component::Component;
+pub use component::Grid;
 pub use component::Image;
 pub use component::InputStream;
+pub use component::Mask;
 pub use component::Rescaler;
 pub use component::Shader;
+pub use component::Subtitles;
 pub use component::Text;
+pub use component::Ticker;
 pub use component::Tiles;
 pub use component::View;
 pub use component::WebView;
@@ -40,12 +51,16 @@ pub use component::WebView;
 pub use register_input::Mp4Input;
 pub use register_output::Mp4Output;
 pub use register_output::RtpOutput;
+pub use register_output::UpdateEncoderRequest;
 
 pub use register_input::DeckLink;
 pub use register_input::RtpInput;
 
+pub use renderer::FontSpec;
 pub use renderer::ImageSpec;
+pub use renderer::LutSpec;
 pub use renderer::ShaderSpec;
+pub use renderer::SubtitlesSpec;
 pub use renderer::WebRendererSpec;
 
 pub use util::Resolution;
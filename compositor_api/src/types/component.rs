@@ -17,6 +17,13 @@ pub enum Component {
     Text(Text),
     Tiles(Tiles),
     Rescaler(Rescaler),
+    Grid(Grid),
+    Mask(Mask),
+    ChromaKey(ChromaKey),
+    ColorCorrection(ColorCorrection),
+    Ticker(Ticker),
+    Subtitles(Subtitles),
+    AudioMeter(AudioMeter),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -75,12 +82,30 @@ pub struct View {
     /// effect if the previous scene already contained a `View` component with the same id.
     pub transition: Option<Transition>,
 
+    /// Animation used when this component is added to the scene. It is applied if the
+    /// previous scene did not contain a component with the same `id`. Requires `id`.
+    pub enter_transition: Option<EnterExitTransition>,
+
+    /// Animation used when this component is removed from the scene. Component is rendered
+    /// until the animation finishes. It is only applied if its parent is a `View`, `Tiles`
+    /// or `Grid` component that is present in both scenes (with the same `id`). The removed
+    /// component keeps its space in the parent until the next scene update. Requires `id`.
+    pub exit_transition: Option<EnterExitTransition>,
+
+    /// List of keyframe animations. Animations start when the component is added to the
+    /// scene. If a scene update contains the same animation (with exactly the same parameters)
+    /// for a component with the same `id`, the animation continues instead of restarting.
+    pub animations: Option<Vec<KeyframeAnimation>>,
+
     /// (**default=`"hidden"`**) Controls what happens to content that is too big to fit into an area.
     pub overflow: Option<Overflow>,
 
     /// (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
     pub background_color_rgba: Option<RGBAColor>,
 
+    /// Gradient used as a background. If defined, `background_color_rgba` is ignored.
+    pub background_gradient: Option<Gradient>,
+
     /// (**default=`0.0`**) Radius of a rounded corner.
     pub border_radius: Option<f32>,
 
@@ -90,8 +115,119 @@ pub struct View {
     /// (**default=`"#00000000"`**) Border color in a `"#RRGGBBAA"` format.
     pub border_color_rgba: Option<RGBAColor>,
 
+    /// Gradient used to fill a border. If defined, `border_color_rgba` is ignored. Positions
+    /// are relative to the whole component, including the border.
+    pub border_gradient: Option<Gradient>,
+
     /// List of box shadows.
     pub box_shadow: Option<Vec<BoxShadow>>,
+
+    /// (**default=`1.0`**) Opacity of the component and all of its children. Value
+    /// between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+    /// together first, so overlapping children are not visible through each other. If the
+    /// value is lower than `1.0`, content is clipped to the component's bounds.
+    pub opacity: Option<f32>,
+
+    /// (**default=`"normal"`**) Defines how colors of the component are combined with
+    /// everything rendered below it. The component is composed with its children first
+    /// and the result is blended as a whole. Children with their own blend mode are
+    /// blended with the content of this component rendered below them.
+    pub blend_mode: Option<BlendMode>,
+
+    /// (**default=`0.0`**) Radius of a Gaussian blur applied to the component and all
+    /// of its children. The blurred content is clipped to the component's bounds.
+    pub blur_radius: Option<f32>,
+
+    /// (**default=`0.0`**) Radius of a Gaussian blur applied to everything rendered
+    /// behind the component (a frosted-glass effect). Only visible through transparent
+    /// parts of the component, e.g. a semi-transparent `background_color_rgba`.
+    pub backdrop_blur: Option<f32>,
+
+    /// (**default=`0.0`**) Padding on all sides, in pixels. Padding is added to the size of
+    /// the component (similarly to a border) and only affects statically positioned children.
+    pub padding: Option<f32>,
+    /// Padding on the top side, in pixels. Overrides `padding`.
+    pub padding_top: Option<f32>,
+    /// Padding on the right side, in pixels. Overrides `padding`.
+    pub padding_right: Option<f32>,
+    /// Padding on the bottom side, in pixels. Overrides `padding`.
+    pub padding_bottom: Option<f32>,
+    /// Padding on the left side, in pixels. Overrides `padding`.
+    pub padding_left: Option<f32>,
+
+    /// (**default=`0.0`**) Space in pixels between statically positioned children.
+    pub gap: Option<f32>,
+
+    /// (**default=`"start"`**) Alignment of statically positioned children along the `direction`
+    /// axis. Only has an effect if children do not fill the entire space.
+    pub justify_content: Option<JustifyContent>,
+
+    /// (**default=`"stretch"`**) Alignment of statically positioned children along the axis
+    /// perpendicular to the `direction`.
+    pub align_items: Option<AlignItems>,
+
+    /// (**default=`0.0`**) Defines how much of the free space in the parent `View` is added
+    /// to this component. Only used if the component is statically positioned inside a `View`.
+    pub flex_grow: Option<f32>,
+
+    /// (**default=`0.0`**) Defines how much this component shrinks if the statically positioned
+    /// children of the parent `View` do not fit. Shrinking is weighted by the component size.
+    /// Only used if the component is statically positioned inside a `View`.
+    pub flex_shrink: Option<f32>,
+
+    /// Position of the component inside of a parent `Grid` component. The scene update fails
+    /// if it is defined and the parent is not a `Grid`.
+    pub grid_placement: Option<GridPlacement>,
+
+    /// (**default=`false`**) Pins the component if it is a child of a `Tiles` component.
+    /// In spotlight modes, pinned children are placed in the spotlight area, in other modes
+    /// they are placed first.
+    pub pinned: Option<bool>,
+}
+
+/// Fill that smoothly changes between colors of its stops. Positions are relative to
+/// the filled area.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Gradient {
+    /// Colors change along a line going through the center of the area.
+    Linear {
+        /// (**default=`180.0`**) Direction of the gradient line in degrees. `0.0` points
+        /// up and `90.0` points right.
+        angle: Option<f32>,
+        /// List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+        stops: Vec<GradientStop>,
+    },
+    /// Colors change with a distance from the center. The last stop is placed at the
+    /// corner farthest from the center.
+    Radial {
+        /// (**default=`0.5`**) Horizontal position of the center as a fraction of the width.
+        center_x: Option<f32>,
+        /// (**default=`0.5`**) Vertical position of the center as a fraction of the height.
+        center_y: Option<f32>,
+        /// List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+        stops: Vec<GradientStop>,
+    },
+    /// Colors change clockwise around the center.
+    Conic {
+        /// (**default=`0.0`**) Angle in degrees where the gradient starts. `0.0` points up.
+        angle: Option<f32>,
+        /// (**default=`0.5`**) Horizontal position of the center as a fraction of the width.
+        center_x: Option<f32>,
+        /// (**default=`0.5`**) Vertical position of the center as a fraction of the height.
+        center_y: Option<f32>,
+        /// List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+        stops: Vec<GradientStop>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GradientStop {
+    /// Position of the stop on the gradient, between `0.0` (start) and `1.0` (end).
+    pub offset: f32,
+    /// Color in a `"#RRGGBBAA"` format.
+    pub color_rgba: RGBAColor,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -132,6 +268,38 @@ pub enum ViewDirection {
     Column,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JustifyContent {
+    /// Children are placed at the start of the View.
+    Start,
+    /// Children are placed at the end of the View.
+    End,
+    /// Children are placed in the center of the View.
+    Center,
+    /// Free space is distributed equally between children.
+    SpaceBetween,
+    /// Free space is distributed equally around children (space at the edges is
+    /// half of the space between children).
+    SpaceAround,
+    /// Free space is distributed equally between children and the edges.
+    SpaceEvenly,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlignItems {
+    /// Children are placed at the start of the cross axis.
+    Start,
+    /// Children are placed at the end of the cross axis.
+    End,
+    /// Children are placed in the center of the cross axis.
+    Center,
+    /// Children without an explicit size are stretched to fill the View, other
+    /// children are placed at the start.
+    Stretch,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Rescaler {
@@ -183,6 +351,21 @@ pub struct Rescaler {
     /// effect if the previous scene already contained a `Rescaler` component with the same id.
     pub transition: Option<Transition>,
 
+    /// Animation used when this component is added to the scene. It is applied if the
+    /// previous scene did not contain a component with the same `id`. Requires `id`.
+    pub enter_transition: Option<EnterExitTransition>,
+
+    /// Animation used when this component is removed from the scene. Component is rendered
+    /// until the animation finishes. It is only applied if its parent is a `View`, `Tiles`
+    /// or `Grid` component that is present in both scenes (with the same `id`). The removed
+    /// component keeps its space in the parent until the next scene update. Requires `id`.
+    pub exit_transition: Option<EnterExitTransition>,
+
+    /// List of keyframe animations. Animations start when the component is added to the
+    /// scene. If a scene update contains the same animation (with exactly the same parameters)
+    /// for a component with the same `id`, the animation continues instead of restarting.
+    pub animations: Option<Vec<KeyframeAnimation>>,
+
     /// (**default=`0.0`**) Radius of a rounded corner.
     pub border_radius: Option<f32>,
 
@@ -194,6 +377,71 @@ pub struct Rescaler {
 
     /// List of box shadows.
     pub box_shadow: Option<Vec<BoxShadow>>,
+
+    /// (**default=`1.0`**) Opacity of the component and all of its children. Value
+    /// between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+    /// together first, so overlapping children are not visible through each other. If the
+    /// value is lower than `1.0`, content is clipped to the component's bounds.
+    pub opacity: Option<f32>,
+
+    /// (**default=`"normal"`**) Defines how colors of the component are combined with
+    /// everything rendered below it. The component is composed with its children first
+    /// and the result is blended as a whole. Children with their own blend mode are
+    /// blended with the content of this component rendered below them.
+    pub blend_mode: Option<BlendMode>,
+
+    /// (**default=`0.0`**) Radius of a Gaussian blur applied to the component and its
+    /// child. The blurred content is clipped to the component's bounds.
+    pub blur_radius: Option<f32>,
+
+    /// (**default=`0.0`**) Defines how much of the free space in the parent `View` is added
+    /// to this component. Only used if the component is statically positioned inside a `View`.
+    pub flex_grow: Option<f32>,
+
+    /// (**default=`0.0`**) Defines how much this component shrinks if the statically positioned
+    /// children of the parent `View` do not fit. Shrinking is weighted by the component size.
+    /// Only used if the component is statically positioned inside a `View`.
+    pub flex_shrink: Option<f32>,
+
+    /// Position of the component inside of a parent `Grid` component. The scene update fails
+    /// if it is defined and the parent is not a `Grid`.
+    pub grid_placement: Option<GridPlacement>,
+
+    /// (**default=`false`**) Pins the component if it is a child of a `Tiles` component.
+    /// In spotlight modes, pinned children are placed in the spotlight area, in other modes
+    /// they are placed first.
+    pub pinned: Option<bool>,
+
+    /// Part of the child that should be rendered. The selected rectangle is rescaled
+    /// instead of the entire child.
+    pub crop: Option<RescalerCrop>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RescalerCrop {
+    /// (**default=`0.0`**) Distance between the top edge of the child and the top edge
+    /// of the cropped rectangle.
+    pub top: Option<f32>,
+    /// (**default=`0.0`**) Distance between the left edge of the child and the left edge
+    /// of the cropped rectangle.
+    pub left: Option<f32>,
+    /// Width of the cropped rectangle.
+    pub width: f32,
+    /// Height of the cropped rectangle.
+    pub height: f32,
+    /// (**default=`"pixels"`**) Unit of the `top`, `left`, `width` and `height` fields.
+    pub unit: Option<CropUnit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CropUnit {
+    /// Values are in pixels of the child component.
+    Pixels,
+    /// Values are relative to the size of the child component, e.g. `width` equal `1.0`
+    /// represents the entire width of the child.
+    Normalized,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -245,6 +493,14 @@ pub struct Shader {
 
     /// Id of a shader. It identifies a shader registered using a [`register shader`](../routes.md#register-shader) request.
     pub shader_id: RendererId,
+    /// Ids of inputs which audio levels are passed inside the shader as:
+    ///
+    /// ```wgsl
+    /// @group(3) @binding(0) var<uniform> audio_levels: array<vec4<f32>, 16>;
+    /// ```
+    /// Levels are linear values in the order of this list, `x` is the RMS level and `y` is the peak
+    /// level. Up to 16 inputs are supported.
+    pub audio_inputs: Option<Vec<InputId>>,
     /// Object that will be serialized into a `struct` and passed inside the shader as:
     ///
     /// ```wgsl
@@ -261,6 +517,143 @@ pub struct Shader {
     pub resolution: Resolution,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Mask {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// Component that will be clipped by the mask.
+    pub child: Box<Component>,
+
+    /// Component used as a mask, e.g. an `Image` or a `Shader`. Its output is stretched
+    /// to the resolution of the `Mask` component. Mutually exclusive with `path`.
+    pub source: Option<Box<Component>>,
+    /// SVG path data (in a format of the `d` attribute) in pixel coordinates of the `Mask`
+    /// component. Area inside the path is visible. Mutually exclusive with `source`.
+    pub path: Option<String>,
+    /// (**default=`"alpha"`**) Defines how visibility is calculated from the `source`
+    /// component. Ignored if `path` is used.
+    pub mode: Option<MaskMode>,
+
+    /// Resolution of the component. Both the child and the mask are stretched to this resolution.
+    pub resolution: Resolution,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChromaKey {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// Component that will be keyed.
+    pub child: Box<Component>,
+
+    /// (**default=`"#00FF00FF"`**) Color that will be removed in a `"#RRGGBBAA"` format.
+    /// Alpha channel is ignored.
+    pub key_color_rgba: Option<RGBAColor>,
+    /// (**default=`0.4`**) Pixels with a color closer to the key color than this value
+    /// are fully transparent. The distance is calculated between chroma components of
+    /// colors in linear RGB.
+    pub similarity: Option<f32>,
+    /// (**default=`0.08`**) Width of the range above `similarity` in which pixels are
+    /// partially transparent. Higher values produce softer edges.
+    pub smoothness: Option<f32>,
+    /// (**default=`0.1`**) Value between `0.0` and `1.0` that defines how strongly the key
+    /// color reflected on the remaining pixels is suppressed.
+    pub spill: Option<f32>,
+
+    /// Resolution of the component. The child is stretched to this resolution.
+    pub resolution: Resolution,
+
+    /// Defines how this component will behave during a scene update. This will only have an
+    /// effect if the previous scene already contained a `ChromaKey` component with the same id.
+    /// All of the keying parameters are animated.
+    pub transition: Option<Transition>,
+
+    /// List of keyframe animations of `key_color_rgba`, `similarity`, `smoothness` and `spill`.
+    /// Animations start when the component is added to the scene.
+    pub animations: Option<Vec<KeyframeAnimation>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ColorCorrection {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// Component which colors will be adjusted.
+    pub child: Box<Component>,
+
+    /// (**default=`0.0`**) Value added to all color channels. Positive values brighten
+    /// the image, negative values darken it.
+    pub brightness: Option<f32>,
+    /// (**default=`1.0`**) Multiplier of the distance from mid-gray. Values above `1.0`
+    /// increase contrast, values below `1.0` decrease it.
+    pub contrast: Option<f32>,
+    /// (**default=`1.0`**) `0.0` produces a grayscale image, values above `1.0` make
+    /// colors more vivid.
+    pub saturation: Option<f32>,
+    /// (**default=`1.0`**) Gamma correction applied to all channels. Values above `1.0`
+    /// brighten midtones, values below `1.0` darken them.
+    pub gamma: Option<f32>,
+    /// (**default=`0.0`**) White balance temperature, between `-1.0` (cooler, blue) and
+    /// `1.0` (warmer, orange).
+    pub temperature: Option<f32>,
+    /// (**default=`0.0`**) White balance tint, between `-1.0` (green) and `1.0` (magenta).
+    pub tint: Option<f32>,
+    /// Per-channel adjustment of shadows, midtones and highlights.
+    pub lift_gamma_gain: Option<LiftGammaGain>,
+
+    /// Id of a LUT. It identifies a LUT registered using a [`register LUT`](../routes.md#register-lut)
+    /// request. The LUT is applied after all other adjustments.
+    pub lut_id: Option<RendererId>,
+    /// (**default=`1.0`**) Value between `0.0` and `1.0` that defines how strongly the LUT is
+    /// applied. `0.0` means that the LUT has no effect.
+    pub lut_strength: Option<f32>,
+
+    /// Resolution of the component. The child is stretched to this resolution.
+    pub resolution: Resolution,
+
+    /// Defines how this component will behave during a scene update. This will only have an
+    /// effect if the previous scene already contained a `ColorCorrection` component with the
+    /// same id. All of the numeric parameters are animated.
+    pub transition: Option<Transition>,
+
+    /// List of keyframe animations of `brightness`, `contrast`, `saturation`, `gamma`,
+    /// `temperature`, `tint` and `lut_strength`. Animations start when the component is added
+    /// to the scene.
+    pub animations: Option<Vec<KeyframeAnimation>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LiftGammaGain {
+    /// (**default=`{ red: 0.0, green: 0.0, blue: 0.0 }`**) Value added to shadows. White is
+    /// not affected.
+    pub lift: Option<ColorChannels>,
+    /// (**default=`{ red: 1.0, green: 1.0, blue: 1.0 }`**) Gamma correction of midtones.
+    pub gamma: Option<ColorChannels>,
+    /// (**default=`{ red: 1.0, green: 1.0, blue: 1.0 }`**) Multiplier of highlights. Black is
+    /// not affected.
+    pub gain: Option<ColorChannels>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ColorChannels {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MaskMode {
+    /// Alpha channel of the mask defines visibility.
+    Alpha,
+    /// Luminance of the mask defines visibility, white is fully visible and black is
+    /// fully transparent.
+    Luminance,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(
     tag = "type",
@@ -289,8 +682,15 @@ pub struct Text {
     /// Id of a component.
     pub id: Option<ComponentId>,
 
-    /// Text that will be rendered.
-    pub text: Arc<str>,
+    /// Text that will be rendered. Exactly one of `text`, `spans` or `dynamic` needs to be defined.
+    pub text: Option<Arc<str>>,
+    /// Fragments of the text that will be rendered, each with its own styling. Properties
+    /// not defined on a span are inherited from the `Text` component. Exactly one of `text`,
+    /// `spans` or `dynamic` needs to be defined.
+    pub spans: Option<Vec<TextSpan>>,
+    /// Text generated for every frame, e.g. a clock or a timecode. Exactly one of `text`,
+    /// `spans` or `dynamic` needs to be defined.
+    pub dynamic: Option<DynamicText>,
 
     /// Width of a texture that text will be rendered on. If not provided, the resulting texture
     /// will be sized based on the defined text but limited to `max_width` value.
@@ -312,6 +712,10 @@ pub struct Text {
     pub line_height: Option<f32>,
     /// (**default=`"#FFFFFFFF"`**) Font color in `#RRGGBBAA` format.
     pub color_rgba: Option<RGBAColor>,
+    /// Gradient used to fill the text. If defined, `color_rgba` is ignored. The gradient
+    /// is applied by the parent component, so the `Text` has to be a child of a layout
+    /// component (e.g. `View`).
+    pub color_gradient: Option<Gradient>,
     /// (**default=`"#00000000"`**) Background color in `#RRGGBBAA` format.
     pub background_color_rgba: Option<RGBAColor>,
     /// (**default=`"Verdana"`**) Font family. Provide [family-name](https://www.w3.org/TR/2018/REC-css-fonts-3-20180920/#family-name-value)
@@ -325,6 +729,90 @@ pub struct Text {
     pub wrap: Option<TextWrapMode>,
     /// (**default=`"normal"`**) Font weight. The selected font needs to support the specified weight.
     pub weight: Option<TextWeight>,
+    /// Outline drawn around the glyphs.
+    pub stroke: Option<TextStroke>,
+    /// Copy of the text (including a stroke) drawn below it.
+    pub shadow: Option<TextShadow>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextSpan {
+    /// Text of the span.
+    pub text: Arc<str>,
+    /// Font size in pixels. Defaults to the `font_size` of the `Text` component.
+    pub font_size: Option<f32>,
+    /// Font color in `#RRGGBBAA` format. Defaults to the `color_rgba` of the `Text` component.
+    pub color_rgba: Option<RGBAColor>,
+    /// Font family. Defaults to the `font_family` of the `Text` component.
+    pub font_family: Option<Arc<str>>,
+    /// Font style. Defaults to the `style` of the `Text` component.
+    pub style: Option<TextStyle>,
+    /// Font weight. Defaults to the `weight` of the `Text` component.
+    pub weight: Option<TextWeight>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextStroke {
+    /// Width of the outline in pixels, between `0` and `20`.
+    pub width: f32,
+    /// (**default=`"#000000FF"`**) Color of the outline in `#RRGGBBAA` format.
+    pub color_rgba: Option<RGBAColor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextShadow {
+    /// (**default=`0.0`**) Horizontal offset of the shadow in pixels.
+    pub offset_x: Option<f32>,
+    /// (**default=`0.0`**) Vertical offset of the shadow in pixels.
+    pub offset_y: Option<f32>,
+    /// (**default=`"#000000FF"`**) Color of the shadow in `#RRGGBBAA` format.
+    pub color_rgba: Option<RGBAColor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DynamicText {
+    /// Current time of the system clock.
+    Clock {
+        /// (**default=`"%H:%M:%S"`**) Format of the time, see [chrono documentation](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
+        /// for supported specifiers.
+        format: Option<Arc<str>>,
+        /// (**default=`"utc"`**) Timezone of the clock:
+        /// - `"utc"`
+        /// - `"local"` - timezone of the machine running the compositor.
+        /// - IANA timezone name, e.g. `"Europe/Warsaw"`. Daylight saving time changes are applied.
+        /// - Fixed offset from UTC in `"+HH:MM"` or `"-HH:MM"` format, e.g. `"+02:00"`.
+        timezone: Option<Arc<str>>,
+    },
+    /// PTS of the output frame formatted as a SMPTE timecode `HH:MM:SS:FF`.
+    Timecode {
+        /// Framerate used to calculate frame numbers. It should match the framerate of the output.
+        framerate: Framerate,
+        /// Use drop-frame timecode (`HH:MM:SS;FF`). Only supported for 29.97 (`"30000/1001"`)
+        /// and 59.94 (`"60000/1001"`) framerates. Defaults to `true` for those framerates
+        /// and to `false` otherwise.
+        drop_frame: Option<bool>,
+    },
+    /// Time left until `end_timestamp_ms` in `HH:MM:SS` format.
+    Countdown {
+        /// Unix timestamp in milliseconds. Remaining time is calculated based on the
+        /// system clock.
+        end_timestamp_ms: u64,
+    },
+    /// Number increased by `step` every `interval_ms`. Counting starts when the component
+    /// is added to the scene. If a scene update contains a counter with the same `id`, it
+    /// keeps counting from its current value.
+    Counter {
+        /// (**default=`0`**) Initial value.
+        start: Option<i64>,
+        /// (**default=`1`**) Value added every interval, can be negative.
+        step: Option<i64>,
+        /// (**default=`1000`**) Interval in milliseconds.
+        interval_ms: Option<f64>,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -370,11 +858,78 @@ pub enum TextWeight {
     Black,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Grid {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// List of component's children. Children are placed in cells based on their
+    /// `grid_placement` field.
+    pub children: Option<Vec<Component>>,
+
+    /// Width of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub width: Option<f32>,
+    /// Height of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub height: Option<f32>,
+
+    /// (**default=`["1fr"]`**) Sizes of columns.
+    pub columns: Option<Vec<GridTrack>>,
+    /// (**default=`[]`**) Sizes of rows. If children do not fit into defined rows, additional
+    /// `"auto"` rows are added.
+    pub rows: Option<Vec<GridTrack>>,
+    /// (**default=`0`**) Space between columns in pixels.
+    pub column_gap: Option<f32>,
+    /// (**default=`0`**) Space between rows in pixels.
+    pub row_gap: Option<f32>,
+
+    /// (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+    pub background_color_rgba: Option<RGBAColor>,
+    /// (**default=`1.0`**) Opacity of the component and all of its children. Value
+    /// between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+    /// together first, so overlapping children are not visible through each other. If the
+    /// value is lower than `1.0`, content is clipped to the component's bounds.
+    pub opacity: Option<f32>,
+
+    /// Defines how this component will behave during a scene update. This will only have an
+    /// effect if the previous scene already contained a `Grid` component with the same id.
+    pub transition: Option<Transition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GridPlacement {
+    /// Index of the first row occupied by the component (starting from 0). Maximum
+    /// value is `1000`.
+    pub row: Option<u32>,
+    /// Index of the first column occupied by the component (starting from 0). Maximum
+    /// value is `1000`.
+    pub column: Option<u32>,
+    /// (**default=`1`**) Number of rows occupied by the component. Maximum value is `1000`.
+    pub row_span: Option<u32>,
+    /// (**default=`1`**) Number of columns occupied by the component. Maximum value
+    /// is `1000`.
+    pub column_span: Option<u32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub enum Interpolation {
-    Linear,
-    Spring,
+pub enum TilesLayoutMode {
+    /// All tiles have the same size and are placed in a grid.
+    Grid,
+    /// Pinned tiles take most of the space on the left, remaining tiles form a column on the right.
+    SpotlightLeft,
+    /// Pinned tiles take most of the space on the top, remaining tiles form a row at the bottom.
+    SpotlightTop,
+    /// All tiles are placed in a single row.
+    Filmstrip,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -410,6 +965,18 @@ pub struct Tiles {
     pub horizontal_align: Option<HorizontalAlign>,
     /// (**default=`"center"`**) Vertical alignment of tiles.
     pub vertical_align: Option<VerticalAlign>,
+    /// (**default=`1.0`**) Opacity of the component and all of its children. Value
+    /// between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+    /// together first, so overlapping children are not visible through each other. If the
+    /// value is lower than `1.0`, content is clipped to the component's bounds.
+    pub opacity: Option<f32>,
+
+    /// (**default=`"grid"`**) Defines how tiles are arranged.
+    pub layout_mode: Option<TilesLayoutMode>,
+    /// Ids of child components that define the order of tiles. Listed children are placed
+    /// first (in the listed order), remaining children keep the order in which they are defined.
+    /// It allows keeping a stable order of tiles independently of the order of children.
+    pub order: Option<Vec<ComponentId>>,
 
     /// Defines how this component will behave during a scene update. This will only have an
     /// effect if the previous scene already contained a `Tiles` component with the same id.
@@ -417,3 +984,202 @@ pub struct Tiles {
 
     pub border_radius: Option<f32>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Ticker {
+    /// Id of a component. Required to preserve the scroll position and to append a new
+    /// content at the end of the current loop when the scene is updated.
+    pub id: Option<ComponentId>,
+
+    /// Text rendered in a loop. The `id` of this component is ignored.
+    pub text: Text,
+    /// (**default=`" • "`**) Text rendered after each iteration of the loop. It uses
+    /// the same style as the `text`.
+    pub separator: Option<Arc<str>>,
+    /// (**default=`100`**) Scroll speed in pixels per second.
+    pub speed: Option<f32>,
+    /// (**default=`"left"`**) Direction in which the text moves.
+    pub direction: Option<TickerDirection>,
+
+    /// Width of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub width: Option<f32>,
+    /// Height of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub height: Option<f32>,
+
+    /// Distance in pixels between this component's top edge and its parent's top edge.
+    /// If this field is defined, then the component will ignore a layout defined by its parent.
+    pub top: Option<f32>,
+    /// Distance in pixels between this component's left edge and its parent's left edge.
+    /// If this field is defined, this element will be absolutely positioned, instead of being
+    /// laid out by its parent.
+    pub left: Option<f32>,
+    /// Distance in pixels between the bottom edge of this component and the bottom edge of its
+    /// parent. If this field is defined, this element will be absolutely positioned, instead of
+    /// being laid out by its parent.
+    pub bottom: Option<f32>,
+    /// Distance in pixels between this component's right edge and its parent's right edge.
+    /// If this field is defined, this element will be absolutely positioned, instead of being
+    /// laid out by its parent.
+    pub right: Option<f32>,
+    /// Rotation of a component in degrees. If this field is defined, this element will be
+    /// absolutely positioned, instead of being laid out by its parent.
+    pub rotation: Option<f32>,
+
+    /// (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+    pub background_color_rgba: Option<RGBAColor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Subtitles {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// Id of subtitles registered using a [`RegisterSubtitles`](../routes.md#register-subtitles)
+    /// request.
+    pub subtitles_id: RendererId,
+    /// (**default=`0`**) Offset in milliseconds added to the timestamps of the cues. Positive
+    /// values display the cues later.
+    pub offset_ms: Option<f64>,
+
+    /// Width of the component in pixels.
+    pub width: f32,
+    /// Height of the component in pixels.
+    pub height: f32,
+    /// (**default=`"bottom"`**) Vertical position of the cue text inside the component.
+    pub vertical_align: Option<VerticalAlign>,
+
+    /// Font size in pixels.
+    pub font_size: f32,
+    /// Distance between lines in pixels. Defaults to the value of the `font_size` property.
+    pub line_height: Option<f32>,
+    /// (**default=`"#FFFFFFFF"`**) Font color in `#RRGGBBAA` format.
+    pub color_rgba: Option<RGBAColor>,
+    /// (**default=`"#00000000"`**) Background color in `#RRGGBBAA` format.
+    pub background_color_rgba: Option<RGBAColor>,
+    /// (**default=`"Verdana"`**) Font family. Provide [family-name](https://www.w3.org/TR/2018/REC-css-fonts-3-20180920/#family-name-value)
+    /// for a specific font. "generic-family" values like e.g. "sans-serif" will not work.
+    pub font_family: Option<Arc<str>>,
+    /// (**default=`"normal"`**) Font style. The selected font needs to support the specified style.
+    pub style: Option<TextStyle>,
+    /// (**default=`"center"`**) Text align.
+    pub align: Option<HorizontalAlign>,
+    /// (**default=`"word"`**) Text wrapping options.
+    pub wrap: Option<TextWrapMode>,
+    /// (**default=`"normal"`**) Font weight. The selected font needs to support the specified weight.
+    pub weight: Option<TextWeight>,
+    /// Outline drawn around the glyphs.
+    pub stroke: Option<TextStroke>,
+    /// Copy of the text (including a stroke) drawn below it.
+    pub shadow: Option<TextShadow>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TickerDirection {
+    /// Text enters from the right edge and moves to the left.
+    Left,
+    /// Text enters from the left edge and moves to the right.
+    Right,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AudioMeter {
+    /// Id of a component.
+    pub id: Option<ComponentId>,
+    /// Id of an input which audio level is displayed. It identifies a stream registered
+    /// using a [`RegisterInputStream`](../routes.md#register-input) request.
+    pub input_id: InputId,
+
+    /// (**default=`{ "type": "bar" }`**) Visual style of the meter.
+    pub style: Option<AudioMeterStyle>,
+    /// (**default=`"vertical"`**) Direction in which the meter fills.
+    pub orientation: Option<AudioMeterOrientation>,
+
+    /// (**default=`-60`**) Level in dBFS displayed at the start of the meter. Quieter
+    /// signal is displayed as silence.
+    pub min_db: Option<f32>,
+    /// (**default=`-18`**) Level in dBFS above which `warning_color_rgba` is used.
+    pub warning_db: Option<f32>,
+    /// (**default=`-6`**) Level in dBFS above which `clip_color_rgba` is used.
+    pub clip_db: Option<f32>,
+
+    /// (**default=`"#22C55EFF"`**) Color of the levels below `warning_db` in a `"#RRGGBBAA"` format.
+    pub color_rgba: Option<RGBAColor>,
+    /// (**default=`"#EAB308FF"`**) Color of the levels between `warning_db` and `clip_db`
+    /// in a `"#RRGGBBAA"` format.
+    pub warning_color_rgba: Option<RGBAColor>,
+    /// (**default=`"#EF4444FF"`**) Color of the levels above `clip_db` in a `"#RRGGBBAA"` format.
+    pub clip_color_rgba: Option<RGBAColor>,
+    /// (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+    pub background_color_rgba: Option<RGBAColor>,
+
+    /// Width of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub width: Option<f32>,
+    /// Height of a component in pixels. Exact behavior might be different based on the parent
+    /// component:
+    /// - If the parent component is a layout, check sections "Absolute positioning" and "Static
+    ///   positioning" of that component.
+    /// - If the parent component is not a layout, then this field is required.
+    pub height: Option<f32>,
+
+    /// Distance in pixels between this component's top edge and its parent's top edge.
+    /// If this field is defined, then the component will ignore a layout defined by its parent.
+    pub top: Option<f32>,
+    /// Distance in pixels between this component's left edge and its parent's left edge.
+    /// If this field is defined, this element will be absolutely positioned, instead of being
+    /// laid out by its parent.
+    pub left: Option<f32>,
+    /// Distance in pixels between the bottom edge of this component and the bottom edge of its
+    /// parent. If this field is defined, this element will be absolutely positioned, instead of
+    /// being laid out by its parent.
+    pub bottom: Option<f32>,
+    /// Distance in pixels between this component's right edge and its parent's right edge.
+    /// If this field is defined, this element will be absolutely positioned, instead of being
+    /// laid out by its parent.
+    pub right: Option<f32>,
+    /// Rotation of a component in degrees. If this field is defined, this element will be
+    /// absolutely positioned, instead of being laid out by its parent.
+    pub rotation: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AudioMeterStyle {
+    /// Continuous bar that shows the RMS level with a marker on the peak level.
+    Bar,
+    /// Bar split into segments that are lit up to the RMS level.
+    Segmented {
+        /// (**default=`12`**) Number of segments, between 1 and 32.
+        segments: Option<u32>,
+    },
+    /// Peak levels over time. The newest level is displayed at the end of the meter.
+    Waveform {
+        /// (**default=`5000`**) Time range displayed by the waveform in milliseconds.
+        duration_ms: Option<f64>,
+        /// (**default=`24`**) Number of bars, between 1 and 48.
+        bars: Option<u32>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioMeterOrientation {
+    /// Meter fills from the bottom, waveform scrolls down.
+    Vertical,
+    /// Meter fills from the left, waveform scrolls to the left.
+    Horizontal,
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use compositor_render::scene;
 use compositor_render::scene::BorderRadius;
@@ -21,6 +22,15 @@ impl TryFrom<Component> for scene::Component {
             Component::Text(text) => Ok(Self::Text(text.try_into()?)),
             Component::Tiles(tiles) => Ok(Self::Tiles(tiles.try_into()?)),
             Component::Rescaler(rescaler) => Ok(Self::Rescaler(rescaler.try_into()?)),
+            Component::Grid(grid) => Ok(Self::Grid(grid.try_into()?)),
+            Component::Mask(mask) => Ok(Self::Mask(mask.try_into()?)),
+            Component::ChromaKey(chroma_key) => Ok(Self::ChromaKey(chroma_key.try_into()?)),
+            Component::ColorCorrection(color_correction) => {
+                Ok(Self::ColorCorrection(color_correction.try_into()?))
+            }
+            Component::Ticker(ticker) => Ok(Self::Ticker(ticker.try_into()?)),
+            Component::Subtitles(subtitles) => Ok(Self::Subtitles(subtitles.try_into()?)),
+            Component::AudioMeter(audio_meter) => Ok(Self::AudioMeter(audio_meter.try_into()?)),
         }
     }
 }
@@ -101,19 +111,82 @@ impl TryFrom<View> for scene::ViewComponent {
                 .background_color_rgba
                 .map(TryInto::try_into)
                 .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 0)))?,
+            background_gradient: view
+                .background_gradient
+                .map(TryInto::try_into)
+                .transpose()?,
             transition: view.transition.map(TryInto::try_into).transpose()?,
+            enter_transition: parse_enter_exit_transition(
+                view.enter_transition,
+                view.id.is_some(),
+                "enter_transition",
+            )?,
+            exit_transition: parse_enter_exit_transition(
+                view.exit_transition,
+                view.id.is_some(),
+                "exit_transition",
+            )?,
+            animations: parse_animations(view.animations, "View", |property| match property {
+                AnimatedProperty::Top
+                | AnimatedProperty::Left
+                | AnimatedProperty::Bottom
+                | AnimatedProperty::Right
+                | AnimatedProperty::Rotation => is_absolute_position,
+                AnimatedProperty::Width
+                | AnimatedProperty::Height
+                | AnimatedProperty::Opacity
+                | AnimatedProperty::BorderWidth
+                | AnimatedProperty::BorderRadius
+                | AnimatedProperty::BorderColorRgba
+                | AnimatedProperty::BackgroundColorRgba
+                | AnimatedProperty::BlurRadius
+                | AnimatedProperty::BackdropBlur => true,
+                _ => false,
+            })?,
             border_radius: BorderRadius::new_with_radius(view.border_radius.unwrap_or(0.0)),
             border_width: view.border_width.unwrap_or(0.0),
             border_color: view
                 .border_color_rgba
                 .map(TryInto::try_into)
                 .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 0)))?,
+            border_gradient: view.border_gradient.map(TryInto::try_into).transpose()?,
             box_shadow: view
                 .box_shadow
                 .unwrap_or_default()
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            opacity: parse_opacity(view.opacity)?,
+            blend_mode: view
+                .blend_mode
+                .map(Into::into)
+                .unwrap_or(scene::BlendMode::Normal),
+            blur_radius: parse_non_negative("blur_radius", view.blur_radius)?,
+            backdrop_blur: parse_non_negative("backdrop_blur", view.backdrop_blur)?,
+            padding: parse_padding(&view)?,
+            gap: parse_non_negative("gap", view.gap)?,
+            justify_content: match view.justify_content {
+                Some(JustifyContent::Start) | None => scene::JustifyContent::Start,
+                Some(JustifyContent::End) => scene::JustifyContent::End,
+                Some(JustifyContent::Center) => scene::JustifyContent::Center,
+                Some(JustifyContent::SpaceBetween) => scene::JustifyContent::SpaceBetween,
+                Some(JustifyContent::SpaceAround) => scene::JustifyContent::SpaceAround,
+                Some(JustifyContent::SpaceEvenly) => scene::JustifyContent::SpaceEvenly,
+            },
+            align_items: match view.align_items {
+                Some(AlignItems::Start) => scene::AlignItems::Start,
+                Some(AlignItems::End) => scene::AlignItems::End,
+                Some(AlignItems::Center) => scene::AlignItems::Center,
+                Some(AlignItems::Stretch) | None => scene::AlignItems::Stretch,
+            },
+            flex_grow: parse_non_negative("flex_grow", view.flex_grow)?,
+            flex_shrink: parse_non_negative("flex_shrink", view.flex_shrink)?,
+            grid_placement: view
+                .grid_placement
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            pinned: view.pinned.unwrap_or(false),
         })
     }
 }
@@ -128,6 +201,7 @@ impl TryFrom<Rescaler> for scene::RescalerComponent {
         const HORIZONTAL_REQUIRED_MSG: &str =
             "Non-static \"Rescaler\" component requires either \"left\" or \"right\" coordinate.";
         const HORIZONTAL_ONLY_ONE_MSG: &str = "Fields \"left\" and \"right\" are mutually exclusive, you can only specify one on a \"Rescaler\" component.";
+        let has_crop = rescaler.crop.is_some();
         let is_absolute_position = rescaler.top.is_some()
             || rescaler.bottom.is_some()
             || rescaler.left.is_some()
@@ -178,6 +252,39 @@ impl TryFrom<Rescaler> for scene::RescalerComponent {
                 .unwrap_or(VerticalAlign::Center)
                 .into(),
             transition: rescaler.transition.map(TryInto::try_into).transpose()?,
+            enter_transition: parse_enter_exit_transition(
+                rescaler.enter_transition,
+                rescaler.id.is_some(),
+                "enter_transition",
+            )?,
+            exit_transition: parse_enter_exit_transition(
+                rescaler.exit_transition,
+                rescaler.id.is_some(),
+                "exit_transition",
+            )?,
+            animations: parse_animations(
+                rescaler.animations,
+                "Rescaler",
+                |property| match property {
+                    AnimatedProperty::Top
+                    | AnimatedProperty::Left
+                    | AnimatedProperty::Bottom
+                    | AnimatedProperty::Right
+                    | AnimatedProperty::Rotation => is_absolute_position,
+                    AnimatedProperty::CropTop
+                    | AnimatedProperty::CropLeft
+                    | AnimatedProperty::CropWidth
+                    | AnimatedProperty::CropHeight => has_crop,
+                    AnimatedProperty::Width
+                    | AnimatedProperty::Height
+                    | AnimatedProperty::Opacity
+                    | AnimatedProperty::BorderWidth
+                    | AnimatedProperty::BorderRadius
+                    | AnimatedProperty::BorderColorRgba
+                    | AnimatedProperty::BlurRadius => true,
+                    _ => false,
+                },
+            )?,
             border_radius: BorderRadius::new_with_radius(rescaler.border_radius.unwrap_or(0.0)),
             border_width: rescaler.border_width.unwrap_or(0.0),
             border_color: rescaler
@@ -190,18 +297,244 @@ impl TryFrom<Rescaler> for scene::RescalerComponent {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            opacity: parse_opacity(rescaler.opacity)?,
+            blend_mode: rescaler
+                .blend_mode
+                .map(Into::into)
+                .unwrap_or(scene::BlendMode::Normal),
+            blur_radius: parse_non_negative("blur_radius", rescaler.blur_radius)?,
+            flex_grow: parse_non_negative("flex_grow", rescaler.flex_grow)?,
+            flex_shrink: parse_non_negative("flex_shrink", rescaler.flex_shrink)?,
+            grid_placement: rescaler
+                .grid_placement
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            pinned: rescaler.pinned.unwrap_or(false),
+            crop: rescaler.crop.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<RescalerCrop> for scene::RescalerCrop {
+    type Error = TypeError;
+
+    fn try_from(crop: RescalerCrop) -> Result<Self, Self::Error> {
+        if crop.width <= 0.0 || crop.height <= 0.0 {
+            return Err(TypeError::new(
+                "Crop \"width\" and \"height\" have to be positive numbers.",
+            ));
+        }
+        Ok(Self {
+            top: crop.top.unwrap_or(0.0),
+            left: crop.left.unwrap_or(0.0),
+            width: crop.width,
+            height: crop.height,
+            unit: match crop.unit {
+                Some(CropUnit::Pixels) | None => scene::CropUnit::Pixels,
+                Some(CropUnit::Normalized) => scene::CropUnit::Normalized,
+            },
+        })
+    }
+}
+
+impl TryFrom<Mask> for scene::MaskComponent {
+    type Error = TypeError;
+
+    fn try_from(mask: Mask) -> Result<Self, Self::Error> {
+        let source = match (mask.source, mask.path) {
+            (Some(source), None) => scene::MaskSource::Component(Box::new((*source).try_into()?)),
+            (None, Some(path)) => scene::MaskSource::Path(path.into()),
+            (None, None) => {
+                return Err(TypeError::new(
+                    "\"Mask\" component requires either \"source\" or \"path\" field.",
+                ))
+            }
+            (Some(_), Some(_)) => {
+                return Err(TypeError::new(
+                    "Fields \"source\" and \"path\" are mutually exclusive, you can only specify one on a \"Mask\" component.",
+                ))
+            }
+        };
+        let mode = match mask.mode {
+            Some(MaskMode::Alpha) | None => scene::MaskMode::Alpha,
+            Some(MaskMode::Luminance) => scene::MaskMode::Luminance,
+        };
+        Ok(Self {
+            id: mask.id.map(Into::into),
+            child: Box::new((*mask.child).try_into()?),
+            source,
+            mode,
+            size: mask.resolution.into(),
+        })
+    }
+}
+
+impl TryFrom<ChromaKey> for scene::ChromaKeyComponent {
+    type Error = TypeError;
+
+    fn try_from(chroma_key: ChromaKey) -> Result<Self, Self::Error> {
+        let spill = chroma_key.spill.unwrap_or(0.1);
+        if !(0.0..=1.0).contains(&spill) {
+            return Err(TypeError::new(format!(
+                "Field \"spill\" has to be a value between 0.0 and 1.0, received {spill}."
+            )));
+        }
+        Ok(Self {
+            id: chroma_key.id.map(Into::into),
+            child: Box::new((*chroma_key.child).try_into()?),
+            key_color: chroma_key
+                .key_color_rgba
+                .map(TryInto::try_into)
+                .unwrap_or(Ok(scene::RGBAColor(0, 255, 0, 255)))?,
+            similarity: parse_non_negative(
+                "similarity",
+                Some(chroma_key.similarity.unwrap_or(0.4)),
+            )?,
+            smoothness: parse_non_negative(
+                "smoothness",
+                Some(chroma_key.smoothness.unwrap_or(0.08)),
+            )?,
+            spill,
+            transition: chroma_key.transition.map(TryInto::try_into).transpose()?,
+            animations: parse_animations(chroma_key.animations, "ChromaKey", |property| {
+                matches!(
+                    property,
+                    AnimatedProperty::KeyColorRgba
+                        | AnimatedProperty::Similarity
+                        | AnimatedProperty::Smoothness
+                        | AnimatedProperty::Spill
+                )
+            })?,
+            size: chroma_key.resolution.into(),
+        })
+    }
+}
+
+impl TryFrom<ColorCorrection> for scene::ColorCorrectionComponent {
+    type Error = TypeError;
+
+    fn try_from(color_correction: ColorCorrection) -> Result<Self, Self::Error> {
+        let gamma = color_correction.gamma.unwrap_or(1.0);
+        if gamma <= 0.0 {
+            return Err(TypeError::new(format!(
+                "Field \"gamma\" has to be a positive number, received {gamma}."
+            )));
+        }
+
+        Ok(Self {
+            id: color_correction.id.map(Into::into),
+            child: Box::new((*color_correction.child).try_into()?),
+            brightness: color_correction.brightness.unwrap_or(0.0),
+            contrast: parse_non_negative(
+                "contrast",
+                Some(color_correction.contrast.unwrap_or(1.0)),
+            )?,
+            saturation: parse_non_negative(
+                "saturation",
+                Some(color_correction.saturation.unwrap_or(1.0)),
+            )?,
+            gamma,
+            temperature: parse_in_range(
+                "temperature",
+                color_correction.temperature.unwrap_or(0.0),
+                -1.0,
+                1.0,
+            )?,
+            tint: parse_in_range("tint", color_correction.tint.unwrap_or(0.0), -1.0, 1.0)?,
+            lift_gamma_gain: color_correction
+                .lift_gamma_gain
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or(scene::LiftGammaGain::IDENTITY),
+            lut_id: color_correction.lut_id.map(Into::into),
+            lut_strength: parse_in_range(
+                "lut_strength",
+                color_correction.lut_strength.unwrap_or(1.0),
+                0.0,
+                1.0,
+            )?,
+            transition: color_correction
+                .transition
+                .map(TryInto::try_into)
+                .transpose()?,
+            animations: parse_animations(
+                color_correction.animations,
+                "ColorCorrection",
+                |property| {
+                    matches!(
+                        property,
+                        AnimatedProperty::Brightness
+                            | AnimatedProperty::Contrast
+                            | AnimatedProperty::Saturation
+                            | AnimatedProperty::Gamma
+                            | AnimatedProperty::Temperature
+                            | AnimatedProperty::Tint
+                            | AnimatedProperty::LutStrength
+                    )
+                },
+            )?,
+            size: color_correction.resolution.into(),
+        })
+    }
+}
+
+impl TryFrom<LiftGammaGain> for scene::LiftGammaGain {
+    type Error = TypeError;
+
+    fn try_from(lift_gamma_gain: LiftGammaGain) -> Result<Self, Self::Error> {
+        let identity = scene::LiftGammaGain::IDENTITY;
+        let gamma = lift_gamma_gain
+            .gamma
+            .map(Into::into)
+            .unwrap_or(identity.gamma);
+        if [gamma.red, gamma.green, gamma.blue]
+            .iter()
+            .any(|value| *value <= 0.0)
+        {
+            return Err(TypeError::new(
+                "Channels of \"lift_gamma_gain.gamma\" have to be positive numbers.",
+            ));
+        }
+        Ok(Self {
+            lift: lift_gamma_gain
+                .lift
+                .map(Into::into)
+                .unwrap_or(identity.lift),
+            gamma,
+            gain: lift_gamma_gain
+                .gain
+                .map(Into::into)
+                .unwrap_or(identity.gain),
         })
     }
 }
 
+impl From<ColorChannels> for scene::ColorChannels {
+    fn from(channels: ColorChannels) -> Self {
+        Self {
+            red: channels.red,
+            green: channels.green,
+            blue: channels.blue,
+        }
+    }
+}
+
 impl TryFrom<Shader> for scene::ShaderComponent {
     type Error = TypeError;
 
     fn try_from(shader: Shader) -> Result<Self, Self::Error> {
+        let audio_inputs = shader.audio_inputs.unwrap_or_default();
+        if audio_inputs.len() > 16 {
+            return Err(TypeError::new(
+                "\"audio_inputs\" property on a Shader component can't have more than 16 inputs.",
+            ));
+        }
         Ok(Self {
             id: shader.id.map(Into::into),
             shader_id: shader.shader_id.into(),
             shader_param: shader.shader_param.map(Into::into),
+            audio_inputs: audio_inputs.into_iter().map(Into::into).collect(),
             size: shader.resolution.into(),
             children: shader
                 .children
@@ -248,11 +581,34 @@ impl TryFrom<Text> for scene::TextComponent {
     type Error = TypeError;
 
     fn try_from(text: Text) -> Result<Self, Self::Error> {
-        let style = match text.style {
-            Some(TextStyle::Normal) => scene::TextStyle::Normal,
-            Some(TextStyle::Italic) => scene::TextStyle::Italic,
-            Some(TextStyle::Oblique) => scene::TextStyle::Oblique,
-            None => scene::TextStyle::Normal,
+        let plain_span = |text| scene::TextSpan {
+            text,
+            font_family: None,
+            font_size: None,
+            color: None,
+            style: None,
+            weight: None,
+        };
+        let (spans, dynamic) = match (text.text, text.spans, text.dynamic) {
+            (Some(text), None, None) => (vec![plain_span(text)], None),
+            (None, Some(spans), None) => (
+                spans
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, _>>()?,
+                None,
+            ),
+            (None, None, Some(dynamic)) => (vec![], Some(dynamic.try_into()?)),
+            (None, None, None) => {
+                return Err(TypeError::new(
+                    "Text component requires either \"text\", \"spans\" or \"dynamic\" property.",
+                ));
+            }
+            _ => {
+                return Err(TypeError::new(
+                    "\"text\", \"spans\" and \"dynamic\" properties on a Text component are mutually exclusive.",
+                ));
+            }
         };
         let wrap = match text.wrap {
             Some(TextWrapMode::None) => scene::TextWrap::None,
@@ -260,18 +616,6 @@ impl TryFrom<Text> for scene::TextComponent {
             Some(TextWrapMode::Glyph) => scene::TextWrap::Glyph,
             None => scene::TextWrap::None,
         };
-        let weight = match text.weight {
-            Some(TextWeight::Thin) => scene::TextWeight::Thin,
-            Some(TextWeight::ExtraLight) => scene::TextWeight::ExtraLight,
-            Some(TextWeight::Light) => scene::TextWeight::Light,
-            Some(TextWeight::Normal) => scene::TextWeight::Normal,
-            Some(TextWeight::Medium) => scene::TextWeight::Medium,
-            Some(TextWeight::SemiBold) => scene::TextWeight::SemiBold,
-            Some(TextWeight::Bold) => scene::TextWeight::Bold,
-            Some(TextWeight::ExtraBold) => scene::TextWeight::ExtraBold,
-            Some(TextWeight::Black) => scene::TextWeight::Black,
-            None => scene::TextWeight::Normal,
-        };
         let dimensions = match (text.width, text.height, text.max_width, text.max_height) {
             (Some(width), Some(height), _, _) => scene::TextDimensions::Fixed { width, height },
             (None, Some(_), _, _) => {
@@ -290,7 +634,7 @@ impl TryFrom<Text> for scene::TextComponent {
         };
         let text = Self {
             id: text.id.map(Into::into),
-            text: text.text,
+            spans,
             font_size: text.font_size,
             dimensions,
             line_height: text.line_height.unwrap_or(text.font_size),
@@ -298,20 +642,191 @@ impl TryFrom<Text> for scene::TextComponent {
                 .color_rgba
                 .map(TryInto::try_into)
                 .unwrap_or(Ok(scene::RGBAColor(255, 255, 255, 255)))?,
+            color_gradient: text.color_gradient.map(TryInto::try_into).transpose()?,
             font_family: text.font_family.unwrap_or_else(|| Arc::from("Verdana")),
-            style,
+            style: text
+                .style
+                .map(Into::into)
+                .unwrap_or(scene::TextStyle::Normal),
             align: text.align.unwrap_or(HorizontalAlign::Left).into(),
             wrap,
-            weight,
+            weight: text
+                .weight
+                .map(Into::into)
+                .unwrap_or(scene::TextWeight::Normal),
             background_color: text
                 .background_color_rgba
                 .map(TryInto::try_into)
                 .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 0)))?,
+            stroke: text.stroke.map(TryInto::try_into).transpose()?,
+            shadow: text.shadow.map(TryInto::try_into).transpose()?,
+            dynamic,
         };
         Ok(text)
     }
 }
 
+impl TryFrom<DynamicText> for scene::DynamicText {
+    type Error = TypeError;
+
+    fn try_from(dynamic: DynamicText) -> Result<Self, Self::Error> {
+        match dynamic {
+            DynamicText::Clock { format, timezone } => Ok(Self::Clock {
+                format: format.unwrap_or_else(|| Arc::from("%H:%M:%S")),
+                timezone: parse_clock_timezone(timezone.as_deref().unwrap_or("utc"))?,
+            }),
+            DynamicText::Timecode {
+                framerate,
+                drop_frame,
+            } => {
+                let framerate: compositor_render::Framerate = framerate.try_into()?;
+                let is_ntsc = framerate.den == 1001 && matches!(framerate.num, 30000 | 60000);
+                let drop_frame = drop_frame.unwrap_or(is_ntsc);
+                if drop_frame && !is_ntsc {
+                    return Err(TypeError::new(
+                        "Drop-frame timecode is only supported for \"30000/1001\" and \"60000/1001\" framerates.",
+                    ));
+                }
+                Ok(Self::Timecode {
+                    framerate,
+                    drop_frame,
+                })
+            }
+            DynamicText::Countdown { end_timestamp_ms } => Ok(Self::Countdown {
+                end: SystemTime::UNIX_EPOCH + Duration::from_millis(end_timestamp_ms),
+            }),
+            DynamicText::Counter {
+                start,
+                step,
+                interval_ms,
+            } => {
+                let interval_ms = interval_ms.unwrap_or(1000.0);
+                // values too small to be represented are rejected, counter can't be
+                // updated more than once per nanosecond
+                let interval = Duration::try_from_secs_f64(interval_ms / 1000.0)
+                    .ok()
+                    .filter(|interval| !interval.is_zero())
+                    .ok_or_else(|| {
+                        TypeError::new(format!(
+                            "Counter \"interval_ms\" has to be a positive number that fits in a duration, received {interval_ms}."
+                        ))
+                    })?;
+                Ok(Self::Counter {
+                    start: start.unwrap_or(0),
+                    step: step.unwrap_or(1),
+                    interval,
+                })
+            }
+        }
+    }
+}
+
+fn parse_clock_timezone(timezone: &str) -> Result<scene::ClockTimezone, TypeError> {
+    match timezone {
+        "utc" => Ok(scene::ClockTimezone::Utc),
+        "local" => Ok(scene::ClockTimezone::Local),
+        timezone => parse_utc_offset(timezone)
+            .map(scene::ClockTimezone::Offset)
+            .or_else(|| scene::ClockTimezone::named(timezone))
+            .ok_or_else(|| TypeError::new(format!(
+                "Clock \"timezone\" has to be \"utc\", \"local\", an IANA timezone name (e.g. \"Europe/Warsaw\") or an offset in the \"+HH:MM\" or \"-HH:MM\" format, received \"{timezone}\"."
+            ))),
+    }
+}
+
+/// Parses `+HH:MM` or `-HH:MM` into an offset in seconds.
+fn parse_utc_offset(offset: &str) -> Option<i32> {
+    let (sign, offset) = match offset.split_at_checked(1)? {
+        ("+", offset) => (1, offset),
+        ("-", offset) => (-1, offset),
+        _ => return None,
+    };
+    let (hours, minutes) = offset.split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok().filter(|hours| *hours <= 23)?;
+    let minutes: i32 = minutes.parse().ok().filter(|minutes| *minutes <= 59)?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+impl TryFrom<TextSpan> for scene::TextSpan {
+    type Error = TypeError;
+
+    fn try_from(span: TextSpan) -> Result<Self, Self::Error> {
+        Ok(Self {
+            text: span.text,
+            font_family: span.font_family,
+            font_size: span.font_size,
+            color: span.color_rgba.map(TryInto::try_into).transpose()?,
+            style: span.style.map(Into::into),
+            weight: span.weight.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<TextStroke> for scene::TextStroke {
+    type Error = TypeError;
+
+    fn try_from(stroke: TextStroke) -> Result<Self, Self::Error> {
+        if !(0.0..=scene::TextStroke::MAX_WIDTH).contains(&stroke.width) {
+            return Err(TypeError::new(format!(
+                "Text stroke \"width\" has to be between 0 and {}, received {}.",
+                scene::TextStroke::MAX_WIDTH,
+                stroke.width
+            )));
+        }
+        Ok(Self {
+            width: stroke.width,
+            color: stroke
+                .color_rgba
+                .map(TryInto::try_into)
+                .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 255)))?,
+        })
+    }
+}
+
+impl TryFrom<TextShadow> for scene::TextShadow {
+    type Error = TypeError;
+
+    fn try_from(shadow: TextShadow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            offset_x: shadow.offset_x.unwrap_or(0.0),
+            offset_y: shadow.offset_y.unwrap_or(0.0),
+            color: shadow
+                .color_rgba
+                .map(TryInto::try_into)
+                .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 255)))?,
+        })
+    }
+}
+
+impl From<TextStyle> for scene::TextStyle {
+    fn from(style: TextStyle) -> Self {
+        match style {
+            TextStyle::Normal => Self::Normal,
+            TextStyle::Italic => Self::Italic,
+            TextStyle::Oblique => Self::Oblique,
+        }
+    }
+}
+
+impl From<TextWeight> for scene::TextWeight {
+    fn from(weight: TextWeight) -> Self {
+        match weight {
+            TextWeight::Thin => Self::Thin,
+            TextWeight::ExtraLight => Self::ExtraLight,
+            TextWeight::Light => Self::Light,
+            TextWeight::Normal => Self::Normal,
+            TextWeight::Medium => Self::Medium,
+            TextWeight::SemiBold => Self::SemiBold,
+            TextWeight::Bold => Self::Bold,
+            TextWeight::ExtraBold => Self::ExtraBold,
+            TextWeight::Black => Self::Black,
+        }
+    }
+}
+
 impl TryFrom<WebView> for scene::WebViewComponent {
     type Error = TypeError;
 
@@ -359,12 +874,94 @@ impl TryFrom<Tiles> for scene::TilesComponent {
                 .unwrap_or(HorizontalAlign::Center)
                 .into(),
             vertical_align: tiles.vertical_align.unwrap_or(VerticalAlign::Center).into(),
+            opacity: parse_opacity(tiles.opacity)?,
+            layout_mode: match tiles.layout_mode {
+                Some(TilesLayoutMode::Grid) | None => scene::TilesLayoutMode::Grid,
+                Some(TilesLayoutMode::SpotlightLeft) => scene::TilesLayoutMode::SpotlightLeft,
+                Some(TilesLayoutMode::SpotlightTop) => scene::TilesLayoutMode::SpotlightTop,
+                Some(TilesLayoutMode::Filmstrip) => scene::TilesLayoutMode::Filmstrip,
+            },
+            order: tiles
+                .order
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
             transition: tiles.transition.map(TryInto::try_into).transpose()?,
         };
         Ok(result)
     }
 }
 
+impl TryFrom<Grid> for scene::GridComponent {
+    type Error = TypeError;
+
+    fn try_from(grid: Grid) -> Result<Self, Self::Error> {
+        let result = Self {
+            id: grid.id.map(Into::into),
+            children: grid
+                .children
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            width: grid.width,
+            height: grid.height,
+            columns: grid
+                .columns
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            rows: grid
+                .rows
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            column_gap: parse_non_negative("column_gap", grid.column_gap)?,
+            row_gap: parse_non_negative("row_gap", grid.row_gap)?,
+            background_color: grid
+                .background_color_rgba
+                .map(TryInto::try_into)
+                .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 0)))?,
+            opacity: parse_opacity(grid.opacity)?,
+            transition: grid.transition.map(TryInto::try_into).transpose()?,
+        };
+        Ok(result)
+    }
+}
+
+/// Grid allocates every row up to the last occupied one, so indexes and spans are
+/// limited to keep the number of cells reasonable.
+const MAX_GRID_TRACK: u32 = 1000;
+
+impl TryFrom<GridPlacement> for scene::GridPlacement {
+    type Error = TypeError;
+
+    fn try_from(placement: GridPlacement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            row: parse_grid_track(placement.row, "row")?,
+            column: parse_grid_track(placement.column, "column")?,
+            row_span: parse_grid_track(placement.row_span, "row_span")?
+                .unwrap_or(1)
+                .max(1),
+            column_span: parse_grid_track(placement.column_span, "column_span")?
+                .unwrap_or(1)
+                .max(1),
+        })
+    }
+}
+
+fn parse_grid_track(value: Option<u32>, field_name: &str) -> Result<Option<u32>, TypeError> {
+    match value {
+        Some(value) if value > MAX_GRID_TRACK => Err(TypeError::new(format!(
+            "Field \"grid_placement.{field_name}\" can't be larger than {MAX_GRID_TRACK}, received {value}."
+        ))),
+        value => Ok(value),
+    }
+}
+
 impl TryFrom<BoxShadow> for scene::BoxShadow {
     type Error = TypeError;
 
@@ -380,3 +977,378 @@ impl TryFrom<BoxShadow> for scene::BoxShadow {
         })
     }
 }
+
+impl TryFrom<Gradient> for scene::Gradient {
+    type Error = TypeError;
+
+    fn try_from(gradient: Gradient) -> Result<Self, Self::Error> {
+        let (kind, stops) = match gradient {
+            Gradient::Linear { angle, stops } => (
+                scene::GradientKind::Linear {
+                    angle_degrees: angle.unwrap_or(180.0),
+                },
+                stops,
+            ),
+            Gradient::Radial {
+                center_x,
+                center_y,
+                stops,
+            } => (
+                scene::GradientKind::Radial {
+                    center_x: center_x.unwrap_or(0.5),
+                    center_y: center_y.unwrap_or(0.5),
+                },
+                stops,
+            ),
+            Gradient::Conic {
+                angle,
+                center_x,
+                center_y,
+                stops,
+            } => (
+                scene::GradientKind::Conic {
+                    angle_degrees: angle.unwrap_or(0.0),
+                    center_x: center_x.unwrap_or(0.5),
+                    center_y: center_y.unwrap_or(0.5),
+                },
+                stops,
+            ),
+        };
+        if stops.len() < 2 || stops.len() > scene::Gradient::MAX_STOPS {
+            return Err(TypeError::new(format!(
+                "Gradient has to have between 2 and {} color stops, received {}.",
+                scene::Gradient::MAX_STOPS,
+                stops.len()
+            )));
+        }
+        let stops = stops
+            .into_iter()
+            .map(|stop| {
+                Ok(scene::GradientStop {
+                    offset: parse_in_range("offset", stop.offset, 0.0, 1.0)?,
+                    color: stop.color_rgba.try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, TypeError>>()?;
+        if stops
+            .windows(2)
+            .any(|stops| stops[0].offset > stops[1].offset)
+        {
+            return Err(TypeError::new(
+                "Gradient color stops have to be sorted by \"offset\".",
+            ));
+        }
+        Ok(Self { kind, stops })
+    }
+}
+
+fn parse_padding(view: &View) -> Result<scene::Padding, TypeError> {
+    let padding = parse_non_negative("padding", view.padding)?;
+    let side = |field_name, value: Option<f32>| match value {
+        Some(_) => parse_non_negative(field_name, value),
+        None => Ok(padding),
+    };
+    Ok(scene::Padding {
+        top: side("padding_top", view.padding_top)?,
+        right: side("padding_right", view.padding_right)?,
+        bottom: side("padding_bottom", view.padding_bottom)?,
+        left: side("padding_left", view.padding_left)?,
+    })
+}
+
+/// Parse optional field that defaults to `0.0` and can not be negative.
+fn parse_non_negative(field_name: &str, value: Option<f32>) -> Result<f32, TypeError> {
+    match value {
+        None => Ok(0.0),
+        Some(value) if value >= 0.0 => Ok(value),
+        Some(value) => Err(TypeError::new(format!(
+            "Field \"{field_name}\" has to be a non-negative number, received {value}."
+        ))),
+    }
+}
+
+fn parse_in_range(field_name: &str, value: f32, min: f32, max: f32) -> Result<f32, TypeError> {
+    match (min..=max).contains(&value) {
+        true => Ok(value),
+        false => Err(TypeError::new(format!(
+            "Field \"{field_name}\" has to be a value between {min:?} and {max:?}, received {value}."
+        ))),
+    }
+}
+
+/// Enter and exit transitions are matched with the previous scene by `id`. Without
+/// it, a component would be treated as a new one on every scene update.
+fn parse_enter_exit_transition(
+    transition: Option<EnterExitTransition>,
+    has_id: bool,
+    field_name: &str,
+) -> Result<Option<scene::EnterExitTransition>, TypeError> {
+    match (transition, has_id) {
+        (Some(_), false) => Err(TypeError::new(format!(
+            "Field \"{field_name}\" can only be used on a component with an \"id\"."
+        ))),
+        (transition, _) => transition.map(TryInto::try_into).transpose(),
+    }
+}
+
+/// Animations of properties that can't be applied to the component are rejected
+/// instead of being silently ignored.
+fn parse_animations(
+    animations: Option<Vec<KeyframeAnimation>>,
+    component_type: &str,
+    is_supported: impl Fn(AnimatedProperty) -> bool,
+) -> Result<Vec<scene::KeyframeAnimation>, TypeError> {
+    animations
+        .unwrap_or_default()
+        .into_iter()
+        .map(|animation| {
+            if !is_supported(animation.property) {
+                return Err(TypeError::new(format!(
+                    "Property \"{}\" can't be animated on this \"{component_type}\" component.",
+                    animation.property.name()
+                )));
+            }
+            animation.try_into()
+        })
+        .collect()
+}
+
+fn parse_opacity(opacity: Option<f32>) -> Result<f32, TypeError> {
+    match opacity {
+        None => Ok(1.0),
+        Some(opacity) if (0.0..=1.0).contains(&opacity) => Ok(opacity),
+        Some(opacity) => Err(TypeError::new(format!(
+            "Opacity has to be a value between 0.0 and 1.0, received {opacity}."
+        ))),
+    }
+}
+
+impl TryFrom<Ticker> for scene::TickerComponent {
+    type Error = TypeError;
+
+    fn try_from(ticker: Ticker) -> Result<Self, Self::Error> {
+        const VERTICAL_REQUIRED_MSG: &str =
+            "\"Ticker\" component with absolute positioning requires either \"top\" or \"bottom\" coordinate.";
+        const VERTICAL_ONLY_ONE_MSG: &str = "Fields \"top\" and \"bottom\" are mutually exclusive, you can only specify one on a \"Ticker\" component.";
+        const HORIZONTAL_REQUIRED_MSG: &str =
+            "Non-static \"Ticker\" component requires either \"left\" or \"right\" coordinate.";
+        const HORIZONTAL_ONLY_ONE_MSG: &str = "Fields \"left\" and \"right\" are mutually exclusive, you can only specify one on a \"Ticker\" component.";
+        let is_absolute_position = ticker.top.is_some()
+            || ticker.bottom.is_some()
+            || ticker.left.is_some()
+            || ticker.right.is_some()
+            || ticker.rotation.is_some();
+        let position = if is_absolute_position {
+            let position_vertical = match (ticker.top, ticker.bottom) {
+                (Some(top), None) => scene::VerticalPosition::TopOffset(top),
+                (None, Some(bottom)) => scene::VerticalPosition::BottomOffset(bottom),
+                (None, None) => return Err(TypeError::new(VERTICAL_REQUIRED_MSG)),
+                (Some(_), Some(_)) => return Err(TypeError::new(VERTICAL_ONLY_ONE_MSG)),
+            };
+            let position_horizontal = match (ticker.left, ticker.right) {
+                (Some(left), None) => scene::HorizontalPosition::LeftOffset(left),
+                (None, Some(right)) => scene::HorizontalPosition::RightOffset(right),
+                (None, None) => return Err(TypeError::new(HORIZONTAL_REQUIRED_MSG)),
+                (Some(_), Some(_)) => return Err(TypeError::new(HORIZONTAL_ONLY_ONE_MSG)),
+            };
+            Position::Absolute(scene::AbsolutePosition {
+                width: ticker.width,
+                height: ticker.height,
+                position_horizontal,
+                position_vertical,
+                rotation_degrees: ticker.rotation.unwrap_or(0.0),
+            })
+        } else {
+            Position::Static {
+                width: ticker.width,
+                height: ticker.height,
+            }
+        };
+        let speed = ticker.speed.unwrap_or(100.0);
+        if speed < 0.0 {
+            return Err(TypeError::new(
+                "\"speed\" property on a Ticker component can't be negative. Use \"direction\" to change where the text moves.",
+            ));
+        }
+        let direction = match ticker.direction {
+            Some(TickerDirection::Left) | None => scene::TickerDirection::Left,
+            Some(TickerDirection::Right) => scene::TickerDirection::Right,
+        };
+        Ok(Self {
+            id: ticker.id.map(Into::into),
+            position,
+            text: ticker.text.try_into()?,
+            separator: ticker.separator.unwrap_or_else(|| Arc::from(" • ")),
+            speed,
+            direction,
+            background_color: ticker
+                .background_color_rgba
+                .map(TryInto::try_into)
+                .unwrap_or(Ok(scene::RGBAColor(0, 0, 0, 0)))?,
+        })
+    }
+}
+
+impl TryFrom<Subtitles> for scene::SubtitlesComponent {
+    type Error = TypeError;
+
+    fn try_from(subtitles: Subtitles) -> Result<Self, Self::Error> {
+        let offset_ms = subtitles.offset_ms.unwrap_or(0.0);
+        if !offset_ms.is_finite() {
+            return Err(TypeError::new(
+                "\"offset_ms\" property on a Subtitles component has to be a finite number.",
+            ));
+        }
+        let text = Text {
+            id: None,
+            text: Some(Arc::from("")),
+            spans: None,
+            dynamic: None,
+            width: Some(subtitles.width),
+            height: Some(subtitles.height),
+            max_width: None,
+            max_height: None,
+            font_size: subtitles.font_size,
+            line_height: subtitles.line_height,
+            color_rgba: subtitles.color_rgba,
+            color_gradient: None,
+            background_color_rgba: subtitles.background_color_rgba,
+            font_family: subtitles.font_family,
+            style: subtitles.style,
+            align: Some(subtitles.align.unwrap_or(HorizontalAlign::Center)),
+            wrap: Some(subtitles.wrap.unwrap_or(TextWrapMode::Word)),
+            weight: subtitles.weight,
+            stroke: subtitles.stroke,
+            shadow: subtitles.shadow,
+        };
+        Ok(Self {
+            id: subtitles.id.map(Into::into),
+            subtitles_id: subtitles.subtitles_id.into(),
+            offset_ms: offset_ms.round() as i64,
+            text: text.try_into()?,
+            width: subtitles.width,
+            height: subtitles.height,
+            vertical_align: subtitles
+                .vertical_align
+                .unwrap_or(VerticalAlign::Bottom)
+                .into(),
+        })
+    }
+}
+
+impl TryFrom<AudioMeter> for scene::AudioMeterComponent {
+    type Error = TypeError;
+
+    fn try_from(meter: AudioMeter) -> Result<Self, Self::Error> {
+        const VERTICAL_REQUIRED_MSG: &str =
+            "\"AudioMeter\" component with absolute positioning requires either \"top\" or \"bottom\" coordinate.";
+        const VERTICAL_ONLY_ONE_MSG: &str = "Fields \"top\" and \"bottom\" are mutually exclusive, you can only specify one on an \"AudioMeter\" component.";
+        const HORIZONTAL_REQUIRED_MSG: &str =
+            "Non-static \"AudioMeter\" component requires either \"left\" or \"right\" coordinate.";
+        const HORIZONTAL_ONLY_ONE_MSG: &str = "Fields \"left\" and \"right\" are mutually exclusive, you can only specify one on an \"AudioMeter\" component.";
+        let is_absolute_position = meter.top.is_some()
+            || meter.bottom.is_some()
+            || meter.left.is_some()
+            || meter.right.is_some()
+            || meter.rotation.is_some();
+        let position = if is_absolute_position {
+            let position_vertical = match (meter.top, meter.bottom) {
+                (Some(top), None) => scene::VerticalPosition::TopOffset(top),
+                (None, Some(bottom)) => scene::VerticalPosition::BottomOffset(bottom),
+                (None, None) => return Err(TypeError::new(VERTICAL_REQUIRED_MSG)),
+                (Some(_), Some(_)) => return Err(TypeError::new(VERTICAL_ONLY_ONE_MSG)),
+            };
+            let position_horizontal = match (meter.left, meter.right) {
+                (Some(left), None) => scene::HorizontalPosition::LeftOffset(left),
+                (None, Some(right)) => scene::HorizontalPosition::RightOffset(right),
+                (None, None) => return Err(TypeError::new(HORIZONTAL_REQUIRED_MSG)),
+                (Some(_), Some(_)) => return Err(TypeError::new(HORIZONTAL_ONLY_ONE_MSG)),
+            };
+            Position::Absolute(scene::AbsolutePosition {
+                width: meter.width,
+                height: meter.height,
+                position_horizontal,
+                position_vertical,
+                rotation_degrees: meter.rotation.unwrap_or(0.0),
+            })
+        } else {
+            Position::Static {
+                width: meter.width,
+                height: meter.height,
+            }
+        };
+
+        let style = match meter.style {
+            Some(AudioMeterStyle::Bar) | None => scene::AudioMeterStyle::Bar,
+            Some(AudioMeterStyle::Segmented { segments }) => {
+                let segments = segments.unwrap_or(12);
+                if !(1..=32).contains(&segments) {
+                    return Err(TypeError::new(
+                        "AudioMeter \"segments\" has to be a number between 1 and 32.",
+                    ));
+                }
+                scene::AudioMeterStyle::Segmented { segments }
+            }
+            Some(AudioMeterStyle::Waveform { duration_ms, bars }) => {
+                let duration_ms = duration_ms.unwrap_or(5000.0);
+                if duration_ms <= 0.0 || duration_ms > 30_000.0 {
+                    return Err(TypeError::new(
+                        "AudioMeter \"duration_ms\" has to be a positive number not larger than 30000.",
+                    ));
+                }
+                let bars = bars.unwrap_or(24);
+                if !(1..=48).contains(&bars) {
+                    return Err(TypeError::new(
+                        "AudioMeter \"bars\" has to be a number between 1 and 48.",
+                    ));
+                }
+                scene::AudioMeterStyle::Waveform {
+                    duration: Duration::from_secs_f64(duration_ms / 1000.0),
+                    bars,
+                }
+            }
+        };
+        let orientation = match meter.orientation {
+            Some(AudioMeterOrientation::Vertical) | None => scene::AudioMeterOrientation::Vertical,
+            Some(AudioMeterOrientation::Horizontal) => scene::AudioMeterOrientation::Horizontal,
+        };
+
+        let min_db = meter.min_db.unwrap_or(-60.0);
+        let warning_db = meter.warning_db.unwrap_or(-18.0);
+        let clip_db = meter.clip_db.unwrap_or(-6.0);
+        if min_db >= 0.0 {
+            return Err(TypeError::new(
+                "AudioMeter \"min_db\" has to be a negative number.",
+            ));
+        }
+        if warning_db < min_db || clip_db < warning_db || clip_db > 0.0 {
+            return Err(TypeError::new(
+                "AudioMeter levels have to satisfy \"min_db\" <= \"warning_db\" <= \"clip_db\" <= 0.",
+            ));
+        }
+
+        let color = |color: Option<RGBAColor>, default: scene::RGBAColor| {
+            color.map(TryInto::try_into).unwrap_or(Ok(default))
+        };
+        Ok(Self {
+            id: meter.id.map(Into::into),
+            input_id: meter.input_id.into(),
+            position,
+            style,
+            orientation,
+            min_db,
+            warning_db,
+            clip_db,
+            color: color(meter.color_rgba, scene::RGBAColor(0x22, 0xC5, 0x5E, 0xFF))?,
+            warning_color: color(
+                meter.warning_color_rgba,
+                scene::RGBAColor(0xEA, 0xB3, 0x08, 0xFF),
+            )?,
+            clip_color: color(
+                meter.clip_color_rgba,
+                scene::RGBAColor(0xEF, 0x44, 0x44, 0xFF),
+            )?,
+            background_color: color(meter.background_color_rgba, scene::RGBAColor(0, 0, 0, 0))?,
+        })
+    }
+}
@@ -0,0 +1,288 @@
+use std::{sync::Arc, time::Duration};
+
+use compositor_render::scene;
+
+use crate::types::{
+    component::{DynamicText, GridPlacement, TextStroke, View},
+    util::{
+        AnimatedProperty, EnterExitEffect, EnterExitTransition, Keyframe, KeyframeAnimation,
+        KeyframeValue,
+    },
+    ComponentId, TypeError,
+};
+
+fn view() -> View {
+    View {
+        id: None,
+        children: None,
+        width: None,
+        height: None,
+        direction: None,
+        top: None,
+        left: None,
+        bottom: None,
+        right: None,
+        rotation: None,
+        transition: None,
+        enter_transition: None,
+        exit_transition: None,
+        animations: None,
+        overflow: None,
+        background_color_rgba: None,
+        background_gradient: None,
+        border_radius: None,
+        border_width: None,
+        border_color_rgba: None,
+        border_gradient: None,
+        box_shadow: None,
+        opacity: None,
+        blend_mode: None,
+        blur_radius: None,
+        backdrop_blur: None,
+        padding: None,
+        padding_top: None,
+        padding_right: None,
+        padding_bottom: None,
+        padding_left: None,
+        gap: None,
+        justify_content: None,
+        align_items: None,
+        flex_grow: None,
+        flex_shrink: None,
+        grid_placement: None,
+        pinned: None,
+    }
+}
+
+#[test]
+fn test_enter_exit_transition_requires_id() {
+    fn fade() -> Option<EnterExitTransition> {
+        Some(EnterExitTransition {
+            effect: EnterExitEffect::Fade,
+            duration_ms: 500.0,
+            easing_function: None,
+        })
+    }
+    fn test_case(view: View, expected: Result<(), &str>) {
+        assert_eq!(
+            scene::ViewComponent::try_from(view).map(|_| ()),
+            expected.map_err(TypeError::new)
+        );
+    }
+
+    test_case(
+        View {
+            enter_transition: fade(),
+            ..view()
+        },
+        Err("Field \"enter_transition\" can only be used on a component with an \"id\"."),
+    );
+    test_case(
+        View {
+            exit_transition: fade(),
+            ..view()
+        },
+        Err("Field \"exit_transition\" can only be used on a component with an \"id\"."),
+    );
+    test_case(
+        View {
+            id: Some(ComponentId(Arc::from("view"))),
+            enter_transition: fade(),
+            exit_transition: fade(),
+            ..view()
+        },
+        Ok(()),
+    );
+}
+
+#[test]
+fn test_grid_placement_conversion() {
+    fn test_case(placement: GridPlacement, expected: Result<scene::GridPlacement, &str>) {
+        assert_eq!(
+            scene::GridPlacement::try_from(placement),
+            expected.map_err(TypeError::new)
+        );
+    }
+    fn placement(row: Option<u32>, row_span: Option<u32>) -> GridPlacement {
+        GridPlacement {
+            row,
+            column: Some(1),
+            row_span,
+            column_span: None,
+        }
+    }
+
+    test_case(
+        placement(Some(1000), Some(0)),
+        Ok(scene::GridPlacement {
+            row: Some(1000),
+            column: Some(1),
+            row_span: 1,
+            column_span: 1,
+        }),
+    );
+    test_case(
+        placement(Some(u32::MAX), None),
+        Err("Field \"grid_placement.row\" can't be larger than 1000, received 4294967295."),
+    );
+    test_case(
+        placement(None, Some(1001)),
+        Err("Field \"grid_placement.row_span\" can't be larger than 1000, received 1001."),
+    );
+}
+
+#[test]
+fn test_text_stroke_width() {
+    fn test_case(width: f32, expected: Result<f32, &str>) {
+        let stroke = TextStroke {
+            width,
+            color_rgba: None,
+        };
+        assert_eq!(
+            scene::TextStroke::try_from(stroke).map(|stroke| stroke.width),
+            expected.map_err(TypeError::new)
+        );
+    }
+
+    test_case(0.0, Ok(0.0));
+    test_case(20.0, Ok(20.0));
+    test_case(
+        -1.0,
+        Err("Text stroke \"width\" has to be between 0 and 20, received -1."),
+    );
+    test_case(
+        1e9,
+        Err("Text stroke \"width\" has to be between 0 and 20, received 1000000000."),
+    );
+}
+
+#[test]
+fn test_counter_interval() {
+    fn test_case(interval_ms: f64, expected: Result<Duration, &str>) {
+        let counter = DynamicText::Counter {
+            start: None,
+            step: None,
+            interval_ms: Some(interval_ms),
+        };
+        let interval = scene::DynamicText::try_from(counter).map(|dynamic| match dynamic {
+            scene::DynamicText::Counter { interval, .. } => interval,
+            _ => unreachable!(),
+        });
+        assert_eq!(interval, expected.map_err(TypeError::new));
+    }
+
+    test_case(1000.0, Ok(Duration::from_secs(1)));
+    test_case(0.5, Ok(Duration::from_micros(500)));
+    test_case(
+        0.0,
+        Err("Counter \"interval_ms\" has to be a positive number that fits in a duration, received 0."),
+    );
+    test_case(
+        -1.0,
+        Err("Counter \"interval_ms\" has to be a positive number that fits in a duration, received -1."),
+    );
+    test_case(
+        1e30,
+        Err("Counter \"interval_ms\" has to be a positive number that fits in a duration, received 1000000000000000000000000000000."),
+    );
+}
+
+#[test]
+fn test_keyframe_animation_validation() {
+    fn animation(property: AnimatedProperty, duration_ms: f64) -> KeyframeAnimation {
+        KeyframeAnimation {
+            property,
+            keyframes: vec![Keyframe {
+                offset: 0.0,
+                value: KeyframeValue::Number(0.0),
+                easing_function: None,
+            }],
+            duration_ms,
+            delay_ms: None,
+            iterations: None,
+            direction: None,
+        }
+    }
+    fn test_case(view: View, expected: Result<(), &str>) {
+        assert_eq!(
+            scene::ViewComponent::try_from(view).map(|_| ()),
+            expected.map_err(TypeError::new)
+        );
+    }
+
+    test_case(
+        View {
+            animations: Some(vec![animation(AnimatedProperty::Opacity, 1000.0)]),
+            ..view()
+        },
+        Ok(()),
+    );
+    test_case(
+        View {
+            animations: Some(vec![animation(AnimatedProperty::Opacity, 1e30)]),
+            ..view()
+        },
+        Err("Animation \"duration_ms\" has to be a positive number that fits in a duration, received 1000000000000000000000000000000."),
+    );
+    test_case(
+        View {
+            animations: Some(vec![KeyframeAnimation {
+                delay_ms: Some(1e30),
+                ..animation(AnimatedProperty::Opacity, 1000.0)
+            }]),
+            ..view()
+        },
+        Err("Animation \"delay_ms\" has to fit in a duration, received 1000000000000000000000000000000."),
+    );
+    test_case(
+        View {
+            animations: Some(vec![animation(AnimatedProperty::Top, 1000.0)]),
+            ..view()
+        },
+        Err("Property \"top\" can't be animated on this \"View\" component."),
+    );
+    test_case(
+        View {
+            top: Some(0.0),
+            left: Some(0.0),
+            animations: Some(vec![animation(AnimatedProperty::Top, 1000.0)]),
+            ..view()
+        },
+        Ok(()),
+    );
+    test_case(
+        View {
+            animations: Some(vec![animation(AnimatedProperty::Similarity, 1000.0)]),
+            ..view()
+        },
+        Err("Property \"similarity\" can't be animated on this \"View\" component."),
+    );
+}
+
+#[test]
+fn test_clock_timezone() {
+    fn parse(timezone: &str) -> Result<scene::ClockTimezone, TypeError> {
+        let clock = DynamicText::Clock {
+            format: None,
+            timezone: Some(Arc::from(timezone)),
+        };
+        scene::DynamicText::try_from(clock).map(|dynamic| match dynamic {
+            scene::DynamicText::Clock { timezone, .. } => timezone,
+            _ => unreachable!(),
+        })
+    }
+
+    assert!(matches!(parse("utc"), Ok(scene::ClockTimezone::Utc)));
+    assert!(matches!(
+        parse("+02:00"),
+        Ok(scene::ClockTimezone::Offset(7200))
+    ));
+    assert!(matches!(
+        parse("Europe/Warsaw"),
+        Ok(scene::ClockTimezone::Named(_))
+    ));
+    assert_eq!(
+        parse("Mars/Olympus_Mons").unwrap_err(),
+        TypeError::new("Clock \"timezone\" has to be \"utc\", \"local\", an IANA timezone name (e.g. \"Europe/Warsaw\") or an offset in the \"+HH:MM\" or \"-HH:MM\" format, received \"Mars/Olympus_Mons\".")
+    );
+}
@@ -124,6 +124,10 @@ impl TryFrom<RtpInput> for pipeline::RegisterInputOptions {
                             VideoDecoder::VulkanVideo => {
                                 return Err(TypeError::new(NO_VULKAN_VIDEO))
                             }
+
+                            VideoDecoder::Auto => decoder::VideoDecoderOptions {
+                                decoder: pipeline::VideoDecoder::Auto,
+                            },
                         },
                     })
                 })
@@ -188,6 +192,8 @@ impl TryFrom<Mp4Input> for pipeline::RegisterInputOptions {
 
             #[cfg(not(feature = "vk-video"))]
             VideoDecoder::VulkanVideo => return Err(TypeError::new(NO_VULKAN_VIDEO)),
+
+            VideoDecoder::Auto => pipeline::VideoDecoder::Auto,
         };
 
         Ok(pipeline::RegisterInputOptions {
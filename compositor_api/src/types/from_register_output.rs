@@ -1,10 +1,12 @@
+use std::{path::PathBuf, time::Duration};
+
 use compositor_pipeline::pipeline::{
     self,
     encoder::{
         self,
         fdk_aac::AacEncoderOptions,
         ffmpeg_h264::{self},
-        AudioEncoderOptions,
+        vaapi_h264, AudioEncoderOptions,
     },
     output::{
         self,
@@ -34,7 +36,9 @@ impl TryFrom<RtpOutput> for pipeline::RegisterOutputOptions<output::OutputOption
             ));
         }
         let video_codec = video.as_ref().map(|v| match v.encoder {
-            VideoEncoderOptions::FfmpegH264 { .. } => pipeline::VideoCodec::H264,
+            VideoEncoderOptions::FfmpegH264 { .. } | VideoEncoderOptions::VaapiH264 { .. } => {
+                pipeline::VideoCodec::H264
+            }
         });
         let audio_codec = audio.as_ref().map(|a| match a.encoder {
             RtpAudioEncoderOptions::Opus { .. } => pipeline::AudioCodec::Opus,
@@ -122,11 +126,13 @@ impl TryFrom<Mp4Output> for pipeline::RegisterOutputOptions<output::OutputOption
         }
 
         let mp4_video = video.as_ref().map(|v| match v.encoder {
-            VideoEncoderOptions::FfmpegH264 { .. } => Mp4VideoTrack {
-                codec: pipeline::VideoCodec::H264,
-                width: v.resolution.width as u32,
-                height: v.resolution.height as u32,
-            },
+            VideoEncoderOptions::FfmpegH264 { .. } | VideoEncoderOptions::VaapiH264 { .. } => {
+                Mp4VideoTrack {
+                    codec: pipeline::VideoCodec::H264,
+                    width: v.resolution.width as u32,
+                    height: v.resolution.height as u32,
+                }
+            }
         });
         let mp4_audio = audio.as_ref().map(|a| match &a.encoder {
             Mp4AudioEncoderOptions::Aac { channels } => Mp4AudioTrack {
@@ -189,17 +195,75 @@ fn maybe_video_options(
     let encoder_options = match options.encoder {
         VideoEncoderOptions::FfmpegH264 {
             preset,
+            rate_control,
+            gop_size,
+            keyframe_interval_ms,
+            b_frames,
+            profile,
+            level,
+            tune,
             ffmpeg_options,
-        } => pipeline::encoder::VideoEncoderOptions::H264(ffmpeg_h264::Options {
-            preset: preset.unwrap_or(H264EncoderPreset::Fast).into(),
-            resolution: options.resolution.into(),
-            raw_options: ffmpeg_options.unwrap_or_default().into_iter().collect(),
-        }),
+        } => {
+            validate_gop_options(gop_size, b_frames, profile.as_ref())?;
+            let keyframe_interval = parse_keyframe_interval(keyframe_interval_ms)?;
+
+            pipeline::encoder::VideoEncoderOptions::H264(ffmpeg_h264::Options {
+                preset: preset.unwrap_or(H264EncoderPreset::Fast).into(),
+                resolution: options.resolution.into(),
+                rate_control: rate_control
+                    .map(TryInto::try_into)
+                    .transpose()?
+                    .unwrap_or_default(),
+                gop_size,
+                keyframe_interval,
+                b_frames,
+                profile: profile.map(Into::into),
+                level: level.as_deref().map(parse_h264_level).transpose()?,
+                tune: tune.map(Into::into),
+                raw_options: ffmpeg_options.unwrap_or_default().into_iter().collect(),
+            })
+        }
+        VideoEncoderOptions::VaapiH264 {
+            device,
+            software_fallback,
+            rate_control,
+            gop_size,
+            keyframe_interval_ms,
+            b_frames,
+            profile,
+            level,
+            ffmpeg_options,
+        } => {
+            if options.captions_input_id.is_some() {
+                return Err(TypeError::new(
+                    "Captions passthrough (`captions_input_id`) is not supported by the \"vaapi_h264\" encoder.",
+                ));
+            }
+            validate_gop_options(gop_size, b_frames, profile.as_ref())?;
+            let keyframe_interval = parse_keyframe_interval(keyframe_interval_ms)?;
+
+            pipeline::encoder::VideoEncoderOptions::VaapiH264(vaapi_h264::Options {
+                resolution: options.resolution.into(),
+                device: device.map(|device| PathBuf::from(device.as_ref())),
+                rate_control: rate_control
+                    .map(TryInto::try_into)
+                    .transpose()?
+                    .unwrap_or_default(),
+                gop_size,
+                keyframe_interval,
+                b_frames,
+                profile: profile.map(Into::into),
+                level: level.as_deref().map(parse_h264_level).transpose()?,
+                software_fallback: software_fallback.unwrap_or(true),
+                raw_options: ffmpeg_options.unwrap_or_default().into_iter().collect(),
+            })
+        }
     };
 
     let output_options = pipeline::OutputVideoOptions {
         initial: options.initial.try_into()?,
         end_condition: options.send_eos_when.unwrap_or_default().try_into()?,
+        captions_source: options.captions_input_id.map(Into::into),
     };
 
     Ok((Some(encoder_options), Some(output_options)))
@@ -293,6 +357,206 @@ impl From<H264EncoderPreset> for encoder::ffmpeg_h264::EncoderPreset {
     }
 }
 
+impl TryFrom<UpdateEncoderRequest> for pipeline::encoder::VideoEncoderUpdate {
+    type Error = TypeError;
+
+    fn try_from(request: UpdateEncoderRequest) -> Result<Self, Self::Error> {
+        let UpdateEncoderRequest {
+            resolution,
+            rate_control,
+        } = request;
+
+        if resolution.is_none() && rate_control.is_none() {
+            return Err(TypeError::new(
+                "At least one of \"resolution\" and \"rate_control\" fields have to be specified.",
+            ));
+        }
+
+        Ok(pipeline::encoder::VideoEncoderUpdate::H264(
+            ffmpeg_h264::EncoderUpdate {
+                resolution: resolution.map(Into::into),
+                rate_control: rate_control.map(TryInto::try_into).transpose()?,
+            },
+        ))
+    }
+}
+
+impl TryFrom<H264RateControl> for ffmpeg_h264::RateControl {
+    type Error = TypeError;
+
+    fn try_from(value: H264RateControl) -> Result<Self, Self::Error> {
+        const ZERO_BITRATE_MSG: &str = "\"bitrate\" has to be a positive number.";
+        const ZERO_BUFFER_SIZE_MSG: &str = "\"buffer_size\" has to be a positive number.";
+        const MAX_BITRATE_WITHOUT_BUFFER_MSG: &str =
+            "\"max_bitrate\" can only be used together with \"buffer_size\".";
+
+        let rate_control = match value {
+            H264RateControl::Crf {
+                crf,
+                max_bitrate,
+                buffer_size,
+            } => {
+                let crf = crf.unwrap_or(23);
+                if crf > 51 {
+                    return Err(TypeError::new(
+                        "\"crf\" has to be a number between 0 and 51.",
+                    ));
+                }
+                if max_bitrate == Some(0) {
+                    return Err(TypeError::new(
+                        "\"max_bitrate\" has to be a positive number.",
+                    ));
+                }
+                if max_bitrate.is_some() && buffer_size.is_none() {
+                    return Err(TypeError::new(MAX_BITRATE_WITHOUT_BUFFER_MSG));
+                }
+                ffmpeg_h264::RateControl::Crf {
+                    crf,
+                    max_bitrate,
+                    buffer_size,
+                }
+            }
+            H264RateControl::Vbr {
+                bitrate,
+                max_bitrate,
+                buffer_size,
+            } => {
+                if bitrate == 0 {
+                    return Err(TypeError::new(ZERO_BITRATE_MSG));
+                }
+                if max_bitrate.is_some_and(|max_bitrate| max_bitrate < bitrate) {
+                    return Err(TypeError::new(
+                        "\"max_bitrate\" can not be lower than \"bitrate\".",
+                    ));
+                }
+                if max_bitrate.is_some() && buffer_size.is_none() {
+                    return Err(TypeError::new(MAX_BITRATE_WITHOUT_BUFFER_MSG));
+                }
+                ffmpeg_h264::RateControl::Vbr {
+                    bitrate,
+                    max_bitrate,
+                    buffer_size,
+                }
+            }
+            H264RateControl::Cbr {
+                bitrate,
+                buffer_size,
+            } => {
+                if bitrate == 0 {
+                    return Err(TypeError::new(ZERO_BITRATE_MSG));
+                }
+                ffmpeg_h264::RateControl::Cbr {
+                    bitrate,
+                    buffer_size,
+                }
+            }
+        };
+
+        let buffer_size = match rate_control {
+            ffmpeg_h264::RateControl::Crf { buffer_size, .. }
+            | ffmpeg_h264::RateControl::Vbr { buffer_size, .. }
+            | ffmpeg_h264::RateControl::Cbr { buffer_size, .. } => buffer_size,
+        };
+        if buffer_size == Some(0) {
+            return Err(TypeError::new(ZERO_BUFFER_SIZE_MSG));
+        }
+
+        Ok(rate_control)
+    }
+}
+
+impl From<H264Profile> for ffmpeg_h264::EncoderProfile {
+    fn from(value: H264Profile) -> Self {
+        match value {
+            H264Profile::Baseline => ffmpeg_h264::EncoderProfile::Baseline,
+            H264Profile::Main => ffmpeg_h264::EncoderProfile::Main,
+            H264Profile::High => ffmpeg_h264::EncoderProfile::High,
+        }
+    }
+}
+
+impl From<H264EncoderTune> for ffmpeg_h264::EncoderTune {
+    fn from(value: H264EncoderTune) -> Self {
+        match value {
+            H264EncoderTune::Film => ffmpeg_h264::EncoderTune::Film,
+            H264EncoderTune::Animation => ffmpeg_h264::EncoderTune::Animation,
+            H264EncoderTune::Grain => ffmpeg_h264::EncoderTune::Grain,
+            H264EncoderTune::StillImage => ffmpeg_h264::EncoderTune::StillImage,
+            H264EncoderTune::FastDecode => ffmpeg_h264::EncoderTune::FastDecode,
+            H264EncoderTune::ZeroLatency => ffmpeg_h264::EncoderTune::ZeroLatency,
+        }
+    }
+}
+
+fn validate_gop_options(
+    gop_size: Option<u32>,
+    b_frames: Option<u32>,
+    profile: Option<&H264Profile>,
+) -> Result<(), TypeError> {
+    if gop_size == Some(0) {
+        return Err(TypeError::new("\"gop_size\" has to be a positive number."));
+    }
+    if let Some(b_frames) = b_frames {
+        if b_frames > 16 {
+            return Err(TypeError::new(
+                "\"b_frames\" has to be a number between 0 and 16.",
+            ));
+        }
+        if b_frames > 0 && matches!(profile, Some(H264Profile::Baseline)) {
+            return Err(TypeError::new(
+                "B-frames are not supported by the \"baseline\" profile.",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn parse_keyframe_interval(
+    keyframe_interval_ms: Option<f64>,
+) -> Result<Option<Duration>, TypeError> {
+    match keyframe_interval_ms {
+        Some(interval_ms) if interval_ms.is_finite() && interval_ms > 0.0 => {
+            Ok(Some(Duration::from_secs_f64(interval_ms / 1000.0)))
+        }
+        Some(_) => Err(TypeError::new(
+            "\"keyframe_interval_ms\" has to be a positive number.",
+        )),
+        None => Ok(None),
+    }
+}
+
+fn parse_h264_level(level: &str) -> Result<ffmpeg_h264::EncoderLevel, TypeError> {
+    use ffmpeg_h264::EncoderLevel;
+    let level = match level {
+        "1" | "1.0" => EncoderLevel::Level1,
+        "1b" => EncoderLevel::Level1b,
+        "1.1" => EncoderLevel::Level1_1,
+        "1.2" => EncoderLevel::Level1_2,
+        "1.3" => EncoderLevel::Level1_3,
+        "2" | "2.0" => EncoderLevel::Level2,
+        "2.1" => EncoderLevel::Level2_1,
+        "2.2" => EncoderLevel::Level2_2,
+        "3" | "3.0" => EncoderLevel::Level3,
+        "3.1" => EncoderLevel::Level3_1,
+        "3.2" => EncoderLevel::Level3_2,
+        "4" | "4.0" => EncoderLevel::Level4,
+        "4.1" => EncoderLevel::Level4_1,
+        "4.2" => EncoderLevel::Level4_2,
+        "5" | "5.0" => EncoderLevel::Level5,
+        "5.1" => EncoderLevel::Level5_1,
+        "5.2" => EncoderLevel::Level5_2,
+        "6" | "6.0" => EncoderLevel::Level6,
+        "6.1" => EncoderLevel::Level6_1,
+        "6.2" => EncoderLevel::Level6_2,
+        level => {
+            return Err(TypeError::new(format!(
+                "Invalid H264 level \"{level}\". Supported values are: 1, 1b, 1.1, 1.2, 1.3, 2, 2.1, 2.2, 3, 3.1, 3.2, 4, 4.1, 4.2, 5, 5.1, 5.2, 6, 6.1, 6.2."
+            )))
+        }
+    };
+    Ok(level)
+}
+
 impl From<OpusEncoderPreset> for encoder::AudioEncoderPreset {
     fn from(value: OpusEncoderPreset) -> Self {
         match value {
@@ -0,0 +1,74 @@
+use compositor_pipeline::pipeline::encoder::ffmpeg_h264;
+
+use crate::types::{register_output::H264RateControl, TypeError};
+
+#[test]
+fn test_rate_control_conversion() {
+    fn test_case(rate_control: H264RateControl, expected: Result<ffmpeg_h264::RateControl, &str>) {
+        assert_eq!(
+            ffmpeg_h264::RateControl::try_from(rate_control),
+            expected.map_err(TypeError::new)
+        );
+    }
+
+    test_case(
+        H264RateControl::Crf {
+            crf: None,
+            max_bitrate: None,
+            buffer_size: None,
+        },
+        Ok(ffmpeg_h264::RateControl::Crf {
+            crf: 23,
+            max_bitrate: None,
+            buffer_size: None,
+        }),
+    );
+    test_case(
+        H264RateControl::Crf {
+            crf: Some(52),
+            max_bitrate: None,
+            buffer_size: None,
+        },
+        Err("\"crf\" has to be a number between 0 and 51."),
+    );
+    test_case(
+        H264RateControl::Crf {
+            crf: None,
+            max_bitrate: Some(1_000_000),
+            buffer_size: None,
+        },
+        Err("\"max_bitrate\" can only be used together with \"buffer_size\"."),
+    );
+    test_case(
+        H264RateControl::Vbr {
+            bitrate: 2_000_000,
+            max_bitrate: Some(1_000_000),
+            buffer_size: Some(2_000_000),
+        },
+        Err("\"max_bitrate\" can not be lower than \"bitrate\"."),
+    );
+    test_case(
+        H264RateControl::Cbr {
+            bitrate: 0,
+            buffer_size: None,
+        },
+        Err("\"bitrate\" has to be a positive number."),
+    );
+    test_case(
+        H264RateControl::Cbr {
+            bitrate: 1_000_000,
+            buffer_size: Some(0),
+        },
+        Err("\"buffer_size\" has to be a positive number."),
+    );
+    test_case(
+        H264RateControl::Cbr {
+            bitrate: 1_000_000,
+            buffer_size: None,
+        },
+        Ok(ffmpeg_h264::RateControl::Cbr {
+            bitrate: 1_000_000,
+            buffer_size: None,
+        }),
+    );
+}
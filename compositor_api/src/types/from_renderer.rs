@@ -1,5 +1,9 @@
+use base64::Engine;
+use compositor_render::asset::AssetSource;
 use compositor_render::image;
+use compositor_render::lut;
 use compositor_render::shader;
+use compositor_render::subtitles;
 use compositor_render::web_renderer;
 
 use super::renderer::*;
@@ -49,13 +53,13 @@ impl TryFrom<ImageSpec> for compositor_render::RendererSpec {
         fn from_url_or_path(
             url: Option<String>,
             path: Option<String>,
-        ) -> Result<image::ImageSource, TypeError> {
+        ) -> Result<AssetSource, TypeError> {
             match (url, path) {
                 (None, None) => Err(TypeError::new(
                     "\"url\" or \"path\" field is required when registering an image.",
                 )),
-                (None, Some(path)) => Ok(image::ImageSource::LocalPath { path }),
-                (Some(url), None) => Ok(image::ImageSource::Url { url }),
+                (None, Some(path)) => Ok(AssetSource::LocalPath { path }),
+                (Some(url), None) => Ok(AssetSource::Url { url }),
                 (Some(_), Some(_)) => Err(TypeError::new(
                     "\"url\" and \"path\" fields are mutually exclusive when registering an image.",
                 )),
@@ -88,3 +92,67 @@ impl TryFrom<ImageSpec> for compositor_render::RendererSpec {
         Ok(Self::Image(image))
     }
 }
+
+impl TryFrom<LutSpec> for compositor_render::RendererSpec {
+    type Error = TypeError;
+
+    fn try_from(spec: LutSpec) -> Result<Self, Self::Error> {
+        let src =
+            match (spec.url, spec.path) {
+                (None, None) => {
+                    return Err(TypeError::new(
+                        "\"url\" or \"path\" field is required when registering a LUT.",
+                    ))
+                }
+                (None, Some(path)) => AssetSource::LocalPath { path },
+                (Some(url), None) => AssetSource::Url { url },
+                (Some(_), Some(_)) => return Err(TypeError::new(
+                    "\"url\" and \"path\" fields are mutually exclusive when registering a LUT.",
+                )),
+            };
+        Ok(Self::Lut(lut::LutSpec { src }))
+    }
+}
+
+impl TryFrom<SubtitlesSpec> for compositor_render::RendererSpec {
+    type Error = TypeError;
+
+    fn try_from(spec: SubtitlesSpec) -> Result<Self, Self::Error> {
+        let src = match (spec.url, spec.path) {
+            (None, None) => None,
+            (None, Some(path)) => Some(AssetSource::LocalPath { path }),
+            (Some(url), None) => Some(AssetSource::Url { url }),
+            (Some(_), Some(_)) => return Err(TypeError::new(
+                "\"url\" and \"path\" fields are mutually exclusive when registering subtitles.",
+            )),
+        };
+        Ok(Self::Subtitles(subtitles::SubtitlesSpec { src }))
+    }
+}
+
+impl TryFrom<FontSpec> for AssetSource {
+    type Error = TypeError;
+
+    fn try_from(spec: FontSpec) -> Result<Self, Self::Error> {
+        match (spec.url, spec.path, spec.base64) {
+            (Some(url), None, None) => Ok(Self::Url { url }),
+            (None, Some(path), None) => Ok(Self::LocalPath { path }),
+            (None, None, Some(base64)) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(base64)
+                    .map_err(|err| {
+                        TypeError::new(format!("Invalid base64 content of a font file: {err}"))
+                    })?;
+                Ok(Self::Bytes {
+                    bytes: bytes.into(),
+                })
+            }
+            (None, None, None) => Err(TypeError::new(
+                "\"url\", \"path\" or \"base64\" field is required when registering a font.",
+            )),
+            _ => Err(TypeError::new(
+                "\"url\", \"path\" and \"base64\" fields are mutually exclusive when registering a font.",
+            )),
+        }
+    }
+}
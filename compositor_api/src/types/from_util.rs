@@ -26,8 +26,23 @@ impl TryFrom<Transition> for scene::Transition {
     type Error = TypeError;
 
     fn try_from(transition: Transition) -> Result<Self, Self::Error> {
-        let interpolation_kind = match transition.easing_function.unwrap_or(EasingFunction::Linear)
-        {
+        let interpolation_kind = transition
+            .easing_function
+            .unwrap_or(EasingFunction::Linear)
+            .try_into()?;
+
+        Ok(Self {
+            duration: Duration::from_secs_f64(transition.duration_ms / 1000.0),
+            interpolation_kind,
+        })
+    }
+}
+
+impl TryFrom<EasingFunction> for scene::InterpolationKind {
+    type Error = TypeError;
+
+    fn try_from(easing_function: EasingFunction) -> Result<Self, Self::Error> {
+        let interpolation_kind = match easing_function {
             EasingFunction::Linear => scene::InterpolationKind::Linear,
             EasingFunction::Bounce => scene::InterpolationKind::Bounce,
             EasingFunction::CubicBezier { points } => {
@@ -49,11 +64,242 @@ impl TryFrom<Transition> for scene::Transition {
                     y2: points[3],
                 }
             }
+            EasingFunction::Spring {
+                stiffness,
+                damping,
+                mass,
+                initial_velocity,
+            } => {
+                let stiffness = stiffness.unwrap_or(100.0);
+                let damping = damping.unwrap_or(10.0);
+                let mass = mass.unwrap_or(1.0);
+                let initial_velocity = initial_velocity.unwrap_or(0.0);
+                if stiffness <= 0.0 {
+                    return Err(TypeError::new("Spring stiffness has to be larger than 0."));
+                }
+                if damping < 0.0 {
+                    return Err(TypeError::new(
+                        "Spring damping has to be larger or equal to 0.",
+                    ));
+                }
+                if mass <= 0.0 {
+                    return Err(TypeError::new("Spring mass has to be larger than 0."));
+                }
+                if !initial_velocity.is_finite() {
+                    return Err(TypeError::new("Spring initial velocity has to be finite."));
+                }
+
+                scene::InterpolationKind::Spring {
+                    stiffness,
+                    damping,
+                    mass,
+                    initial_velocity,
+                }
+            }
         };
+        Ok(interpolation_kind)
+    }
+}
+
+impl TryFrom<KeyframeAnimation> for scene::KeyframeAnimation {
+    type Error = TypeError;
+
+    fn try_from(animation: KeyframeAnimation) -> Result<Self, Self::Error> {
+        let property = match animation.property {
+            AnimatedProperty::Top => scene::AnimatedProperty::Top,
+            AnimatedProperty::Left => scene::AnimatedProperty::Left,
+            AnimatedProperty::Bottom => scene::AnimatedProperty::Bottom,
+            AnimatedProperty::Right => scene::AnimatedProperty::Right,
+            AnimatedProperty::Width => scene::AnimatedProperty::Width,
+            AnimatedProperty::Height => scene::AnimatedProperty::Height,
+            AnimatedProperty::Rotation => scene::AnimatedProperty::Rotation,
+            AnimatedProperty::Opacity => scene::AnimatedProperty::Opacity,
+            AnimatedProperty::BorderWidth => scene::AnimatedProperty::BorderWidth,
+            AnimatedProperty::BorderRadius => scene::AnimatedProperty::BorderRadius,
+            AnimatedProperty::BorderColorRgba => scene::AnimatedProperty::BorderColor,
+            AnimatedProperty::BackgroundColorRgba => scene::AnimatedProperty::BackgroundColor,
+            AnimatedProperty::CropTop => scene::AnimatedProperty::CropTop,
+            AnimatedProperty::CropLeft => scene::AnimatedProperty::CropLeft,
+            AnimatedProperty::CropWidth => scene::AnimatedProperty::CropWidth,
+            AnimatedProperty::CropHeight => scene::AnimatedProperty::CropHeight,
+            AnimatedProperty::KeyColorRgba => scene::AnimatedProperty::KeyColor,
+            AnimatedProperty::Similarity => scene::AnimatedProperty::Similarity,
+            AnimatedProperty::Smoothness => scene::AnimatedProperty::Smoothness,
+            AnimatedProperty::Spill => scene::AnimatedProperty::Spill,
+            AnimatedProperty::Brightness => scene::AnimatedProperty::Brightness,
+            AnimatedProperty::Contrast => scene::AnimatedProperty::Contrast,
+            AnimatedProperty::Saturation => scene::AnimatedProperty::Saturation,
+            AnimatedProperty::Gamma => scene::AnimatedProperty::Gamma,
+            AnimatedProperty::Temperature => scene::AnimatedProperty::Temperature,
+            AnimatedProperty::Tint => scene::AnimatedProperty::Tint,
+            AnimatedProperty::LutStrength => scene::AnimatedProperty::LutStrength,
+            AnimatedProperty::BlurRadius => scene::AnimatedProperty::BlurRadius,
+            AnimatedProperty::BackdropBlur => scene::AnimatedProperty::BackdropBlur,
+        };
+        let is_color_property = matches!(
+            property,
+            scene::AnimatedProperty::BorderColor
+                | scene::AnimatedProperty::BackgroundColor
+                | scene::AnimatedProperty::KeyColor
+        );
+
+        if animation.keyframes.is_empty() {
+            return Err(TypeError::new(
+                "Animation has to define at least one keyframe.",
+            ));
+        }
+        if animation.duration_ms <= 0.0 {
+            return Err(TypeError::new(
+                "Animation duration has to be a positive number.",
+            ));
+        }
+        let keyframes = animation
+            .keyframes
+            .into_iter()
+            .map(|keyframe| {
+                if !(0.0..=1.0).contains(&keyframe.offset) {
+                    return Err(TypeError::new(
+                        "Keyframe offset has to be in the range [0, 1].",
+                    ));
+                }
+                let value = match (keyframe.value, is_color_property) {
+                    (KeyframeValue::Number(value), false) => scene::KeyframeValue::Number(value),
+                    (KeyframeValue::Color(color), true) => {
+                        scene::KeyframeValue::Color(color.try_into()?)
+                    }
+                    (_, true) => {
+                        return Err(TypeError::new(
+                            "Keyframe value of a color property has to be a color in a \"#RRGGBBAA\" format.",
+                        ))
+                    }
+                    (_, false) => {
+                        return Err(TypeError::new(
+                            "Keyframe value of a numeric property has to be a number.",
+                        ))
+                    }
+                };
+                Ok(scene::Keyframe {
+                    offset: keyframe.offset,
+                    value,
+                    interpolation_kind: keyframe
+                        .easing_function
+                        .unwrap_or(EasingFunction::Linear)
+                        .try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, TypeError>>()?;
+        if keyframes
+            .windows(2)
+            .any(|pair| pair[0].offset > pair[1].offset)
+        {
+            return Err(TypeError::new("Keyframes have to be sorted by offset."));
+        }
+
+        let iterations = match animation.iterations {
+            None => Some(1),
+            Some(AnimationIterations::Count(0)) => {
+                return Err(TypeError::new(
+                    "Animation has to have at least one iteration.",
+                ))
+            }
+            Some(AnimationIterations::Count(count)) => Some(count),
+            Some(AnimationIterations::String(value)) if value == "infinite" => None,
+            Some(AnimationIterations::String(_)) => {
+                return Err(TypeError::new(
+                    "Animation iterations have to be a positive integer or \"infinite\".",
+                ))
+            }
+        };
+
+        let duration = Duration::try_from_secs_f64(animation.duration_ms / 1000.0).map_err(|_| {
+            TypeError::new(format!(
+                "Animation \"duration_ms\" has to be a positive number that fits in a duration, received {}.",
+                animation.duration_ms
+            ))
+        })?;
+        let delay_ms = animation.delay_ms.unwrap_or(0.0).max(0.0);
+        let delay = Duration::try_from_secs_f64(delay_ms / 1000.0).map_err(|_| {
+            TypeError::new(format!(
+                "Animation \"delay_ms\" has to fit in a duration, received {delay_ms}."
+            ))
+        })?;
 
         Ok(Self {
-            duration: Duration::from_secs_f64(transition.duration_ms / 1000.0),
-            interpolation_kind,
+            property,
+            keyframes,
+            duration,
+            delay,
+            iterations,
+            direction: match animation.direction.unwrap_or(AnimationDirection::Normal) {
+                AnimationDirection::Normal => scene::AnimationDirection::Normal,
+                AnimationDirection::Reverse => scene::AnimationDirection::Reverse,
+                AnimationDirection::Alternate => scene::AnimationDirection::Alternate,
+                AnimationDirection::AlternateReverse => scene::AnimationDirection::AlternateReverse,
+            },
+        })
+    }
+}
+
+impl AnimatedProperty {
+    /// Name of the property in the API.
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            AnimatedProperty::Top => "top",
+            AnimatedProperty::Left => "left",
+            AnimatedProperty::Bottom => "bottom",
+            AnimatedProperty::Right => "right",
+            AnimatedProperty::Width => "width",
+            AnimatedProperty::Height => "height",
+            AnimatedProperty::Rotation => "rotation",
+            AnimatedProperty::Opacity => "opacity",
+            AnimatedProperty::BorderWidth => "border_width",
+            AnimatedProperty::BorderRadius => "border_radius",
+            AnimatedProperty::BorderColorRgba => "border_color_rgba",
+            AnimatedProperty::BackgroundColorRgba => "background_color_rgba",
+            AnimatedProperty::CropTop => "crop_top",
+            AnimatedProperty::CropLeft => "crop_left",
+            AnimatedProperty::CropWidth => "crop_width",
+            AnimatedProperty::CropHeight => "crop_height",
+            AnimatedProperty::KeyColorRgba => "key_color_rgba",
+            AnimatedProperty::Similarity => "similarity",
+            AnimatedProperty::Smoothness => "smoothness",
+            AnimatedProperty::Spill => "spill",
+            AnimatedProperty::Brightness => "brightness",
+            AnimatedProperty::Contrast => "contrast",
+            AnimatedProperty::Saturation => "saturation",
+            AnimatedProperty::Gamma => "gamma",
+            AnimatedProperty::Temperature => "temperature",
+            AnimatedProperty::Tint => "tint",
+            AnimatedProperty::LutStrength => "lut_strength",
+            AnimatedProperty::BlurRadius => "blur_radius",
+            AnimatedProperty::BackdropBlur => "backdrop_blur",
+        }
+    }
+}
+
+impl TryFrom<EnterExitTransition> for scene::EnterExitTransition {
+    type Error = TypeError;
+
+    fn try_from(transition: EnterExitTransition) -> Result<Self, Self::Error> {
+        let effect = match transition.effect {
+            EnterExitEffect::Fade => scene::EnterExitEffect::Fade,
+            EnterExitEffect::Slide { from } => scene::EnterExitEffect::Slide {
+                from: match from {
+                    SlideEdge::Top => scene::SlideEdge::Top,
+                    SlideEdge::Bottom => scene::SlideEdge::Bottom,
+                    SlideEdge::Left => scene::SlideEdge::Left,
+                    SlideEdge::Right => scene::SlideEdge::Right,
+                },
+            },
+            EnterExitEffect::Scale => scene::EnterExitEffect::Scale,
+        };
+        let transition = Transition {
+            duration_ms: transition.duration_ms,
+            easing_function: transition.easing_function,
+        };
+        Ok(Self {
+            effect,
+            transition: transition.try_into()?,
         })
     }
 }
@@ -80,6 +326,19 @@ impl From<VerticalAlign> for scene::VerticalAlign {
     }
 }
 
+impl From<BlendMode> for scene::BlendMode {
+    fn from(blend_mode: BlendMode) -> Self {
+        match blend_mode {
+            BlendMode::Normal => scene::BlendMode::Normal,
+            BlendMode::Multiply => scene::BlendMode::Multiply,
+            BlendMode::Screen => scene::BlendMode::Screen,
+            BlendMode::Overlay => scene::BlendMode::Overlay,
+            BlendMode::Add => scene::BlendMode::Add,
+            BlendMode::Difference => scene::BlendMode::Difference,
+        }
+    }
+}
+
 impl From<Degree> for scene::Degree {
     fn from(value: Degree) -> Self {
         Self(value.0)
@@ -127,6 +386,28 @@ impl TryFrom<AspectRatio> for (u32, u32) {
     }
 }
 
+impl TryFrom<GridTrack> for scene::GridTrack {
+    type Error = TypeError;
+
+    fn try_from(track: GridTrack) -> Result<Self, Self::Error> {
+        const ERROR_MESSAGE: &str = "Grid track needs to be a string in the \"<N>px\", \"<N>fr\" or \"auto\" format, where N is a non-negative number.";
+        let parse_number = |value: &str| match value.trim().parse::<f32>() {
+            Ok(value) if value >= 0.0 => Ok(value),
+            _ => Err(TypeError::new(ERROR_MESSAGE)),
+        };
+        let track = track.0.trim();
+        if track == "auto" {
+            Ok(scene::GridTrack::Auto)
+        } else if let Some(pixels) = track.strip_suffix("px") {
+            Ok(scene::GridTrack::Pixels(parse_number(pixels)?))
+        } else if let Some(fraction) = track.strip_suffix("fr") {
+            Ok(scene::GridTrack::Fraction(parse_number(fraction)?))
+        } else {
+            Err(TypeError::new(ERROR_MESSAGE))
+        }
+    }
+}
+
 impl TryFrom<RGBColor> for scene::RGBColor {
     type Error = TypeError;
 
@@ -144,4 +144,9 @@ pub enum VideoDecoder {
     /// to be compiled with the `vk-video` feature enabled (enabled by default on platforms which
     /// support Vulkan, i.e. non-Apple operating systems and not the web).
     VulkanVideo,
+
+    /// Use the first decoder that can be initialized. Hardware decoders are tried first
+    /// (`vulkan_video` if the program was compiled with the `vk-video` feature), then the
+    /// software decoder based on ffmpeg.
+    Auto,
 }
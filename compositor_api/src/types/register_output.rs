@@ -49,6 +49,8 @@ pub struct OutputVideoOptions {
     pub encoder: VideoEncoderOptions,
     /// Root of a component tree/scene that should be rendered for the output. Use [`update_output` request](../routes.md#update-output) to update this value after registration. [Learn more](../../concept/component.md).
     pub initial: Video,
+    /// ID of an input from which closed captions (CEA-608/708 carried in H.264 SEI messages) should be passed to this output. Captions are re-inserted into the output stream with timestamps of the output frames. Supported only by the `ffmpeg_h264` encoder.
+    pub captions_input_id: Option<InputId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -85,11 +87,130 @@ pub enum VideoEncoderOptions {
         /// (**default=`"fast"`**) Preset for an encoder. See `FFmpeg` [docs](https://trac.ffmpeg.org/wiki/Encode/H.264#Preset) to learn more.
         preset: Option<H264EncoderPreset>,
 
+        /// (**default=`{ "type": "crf", "crf": 23 }`**) Rate control mode of the encoder.
+        rate_control: Option<H264RateControl>,
+
+        /// Number of frames between keyframes (GOP length). When specified, scene-cut detection
+        /// is disabled and keyframes are produced at a fixed interval.
+        gop_size: Option<u32>,
+
+        /// Maximal interval between keyframes in milliseconds. Keyframes are forced based on the
+        /// output PTS, so this value does not depend on the framerate.
+        keyframe_interval_ms: Option<f64>,
+
+        /// Maximal number of consecutive B-frames. Use `0` to disable B-frames.
+        b_frames: Option<u32>,
+
+        /// H264 profile. If not specified, the encoder picks the profile based on other options.
+        profile: Option<H264Profile>,
+
+        /// H264 level, e.g. `"3.1"`, `"4.1"` or `"5.2"`.
+        level: Option<Arc<str>>,
+
+        /// Tune encoder settings for a specific type of content or use case.
+        tune: Option<H264EncoderTune>,
+
         /// Raw FFmpeg encoder options. See [docs](https://ffmpeg.org/ffmpeg-codecs.html) for more.
+        /// Those options take precedence over values derived from other fields.
+        ffmpeg_options: Option<HashMap<String, String>>,
+    },
+    /// Hardware accelerated H264 encoder that uses VA-API (Linux only).
+    ///
+    /// Only encoding runs on the hardware. Rendered frames are still read back to the CPU
+    /// and uploaded to VA-API surfaces, the same way as for the `ffmpeg_h264` encoder.
+    #[serde(rename = "vaapi_h264")]
+    VaapiH264 {
+        /// Path to a DRM render node, e.g. `"/dev/dri/renderD128"`. If not specified, all render
+        /// nodes are checked and the first one that supports H264 encoding is used.
+        device: Option<Arc<str>>,
+
+        /// (**default=`true`**) Use the `ffmpeg_h264` encoder if VA-API encoding is not available.
+        software_fallback: Option<bool>,
+
+        /// (**default=`{ "type": "crf", "crf": 23 }`**) Rate control mode of the encoder. The
+        /// `crf` mode is mapped to a constant QP mode.
+        rate_control: Option<H264RateControl>,
+
+        /// Number of frames between keyframes (GOP length).
+        gop_size: Option<u32>,
+
+        /// Maximal interval between keyframes in milliseconds.
+        keyframe_interval_ms: Option<f64>,
+
+        /// Maximal number of consecutive B-frames. Use `0` to disable B-frames.
+        b_frames: Option<u32>,
+
+        /// H264 profile. `baseline` is mapped to the constrained baseline profile.
+        profile: Option<H264Profile>,
+
+        /// H264 level, e.g. `"3.1"`, `"4.1"` or `"5.2"`.
+        level: Option<Arc<str>>,
+
+        /// Raw FFmpeg options of the `h264_vaapi` encoder. They are not used by the software fallback.
         ffmpeg_options: Option<HashMap<String, String>>,
     },
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateEncoderRequest {
+    /// New output resolution. Scene is re-rendered in the new resolution and the encoder
+    /// is restarted, so the stream continues from a keyframe.
+    pub resolution: Option<Resolution>,
+    /// New rate control settings of the video encoder. Bitrate is changed without restarting
+    /// the encoder if the rate control mode stays the same.
+    pub rate_control: Option<H264RateControl>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum H264RateControl {
+    /// Quality-based variable bitrate.
+    Crf {
+        /// (**default=`23`**) Constant rate factor (0-51). Lower values mean better quality.
+        crf: Option<u8>,
+        /// Upper limit of the bitrate in bits per second. Requires `buffer_size`.
+        max_bitrate: Option<u64>,
+        /// Size of the VBV buffer in bits.
+        buffer_size: Option<u64>,
+    },
+    /// Variable bitrate with a target average bitrate.
+    Vbr {
+        /// Average bitrate in bits per second.
+        bitrate: u64,
+        /// Upper limit of the bitrate in bits per second. Requires `buffer_size`.
+        max_bitrate: Option<u64>,
+        /// Size of the VBV buffer in bits.
+        buffer_size: Option<u64>,
+    },
+    /// Constant bitrate.
+    Cbr {
+        /// Bitrate in bits per second.
+        bitrate: u64,
+        /// (**default=`bitrate`**) Size of the VBV buffer in bits.
+        buffer_size: Option<u64>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum H264Profile {
+    Baseline,
+    Main,
+    High,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum H264EncoderTune {
+    Film,
+    Animation,
+    Grain,
+    StillImage,
+    FastDecode,
+    ZeroLatency,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum RtpAudioEncoderOptions {
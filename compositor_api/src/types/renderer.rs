@@ -58,3 +58,35 @@ pub enum ImageSpec {
         path: Option<String>,
     },
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LutSpec {
+    /// Url to download a `.cube` file. This field is mutually exclusive with the `path` field.
+    pub url: Option<String>,
+    /// Path to a `.cube` file. This field is mutually exclusive with the `url` field.
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubtitlesSpec {
+    /// Url to download a `.srt` or `.vtt` file. This field is mutually exclusive with
+    /// the `path` field.
+    pub url: Option<String>,
+    /// Path to a `.srt` or `.vtt` file. This field is mutually exclusive with the `url` field.
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FontSpec {
+    /// Url to download a font file (`.ttf`, `.otf` or `.ttc`). This field is mutually exclusive
+    /// with the `path` and `base64` fields.
+    pub url: Option<String>,
+    /// Path to a font file. This field is mutually exclusive with the `url` and `base64` fields.
+    pub path: Option<String>,
+    /// Base64 encoded content of a font file. This field is mutually exclusive with the `url`
+    /// and `path` fields.
+    pub base64: Option<String>,
+}
@@ -19,17 +19,176 @@ pub struct Transition {
     pub easing_function: Option<EasingFunction>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct EnterExitTransition {
+    /// Animation that is applied to the component.
+    pub effect: EnterExitEffect,
+    /// Duration of a transition in milliseconds.
+    pub duration_ms: f64,
+    /// (**default=`"linear"`**) Easing function to be used for the transition.
+    pub easing_function: Option<EasingFunction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EnterExitEffect {
+    /// Change opacity of the component.
+    Fade,
+    /// Move the component by its own width or height. On enter, the component moves in
+    /// from the specified edge, on exit it moves out towards it.
+    Slide { from: SlideEdge },
+    /// Scale the component around its center.
+    Scale,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlideEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Animation of a single numeric or color property of a component.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct KeyframeAnimation {
+    /// Animated property.
+    pub property: AnimatedProperty,
+    /// List of keyframes sorted by `offset`. At least one keyframe is required.
+    pub keyframes: Vec<Keyframe>,
+    /// Duration of a single iteration in milliseconds.
+    pub duration_ms: f64,
+    /// (**default=`0`**) Delay in milliseconds before the first iteration starts.
+    pub delay_ms: Option<f64>,
+    /// (**default=`1`**) Number of iterations. Use `"infinite"` to repeat the animation forever.
+    pub iterations: Option<AnimationIterations>,
+    /// (**default=`"normal"`**) Direction in which keyframes are played.
+    pub direction: Option<AnimationDirection>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct Keyframe {
+    /// Position of the keyframe within a single iteration, in the range `[0, 1]`.
+    pub offset: f64,
+    /// Value of the property. A number for numeric properties or a color in a `"#RRGGBBAA"`
+    /// format for color properties.
+    pub value: KeyframeValue,
+    /// (**default=`"linear"`**) Easing function used between this keyframe and the next one.
+    pub easing_function: Option<EasingFunction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum KeyframeValue {
+    Number(f32),
+    Color(RGBAColor),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum AnimationIterations {
+    Count(u32),
+    String(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimatedProperty {
+    /// Only supported for absolutely positioned components.
+    Top,
+    /// Only supported for absolutely positioned components.
+    Left,
+    /// Only supported for absolutely positioned components.
+    Bottom,
+    /// Only supported for absolutely positioned components.
+    Right,
+    Width,
+    Height,
+    /// Only supported for absolutely positioned components.
+    Rotation,
+    Opacity,
+    BorderWidth,
+    BorderRadius,
+    BorderColorRgba,
+    /// Only supported for the `View` component.
+    BackgroundColorRgba,
+    /// Only supported for the `Rescaler` component with `crop` defined.
+    CropTop,
+    /// Only supported for the `Rescaler` component with `crop` defined.
+    CropLeft,
+    /// Only supported for the `Rescaler` component with `crop` defined.
+    CropWidth,
+    /// Only supported for the `Rescaler` component with `crop` defined.
+    CropHeight,
+    /// Only supported for the `ChromaKey` component.
+    KeyColorRgba,
+    /// Only supported for the `ChromaKey` component.
+    Similarity,
+    /// Only supported for the `ChromaKey` component.
+    Smoothness,
+    /// Only supported for the `ChromaKey` component.
+    Spill,
+    /// Only supported for the `ColorCorrection` component.
+    Brightness,
+    /// Only supported for the `ColorCorrection` component.
+    Contrast,
+    /// Only supported for the `ColorCorrection` component.
+    Saturation,
+    /// Only supported for the `ColorCorrection` component.
+    Gamma,
+    /// Only supported for the `ColorCorrection` component.
+    Temperature,
+    /// Only supported for the `ColorCorrection` component.
+    Tint,
+    /// Only supported for the `ColorCorrection` component.
+    LutStrength,
+    /// Only supported for the `View` and `Rescaler` components.
+    BlurRadius,
+    /// Only supported for the `View` component.
+    BackdropBlur,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimationDirection {
+    /// Every iteration is played from the first to the last keyframe.
+    Normal,
+    /// Every iteration is played from the last to the first keyframe.
+    Reverse,
+    /// Direction changes every iteration, starting with `normal`.
+    Alternate,
+    /// Direction changes every iteration, starting with `reverse`.
+    AlternateReverse,
+}
+
 /// Easing functions are used to interpolate between two values over time.
 ///
 /// Custom easing functions can be implemented with cubic Bézier.
 /// The control points are defined with `points` field by providing four numerical values: `x1`, `y1`, `x2` and `y2`. The `x1` and `x2` values have to be in the range `[0; 1]`. The cubic Bézier result is clamped to the range `[0; 1]`.
 /// You can find example control point configurations [here](https://easings.net/).
+///
+/// Spring easing simulates a physical spring. If the spring does not settle before
+/// the end of the transition, the final value is applied at the end. When a transition
+/// that uses spring easing interrupts another transition, it preserves the current velocity.
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(tag = "function_name", rename_all = "snake_case")]
 pub enum EasingFunction {
     Linear,
     Bounce,
-    CubicBezier { points: [f64; 4] },
+    CubicBezier {
+        points: [f64; 4],
+    },
+    Spring {
+        /// (**default=`100`**) Stiffness of the spring. Has to be larger than 0.
+        stiffness: Option<f64>,
+        /// (**default=`10`**) Damping of the spring. Has to be larger or equal to 0.
+        damping: Option<f64>,
+        /// (**default=`1`**) Mass attached to the spring. Has to be larger than 0.
+        mass: Option<f64>,
+        /// (**default=`0`**) Initial velocity, in units of the transition distance per second.
+        initial_velocity: Option<f64>,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -50,9 +209,33 @@ pub enum VerticalAlign {
     Justified,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlendMode {
+    /// Component is drawn over the content below it.
+    Normal,
+    /// Colors are multiplied, the result is always darker.
+    Multiply,
+    /// Inverted colors are multiplied, the result is always lighter.
+    Screen,
+    /// `multiply` for dark parts of the content below and `screen` for the bright ones.
+    Overlay,
+    /// Colors are added.
+    Add,
+    /// Absolute difference between colors.
+    Difference,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 pub struct AspectRatio(pub(super) String);
 
+/// Size of a grid row or column in one of the formats:
+/// - `"<N>px"` - size in pixels, e.g. `"200px"`.
+/// - `"<N>fr"` - share of the space left after sizing all other tracks, e.g. `"1fr"`.
+/// - `"auto"` - size of the largest child placed only in this track (if its size is known).
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+pub struct GridTrack(pub(super) String);
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 pub struct Degree(pub f64);
 
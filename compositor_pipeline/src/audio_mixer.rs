@@ -3,9 +3,10 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use compositor_render::{error::UpdateSceneError, OutputId};
+use compositor_render::{error::UpdateSceneError, AudioLevels, OutputId};
 use tracing::trace;
 
+mod levels;
 mod mix;
 mod prepare_inputs;
 mod types;
@@ -13,6 +14,7 @@ mod types;
 pub use types::*;
 
 use self::{
+    levels::input_levels,
     mix::mix_samples,
     prepare_inputs::{expected_samples_count, prepare_input_samples},
 };
@@ -28,9 +30,11 @@ struct OutputInfo {
 pub(super) struct AudioMixer(Arc<Mutex<InternalAudioMixer>>);
 
 impl AudioMixer {
-    pub fn new(output_sample_rate: u32) -> Self {
+    /// Levels of the input samples are reported to `audio_levels`.
+    pub fn new(output_sample_rate: u32, audio_levels: AudioLevels) -> Self {
         Self(Arc::new(Mutex::new(InternalAudioMixer::new(
             output_sample_rate,
+            audio_levels,
         ))))
     }
 
@@ -73,13 +77,15 @@ impl AudioMixer {
 pub(super) struct InternalAudioMixer {
     outputs: HashMap<OutputId, OutputInfo>,
     output_sample_rate: u32,
+    audio_levels: AudioLevels,
 }
 
 impl InternalAudioMixer {
-    pub fn new(output_sample_rate: u32) -> Self {
+    pub fn new(output_sample_rate: u32, audio_levels: AudioLevels) -> Self {
         Self {
             outputs: HashMap::new(),
             output_sample_rate,
+            audio_levels,
         }
     }
 
@@ -99,12 +105,15 @@ impl InternalAudioMixer {
 
     pub fn mix_samples(&mut self, samples_set: InputSamplesSet) -> OutputSamplesSet {
         let start_pts = samples_set.start_pts;
+        let end_pts = samples_set.end_pts;
         let samples_count = expected_samples_count(
             samples_set.start_pts,
             samples_set.end_pts,
             self.output_sample_rate,
         );
         let input_samples = prepare_input_samples(samples_set, self.output_sample_rate);
+        self.audio_levels
+            .push(start_pts, end_pts, input_levels(&input_samples));
 
         OutputSamplesSet(
             self.outputs
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use compositor_render::{AudioLevel, InputId};
+
+/// Levels of the input samples, calculated before the volume of the output mix is applied.
+pub(super) fn input_levels(
+    input_samples: &HashMap<InputId, Vec<(i16, i16)>>,
+) -> impl Iterator<Item = (InputId, AudioLevel)> + '_ {
+    input_samples
+        .iter()
+        .filter(|(_, samples)| !samples.is_empty())
+        .map(|(input_id, samples)| (input_id.clone(), samples_level(samples)))
+}
+
+/// RMS and peak of both channels combined.
+fn samples_level(samples: &[(i16, i16)]) -> AudioLevel {
+    let mut squares_sum = 0.0;
+    let mut peak = 0;
+    for (l, r) in samples {
+        squares_sum += (*l as f64).powi(2) + (*r as f64).powi(2);
+        peak = peak.max(l.unsigned_abs()).max(r.unsigned_abs());
+    }
+    let full_scale = -(i16::MIN as f64);
+    let rms = (squares_sum / (2 * samples.len()) as f64).sqrt() / full_scale;
+
+    AudioLevel {
+        rms: rms as f32,
+        peak: (peak as f64 / full_scale) as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_level() {
+        let square_wave: Vec<(i16, i16)> = (0..100)
+            .map(|i| match i % 2 {
+                0 => (16384, 16384),
+                _ => (-16384, -16384),
+            })
+            .collect();
+        assert_eq!(
+            samples_level(&square_wave),
+            AudioLevel {
+                rms: 0.5,
+                peak: 0.5
+            }
+        );
+
+        let left_only = vec![(i16::MIN, 0); 10];
+        let level = samples_level(&left_only);
+        assert_eq!(level.peak, 1.0);
+        assert!((level.rms - f32::sqrt(0.5)).abs() < 1e-6);
+    }
+}
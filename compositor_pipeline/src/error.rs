@@ -1,7 +1,8 @@
 use compositor_render::{
     error::{
-        InitRendererEngineError, RegisterError, RegisterRendererError, RequestKeyframeError,
-        UnregisterRendererError, UpdateSceneError, WgpuError,
+        AddSubtitleCueError, InitRendererEngineError, RegisterError, RegisterFontError,
+        RegisterRendererError, RequestKeyframeError, UnregisterFontError, UnregisterRendererError,
+        UpdateSceneError, WgpuError,
     },
     InputId, OutputId,
 };
@@ -50,6 +51,9 @@ pub enum RegisterOutputError {
 
     #[error("Failed to register output stream \"{0}\". At least one of \"video\" and \"audio\" must be specified.")]
     NoVideoAndAudio(OutputId),
+
+    #[error("Failed to register output stream \"{0}\". Captions source \"{1}\" is not a registered input.")]
+    UnknownCaptionsSource(OutputId, InputId),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -74,6 +78,29 @@ pub enum UnregisterOutputError {
     StillInUse(OutputId),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateEncoderError {
+    #[error("Output \"{0}\" does not exist, register it first before updating its encoder.")]
+    OutputNotRegistered(OutputId),
+
+    #[error(
+        "Output \"{0}\" is a raw output. Encoder update is only available for encoded outputs."
+    )]
+    RawOutput(OutputId),
+
+    #[error("Output \"{0}\" is not a video output. Can't update encoder of non video output.")]
+    NoVideoOutput(OutputId),
+
+    #[error("Failed to update encoder of output \"{0}\". Resolution in each dimension has to be divisible by 2.")]
+    UnsupportedResolution(OutputId),
+
+    #[error("Failed to update encoder of output \"{0}\". Resolution of an MP4 output can't be changed after registration.")]
+    ResolutionChangeNotSupported(OutputId),
+
+    #[error("Failed to update the scene for the new resolution of output \"{0}\".")]
+    SceneError(OutputId, #[source] UpdateSceneError),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum OutputInitError {
     #[error("An unsupported codec was requested: {0:?}.")]
@@ -97,6 +124,9 @@ pub enum EncoderInitError {
     #[error("Could not find an ffmpeg codec")]
     NoCodec,
 
+    #[error("Could not find a device that supports VA-API H264 encoding.")]
+    NoVaapiDevice,
+
     #[error(transparent)]
     FfmpegError(#[from] ffmpeg_next::Error),
 
@@ -140,6 +170,9 @@ pub enum InputInitError {
 
     #[error("Pipeline couldn't detect a vulkan video compatible device when it was being initialized. Cannot create a vulkan video decoder")]
     VulkanContextRequiredForVulkanDecoder,
+
+    #[error("None of the video decoders could be initialized.")]
+    NoVideoDecoderAvailable,
 }
 
 pub enum ErrorType {
@@ -207,6 +240,9 @@ impl From<&RegisterOutputError> for PipelineErrorInfo {
             RegisterOutputError::NoVideoAndAudio(_) => {
                 PipelineErrorInfo::new(NO_VIDEO_OR_AUDIO_FOR_OUTPUT, ErrorType::UserError)
             }
+            RegisterOutputError::UnknownCaptionsSource(_, _) => {
+                PipelineErrorInfo::new(INPUT_STREAM_NOT_FOUND, ErrorType::UserError)
+            }
         }
     }
 }
@@ -245,6 +281,27 @@ impl From<&UnregisterOutputError> for PipelineErrorInfo {
     }
 }
 
+const UPDATE_ENCODER_ERROR: &str = "UPDATE_ENCODER_ERROR";
+
+impl From<&UpdateEncoderError> for PipelineErrorInfo {
+    fn from(err: &UpdateEncoderError) -> Self {
+        match err {
+            UpdateEncoderError::OutputNotRegistered(_) => {
+                PipelineErrorInfo::new(OUTPUT_STREAM_NOT_FOUND, ErrorType::EntityNotFound)
+            }
+            UpdateEncoderError::RawOutput(_)
+            | UpdateEncoderError::NoVideoOutput(_)
+            | UpdateEncoderError::ResolutionChangeNotSupported(_) => {
+                PipelineErrorInfo::new(UPDATE_ENCODER_ERROR, ErrorType::UserError)
+            }
+            UpdateEncoderError::UnsupportedResolution(_) => {
+                PipelineErrorInfo::new(UNSUPPORTED_RESOLUTION, ErrorType::UserError)
+            }
+            UpdateEncoderError::SceneError(_, err) => err.into(),
+        }
+    }
+}
+
 const BUILD_SCENE_ERROR: &str = "BUILD_SCENE_ERROR";
 
 impl From<&UpdateSceneError> for PipelineErrorInfo {
@@ -284,6 +341,9 @@ impl From<&RequestKeyframeError> for PipelineErrorInfo {
 const WGPU_INIT_ERROR: &str = "WGPU_INIT_ERROR";
 const WEB_RENDERER_INIT_ERROR: &str = "WEB_RENDERER_INIT_ERROR";
 const LAYOUT_INIT_ERROR: &str = "LAYOUT_INIT_ERROR";
+const MASK_INIT_ERROR: &str = "MASK_INIT_ERROR";
+const CHROMA_KEY_INIT_ERROR: &str = "CHROMA_KEY_INIT_ERROR";
+const COLOR_CORRECTION_INIT_ERROR: &str = "COLOR_CORRECTION_INIT_ERROR";
 
 impl From<&InitRendererEngineError> for PipelineErrorInfo {
     fn from(err: &InitRendererEngineError) -> Self {
@@ -297,6 +357,15 @@ impl From<&InitRendererEngineError> for PipelineErrorInfo {
             InitRendererEngineError::LayoutTransformationsInitError(_) => {
                 PipelineErrorInfo::new(LAYOUT_INIT_ERROR, ErrorType::ServerError)
             }
+            InitRendererEngineError::MaskTransformationInitError(_) => {
+                PipelineErrorInfo::new(MASK_INIT_ERROR, ErrorType::ServerError)
+            }
+            InitRendererEngineError::ChromaKeyTransformationInitError(_) => {
+                PipelineErrorInfo::new(CHROMA_KEY_INIT_ERROR, ErrorType::ServerError)
+            }
+            InitRendererEngineError::ColorCorrectionTransformationInitError(_) => {
+                PipelineErrorInfo::new(COLOR_CORRECTION_INIT_ERROR, ErrorType::ServerError)
+            }
         }
     }
 }
@@ -304,6 +373,8 @@ impl From<&InitRendererEngineError> for PipelineErrorInfo {
 const ENTITY_ALREADY_REGISTERED: &str = "ENTITY_ALREADY_REGISTERED";
 const INVALID_SHADER: &str = "INVALID_SHADER";
 const REGISTER_IMAGE_ERROR: &str = "REGISTER_IMAGE_ERROR";
+const REGISTER_LUT_ERROR: &str = "REGISTER_LUT_ERROR";
+const REGISTER_SUBTITLES_ERROR: &str = "REGISTER_SUBTITLES_ERROR";
 const REGISTER_WEB_RENDERER_ERROR: &str = "REGISTER_WEB_RENDERER_ERROR";
 
 impl From<&RegisterRendererError> for PipelineErrorInfo {
@@ -320,6 +391,12 @@ impl From<&RegisterRendererError> for PipelineErrorInfo {
             RegisterRendererError::Image(_, _) => {
                 PipelineErrorInfo::new(REGISTER_IMAGE_ERROR, ErrorType::UserError)
             }
+            RegisterRendererError::Lut(_, _) => {
+                PipelineErrorInfo::new(REGISTER_LUT_ERROR, ErrorType::UserError)
+            }
+            RegisterRendererError::Subtitles(_, _) => {
+                PipelineErrorInfo::new(REGISTER_SUBTITLES_ERROR, ErrorType::UserError)
+            }
             RegisterRendererError::Web(_, _) => {
                 PipelineErrorInfo::new(REGISTER_WEB_RENDERER_ERROR, ErrorType::ServerError)
             }
@@ -339,6 +416,34 @@ impl From<&UnregisterRendererError> for PipelineErrorInfo {
     }
 }
 
+impl From<&AddSubtitleCueError> for PipelineErrorInfo {
+    fn from(err: &AddSubtitleCueError) -> Self {
+        match err {
+            AddSubtitleCueError::NotFound(_) => {
+                PipelineErrorInfo::new(ENTITY_NOT_FOUND, ErrorType::EntityNotFound)
+            }
+        }
+    }
+}
+
+const REGISTER_FONT_ERROR: &str = "REGISTER_FONT_ERROR";
+
+impl From<&RegisterFontError> for PipelineErrorInfo {
+    fn from(_err: &RegisterFontError) -> Self {
+        PipelineErrorInfo::new(REGISTER_FONT_ERROR, ErrorType::UserError)
+    }
+}
+
+impl From<&UnregisterFontError> for PipelineErrorInfo {
+    fn from(err: &UnregisterFontError) -> Self {
+        match err {
+            UnregisterFontError::NotRegistered(_) => {
+                PipelineErrorInfo::new(ENTITY_NOT_FOUND, ErrorType::EntityNotFound)
+            }
+        }
+    }
+}
+
 const WGPU_VALIDATION_ERROR: &str = "WGPU_VALIDATION_ERROR";
 const WGPU_OUT_OF_MEMORY_ERROR: &str = "WGPU_OUT_OF_MEMORY_ERROR";
 const WGPU_INTERNAL_ERROR: &str = "WGPU_INTERNAL_ERROR";
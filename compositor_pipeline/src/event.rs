@@ -14,6 +14,7 @@ pub enum Event {
     VideoInputStreamPlaying(InputId),
     AudioInputStreamEos(InputId),
     VideoInputStreamEos(InputId),
+    VideoInputDecodeError(InputId),
     OutputDone(OutputId),
 }
 
@@ -40,6 +41,7 @@ impl From<Event> for event_handler::Event {
             Event::VideoInputStreamPlaying(id) => input_event("VIDEO_INPUT_PLAYING", id),
             Event::AudioInputStreamEos(id) => input_event("AUDIO_INPUT_EOS", id),
             Event::VideoInputStreamEos(id) => input_event("VIDEO_INPUT_EOS", id),
+            Event::VideoInputDecodeError(id) => input_event("VIDEO_INPUT_DECODE_ERROR", id),
             Event::OutputDone(id) => output_event("OUTPUT_DONE", id),
         }
     }
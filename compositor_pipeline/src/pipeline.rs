@@ -2,12 +2,16 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::RwLock;
 use std::sync::Weak;
 use std::thread;
 use std::time::Duration;
 
+use bytes::Bytes;
+use compositor_render::asset::AssetSource;
 use compositor_render::error::{
-    ErrorStack, RegisterRendererError, RequestKeyframeError, UnregisterRendererError,
+    AddSubtitleCueError, ErrorStack, RegisterFontError, RegisterRendererError,
+    RequestKeyframeError, UnregisterFontError, UnregisterRendererError,
 };
 use compositor_render::scene::Component;
 use compositor_render::web_renderer::WebRendererInitOptions;
@@ -19,7 +23,6 @@ use compositor_render::WgpuFeatures;
 use compositor_render::{error::UpdateSceneError, Renderer};
 use compositor_render::{EventLoop, InputId, OutputId, RendererId, RendererSpec};
 use crossbeam_channel::{bounded, Receiver};
-use glyphon::fontdb;
 use input::InputInitInfo;
 use input::RawDataInputOptions;
 use output::EncodedDataOutputOptions;
@@ -34,6 +37,7 @@ use crate::audio_mixer::{AudioChannels, AudioMixingParams};
 use crate::error::InitPipelineError;
 use crate::error::{
     RegisterInputError, RegisterOutputError, UnregisterInputError, UnregisterOutputError,
+    UpdateEncoderError,
 };
 
 use crate::event::Event;
@@ -44,6 +48,7 @@ use crate::queue::QueueAudioOutput;
 use crate::queue::QueueInputOptions;
 use crate::queue::{self, Queue, QueueOptions, QueueVideoOutput};
 
+use self::encoder::VideoEncoderUpdate;
 use self::input::InputOptions;
 
 pub mod decoder;
@@ -87,6 +92,8 @@ pub struct RegisterOutputOptions<T> {
 pub struct OutputVideoOptions {
     pub initial: Component,
     pub end_condition: PipelineOutputEndCondition,
+    /// Input from which closed captions should be passed to this output.
+    pub captions_source: Option<InputId>,
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +117,10 @@ pub struct Pipeline {
     queue: Arc<Queue>,
     renderer: Renderer,
     audio_mixer: AudioMixer,
+    /// Input from which closed captions are passed to each output. Updated only when
+    /// outputs are registered or unregistered, so the renderer thread can read it
+    /// without locking the whole pipeline.
+    captions_sources: Arc<RwLock<HashMap<OutputId, InputId>>>,
     is_started: bool,
 }
 
@@ -181,13 +192,15 @@ impl Pipeline {
             .join(format!("live-compositor-{}", rand::random::<u64>()));
         std::fs::create_dir_all(&download_dir).map_err(InitPipelineError::CreateDownloadDir)?;
 
+        let audio_mixer = AudioMixer::new(opts.output_sample_rate, renderer.audio_levels());
         let event_emitter = Arc::new(EventEmitter::new());
         let pipeline = Pipeline {
             outputs: HashMap::new(),
             inputs: HashMap::new(),
             queue: Queue::new(opts.queue_options, &event_emitter),
             renderer,
-            audio_mixer: AudioMixer::new(opts.output_sample_rate),
+            audio_mixer,
+            captions_sources: Arc::new(RwLock::new(HashMap::new())),
             is_started: false,
             ctx: PipelineCtx {
                 output_sample_rate: opts.output_sample_rate,
@@ -297,6 +310,7 @@ impl Pipeline {
 
         self.audio_mixer.unregister_output(output_id);
         self.outputs.remove(output_id);
+        self.captions_sources.write().unwrap().remove(output_id);
         self.renderer.unregister_output(output_id);
         Ok(())
     }
@@ -320,6 +334,18 @@ impl Pipeline {
             .unregister_renderer(renderer_id, registry_type)
     }
 
+    /// If `start` is not provided, the cue starts at the PTS of the last rendered frame.
+    pub fn add_subtitle_cue(
+        &self,
+        subtitles_id: &RendererId,
+        start: Option<Duration>,
+        duration: Duration,
+        text: String,
+    ) -> Result<(), AddSubtitleCueError> {
+        self.renderer
+            .add_subtitle_cue(subtitles_id, start, duration, text)
+    }
+
     pub fn update_output(
         &mut self,
         output_id: OutputId,
@@ -346,8 +372,63 @@ impl Pipeline {
         output.output.request_keyframe(output_id)
     }
 
-    pub fn register_font(&self, font_source: fontdb::Source) {
-        self.renderer.register_font(font_source);
+    pub fn update_encoder(
+        &mut self,
+        output_id: OutputId,
+        update: VideoEncoderUpdate,
+    ) -> Result<(), UpdateEncoderError> {
+        let resolution = update.resolution();
+        if let Some(resolution) = resolution {
+            if resolution.width % 2 != 0 || resolution.height % 2 != 0 {
+                return Err(UpdateEncoderError::UnsupportedResolution(output_id));
+            }
+        }
+
+        let Some(output) = self.outputs.get_mut(&output_id) else {
+            return Err(UpdateEncoderError::OutputNotRegistered(output_id));
+        };
+        let encoder = output
+            .output
+            .video_encoder_for_update(&output_id, resolution)?;
+
+        if let Some(resolution) = resolution {
+            // Encoder has to expect the new resolution before the first frame with that
+            // resolution is rendered, otherwise that frame would be dropped.
+            let previous_resolution = encoder.resolution();
+            encoder.update(encoder.resolution_update(resolution));
+
+            info!(?output_id, ?resolution, "Update output resolution");
+            if let Err(err) = self
+                .renderer
+                .update_output_resolution(output_id.clone(), resolution)
+            {
+                encoder.update(encoder.resolution_update(previous_resolution));
+                return Err(UpdateEncoderError::SceneError(output_id, err));
+            }
+        }
+
+        // Rate control is applied only when the resize succeeded, so a failed request
+        // does not change the encoder configuration.
+        encoder.update(update.without_resolution());
+
+        Ok(())
+    }
+
+    /// Returns font families defined in the registered file.
+    pub fn register_font(
+        pipeline: &Arc<Mutex<Self>>,
+        font_source: AssetSource,
+    ) -> Result<Vec<String>, RegisterFontError> {
+        let renderer = pipeline.lock().unwrap().renderer.clone();
+        renderer.register_font(font_source)
+    }
+
+    pub fn unregister_font(&self, font_family: &str) -> Result<(), UnregisterFontError> {
+        self.renderer.unregister_font(font_family)
+    }
+
+    pub fn font_families(&self) -> Vec<String> {
+        self.renderer.font_families()
     }
 
     fn check_output_spec(
@@ -460,8 +541,11 @@ fn run_renderer_thread(
     pipeline: Weak<Mutex<Pipeline>>,
     frames_receiver: Receiver<QueueVideoOutput>,
 ) {
-    let renderer = match pipeline.upgrade() {
-        Some(pipeline) => pipeline.lock().unwrap().renderer.clone(),
+    let (renderer, captions_sources) = match pipeline.upgrade() {
+        Some(pipeline) => {
+            let guard = pipeline.lock().unwrap();
+            (guard.renderer.clone(), guard.captions_sources.clone())
+        }
         None => {
             warn!("Pipeline stopped before render thread was started.");
             return;
@@ -498,6 +582,15 @@ fn run_renderer_thread(
                 })
                 .collect();
 
+        let input_captions: HashMap<InputId, Bytes> = input_frames
+            .frames
+            .iter_mut()
+            .filter_map(|(input_id, event)| match event {
+                PipelineEvent::Data(frame) => Some((input_id.clone(), frame.captions.take()?)),
+                PipelineEvent::EOS => None,
+            })
+            .collect();
+
         let input_frames: FrameSet<InputId> = input_frames.into();
         trace!(?input_frames, "Rendering frames");
         let output_frames = renderer.render(input_frames);
@@ -509,7 +602,13 @@ fn run_renderer_thread(
             continue;
         };
 
-        for (output_id, frame) in output_frames.frames {
+        let captions_sources = captions_sources.read().unwrap();
+        for (output_id, mut frame) in output_frames.frames {
+            frame.captions = captions_sources
+                .get(&output_id)
+                .and_then(|input_id| input_captions.get(input_id))
+                .cloned();
+
             let Some(frame_sender) = output_frame_senders.get(&output_id) else {
                 warn!(?output_id, "Received new frame from renderer after EOS.");
                 continue;
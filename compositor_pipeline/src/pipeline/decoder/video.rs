@@ -1,5 +1,6 @@
 use compositor_render::{Frame, InputId};
 use crossbeam_channel::{Receiver, Sender};
+use tracing::{info, warn};
 
 use crate::{
     error::InputInitError,
@@ -9,7 +10,11 @@ use crate::{
 
 use super::VideoDecoderOptions;
 
+mod annex_b;
+#[cfg(feature = "vk-video")]
+mod captions;
 mod ffmpeg_h264;
+mod recovery;
 #[cfg(feature = "vk-video")]
 mod vulkan_video;
 
@@ -18,13 +23,71 @@ pub fn start_video_decoder_thread(
     pipeline_ctx: &PipelineCtx,
     chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
     frame_sender: Sender<PipelineEvent<Frame>>,
+    keyframe_request_sender: Option<Sender<()>>,
     input_id: InputId,
 ) -> Result<(), InputInitError> {
     match options.decoder {
+        VideoDecoder::Auto => {
+            let decoder = start_first_available(VideoDecoder::AUTO_FALLBACK_ORDER, |decoder| {
+                start_decoder(
+                    decoder,
+                    pipeline_ctx,
+                    chunks_receiver.clone(),
+                    frame_sender.clone(),
+                    keyframe_request_sender.clone(),
+                    input_id.clone(),
+                )
+            })?;
+            info!(?decoder, "Selected video decoder.");
+            Ok(())
+        }
+        decoder => start_decoder(
+            decoder,
+            pipeline_ctx,
+            chunks_receiver,
+            frame_sender,
+            keyframe_request_sender,
+            input_id,
+        ),
+    }
+}
+
+/// Starts decoders in order until one of them succeeds. Returns the started decoder
+/// or the error of the last one.
+fn start_first_available(
+    decoders: &[VideoDecoder],
+    mut start: impl FnMut(VideoDecoder) -> Result<(), InputInitError>,
+) -> Result<VideoDecoder, InputInitError> {
+    let mut last_error = None;
+    for decoder in decoders {
+        match start(*decoder) {
+            Ok(()) => return Ok(*decoder),
+            Err(err) => {
+                warn!(
+                    ?decoder,
+                    "Failed to start video decoder, trying next one: {err}"
+                );
+                last_error = Some(err);
+            }
+        }
+    }
+    Err(last_error.unwrap_or(InputInitError::NoVideoDecoderAvailable))
+}
+
+fn start_decoder(
+    decoder: VideoDecoder,
+    pipeline_ctx: &PipelineCtx,
+    chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
+    frame_sender: Sender<PipelineEvent<Frame>>,
+    keyframe_request_sender: Option<Sender<()>>,
+    input_id: InputId,
+) -> Result<(), InputInitError> {
+    match decoder {
         VideoDecoder::FFmpegH264 => ffmpeg_h264::start_ffmpeg_decoder_thread(
             pipeline_ctx,
             chunks_receiver,
             frame_sender,
+            keyframe_request_sender,
             input_id,
         ),
 
@@ -33,7 +96,62 @@ pub fn start_video_decoder_thread(
             pipeline_ctx,
             chunks_receiver,
             frame_sender,
+            keyframe_request_sender,
             input_id,
         ),
+
+        VideoDecoder::Auto => Err(InputInitError::NoVideoDecoderAvailable),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::InputInitError, pipeline::VideoDecoder};
+
+    use super::start_first_available;
+
+    #[test]
+    fn auto_falls_back_to_software_decoder() {
+        let mut tried = vec![];
+        let selected = start_first_available(VideoDecoder::AUTO_FALLBACK_ORDER, |decoder| {
+            tried.push(decoder);
+            match decoder {
+                VideoDecoder::FFmpegH264 => Ok(()),
+                _ => Err(InputInitError::VulkanContextRequiredForVulkanDecoder),
+            }
+        });
+        assert_eq!(selected.unwrap(), VideoDecoder::FFmpegH264);
+        assert_eq!(tried, VideoDecoder::AUTO_FALLBACK_ORDER);
+    }
+
+    #[test]
+    fn first_decoder_that_starts_is_selected() {
+        let mut tried = vec![];
+        let selected =
+            start_first_available(&[VideoDecoder::FFmpegH264, VideoDecoder::Auto], |decoder| {
+                tried.push(decoder);
+                Ok(())
+            });
+        assert_eq!(selected.unwrap(), VideoDecoder::FFmpegH264);
+        assert_eq!(tried, [VideoDecoder::FFmpegH264]);
+    }
+
+    #[test]
+    fn error_of_the_last_decoder_is_returned() {
+        let selected =
+            start_first_available(&[VideoDecoder::FFmpegH264, VideoDecoder::Auto], |decoder| {
+                match decoder {
+                    VideoDecoder::FFmpegH264 => Err(InputInitError::NoVideoDecoderAvailable),
+                    _ => Err(InputInitError::VulkanContextRequiredForVulkanDecoder),
+                }
+            });
+        assert!(matches!(
+            selected,
+            Err(InputInitError::VulkanContextRequiredForVulkanDecoder)
+        ));
+        assert!(matches!(
+            start_first_available(&[], |_| Ok(())),
+            Err(InputInitError::NoVideoDecoderAvailable)
+        ));
     }
 }
@@ -0,0 +1,67 @@
+//! Minimal helpers for inspecting H264 access units in the Annex B format.
+//! Decoders handle the actual parsing, those are only used to extract metadata
+//! that is not exposed by them.
+
+pub(super) const NAL_TYPE_IDR: u8 = 5;
+pub(super) const NAL_TYPE_SEI: u8 = 6;
+
+/// Iterate over NAL units (without start codes) in an Annex B encoded buffer.
+pub(super) fn nal_units(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut start_code_ends = data
+        .windows(3)
+        .enumerate()
+        .filter(|(_, window)| *window == [0, 0, 1])
+        .map(|(i, _)| i + 3)
+        .peekable();
+
+    std::iter::from_fn(move || {
+        let start = start_code_ends.next()?;
+        let end = match start_code_ends.peek() {
+            // strip the start code (and a possible leading zero of a 4 byte start code)
+            Some(next_start) => {
+                let end = next_start - 3;
+                if end > start && data[end - 1] == 0 {
+                    end - 1
+                } else {
+                    end
+                }
+            }
+            None => data.len(),
+        };
+        Some(&data[start..end.max(start)])
+    })
+}
+
+pub(super) fn nal_unit_type(nal: &[u8]) -> Option<u8> {
+    nal.first().map(|header| header & 0x1f)
+}
+
+/// Remove emulation prevention bytes (`0x03` in `0x00 0x00 0x03`).
+#[cfg(feature = "vk-video")]
+pub(super) fn to_rbsp(nal: &[u8]) -> Vec<u8> {
+    let mut rbsp = Vec::with_capacity(nal.len());
+    let mut zeros = 0;
+    for &byte in nal {
+        if zeros >= 2 && byte == 3 {
+            zeros = 0;
+            continue;
+        }
+        zeros = if byte == 0 { zeros + 1 } else { 0 };
+        rbsp.push(byte);
+    }
+    rbsp
+}
+
+/// Read SEI payload type or payload size. Those values are encoded as a sequence
+/// of `0xFF` bytes followed by a last byte, the value is a sum of all of them.
+pub(super) fn read_sei_value(data: &mut &[u8]) -> Option<usize> {
+    let mut value = 0;
+    loop {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+        value += byte as usize;
+        if byte != 0xff {
+            return Some(value);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use bytes::{Bytes, BytesMut};
+
+use super::annex_b::{self, NAL_TYPE_SEI};
+
+const SEI_PAYLOAD_TYPE_USER_DATA_REGISTERED: usize = 4;
+
+/// `itu_t_t35_country_code`, `itu_t_t35_provider_code`, `user_identifier` ("GA94")
+/// and `user_data_type_code` of ATSC A/53 closed captions.
+const A53_CC_HEADER: [u8; 8] = [0xb5, 0x00, 0x31, b'G', b'A', b'9', b'4', 0x03];
+
+/// Extract CEA-708 `cc_data` from all ATSC A/53 SEI messages in an Annex B encoded
+/// access unit. Result has the same format as ffmpeg's `AV_FRAME_DATA_A53_CC` side data.
+pub(super) fn extract_a53_captions(data: &[u8]) -> Option<Bytes> {
+    let mut cc_data = BytesMut::new();
+    for nal in annex_b::nal_units(data) {
+        if annex_b::nal_unit_type(nal) != Some(NAL_TYPE_SEI) {
+            continue;
+        }
+        let rbsp = annex_b::to_rbsp(&nal[1..]);
+        let mut reader = rbsp.as_slice();
+
+        // stop on rbsp_trailing_bits
+        while reader.len() > 1 {
+            let (Some(payload_type), Some(payload_size)) = (
+                annex_b::read_sei_value(&mut reader),
+                annex_b::read_sei_value(&mut reader),
+            ) else {
+                break;
+            };
+            if payload_size > reader.len() {
+                break;
+            }
+            let (payload, rest) = reader.split_at(payload_size);
+            if payload_type == SEI_PAYLOAD_TYPE_USER_DATA_REGISTERED {
+                append_cc_data(payload, &mut cc_data);
+            }
+            reader = rest;
+        }
+    }
+
+    match cc_data.is_empty() {
+        true => None,
+        false => Some(cc_data.freeze()),
+    }
+}
+
+fn append_cc_data(payload: &[u8], cc_data: &mut BytesMut) {
+    let Some([flags, _em_data, rest @ ..]) = payload.strip_prefix(&A53_CC_HEADER) else {
+        return;
+    };
+    let process_cc_data = flags & 0x40 != 0;
+    let cc_count = (flags & 0x1f) as usize;
+    if process_cc_data && rest.len() >= cc_count * 3 {
+        cc_data.extend_from_slice(&rest[..cc_count * 3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract_a53_captions;
+
+    #[test]
+    fn extracts_cc_data_from_sei() {
+        let access_unit = [
+            0, 0, 0, 1, 0x06, // SEI NAL
+            0x04, 0x11, // payload type 4, size 17
+            0xb5, 0x00, 0x31, b'G', b'A', b'9', b'4', 0x03, //
+            0x42, 0xff, // process_cc_data_flag, cc_count = 2, em_data
+            0xfc, 0x94, 0x20, 0xfc, 0x94, 0xae, // cc_data
+            0xff, // marker bits
+            0x80, // rbsp trailing bits
+            0, 0, 1, 0x65, 0x88, // IDR slice
+        ];
+        assert_eq!(
+            extract_a53_captions(&access_unit).as_deref(),
+            Some([0xfc, 0x94, 0x20, 0xfc, 0x94, 0xae].as_slice())
+        );
+    }
+
+    #[test]
+    fn ignores_access_units_without_captions() {
+        let access_unit = [
+            0, 0, 0, 1, 0x06, 0x06, 0x01, 0xc4, 0x80, 0, 0, 1, 0x41, 0x9a,
+        ];
+        assert_eq!(extract_a53_captions(&access_unit), None);
+    }
+}
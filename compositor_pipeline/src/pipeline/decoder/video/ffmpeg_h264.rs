@@ -9,23 +9,31 @@ use crate::{
     queue::PipelineEvent,
 };
 
+use super::recovery::DecoderErrorRecovery;
+
 use compositor_render::{Frame, FrameData, InputId, Resolution, YuvPlanes};
 use crossbeam_channel::{Receiver, Sender};
 use ffmpeg_next::{
     codec::{Context, Id},
     format::Pixel,
-    frame::Video,
+    frame::{side_data::Type as SideDataType, Video},
     media::Type,
     Rational,
 };
 use tracing::{debug, error, span, trace, warn, Level};
 
 pub fn start_ffmpeg_decoder_thread(
-    _pipeline_ctx: &PipelineCtx,
+    pipeline_ctx: &PipelineCtx,
     chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
     frame_sender: Sender<PipelineEvent<Frame>>,
+    keyframe_request_sender: Option<Sender<()>>,
     input_id: InputId,
 ) -> Result<(), InputInitError> {
+    let recovery = DecoderErrorRecovery::new(
+        input_id.clone(),
+        pipeline_ctx.event_emitter.clone(),
+        keyframe_request_sender,
+    );
     let (init_result_sender, init_result_receiver) = crossbeam_channel::bounded(0);
 
     let mut parameters = ffmpeg_next::codec::Parameters::new();
@@ -50,6 +58,7 @@ pub fn start_ffmpeg_decoder_thread(
                 init_result_sender,
                 chunks_receiver,
                 frame_sender,
+                recovery,
             )
         })
         .unwrap();
@@ -72,6 +81,7 @@ fn run_decoder_thread(
     init_result_sender: Sender<Result<(), InputInitError>>,
     chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
     frame_sender: Sender<PipelineEvent<Frame>>,
+    mut recovery: DecoderErrorRecovery,
 ) {
     let decoder = Context::from_parameters(parameters.clone())
         .map_err(InputInitError::FfmpegError)
@@ -116,6 +126,11 @@ fn run_decoder_thread(
             continue;
         }
 
+        if recovery.should_drop_chunk(&chunk.data) {
+            trace!(pts=?chunk.pts, "Dropping chunk, waiting for a recovery point.");
+            continue;
+        }
+
         let av_packet: ffmpeg_next::Packet = match chunk_to_av(chunk) {
             Ok(packet) => packet,
             Err(err) => {
@@ -128,11 +143,18 @@ fn run_decoder_thread(
             Ok(()) => {}
             Err(e) => {
                 warn!("Failed to send a packet to decoder: {}", e);
+                recovery.on_decode_error();
+                decoder.flush();
                 continue;
             }
         }
 
         while decoder.receive_frame(&mut decoded_frame).is_ok() {
+            if !recovery.on_frame_decoded(decoded_frame.is_corrupt()) {
+                trace!("Dropping corrupted frame.");
+                continue;
+            }
+
             let frame = match frame_from_av(&mut decoded_frame, &mut pts_offset) {
                 Ok(frame) => frame,
                 Err(err) => {
@@ -206,6 +228,9 @@ fn frame_from_av(
         Pixel::UYVY422 => FrameData::InterleavedYuv422(copy_plane_from_av(decoded, 0)),
         fmt => return Err(DecoderFrameConversionError::UnsupportedPixelFormat(fmt)),
     };
+    let captions = decoded
+        .side_data(SideDataType::A53CC)
+        .map(|side_data| bytes::Bytes::copy_from_slice(side_data.data()));
     Ok(Frame {
         data,
         resolution: Resolution {
@@ -213,6 +238,7 @@ fn frame_from_av(
             height: decoded.height().try_into().unwrap(),
         },
         pts,
+        captions,
     })
 }
 
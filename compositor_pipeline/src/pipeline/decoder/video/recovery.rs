@@ -0,0 +1,246 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use compositor_render::InputId;
+use crossbeam_channel::Sender;
+use tracing::{debug, warn};
+
+use crate::event::{Event, EventEmitter};
+
+use super::annex_b::{self, NAL_TYPE_IDR, NAL_TYPE_SEI};
+
+/// Minimal interval between consecutive keyframe requests sent upstream while
+/// the decoder is waiting for a recovery point.
+const KEYFRAME_REQUEST_INTERVAL: Duration = Duration::from_millis(500);
+
+const SEI_PAYLOAD_TYPE_RECOVERY_POINT: usize = 6;
+
+/// Tracks decoder errors and decides which chunks should be dropped until the
+/// stream reaches a point from which it can be decoded correctly again.
+pub(super) struct DecoderErrorRecovery {
+    input_id: InputId,
+    event_emitter: Arc<EventEmitter>,
+    keyframe_request_sender: Option<Sender<()>>,
+    waiting_for_recovery_point: bool,
+    /// Decoding was resumed at a recovery point, but the decoder did not produce
+    /// a correct frame yet.
+    resuming: bool,
+    last_keyframe_request: Option<Instant>,
+}
+
+impl DecoderErrorRecovery {
+    pub fn new(
+        input_id: InputId,
+        event_emitter: Arc<EventEmitter>,
+        keyframe_request_sender: Option<Sender<()>>,
+    ) -> Self {
+        Self {
+            input_id,
+            event_emitter,
+            keyframe_request_sender,
+            waiting_for_recovery_point: false,
+            resuming: false,
+            last_keyframe_request: None,
+        }
+    }
+
+    /// Should be called when the decoder failed to decode a chunk or produced a corrupted
+    /// frame. All following chunks will be dropped until a recovery point is found.
+    pub fn on_decode_error(&mut self) {
+        if !self.waiting_for_recovery_point {
+            warn!("Decoding error, dropping frames until the next recovery point.");
+            self.waiting_for_recovery_point = true;
+            self.event_emitter
+                .emit(Event::VideoInputDecodeError(self.input_id.clone()));
+        }
+        self.request_keyframe();
+    }
+
+    /// Returns `true` if the decoded frame should be passed further.
+    ///
+    /// After resuming at a recovery point SEI the decoder can still mark frames as
+    /// corrupted until the recovery frame count is reached. Those frames are dropped
+    /// without being treated as a new error.
+    pub fn on_frame_decoded(&mut self, is_corrupted: bool) -> bool {
+        if is_corrupted {
+            if !self.resuming {
+                self.on_decode_error();
+            }
+            return false;
+        }
+        self.resuming = false;
+        !self.waiting_for_recovery_point
+    }
+
+    /// Returns `true` if the chunk should not be passed to the decoder.
+    pub fn should_drop_chunk(&mut self, data: &[u8]) -> bool {
+        if !self.waiting_for_recovery_point {
+            return false;
+        }
+        if is_recovery_point(data) {
+            debug!("Recovery point found, resuming decoding.");
+            self.waiting_for_recovery_point = false;
+            self.resuming = true;
+            return false;
+        }
+        self.request_keyframe();
+        true
+    }
+
+    fn request_keyframe(&mut self) {
+        let Some(sender) = &self.keyframe_request_sender else {
+            return;
+        };
+        let now = Instant::now();
+        let should_request = self.last_keyframe_request.map_or(true, |last| {
+            now.duration_since(last) >= KEYFRAME_REQUEST_INTERVAL
+        });
+        if should_request {
+            self.last_keyframe_request = Some(now);
+            // Channel is bounded, if there is a pending request already there is no
+            // need to queue another one.
+            let _ = sender.try_send(());
+        }
+    }
+}
+
+/// Checks if an Annex B encoded access unit contains an IDR slice or a recovery
+/// point SEI message.
+///
+/// Only the first SEI message of each SEI NAL unit is inspected.
+fn is_recovery_point(data: &[u8]) -> bool {
+    annex_b::nal_units(data).any(|nal| match annex_b::nal_unit_type(nal) {
+        Some(NAL_TYPE_IDR) => true,
+        Some(NAL_TYPE_SEI) => {
+            let mut payload = &nal[1..];
+            annex_b::read_sei_value(&mut payload) == Some(SEI_PAYLOAD_TYPE_RECOVERY_POINT)
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use compositor_render::InputId;
+    use crossbeam_channel::Receiver;
+
+    use crate::event::{Event, EventEmitter};
+
+    use super::{is_recovery_point, DecoderErrorRecovery};
+
+    const IDR: [u8; 7] = [0, 0, 1, 0x65, 0x88, 0x84, 0x00];
+    const NON_IDR: [u8; 6] = [0, 0, 1, 0x41, 0x9a, 0x02];
+
+    fn recovery() -> (DecoderErrorRecovery, Receiver<()>, Receiver<Event>) {
+        let event_emitter = Arc::new(EventEmitter::new());
+        let events = event_emitter.subscribe();
+        let (keyframe_sender, keyframe_receiver) = crossbeam_channel::bounded(10);
+        let recovery = DecoderErrorRecovery::new(
+            InputId("input_1".into()),
+            event_emitter,
+            Some(keyframe_sender),
+        );
+        (recovery, keyframe_receiver, events)
+    }
+
+    fn decode_errors(events: &Receiver<Event>) -> usize {
+        events
+            .try_iter()
+            .filter(|event| matches!(event, Event::VideoInputDecodeError(_)))
+            .count()
+    }
+
+    #[test]
+    fn chunks_are_dropped_until_recovery_point() {
+        let (mut recovery, _, _) = recovery();
+        assert!(!recovery.should_drop_chunk(&NON_IDR));
+
+        recovery.on_decode_error();
+        assert!(recovery.should_drop_chunk(&NON_IDR));
+        assert!(recovery.should_drop_chunk(&NON_IDR));
+        assert!(!recovery.should_drop_chunk(&IDR));
+        assert!(!recovery.should_drop_chunk(&NON_IDR));
+    }
+
+    #[test]
+    fn frames_are_dropped_while_waiting_for_recovery_point() {
+        let (mut recovery, _, _) = recovery();
+        assert!(recovery.on_frame_decoded(false));
+
+        recovery.on_decode_error();
+        assert!(!recovery.on_frame_decoded(false));
+
+        recovery.should_drop_chunk(&IDR);
+        assert!(recovery.on_frame_decoded(false));
+    }
+
+    #[test]
+    fn error_is_reported_once_until_recovery() {
+        let (mut recovery, _, events) = recovery();
+        recovery.on_decode_error();
+        recovery.on_decode_error();
+        assert!(!recovery.on_frame_decoded(true));
+        assert_eq!(decode_errors(&events), 1);
+
+        recovery.should_drop_chunk(&IDR);
+        recovery.on_frame_decoded(false);
+        recovery.on_decode_error();
+        assert_eq!(decode_errors(&events), 1);
+    }
+
+    #[test]
+    fn corrupted_frames_after_recovery_point_are_not_new_errors() {
+        let (mut recovery, _, events) = recovery();
+        recovery.on_decode_error();
+        recovery.should_drop_chunk(&IDR);
+        assert_eq!(decode_errors(&events), 1);
+
+        // decoder can report corrupted frames until the recovery frame count is reached
+        assert!(!recovery.on_frame_decoded(true));
+        assert!(!recovery.should_drop_chunk(&NON_IDR));
+        assert_eq!(decode_errors(&events), 0);
+
+        // after the first correct frame, corruption is an error again
+        assert!(recovery.on_frame_decoded(false));
+        assert!(!recovery.on_frame_decoded(true));
+        assert!(recovery.should_drop_chunk(&NON_IDR));
+        assert_eq!(decode_errors(&events), 1);
+    }
+
+    #[test]
+    fn keyframe_requests_are_rate_limited() {
+        let (mut recovery, keyframe_requests, _) = recovery();
+        recovery.on_decode_error();
+        for _ in 0..5 {
+            recovery.should_drop_chunk(&NON_IDR);
+        }
+        assert_eq!(keyframe_requests.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn detects_idr_slice() {
+        let sps_pps_idr = [
+            0, 0, 0, 1, 0x67, 0x42, 0, 0, 0, 1, 0x68, 0xce, 0, 0, 1, 0x65, 0x88, 0x84,
+        ];
+        assert!(is_recovery_point(&sps_pps_idr));
+    }
+
+    #[test]
+    fn detects_recovery_point_sei() {
+        let sei_non_idr = [
+            0, 0, 0, 1, 0x06, 0x06, 0x01, 0xc4, 0x80, 0, 0, 1, 0x41, 0x9a,
+        ];
+        assert!(is_recovery_point(&sei_non_idr));
+    }
+
+    #[test]
+    fn ignores_non_idr_slices() {
+        let sei_non_idr = [0, 0, 0, 1, 0x06, 0x05, 0x10, 0xaa, 0, 0, 1, 0x41, 0x9a];
+        assert!(!is_recovery_point(&sei_non_idr));
+        assert!(!is_recovery_point(&[0, 0, 1, 0x41, 0x9a, 0x02]));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
 use compositor_render::{Frame, FrameData, InputId, Resolution};
 use crossbeam_channel::{Receiver, Sender};
@@ -11,15 +11,23 @@ use crate::{
     queue::PipelineEvent,
 };
 
+use super::{captions::extract_a53_captions, recovery::DecoderErrorRecovery};
+
 pub fn start_vulkan_video_decoder_thread(
     pipeline_ctx: &PipelineCtx,
     chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
     frame_sender: Sender<PipelineEvent<Frame>>,
+    keyframe_request_sender: Option<Sender<()>>,
     input_id: InputId,
 ) -> Result<(), InputInitError> {
     let Some(vulkan_ctx) = pipeline_ctx.vulkan_ctx.clone() else {
         return Err(InputInitError::VulkanContextRequiredForVulkanDecoder);
     };
+    let recovery = DecoderErrorRecovery::new(
+        input_id.clone(),
+        pipeline_ctx.event_emitter.clone(),
+        keyframe_request_sender,
+    );
 
     let (init_result_sender, init_result_receiver) = crossbeam_channel::bounded(0);
 
@@ -37,6 +45,7 @@ pub fn start_vulkan_video_decoder_thread(
                 init_result_sender,
                 chunks_receiver,
                 frame_sender,
+                recovery,
             )
         })
         .unwrap();
@@ -51,6 +60,7 @@ fn run_decoder_thread(
     init_result_sender: Sender<Result<(), InputInitError>>,
     chunks_receiver: Receiver<PipelineEvent<EncodedChunk>>,
     frame_sender: Sender<PipelineEvent<Frame>>,
+    mut recovery: DecoderErrorRecovery,
 ) {
     let mut decoder = match vulkan_device.create_wgpu_textures_decoder() {
         Ok(decoder) => {
@@ -63,6 +73,10 @@ fn run_decoder_thread(
         }
     };
 
+    // Captions are extracted from chunks in decoding order, but frames are produced
+    // in presentation order, so they need to be matched by PTS.
+    let mut pending_captions = BTreeMap::new();
+
     for chunk in chunks_receiver {
         let chunk = match chunk {
             PipelineEvent::Data(chunk) => chunk,
@@ -79,15 +93,36 @@ fn run_decoder_thread(
             continue;
         }
 
-        let result = match decoder.decode(&chunk.data, Some(chunk.pts.as_micros() as u64)) {
+        if recovery.should_drop_chunk(&chunk.data) {
+            trace!(pts=?chunk.pts, "Dropping chunk, waiting for a recovery point.");
+            continue;
+        }
+
+        let chunk_pts = chunk.pts.as_micros() as u64;
+        if let Some(captions) = extract_a53_captions(&chunk.data) {
+            pending_captions.insert(chunk_pts, captions);
+        }
+
+        let result = match decoder.decode(&chunk.data, Some(chunk_pts)) {
             Ok(res) => res,
             Err(err) => {
                 warn!("Failed to decode frame: {err}");
+                recovery.on_decode_error();
                 continue;
             }
         };
 
         for vk_video::Frame { frame, pts } in result {
+            let pts = pts.unwrap();
+            let captions = pending_captions.remove(&pts);
+            // drop captions of frames that were never produced by the decoder
+            pending_captions = pending_captions.split_off(&pts);
+
+            if !recovery.on_frame_decoded(false) {
+                trace!("Dropping frame, waiting for a recovery point.");
+                continue;
+            }
+
             let resolution = Resolution {
                 width: frame.width() as usize,
                 height: frame.height() as usize,
@@ -95,8 +130,9 @@ fn run_decoder_thread(
 
             let frame = Frame {
                 data: FrameData::Nv12WgpuTexture(frame.into()),
-                pts: Duration::from_micros(pts.unwrap()),
+                pts: Duration::from_micros(pts),
                 resolution,
+                captions,
             };
 
             trace!(pts=?frame.pts, "H264 decoder produced a frame.");
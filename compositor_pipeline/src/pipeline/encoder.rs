@@ -1,7 +1,7 @@
 use compositor_render::{Frame, OutputId, Resolution};
 use crossbeam_channel::{bounded, Receiver, Sender};
 use fdk_aac::AacEncoder;
-use log::error;
+use log::{error, warn};
 
 use crate::{
     audio_mixer::{AudioChannels, OutputSamples},
@@ -9,13 +9,18 @@ use crate::{
     queue::PipelineEvent,
 };
 
-use self::{ffmpeg_h264::LibavH264Encoder, opus::OpusEncoder};
+use self::{
+    ffmpeg_h264::LibavH264Encoder,
+    opus::OpusEncoder,
+    vaapi_h264::{Backend, VaapiH264Encoder},
+};
 
 use super::types::EncoderOutputEvent;
 
 pub mod fdk_aac;
 pub mod ffmpeg_h264;
 pub mod opus;
+pub mod vaapi_h264;
 
 pub struct EncoderOptions {
     pub video: Option<VideoEncoderOptions>,
@@ -25,6 +30,32 @@ pub struct EncoderOptions {
 #[derive(Debug, Clone)]
 pub enum VideoEncoderOptions {
     H264(ffmpeg_h264::Options),
+    VaapiH264(vaapi_h264::Options),
+}
+
+#[derive(Debug, Clone)]
+pub enum VideoEncoderUpdate {
+    H264(ffmpeg_h264::EncoderUpdate),
+}
+
+impl VideoEncoderUpdate {
+    pub fn resolution(&self) -> Option<Resolution> {
+        match self {
+            VideoEncoderUpdate::H264(update) => update.resolution,
+        }
+    }
+
+    /// The same update without the resolution change.
+    pub fn without_resolution(self) -> Self {
+        match self {
+            VideoEncoderUpdate::H264(update) => {
+                VideoEncoderUpdate::H264(ffmpeg_h264::EncoderUpdate {
+                    resolution: None,
+                    ..update
+                })
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -47,6 +78,7 @@ pub struct Encoder {
 
 pub enum VideoEncoder {
     H264(LibavH264Encoder),
+    VaapiH264(VaapiH264Encoder),
 }
 
 pub enum AudioEncoder {
@@ -93,6 +125,7 @@ impl Encoder {
     pub fn frame_sender(&self) -> Option<&Sender<PipelineEvent<Frame>>> {
         match &self.video {
             Some(VideoEncoder::H264(encoder)) => Some(encoder.frame_sender()),
+            Some(VideoEncoder::VaapiH264(encoder)) => Some(encoder.frame_sender()),
             None => {
                 error!("Non video encoder received frame to send.");
                 None
@@ -115,6 +148,7 @@ impl VideoEncoderOptions {
     pub fn resolution(&self) -> Resolution {
         match self {
             VideoEncoderOptions::H264(opt) => opt.resolution,
+            VideoEncoderOptions::VaapiH264(opt) => opt.resolution,
         }
     }
 }
@@ -129,18 +163,65 @@ impl VideoEncoder {
             VideoEncoderOptions::H264(options) => Ok(Self::H264(LibavH264Encoder::new(
                 output_id, options, sender,
             )?)),
+            VideoEncoderOptions::VaapiH264(options) => {
+                let backend = vaapi_h264::select_backend(
+                    &options,
+                    &vaapi_h264::render_nodes(),
+                    vaapi_h264::is_device_supported,
+                )?;
+                let err = match backend {
+                    Backend::Vaapi(device) => match VaapiH264Encoder::new(
+                        output_id,
+                        options.clone(),
+                        device,
+                        sender.clone(),
+                    ) {
+                        Ok(encoder) => return Ok(Self::VaapiH264(encoder)),
+                        Err(err) if options.software_fallback => err,
+                        Err(err) => return Err(err),
+                    },
+                    Backend::Software => EncoderInitError::NoVaapiDevice,
+                };
+                warn!("VA-API encoder is not available, falling back to libx264: {err}");
+                Ok(Self::H264(LibavH264Encoder::new(
+                    output_id,
+                    options.software_fallback_options(),
+                    sender,
+                )?))
+            }
         }
     }
 
     pub fn resolution(&self) -> Resolution {
         match self {
             Self::H264(encoder) => encoder.resolution(),
+            Self::VaapiH264(encoder) => encoder.resolution(),
+        }
+    }
+
+    /// Update that only changes the resolution expected by the encoder.
+    pub fn resolution_update(&self, resolution: Resolution) -> VideoEncoderUpdate {
+        match self {
+            Self::H264(_) | Self::VaapiH264(_) => {
+                VideoEncoderUpdate::H264(ffmpeg_h264::EncoderUpdate {
+                    resolution: Some(resolution),
+                    ..Default::default()
+                })
+            }
         }
     }
 
     pub fn request_keyframe(&self) {
         match self {
             Self::H264(encoder) => encoder.request_keyframe(),
+            Self::VaapiH264(encoder) => encoder.request_keyframe(),
+        }
+    }
+
+    pub fn update(&mut self, update: VideoEncoderUpdate) {
+        match (self, update) {
+            (Self::H264(encoder), VideoEncoderUpdate::H264(update)) => encoder.update(update),
+            (Self::VaapiH264(encoder), VideoEncoderUpdate::H264(update)) => encoder.update(update),
         }
     }
 }
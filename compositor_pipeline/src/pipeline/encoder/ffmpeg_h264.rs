@@ -79,17 +79,226 @@ impl EncoderPreset {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateControl {
+    /// Quality-based VBR. Optionally capped by `max_bitrate` (requires `buffer_size`).
+    Crf {
+        crf: u8,
+        max_bitrate: Option<u64>,
+        buffer_size: Option<u64>,
+    },
+    /// Average bitrate. All values are in bits (per second).
+    Vbr {
+        bitrate: u64,
+        max_bitrate: Option<u64>,
+        buffer_size: Option<u64>,
+    },
+    /// Constant bitrate with a VBV buffer. If `buffer_size` is not provided
+    /// it defaults to one second of video (equal to `bitrate`).
+    Cbr {
+        bitrate: u64,
+        buffer_size: Option<u64>,
+    },
+}
+
+impl Default for RateControl {
+    fn default() -> Self {
+        Self::Crf {
+            crf: 23,
+            max_bitrate: None,
+            buffer_size: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncoderProfile {
+    Baseline,
+    Main,
+    High,
+}
+
+impl EncoderProfile {
+    fn to_str(self) -> &'static str {
+        match self {
+            EncoderProfile::Baseline => "baseline",
+            EncoderProfile::Main => "main",
+            EncoderProfile::High => "high",
+        }
+    }
+}
+
+/// H264 level as defined in Annex A of the H264 specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncoderLevel {
+    Level1,
+    Level1b,
+    Level1_1,
+    Level1_2,
+    Level1_3,
+    Level2,
+    Level2_1,
+    Level2_2,
+    Level3,
+    Level3_1,
+    Level3_2,
+    Level4,
+    Level4_1,
+    Level4_2,
+    Level5,
+    Level5_1,
+    Level5_2,
+    Level6,
+    Level6_1,
+    Level6_2,
+}
+
+impl EncoderLevel {
+    pub(super) fn to_str(self) -> &'static str {
+        match self {
+            EncoderLevel::Level1 => "1",
+            EncoderLevel::Level1b => "1b",
+            EncoderLevel::Level1_1 => "1.1",
+            EncoderLevel::Level1_2 => "1.2",
+            EncoderLevel::Level1_3 => "1.3",
+            EncoderLevel::Level2 => "2",
+            EncoderLevel::Level2_1 => "2.1",
+            EncoderLevel::Level2_2 => "2.2",
+            EncoderLevel::Level3 => "3",
+            EncoderLevel::Level3_1 => "3.1",
+            EncoderLevel::Level3_2 => "3.2",
+            EncoderLevel::Level4 => "4",
+            EncoderLevel::Level4_1 => "4.1",
+            EncoderLevel::Level4_2 => "4.2",
+            EncoderLevel::Level5 => "5",
+            EncoderLevel::Level5_1 => "5.1",
+            EncoderLevel::Level5_2 => "5.2",
+            EncoderLevel::Level6 => "6",
+            EncoderLevel::Level6_1 => "6.1",
+            EncoderLevel::Level6_2 => "6.2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncoderTune {
+    Film,
+    Animation,
+    Grain,
+    StillImage,
+    FastDecode,
+    ZeroLatency,
+}
+
+impl EncoderTune {
+    fn to_str(self) -> &'static str {
+        match self {
+            EncoderTune::Film => "film",
+            EncoderTune::Animation => "animation",
+            EncoderTune::Grain => "grain",
+            EncoderTune::StillImage => "stillimage",
+            EncoderTune::FastDecode => "fastdecode",
+            EncoderTune::ZeroLatency => "zerolatency",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Options {
     pub preset: EncoderPreset,
     pub resolution: Resolution,
+    pub rate_control: RateControl,
+    /// Number of frames between keyframes. Scene-cut detection is disabled
+    /// when this value is set, so GOP length is fixed.
+    pub gop_size: Option<u32>,
+    /// Force a keyframe at least once per interval (based on frame PTS).
+    pub keyframe_interval: Option<Duration>,
+    pub b_frames: Option<u32>,
+    pub profile: Option<EncoderProfile>,
+    pub level: Option<EncoderLevel>,
+    pub tune: Option<EncoderTune>,
     pub raw_options: Vec<(String, String)>,
 }
 
+impl Options {
+    /// Encoder options derived from typed fields. Values from `raw_options`
+    /// take precedence over them.
+    fn typed_options(&self) -> Vec<(&'static str, String)> {
+        let mut opts = vec![];
+        match self.rate_control {
+            RateControl::Crf {
+                crf,
+                max_bitrate,
+                buffer_size,
+            } => {
+                opts.push(("crf", crf.to_string()));
+                if let Some(max_bitrate) = max_bitrate {
+                    opts.push(("maxrate", max_bitrate.to_string()));
+                }
+                if let Some(buffer_size) = buffer_size {
+                    opts.push(("bufsize", buffer_size.to_string()));
+                }
+            }
+            RateControl::Vbr {
+                bitrate,
+                max_bitrate,
+                buffer_size,
+            } => {
+                opts.push(("b", bitrate.to_string()));
+                if let Some(max_bitrate) = max_bitrate {
+                    opts.push(("maxrate", max_bitrate.to_string()));
+                }
+                if let Some(buffer_size) = buffer_size {
+                    opts.push(("bufsize", buffer_size.to_string()));
+                }
+            }
+            RateControl::Cbr {
+                bitrate,
+                buffer_size,
+            } => {
+                opts.push(("b", bitrate.to_string()));
+                opts.push(("minrate", bitrate.to_string()));
+                opts.push(("maxrate", bitrate.to_string()));
+                opts.push(("bufsize", buffer_size.unwrap_or(bitrate).to_string()));
+                opts.push(("nal-hrd", "cbr".to_string()));
+            }
+        }
+        if let Some(gop_size) = self.gop_size {
+            opts.push(("g", gop_size.to_string()));
+            opts.push(("keyint_min", gop_size.to_string()));
+            opts.push(("sc_threshold", "0".to_string()));
+        }
+        if let Some(b_frames) = self.b_frames {
+            opts.push(("bf", b_frames.to_string()));
+        }
+        if let Some(profile) = self.profile {
+            opts.push(("profile", profile.to_str().to_string()));
+        }
+        if let Some(level) = self.level {
+            opts.push(("level", level.to_str().to_string()));
+        }
+        if let Some(tune) = self.tune {
+            opts.push(("tune", tune.to_str().to_string()));
+        }
+        opts
+    }
+}
+
+/// Encoder settings that can be changed while the encoder is running.
+#[derive(Debug, Clone, Default)]
+pub struct EncoderUpdate {
+    /// Encoder is restarted when the first frame with a new resolution is received.
+    pub resolution: Option<Resolution>,
+    /// Bitrate values are updated in place if the rate control mode is not changed,
+    /// otherwise encoder is restarted on the next frame.
+    pub rate_control: Option<RateControl>,
+}
+
 pub struct LibavH264Encoder {
     resolution: Resolution,
     frame_sender: Sender<PipelineEvent<Frame>>,
     keyframe_req_sender: Sender<()>,
+    update_sender: Sender<EncoderUpdate>,
 }
 
 impl LibavH264Encoder {
@@ -101,6 +310,7 @@ impl LibavH264Encoder {
         let (frame_sender, frame_receiver) = crossbeam_channel::bounded(5);
         let (result_sender, result_receiver) = crossbeam_channel::bounded(0);
         let (keyframe_req_sender, keyframe_req_receiver) = crossbeam_channel::unbounded();
+        let (update_sender, update_receiver) = crossbeam_channel::unbounded();
 
         let options_clone = options.clone();
         let output_id = output_id.clone();
@@ -118,6 +328,7 @@ impl LibavH264Encoder {
                     options_clone,
                     frame_receiver,
                     keyframe_req_receiver,
+                    update_receiver,
                     chunks_sender,
                     &result_sender,
                 );
@@ -138,6 +349,7 @@ impl LibavH264Encoder {
             frame_sender,
             resolution: options.resolution,
             keyframe_req_sender,
+            update_sender,
         })
     }
 
@@ -154,15 +366,155 @@ impl LibavH264Encoder {
             debug!(%err, "Failed to send keyframe request to the encoder.");
         }
     }
+
+    pub fn update(&mut self, update: EncoderUpdate) {
+        if let Some(resolution) = update.resolution {
+            self.resolution = resolution;
+        }
+        if let Err(err) = self.update_sender.send(update) {
+            debug!(%err, "Failed to send update to the encoder.");
+        }
+    }
 }
 
 fn run_encoder_thread(
-    options: Options,
+    mut options: Options,
     frame_receiver: Receiver<PipelineEvent<Frame>>,
     keyframe_req_receiver: Receiver<()>,
+    update_receiver: Receiver<EncoderUpdate>,
     packet_sender: Sender<EncoderOutputEvent>,
     result_sender: &Sender<Result<(), EncoderInitError>>,
 ) -> Result<(), EncoderInitError> {
+    let mut encoder_resolution = options.resolution;
+    let mut encoder = open_encoder(&options, encoder_resolution)?;
+
+    result_sender.send(Ok(())).unwrap();
+
+    let mut packet = Packet::empty();
+    let mut next_keyframe_pts = Duration::ZERO;
+    let mut restart_required = false;
+
+    loop {
+        let frame = match frame_receiver.recv() {
+            Ok(PipelineEvent::Data(f)) => f,
+            Ok(PipelineEvent::EOS) => break,
+            Err(_) => break,
+        };
+
+        for update in update_receiver.try_iter() {
+            if let Some(resolution) = update.resolution {
+                options.resolution = resolution;
+            }
+            if let Some(rate_control) = update.rate_control {
+                if !restart_required
+                    && can_reconfigure_in_place(&options.rate_control, &rate_control)
+                {
+                    reconfigure_rate_control(&mut encoder, &rate_control);
+                } else {
+                    restart_required = true;
+                }
+                options.rate_control = rate_control;
+            }
+        }
+
+        match resolution_action(frame.resolution, encoder_resolution, options.resolution) {
+            ResolutionAction::Encode => {}
+            ResolutionAction::Restart => restart_required = true,
+            ResolutionAction::Drop => {
+                warn!(
+                    resolution=?frame.resolution,
+                    "Received frame with unexpected resolution. Dropping"
+                );
+                continue;
+            }
+        }
+
+        let mut keyframe_requested = keyframe_req_receiver.try_recv().is_ok();
+
+        if restart_required {
+            // Drain the old encoder, so the new one starts on a keyframe boundary.
+            debug!(resolution=?frame.resolution, "Restarting encoder.");
+            if !flush_encoder(&mut encoder, &mut packet, &packet_sender) {
+                return Ok(());
+            }
+            encoder_resolution = frame.resolution;
+            encoder = open_encoder(&options, encoder_resolution)?;
+            restart_required = false;
+            keyframe_requested = true;
+        }
+
+        let mut av_frame = frame::Video::new(
+            Pixel::YUV420P,
+            encoder_resolution.width as u32,
+            encoder_resolution.height as u32,
+        );
+
+        let pts = frame.pts;
+        if let Err(e) = frame_into_av(frame, &mut av_frame) {
+            error!(
+                "Failed to convert a frame to an ffmpeg frame: {}. Dropping",
+                e.0
+            );
+            continue;
+        }
+
+        let keyframe_interval_elapsed =
+            options.keyframe_interval.is_some() && pts >= next_keyframe_pts;
+        if keyframe_requested || keyframe_interval_elapsed {
+            av_frame.set_kind(ffmpeg_next::picture::Type::I);
+            if let Some(interval) = options.keyframe_interval {
+                next_keyframe_pts = pts + interval;
+            }
+        }
+
+        if let Err(e) = encoder.send_frame(&av_frame) {
+            error!("Encoder error: {e}.");
+            continue;
+        }
+
+        while let Some(chunk) = receive_chunk(&mut encoder, &mut packet) {
+            if packet_sender.send(EncoderOutputEvent::Data(chunk)).is_err() {
+                warn!("Failed to send encoded video from H264 encoder. Channel closed.");
+                return Ok(());
+            }
+        }
+    }
+
+    if !flush_encoder(&mut encoder, &mut packet, &packet_sender) {
+        return Ok(());
+    }
+
+    if let Err(_err) = packet_sender.send(EncoderOutputEvent::VideoEOS) {
+        warn!("Failed to send EOS from H264 encoder. Channel closed.")
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum ResolutionAction {
+    Encode,
+    Restart,
+    Drop,
+}
+
+/// Frames with the resolution of the running encoder are encoded as is. Frames with
+/// the resolution from the latest update restart the encoder, any other frames are
+/// leftovers from a resolution that was already replaced.
+pub(super) fn resolution_action(
+    frame: Resolution,
+    encoder: Resolution,
+    expected: Resolution,
+) -> ResolutionAction {
+    if frame == encoder {
+        ResolutionAction::Encode
+    } else if frame == expected {
+        ResolutionAction::Restart
+    } else {
+        ResolutionAction::Drop
+    }
+}
+
+fn open_encoder(options: &Options, resolution: Resolution) -> Result<Video, EncoderInitError> {
     let codec = ffmpeg_next::codec::encoder::find(Id::H264).ok_or(EncoderInitError::NoCodec)?;
 
     let mut encoder = Context::new().encoder().video()?;
@@ -171,16 +523,14 @@ fn run_encoder_thread(
     let pts_unit_secs = Rational::new(1, 1_000_000);
     encoder.set_time_base(pts_unit_secs);
     encoder.set_format(Pixel::YUV420P);
-    encoder.set_width(options.resolution.width as u32);
-    encoder.set_height(options.resolution.height as u32);
+    encoder.set_width(resolution.width as u32);
+    encoder.set_height(resolution.height as u32);
 
     // TODO: audit settings below
     // Those values are copied from somewhere, they have to be set because libx264
     // is throwing an error if it detects default ffmpeg settings.
     let defaults = [
         ("preset", options.preset.to_str()),
-        // Quality-based VBR (0-51)
-        ("crf", "23"),
         // Override ffmpeg defaults from https://github.com/mirror/x264/blob/eaa68fad9e5d201d42fde51665f2d137ae96baf0/encoder/encoder.c#L674
         // QP curve compression - libx264 defaults to 0.6 (in case of tune=grain to 0.8)
         ("qcomp", "0.6"),
@@ -205,69 +555,131 @@ fn run_encoder_thread(
         ("subq", options.preset.default_subq_mode()),
     ];
 
-    let encoder_opts_iter = merge_options_with_defaults(&defaults, &options.raw_options);
-    let mut encoder = encoder.open_as_with(codec, Dictionary::from_iter(encoder_opts_iter))?;
-
-    result_sender.send(Ok(())).unwrap();
-
-    let mut packet = Packet::empty();
-
-    loop {
-        let frame = match frame_receiver.recv() {
-            Ok(PipelineEvent::Data(f)) => f,
-            Ok(PipelineEvent::EOS) => break,
-            Err(_) => break,
-        };
+    // Rate control ("crf" or "b") is always part of typed options.
+    let overrides: Vec<(String, String)> = options
+        .typed_options()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .filter(|(key, _)| {
+            !options
+                .raw_options
+                .iter()
+                .any(|(override_key, _)| key == override_key)
+        })
+        .chain(options.raw_options.iter().cloned())
+        .collect();
 
-        let mut av_frame = frame::Video::new(
-            Pixel::YUV420P,
-            options.resolution.width as u32,
-            options.resolution.height as u32,
-        );
+    let encoder_opts_iter = merge_options_with_defaults(&defaults, &overrides);
+    let encoder = encoder.open_as_with(codec, Dictionary::from_iter(encoder_opts_iter))?;
+    Ok(encoder)
+}
 
-        if let Err(e) = frame_into_av(frame, &mut av_frame) {
-            error!(
-                "Failed to convert a frame to an ffmpeg frame: {}. Dropping",
-                e.0
-            );
-            continue;
-        }
+/// Bitrate can be changed without restarting libx264 only if the rate control
+/// mode stays the same and VBV is not enabled or disabled.
+fn can_reconfigure_in_place(current: &RateControl, new: &RateControl) -> bool {
+    match (current, new) {
+        (
+            RateControl::Crf {
+                buffer_size: current,
+                ..
+            },
+            RateControl::Crf {
+                buffer_size: new, ..
+            },
+        )
+        | (
+            RateControl::Vbr {
+                buffer_size: current,
+                ..
+            },
+            RateControl::Vbr {
+                buffer_size: new, ..
+            },
+        ) => current.is_some() == new.is_some(),
+        (RateControl::Cbr { .. }, RateControl::Cbr { .. }) => true,
+        _ => false,
+    }
+}
 
-        if keyframe_req_receiver.try_recv().is_ok() {
-            av_frame.set_kind(ffmpeg_next::picture::Type::I);
+/// libx264 wrapper compares those values with its current configuration before
+/// encoding each frame and calls `x264_encoder_reconfig` if they differ.
+fn reconfigure_rate_control(encoder: &mut Video, rate_control: &RateControl) {
+    let (bitrate, min_bitrate, max_bitrate, buffer_size) = match *rate_control {
+        RateControl::Crf {
+            crf,
+            max_bitrate,
+            buffer_size,
+        } => {
+            let result = unsafe {
+                ffmpeg_next::ffi::av_opt_set_double(
+                    (*encoder.as_mut_ptr()).priv_data,
+                    c"crf".as_ptr(),
+                    crf as f64,
+                    0,
+                )
+            };
+            if result < 0 {
+                warn!(
+                    err=%ffmpeg_next::Error::from(result),
+                    "Failed to update CRF value of the encoder."
+                );
+            }
+            (None, None, max_bitrate, buffer_size)
         }
+        RateControl::Vbr {
+            bitrate,
+            max_bitrate,
+            buffer_size,
+        } => (Some(bitrate), None, max_bitrate, buffer_size),
+        RateControl::Cbr {
+            bitrate,
+            buffer_size,
+        } => (
+            Some(bitrate),
+            Some(bitrate),
+            Some(bitrate),
+            Some(buffer_size.unwrap_or(bitrate)),
+        ),
+    };
 
-        if let Err(e) = encoder.send_frame(&av_frame) {
-            error!("Encoder error: {e}.");
-            continue;
+    if let Some(bitrate) = bitrate {
+        encoder.set_bit_rate(bitrate as usize);
+    }
+    if let Some(max_bitrate) = max_bitrate {
+        encoder.set_max_bit_rate(max_bitrate as usize);
+    }
+    unsafe {
+        let ctx = encoder.as_mut_ptr();
+        if let Some(min_bitrate) = min_bitrate {
+            (*ctx).rc_min_rate = min_bitrate as i64;
         }
-
-        while let Some(chunk) = receive_chunk(&mut encoder, &mut packet) {
-            if packet_sender.send(EncoderOutputEvent::Data(chunk)).is_err() {
-                warn!("Failed to send encoded video from H264 encoder. Channel closed.");
-                return Ok(());
-            }
+        if let Some(buffer_size) = buffer_size {
+            (*ctx).rc_buffer_size = buffer_size as i32;
         }
     }
+    debug!(?rate_control, "Updated encoder rate control.");
+}
 
-    // Flush the encoder
+/// Drain all packets remaining in the encoder. Returns `false` if the packet
+/// receiver was already dropped.
+pub(super) fn flush_encoder(
+    encoder: &mut Video,
+    packet: &mut Packet,
+    packet_sender: &Sender<EncoderOutputEvent>,
+) -> bool {
     if let Err(e) = encoder.send_eof() {
         error!("Failed to enter draining mode on encoder: {e}.");
     }
-    while let Some(chunk) = receive_chunk(&mut encoder, &mut packet) {
+    while let Some(chunk) = receive_chunk(encoder, packet) {
         if packet_sender.send(EncoderOutputEvent::Data(chunk)).is_err() {
             warn!("Failed to send encoded video from H264 encoder. Channel closed.");
-            return Ok(());
+            return false;
         }
     }
-
-    if let Err(_err) = packet_sender.send(EncoderOutputEvent::VideoEOS) {
-        warn!("Failed to send EOS from H264 encoder. Channel closed.")
-    }
-    Ok(())
+    true
 }
 
-fn receive_chunk(encoder: &mut Video, packet: &mut Packet) -> Option<EncodedChunk> {
+pub(super) fn receive_chunk(encoder: &mut Video, packet: &mut Packet) -> Option<EncodedChunk> {
     match encoder.receive_packet(packet) {
         Ok(_) => {
             match encoded_chunk_from_av_packet(
@@ -340,10 +752,31 @@ fn frame_into_av(frame: Frame, av_frame: &mut frame::Video) -> Result<(), FrameC
     write_plane_to_av(av_frame, 1, &data.u_plane);
     write_plane_to_av(av_frame, 2, &data.v_plane);
 
+    if let Some(captions) = frame.captions {
+        attach_captions(av_frame, &captions);
+    }
+
     Ok(())
 }
 
-fn write_plane_to_av(frame: &mut frame::Video, plane: usize, data: &[u8]) {
+/// libx264 wrapper inserts `AV_FRAME_DATA_A53_CC` side data into the bitstream
+/// as ATSC A/53 SEI messages (`a53cc` option is enabled by default).
+fn attach_captions(av_frame: &mut frame::Video, captions: &[u8]) {
+    unsafe {
+        let side_data = ffmpeg_next::ffi::av_frame_new_side_data(
+            av_frame.as_mut_ptr(),
+            ffmpeg_next::ffi::AVFrameSideDataType::AV_FRAME_DATA_A53_CC,
+            captions.len(),
+        );
+        if side_data.is_null() {
+            warn!("Failed to allocate captions side data. Dropping captions.");
+            return;
+        }
+        std::slice::from_raw_parts_mut((*side_data).data, captions.len()).copy_from_slice(captions);
+    }
+}
+
+pub(super) fn write_plane_to_av(frame: &mut frame::Video, plane: usize, data: &[u8]) {
     let stride = frame.stride(plane);
     let width = frame.plane_width(plane) as usize;
 
@@ -352,7 +785,7 @@ fn write_plane_to_av(frame: &mut frame::Video, plane: usize, data: &[u8]) {
         .for_each(|(data, target)| target[..width].copy_from_slice(data));
 }
 
-fn merge_options_with_defaults<'a>(
+pub(super) fn merge_options_with_defaults<'a>(
     defaults: &'a [(&str, &str)],
     overrides: &'a [(String, String)],
 ) -> impl Iterator<Item = (&'a str, &'a str)> {
@@ -394,3 +827,128 @@ fn encoded_chunk_from_av_packet(
         kind,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use compositor_render::Resolution;
+
+    use super::{
+        can_reconfigure_in_place, resolution_action, EncoderLevel, EncoderPreset, EncoderProfile,
+        Options, RateControl, ResolutionAction,
+    };
+
+    fn options(rate_control: RateControl) -> Options {
+        Options {
+            preset: EncoderPreset::Fast,
+            resolution: Resolution {
+                width: 1280,
+                height: 720,
+            },
+            rate_control,
+            gop_size: None,
+            keyframe_interval: None,
+            b_frames: None,
+            profile: None,
+            level: None,
+            tune: None,
+            raw_options: vec![],
+        }
+    }
+
+    #[test]
+    fn default_rate_control_is_crf() {
+        assert_eq!(
+            options(RateControl::default()).typed_options(),
+            vec![("crf", "23".to_string())]
+        );
+    }
+
+    #[test]
+    fn cbr_sets_min_max_and_buffer_size() {
+        let opts = options(RateControl::Cbr {
+            bitrate: 2_000_000,
+            buffer_size: None,
+        })
+        .typed_options();
+        assert_eq!(
+            opts,
+            vec![
+                ("b", "2000000".to_string()),
+                ("minrate", "2000000".to_string()),
+                ("maxrate", "2000000".to_string()),
+                ("bufsize", "2000000".to_string()),
+                ("nal-hrd", "cbr".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn gop_profile_and_level() {
+        let opts = Options {
+            gop_size: Some(60),
+            b_frames: Some(0),
+            profile: Some(EncoderProfile::Main),
+            level: Some(EncoderLevel::Level4_1),
+            ..options(RateControl::Vbr {
+                bitrate: 1_000_000,
+                max_bitrate: Some(1_500_000),
+                buffer_size: Some(2_000_000),
+            })
+        }
+        .typed_options();
+        assert_eq!(
+            opts,
+            vec![
+                ("b", "1000000".to_string()),
+                ("maxrate", "1500000".to_string()),
+                ("bufsize", "2000000".to_string()),
+                ("g", "60".to_string()),
+                ("keyint_min", "60".to_string()),
+                ("sc_threshold", "0".to_string()),
+                ("bf", "0".to_string()),
+                ("profile", "main".to_string()),
+                ("level", "4.1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reconfigure_in_place_only_for_the_same_mode() {
+        let vbr = |buffer_size| RateControl::Vbr {
+            bitrate: 1_000_000,
+            max_bitrate: None,
+            buffer_size,
+        };
+        let cbr = RateControl::Cbr {
+            bitrate: 1_000_000,
+            buffer_size: None,
+        };
+        assert!(can_reconfigure_in_place(&vbr(None), &vbr(None)));
+        assert!(!can_reconfigure_in_place(&vbr(None), &vbr(Some(1000))));
+        assert!(!can_reconfigure_in_place(&vbr(None), &cbr));
+        assert!(can_reconfigure_in_place(&cbr, &cbr));
+    }
+
+    #[test]
+    fn resolution_change() {
+        let old = Resolution {
+            width: 1280,
+            height: 720,
+        };
+        let new = Resolution {
+            width: 640,
+            height: 360,
+        };
+        let other = Resolution {
+            width: 320,
+            height: 180,
+        };
+
+        // Frames rendered before the renderer switched resolution.
+        assert_eq!(resolution_action(old, old, new), ResolutionAction::Encode);
+        // First frame rendered after the switch, update was already received.
+        assert_eq!(resolution_action(new, old, new), ResolutionAction::Restart);
+        assert_eq!(resolution_action(new, new, new), ResolutionAction::Encode);
+        assert_eq!(resolution_action(other, old, new), ResolutionAction::Drop);
+    }
+}
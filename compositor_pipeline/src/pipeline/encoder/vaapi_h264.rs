@@ -0,0 +1,634 @@
+use std::{
+    ffi::CString,
+    path::{Path, PathBuf},
+    ptr,
+    time::Duration,
+};
+
+use compositor_render::{Frame, FrameData, OutputId, Resolution};
+use crossbeam_channel::{Receiver, Sender};
+use ffmpeg_next::{codec::Context, encoder::Video, ffi, frame, Dictionary, Packet, Rational};
+use tracing::{debug, error, span, warn, Level};
+
+use crate::{error::EncoderInitError, pipeline::types::EncoderOutputEvent, queue::PipelineEvent};
+
+use super::ffmpeg_h264::{
+    self, flush_encoder, merge_options_with_defaults, receive_chunk, resolution_action,
+    write_plane_to_av, EncoderLevel, EncoderProfile, EncoderUpdate, RateControl, ResolutionAction,
+};
+
+const VAAPI_ENCODER_NAME: &str = "h264_vaapi";
+const DRI_DIRECTORY: &str = "/dev/dri";
+
+/// `AV_HWFRAME_MAP_WRITE` and `AV_HWFRAME_MAP_OVERWRITE` flags from `libavutil/hwcontext.h`.
+const HWFRAME_MAP_WRITE: i32 = 1 << 1;
+const HWFRAME_MAP_OVERWRITE: i32 = 1 << 2;
+
+/// Resolution of the encoder opened to check if the device can encode H264.
+const PROBE_RESOLUTION: Resolution = Resolution {
+    width: 256,
+    height: 256,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Options {
+    pub resolution: Resolution,
+    /// DRM render node, e.g. `/dev/dri/renderD128`. If not provided, all
+    /// render nodes are probed in order.
+    pub device: Option<PathBuf>,
+    /// `Crf` is mapped to the constant QP mode.
+    pub rate_control: RateControl,
+    pub gop_size: Option<u32>,
+    pub keyframe_interval: Option<Duration>,
+    pub b_frames: Option<u32>,
+    pub profile: Option<EncoderProfile>,
+    pub level: Option<EncoderLevel>,
+    /// Use libx264 encoder if none of the devices supports VA-API H264 encoding.
+    pub software_fallback: bool,
+    pub raw_options: Vec<(String, String)>,
+}
+
+impl Options {
+    /// libx264 configuration used when VA-API encoder can't be initialized. Raw
+    /// options are not passed because they are specific to `h264_vaapi`.
+    pub fn software_fallback_options(&self) -> ffmpeg_h264::Options {
+        ffmpeg_h264::Options {
+            preset: ffmpeg_h264::EncoderPreset::Fast,
+            resolution: self.resolution,
+            rate_control: self.rate_control.clone(),
+            gop_size: self.gop_size,
+            keyframe_interval: self.keyframe_interval,
+            b_frames: self.b_frames,
+            profile: self.profile,
+            level: self.level,
+            tune: None,
+            raw_options: vec![],
+        }
+    }
+
+    fn typed_options(&self) -> Vec<(&'static str, String)> {
+        let mut opts = vec![];
+        match self.rate_control {
+            RateControl::Crf { crf, .. } => {
+                opts.push(("rc_mode", "CQP".to_string()));
+                opts.push(("qp", crf.to_string()));
+            }
+            RateControl::Vbr {
+                bitrate,
+                max_bitrate,
+                buffer_size,
+            } => {
+                opts.push(("rc_mode", "VBR".to_string()));
+                opts.push(("b", bitrate.to_string()));
+                if let Some(max_bitrate) = max_bitrate {
+                    opts.push(("maxrate", max_bitrate.to_string()));
+                }
+                if let Some(buffer_size) = buffer_size {
+                    opts.push(("bufsize", buffer_size.to_string()));
+                }
+            }
+            RateControl::Cbr {
+                bitrate,
+                buffer_size,
+            } => {
+                opts.push(("rc_mode", "CBR".to_string()));
+                opts.push(("b", bitrate.to_string()));
+                opts.push(("maxrate", bitrate.to_string()));
+                opts.push(("bufsize", buffer_size.unwrap_or(bitrate).to_string()));
+            }
+        }
+        if let Some(gop_size) = self.gop_size {
+            opts.push(("g", gop_size.to_string()));
+        }
+        if let Some(b_frames) = self.b_frames {
+            opts.push(("bf", b_frames.to_string()));
+        }
+        if let Some(profile) = self.profile {
+            let profile = match profile {
+                EncoderProfile::Baseline => "constrained_baseline",
+                EncoderProfile::Main => "main",
+                EncoderProfile::High => "high",
+            };
+            opts.push(("profile", profile.to_string()));
+        }
+        if let Some(level) = self.level {
+            opts.push(("level", level.to_str().to_string()));
+        }
+        opts
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Backend {
+    Vaapi(PathBuf),
+    Software,
+}
+
+/// Pick the first device that supports VA-API encoding. Device probing is passed
+/// as an argument, so this logic can be tested on machines without a GPU.
+pub(super) fn select_backend(
+    options: &Options,
+    render_nodes: &[PathBuf],
+    is_device_supported: impl Fn(&Path) -> bool,
+) -> Result<Backend, EncoderInitError> {
+    let candidates = match &options.device {
+        Some(device) => std::slice::from_ref(device),
+        None => render_nodes,
+    };
+    match candidates.iter().find(|device| is_device_supported(device)) {
+        Some(device) => Ok(Backend::Vaapi(device.clone())),
+        None if options.software_fallback => Ok(Backend::Software),
+        None => Err(EncoderInitError::NoVaapiDevice),
+    }
+}
+
+/// List DRM render nodes (`/dev/dri/renderD*`) sorted by name.
+pub(super) fn render_nodes() -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(DRI_DIRECTORY) else {
+        return vec![];
+    };
+    let mut nodes: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("renderD"))
+        .map(|entry| entry.path())
+        .collect();
+    nodes.sort();
+    nodes
+}
+
+/// Creating a VA-API device also succeeds for drivers that can only decode, so the
+/// check opens an encoder, which fails if there is no H264 encode entrypoint.
+pub(super) fn is_device_supported(device: &Path) -> bool {
+    if ffmpeg_next::encoder::find_by_name(VAAPI_ENCODER_NAME).is_none() {
+        return false;
+    }
+    let options = Options {
+        resolution: PROBE_RESOLUTION,
+        device: Some(device.to_path_buf()),
+        rate_control: RateControl::default(),
+        gop_size: None,
+        keyframe_interval: None,
+        b_frames: None,
+        profile: None,
+        level: None,
+        software_fallback: false,
+        raw_options: vec![],
+    };
+    match open_encoder(&options, device, PROBE_RESOLUTION) {
+        Ok(_) => true,
+        Err(err) => {
+            debug!(?device, %err, "Device does not support VA-API H264 encoding.");
+            false
+        }
+    }
+}
+
+pub struct VaapiH264Encoder {
+    resolution: Resolution,
+    frame_sender: Sender<PipelineEvent<Frame>>,
+    keyframe_req_sender: Sender<()>,
+    update_sender: Sender<EncoderUpdate>,
+}
+
+impl VaapiH264Encoder {
+    pub fn new(
+        output_id: &OutputId,
+        options: Options,
+        device: PathBuf,
+        chunks_sender: Sender<EncoderOutputEvent>,
+    ) -> Result<Self, EncoderInitError> {
+        let (frame_sender, frame_receiver) = crossbeam_channel::bounded(5);
+        let (result_sender, result_receiver) = crossbeam_channel::bounded(0);
+        let (keyframe_req_sender, keyframe_req_receiver) = crossbeam_channel::unbounded();
+        let (update_sender, update_receiver) = crossbeam_channel::unbounded();
+
+        let options_clone = options.clone();
+        let output_id = output_id.clone();
+
+        std::thread::Builder::new()
+            .name(format!("VA-API encoder thread for output {}", output_id))
+            .spawn(move || {
+                let _span = span!(
+                    Level::INFO,
+                    "h264 vaapi encoder",
+                    output_id = output_id.to_string(),
+                    device = device.to_string_lossy().to_string(),
+                )
+                .entered();
+                let encoder_result = run_encoder_thread(
+                    options_clone,
+                    device,
+                    frame_receiver,
+                    keyframe_req_receiver,
+                    update_receiver,
+                    chunks_sender,
+                    &result_sender,
+                );
+
+                if let Err(err) = encoder_result {
+                    warn!(%err, "Encoder thread finished with an error.");
+                    if let Err(err) = result_sender.send(Err(err)) {
+                        warn!(%err, "Failed to send error info. Result channel already closed.");
+                    }
+                }
+                debug!("Encoder thread finished.");
+            })
+            .unwrap();
+
+        result_receiver.recv().unwrap()?;
+
+        Ok(Self {
+            frame_sender,
+            resolution: options.resolution,
+            keyframe_req_sender,
+            update_sender,
+        })
+    }
+
+    pub fn frame_sender(&self) -> &Sender<PipelineEvent<Frame>> {
+        &self.frame_sender
+    }
+
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    pub fn request_keyframe(&self) {
+        if let Err(err) = self.keyframe_req_sender.send(()) {
+            debug!(%err, "Failed to send keyframe request to the encoder.");
+        }
+    }
+
+    pub fn update(&mut self, update: EncoderUpdate) {
+        if let Some(resolution) = update.resolution {
+            self.resolution = resolution;
+        }
+        if let Err(err) = self.update_sender.send(update) {
+            debug!(%err, "Failed to send update to the encoder.");
+        }
+    }
+}
+
+/// Encoder together with VA-API device and frames pool that it uses.
+struct VaapiEncoder {
+    // Encoder has to be dropped before hardware contexts.
+    encoder: Video,
+    frames: HwFrames,
+    _device: HwDevice,
+}
+
+fn run_encoder_thread(
+    mut options: Options,
+    device: PathBuf,
+    frame_receiver: Receiver<PipelineEvent<Frame>>,
+    keyframe_req_receiver: Receiver<()>,
+    update_receiver: Receiver<EncoderUpdate>,
+    packet_sender: Sender<EncoderOutputEvent>,
+    result_sender: &Sender<Result<(), EncoderInitError>>,
+) -> Result<(), EncoderInitError> {
+    let mut encoder_resolution = options.resolution;
+    let mut encoder = open_encoder(&options, &device, encoder_resolution)?;
+
+    result_sender.send(Ok(())).unwrap();
+
+    let mut packet = Packet::empty();
+    let mut next_keyframe_pts = Duration::ZERO;
+    let mut restart_required = false;
+
+    loop {
+        let frame = match frame_receiver.recv() {
+            Ok(PipelineEvent::Data(f)) => f,
+            Ok(PipelineEvent::EOS) => break,
+            Err(_) => break,
+        };
+
+        // VA-API rate control can't be reconfigured in place, so any update
+        // restarts the encoder.
+        for update in update_receiver.try_iter() {
+            if let Some(resolution) = update.resolution {
+                options.resolution = resolution;
+            }
+            if let Some(rate_control) = update.rate_control {
+                options.rate_control = rate_control;
+                restart_required = true;
+            }
+        }
+
+        match resolution_action(frame.resolution, encoder_resolution, options.resolution) {
+            ResolutionAction::Encode => {}
+            ResolutionAction::Restart => restart_required = true,
+            ResolutionAction::Drop => {
+                warn!(
+                    resolution=?frame.resolution,
+                    "Received frame with unexpected resolution. Dropping"
+                );
+                continue;
+            }
+        }
+
+        let mut keyframe_requested = keyframe_req_receiver.try_recv().is_ok();
+
+        if restart_required {
+            debug!(resolution=?frame.resolution, "Restarting encoder.");
+            if !flush_encoder(&mut encoder.encoder, &mut packet, &packet_sender) {
+                return Ok(());
+            }
+            encoder_resolution = frame.resolution;
+            encoder = open_encoder(&options, &device, encoder_resolution)?;
+            restart_required = false;
+            keyframe_requested = true;
+        }
+
+        let pts = frame.pts;
+        let mut hw_frame = match upload_frame(frame, &encoder.frames) {
+            Ok(hw_frame) => hw_frame,
+            Err(err) => {
+                error!("Failed to upload a frame to the VA-API surface: {err}. Dropping");
+                continue;
+            }
+        };
+        hw_frame.set_pts(Some(pts.as_micros() as i64));
+
+        let keyframe_interval_elapsed =
+            options.keyframe_interval.is_some() && pts >= next_keyframe_pts;
+        if keyframe_requested || keyframe_interval_elapsed {
+            hw_frame.set_kind(ffmpeg_next::picture::Type::I);
+            if let Some(interval) = options.keyframe_interval {
+                next_keyframe_pts = pts + interval;
+            }
+        }
+
+        if let Err(e) = encoder.encoder.send_frame(&hw_frame) {
+            error!("Encoder error: {e}.");
+            continue;
+        }
+
+        while let Some(chunk) = receive_chunk(&mut encoder.encoder, &mut packet) {
+            if packet_sender.send(EncoderOutputEvent::Data(chunk)).is_err() {
+                warn!("Failed to send encoded video from H264 encoder. Channel closed.");
+                return Ok(());
+            }
+        }
+    }
+
+    if !flush_encoder(&mut encoder.encoder, &mut packet, &packet_sender) {
+        return Ok(());
+    }
+
+    if let Err(_err) = packet_sender.send(EncoderOutputEvent::VideoEOS) {
+        warn!("Failed to send EOS from H264 encoder. Channel closed.")
+    }
+    Ok(())
+}
+
+fn open_encoder(
+    options: &Options,
+    device: &Path,
+    resolution: Resolution,
+) -> Result<VaapiEncoder, EncoderInitError> {
+    let codec =
+        ffmpeg_next::encoder::find_by_name(VAAPI_ENCODER_NAME).ok_or(EncoderInitError::NoCodec)?;
+
+    let hw_device = HwDevice::new(device)?;
+    let hw_frames = HwFrames::new(&hw_device, resolution)?;
+
+    let mut encoder = Context::new().encoder().video()?;
+
+    // We set this to 1 / 1_000_000, bc we use `as_micros` to convert frames to AV packets.
+    let pts_unit_secs = Rational::new(1, 1_000_000);
+    encoder.set_time_base(pts_unit_secs);
+    encoder.set_width(resolution.width as u32);
+    encoder.set_height(resolution.height as u32);
+    unsafe {
+        let ctx = encoder.as_mut_ptr();
+        (*ctx).pix_fmt = ffi::AVPixelFormat::AV_PIX_FMT_VAAPI;
+        // Encoder holds its own reference to the frames context.
+        (*ctx).hw_frames_ctx = ffi::av_buffer_ref(hw_frames.0);
+        if (*ctx).hw_frames_ctx.is_null() {
+            return Err(ffmpeg_next::Error::Other {
+                errno: ffmpeg_next::error::ENOMEM,
+            }
+            .into());
+        }
+    }
+
+    let overrides: Vec<(String, String)> = options
+        .typed_options()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .filter(|(key, _)| {
+            !options
+                .raw_options
+                .iter()
+                .any(|(override_key, _)| key == override_key)
+        })
+        .chain(options.raw_options.iter().cloned())
+        .collect();
+
+    let encoder_opts_iter = merge_options_with_defaults(&[], &overrides);
+    let encoder = encoder.open_as_with(codec, Dictionary::from_iter(encoder_opts_iter))?;
+
+    Ok(VaapiEncoder {
+        encoder,
+        frames: hw_frames,
+        _device: hw_device,
+    })
+}
+
+/// Writes planar YUV 4:2:0 frame as NV12 directly into a VA-API surface from the
+/// frames pool. The surface is mapped into memory, so there is no intermediate
+/// software frame that would have to be copied again by `av_hwframe_transfer_data`.
+///
+/// This is not a zero-copy path: the frame was already read back from the GPU by
+/// the renderer. Importing rendered wgpu textures as VA surfaces (e.g. through
+/// DMA-BUF) is not implemented.
+fn upload_frame(frame: Frame, frames: &HwFrames) -> Result<frame::Video, ffmpeg_next::Error> {
+    let FrameData::PlanarYuv420(data) = frame.data else {
+        error!("Unsupported pixel format {:?}", frame.data);
+        return Err(ffmpeg_next::Error::InvalidData);
+    };
+    let width = frame.resolution.width;
+    let height = frame.resolution.height;
+    if data.y_plane.len() != width * height
+        || data.u_plane.len() != (width / 2) * (height / 2)
+        || data.v_plane.len() != (width / 2) * (height / 2)
+    {
+        error!(
+            "YUV planes do not match frame resolution {:?}",
+            frame.resolution
+        );
+        return Err(ffmpeg_next::Error::InvalidData);
+    }
+
+    let mut hw_frame = frame::Video::empty();
+    let mut mapped = frame::Video::empty();
+    unsafe {
+        let result = ffi::av_hwframe_get_buffer(frames.0, hw_frame.as_mut_ptr(), 0);
+        if result < 0 {
+            return Err(ffmpeg_next::Error::from(result));
+        }
+        (*mapped.as_mut_ptr()).format = ffi::AVPixelFormat::AV_PIX_FMT_NV12 as i32;
+        let result = ffi::av_hwframe_map(
+            mapped.as_mut_ptr(),
+            hw_frame.as_ptr(),
+            HWFRAME_MAP_WRITE | HWFRAME_MAP_OVERWRITE,
+        );
+        if result < 0 {
+            return Err(ffmpeg_next::Error::from(result));
+        }
+    }
+
+    write_plane_to_av(&mut mapped, 0, &data.y_plane);
+
+    let uv_stride = mapped.stride(1);
+    let chroma_width = width / 2;
+    data.u_plane
+        .chunks(chroma_width)
+        .zip(data.v_plane.chunks(chroma_width))
+        .zip(mapped.data_mut(1).chunks_mut(uv_stride))
+        .for_each(|((u_row, v_row), target)| {
+            for (x, (u, v)) in u_row.iter().zip(v_row).enumerate() {
+                target[2 * x] = *u;
+                target[2 * x + 1] = *v;
+            }
+        });
+
+    // Surface is unmapped when the mapping is released.
+    drop(mapped);
+
+    Ok(hw_frame)
+}
+
+struct HwDevice(*mut ffi::AVBufferRef);
+
+impl HwDevice {
+    fn new(device: &Path) -> Result<Self, EncoderInitError> {
+        let device_path = CString::new(device.to_string_lossy().as_bytes())
+            .map_err(|_| EncoderInitError::NoVaapiDevice)?;
+        let mut device_ref = ptr::null_mut();
+        let result = unsafe {
+            ffi::av_hwdevice_ctx_create(
+                &mut device_ref,
+                ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_VAAPI,
+                device_path.as_ptr(),
+                ptr::null_mut(),
+                0,
+            )
+        };
+        if result < 0 {
+            return Err(ffmpeg_next::Error::from(result).into());
+        }
+        Ok(Self(device_ref))
+    }
+}
+
+impl Drop for HwDevice {
+    fn drop(&mut self) {
+        unsafe { ffi::av_buffer_unref(&mut self.0) }
+    }
+}
+
+struct HwFrames(*mut ffi::AVBufferRef);
+
+impl HwFrames {
+    fn new(device: &HwDevice, resolution: Resolution) -> Result<Self, EncoderInitError> {
+        let frames_ref = unsafe { ffi::av_hwframe_ctx_alloc(device.0) };
+        if frames_ref.is_null() {
+            return Err(ffmpeg_next::Error::Other {
+                errno: ffmpeg_next::error::ENOMEM,
+            }
+            .into());
+        }
+        // Wrap it first, so the reference is released on error.
+        let frames = Self(frames_ref);
+        unsafe {
+            let frames_ctx = (*frames.0).data as *mut ffi::AVHWFramesContext;
+            (*frames_ctx).format = ffi::AVPixelFormat::AV_PIX_FMT_VAAPI;
+            (*frames_ctx).sw_format = ffi::AVPixelFormat::AV_PIX_FMT_NV12;
+            (*frames_ctx).width = resolution.width as i32;
+            (*frames_ctx).height = resolution.height as i32;
+            (*frames_ctx).initial_pool_size = 20;
+
+            let result = ffi::av_hwframe_ctx_init(frames.0);
+            if result < 0 {
+                return Err(ffmpeg_next::Error::from(result).into());
+            }
+        }
+        Ok(frames)
+    }
+}
+
+impl Drop for HwFrames {
+    fn drop(&mut self) {
+        unsafe { ffi::av_buffer_unref(&mut self.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use compositor_render::Resolution;
+
+    use crate::{error::EncoderInitError, pipeline::encoder::ffmpeg_h264::RateControl};
+
+    use super::{select_backend, Backend, Options};
+
+    fn options(device: Option<&str>, software_fallback: bool) -> Options {
+        Options {
+            resolution: Resolution {
+                width: 1280,
+                height: 720,
+            },
+            device: device.map(PathBuf::from),
+            rate_control: RateControl::default(),
+            gop_size: None,
+            keyframe_interval: None,
+            b_frames: None,
+            profile: None,
+            level: None,
+            software_fallback,
+            raw_options: vec![],
+        }
+    }
+
+    fn render_nodes() -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/dev/dri/renderD128"),
+            PathBuf::from("/dev/dri/renderD129"),
+        ]
+    }
+
+    #[test]
+    fn picks_first_supported_render_node() {
+        let backend = select_backend(&options(None, true), &render_nodes(), |device| {
+            device == Path::new("/dev/dri/renderD129")
+        });
+        assert_eq!(
+            backend.unwrap(),
+            Backend::Vaapi(PathBuf::from("/dev/dri/renderD129"))
+        );
+    }
+
+    #[test]
+    fn explicit_device_is_the_only_candidate() {
+        let backend = select_backend(
+            &options(Some("/dev/dri/renderD130"), true),
+            &render_nodes(),
+            |device| device == Path::new("/dev/dri/renderD128"),
+        );
+        assert_eq!(backend.unwrap(), Backend::Software);
+    }
+
+    #[test]
+    fn falls_back_to_software_without_devices() {
+        let backend = select_backend(&options(None, true), &[], |_| true);
+        assert_eq!(backend.unwrap(), Backend::Software);
+    }
+
+    #[test]
+    fn fails_without_devices_if_fallback_disabled() {
+        let backend = select_backend(&options(None, false), &render_nodes(), |_| false);
+        assert!(matches!(backend, Err(EncoderInitError::NoVaapiDevice)));
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
 };
 
 use compositor_render::{Frame, InputId};
-use crossbeam_channel::{bounded, Receiver};
+use crossbeam_channel::{bounded, Receiver, Sender};
 use rtp::{RtpReceiver, RtpReceiverOptions};
 
 use self::mp4::{Mp4, Mp4Options};
@@ -64,6 +64,9 @@ pub(super) enum VideoInputReceiver {
     Encoded {
         chunk_receiver: Receiver<PipelineEvent<EncodedChunk>>,
         decoder_options: VideoDecoderOptions,
+        /// Used by the decoder to request a keyframe from the source after a decoding
+        /// error. `None` if the input does not support it.
+        keyframe_request_sender: Option<Sender<()>>,
     },
 }
 
@@ -158,6 +161,7 @@ fn start_input_threads(
             VideoInputReceiver::Encoded {
                 chunk_receiver,
                 decoder_options,
+                keyframe_request_sender,
             } => {
                 let (sender, receiver) = bounded(10);
                 start_video_decoder_thread(
@@ -165,6 +169,7 @@ fn start_input_threads(
                     pipeline_ctx,
                     chunk_receiver,
                     sender,
+                    keyframe_request_sender,
                     input_id.clone(),
                 )?;
                 Some(receiver)
@@ -142,6 +142,7 @@ impl ChannelCallbackAdapter {
             data: FrameData::InterleavedYuv422(data),
             resolution: Resolution { width, height },
             pts,
+            captions: None,
         }
     }
 
@@ -110,6 +110,7 @@ impl Mp4 {
                     decoder_options: VideoDecoderOptions {
                         decoder: options.video_decoder,
                     },
+                    keyframe_request_sender: None,
                 };
                 (Some(reader), Some(input_receiver))
             }
@@ -10,10 +10,10 @@ use crate::{
     queue::PipelineEvent,
 };
 use compositor_render::InputId;
-use crossbeam_channel::{bounded, Receiver, Sender};
-use rtcp::header::PacketType;
+use crossbeam_channel::{bounded, never, select, Receiver, Sender};
+use rtcp::{header::PacketType, payload_feedbacks::picture_loss_indication::PictureLossIndication};
 use tracing::{debug, error, span, warn, Level};
-use webrtc_util::Unmarshal;
+use webrtc_util::{Marshal, Unmarshal};
 
 use self::{
     depayloader::{Depayloader, DepayloaderNewError},
@@ -89,25 +89,33 @@ impl RtpReceiver {
         opts: RtpReceiverOptions,
     ) -> Result<InputInitResult, RtpReceiverError> {
         let should_close = Arc::new(AtomicBool::new(false));
+        let (rtcp_tx, rtcp_rx) = bounded(10);
+        let (keyframe_request_tx, keyframe_request_rx) = bounded(1);
 
         let (port, packets_rx) = match opts.transport_protocol {
             TransportProtocol::Udp => {
-                start_udp_reader_thread(input_id, &opts, should_close.clone())?
+                start_udp_reader_thread(input_id, &opts, rtcp_rx, should_close.clone())?
             }
             TransportProtocol::TcpServer => {
-                start_tcp_server_thread(input_id, &opts, should_close.clone())?
+                start_tcp_server_thread(input_id, &opts, rtcp_rx, should_close.clone())?
             }
         };
 
         let depayloader = Depayloader::new(&opts.stream)?;
 
-        let depayloader_receivers =
-            Self::start_depayloader_thread(input_id, packets_rx, depayloader);
+        let depayloader_receivers = Self::start_depayloader_thread(
+            input_id,
+            packets_rx,
+            depayloader,
+            keyframe_request_rx,
+            rtcp_tx,
+        );
 
         let video = match (depayloader_receivers.video, opts.stream.video) {
             (Some(chunk_receiver), Some(stream)) => Some(VideoInputReceiver::Encoded {
                 chunk_receiver,
                 decoder_options: stream.options,
+                keyframe_request_sender: Some(keyframe_request_tx),
             }),
             _ => None,
         };
@@ -134,6 +142,8 @@ impl RtpReceiver {
         input_id: &InputId,
         receiver: Receiver<bytes::Bytes>,
         depayloader: Depayloader,
+        keyframe_request_receiver: Receiver<()>,
+        rtcp_sender: Sender<bytes::Bytes>,
     ) -> DepayloaderThreadReceivers {
         let (video_sender, video_receiver) = depayloader
             .video
@@ -156,7 +166,14 @@ impl RtpReceiver {
                     input_id = input_id.to_string()
                 )
                 .entered();
-                run_depayloader_thread(receiver, depayloader, video_sender, audio_sender)
+                run_depayloader_thread(
+                    receiver,
+                    depayloader,
+                    video_sender,
+                    audio_sender,
+                    keyframe_request_receiver,
+                    rtcp_sender,
+                )
             })
             .unwrap();
 
@@ -179,6 +196,8 @@ fn run_depayloader_thread(
     mut depayloader: Depayloader,
     video_sender: Option<Sender<PipelineEvent<EncodedChunk>>>,
     audio_sender: Option<Sender<PipelineEvent<EncodedChunk>>>,
+    mut keyframe_request_receiver: Receiver<()>,
+    rtcp_sender: Sender<bytes::Bytes>,
 ) {
     let mut audio_eos_received = audio_sender.as_ref().map(|_| false);
     let mut video_eos_received = video_sender.as_ref().map(|_| false);
@@ -202,9 +221,22 @@ fn run_depayloader_thread(
         }
     };
     loop {
-        let Ok(mut buffer) = receiver.recv() else {
-            debug!("Closing RTP depayloader thread.");
-            break;
+        let mut buffer = select! {
+            recv(receiver) -> buffer => match buffer {
+                Ok(buffer) => buffer,
+                Err(_) => {
+                    debug!("Closing RTP depayloader thread.");
+                    break;
+                }
+            },
+            recv(keyframe_request_receiver) -> request => {
+                match request {
+                    Ok(()) => send_picture_loss_indication(video_ssrc, &rtcp_sender),
+                    // decoder was dropped, stop listening for keyframe requests
+                    Err(_) => keyframe_request_receiver = never(),
+                }
+                continue;
+            }
         };
 
         match rtp::packet::Packet::unmarshal(&mut buffer.clone()) {
@@ -278,6 +310,29 @@ fn run_depayloader_thread(
     maybe_send_video_eos();
 }
 
+/// Request a keyframe from the sender.
+///
+/// [RFC 4585, section 6.3.1. Picture Loss Indication](https://datatracker.ietf.org/doc/html/rfc4585#section-6.3.1)
+fn send_picture_loss_indication(video_ssrc: Option<u32>, rtcp_sender: &Sender<bytes::Bytes>) {
+    let Some(media_ssrc) = video_ssrc else {
+        debug!("Cannot request a keyframe, video SSRC is not known yet.");
+        return;
+    };
+    let pli = PictureLossIndication {
+        sender_ssrc: 0,
+        media_ssrc,
+    };
+    match pli.marshal() {
+        Ok(packet) => {
+            debug!(media_ssrc, "Sending RTCP PLI.");
+            if rtcp_sender.try_send(packet).is_err() {
+                debug!("Failed to send RTCP PLI packet. Channel closed or full.");
+            }
+        }
+        Err(err) => warn!(%err, "Failed to serialize RTCP PLI packet."),
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum DepayloadingError {
     #[error("Bad payload type {0}")]
@@ -1,6 +1,6 @@
 use std::{
     collections::VecDeque,
-    io::Read,
+    io::{Read, Write},
     net::TcpStream,
     sync::{atomic::AtomicBool, Arc},
     thread,
@@ -20,6 +20,7 @@ use super::{RtpReceiverError, RtpReceiverOptions};
 pub(super) fn start_tcp_server_thread(
     input_id: &InputId,
     opts: &RtpReceiverOptions,
+    rtcp_rx: Receiver<bytes::Bytes>,
     should_close: Arc<AtomicBool>,
 ) -> Result<(Port, Receiver<bytes::Bytes>), RtpReceiverError> {
     let (packets_tx, packets_rx) = bounded(1000);
@@ -48,7 +49,7 @@ pub(super) fn start_tcp_server_thread(
                 input_id = input_id.to_string()
             )
             .entered();
-            run_tcp_server_thread(socket, packets_tx, should_close);
+            run_tcp_server_thread(socket, packets_tx, rtcp_rx, should_close);
             debug!("Closing RTP receiver thread (TCP server).");
         })
         .unwrap();
@@ -59,6 +60,7 @@ pub(super) fn start_tcp_server_thread(
 fn run_tcp_server_thread(
     socket: std::net::TcpListener,
     packets_tx: Sender<bytes::Bytes>,
+    rtcp_rx: Receiver<bytes::Bytes>,
     should_close: Arc<AtomicBool>,
 ) {
     // make accept non blocking so we have a chance to handle should_close value
@@ -76,12 +78,20 @@ fn run_tcp_server_thread(
         connected_socket = Some(socket);
     }
 
-    let socket = match connected_socket {
-        Some(socket) => TcpReadPacketStream::new(socket, should_close.clone()),
-        None => {
-            return;
+    let Some(socket) = connected_socket else {
+        return;
+    };
+    let mut rtcp_socket = match socket.try_clone() {
+        Ok(socket) => Some(socket),
+        Err(err) => {
+            error!(
+                "Failed to clone TCP socket, RTCP feedback will not be sent: {}",
+                ErrorStack::new(&err).into_string()
+            );
+            None
         }
     };
+    let socket = TcpReadPacketStream::new(socket, should_close.clone());
 
     for packet in socket {
         trace!(size_bytes = packet.len(), "Received RTP packet");
@@ -89,9 +99,31 @@ fn run_tcp_server_thread(
             debug!("Failed to send raw RTP packet from TCP server element. Channel closed.");
             return;
         }
+
+        for packet in rtcp_rx.try_iter() {
+            let Some(rtcp_socket) = &mut rtcp_socket else {
+                continue;
+            };
+            if let Err(err) = write_packet(rtcp_socket, &packet) {
+                error!(
+                    "Failed to send RTCP packet: {}",
+                    ErrorStack::new(&err).into_string()
+                );
+            }
+        }
     }
 }
 
+/// Write a packet with 2 byte length prefix.
+///
+/// [RFC 4571](https://datatracker.ietf.org/doc/html/rfc4571)
+fn write_packet(socket: &mut TcpStream, packet: &[u8]) -> std::io::Result<()> {
+    let len = u16::try_from(packet.len())
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "Packet too large"))?;
+    socket.write_all(&len.to_be_bytes())?;
+    socket.write_all(packet)
+}
+
 struct TcpReadPacketStream {
     socket: TcpStream,
     buf: VecDeque<u8>,
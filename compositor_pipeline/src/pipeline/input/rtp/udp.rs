@@ -1,4 +1,5 @@
 use std::{
+    net::SocketAddr,
     sync::{atomic::AtomicBool, Arc},
     thread,
 };
@@ -15,6 +16,7 @@ use super::{RtpReceiverError, RtpReceiverOptions};
 pub(super) fn start_udp_reader_thread(
     input_id: &InputId,
     opts: &RtpReceiverOptions,
+    rtcp_rx: Receiver<Bytes>,
     should_close: Arc<AtomicBool>,
 ) -> Result<(Port, Receiver<bytes::Bytes>), RtpReceiverError> {
     let (packets_tx, packets_rx) = unbounded();
@@ -54,7 +56,7 @@ pub(super) fn start_udp_reader_thread(
                 input_id = input_id.to_string()
             )
             .entered();
-            run_udp_receiver_thread(socket, packets_tx, should_close);
+            run_udp_receiver_thread(socket, packets_tx, rtcp_rx, should_close);
             debug!("Closing RTP receiver thread (UDP).");
         })
         .unwrap();
@@ -65,17 +67,30 @@ pub(super) fn start_udp_reader_thread(
 fn run_udp_receiver_thread(
     socket: std::net::UdpSocket,
     packets_tx: Sender<Bytes>,
+    rtcp_rx: Receiver<Bytes>,
     should_close: Arc<AtomicBool>,
 ) {
     let mut buffer = BytesMut::zeroed(65536);
+    let mut sender_addr: Option<SocketAddr> = None;
 
     loop {
         if should_close.load(std::sync::atomic::Ordering::Relaxed) {
             return;
         }
 
+        // RTCP feedback is sent back to the address the RTP stream is received from.
+        for packet in rtcp_rx.try_iter() {
+            let Some(addr) = sender_addr else {
+                debug!("Dropping RTCP packet, sender address is not known yet.");
+                continue;
+            };
+            if let Err(err) = socket.send_to(&packet, addr) {
+                warn!("Failed to send RTCP packet: {err}");
+            }
+        }
+
         // This can be faster if we batched sending the packets through the channel
-        let (received_bytes, _) = match socket.recv_from(&mut buffer) {
+        let (received_bytes, addr) = match socket.recv_from(&mut buffer) {
             Ok(n) => n,
             Err(e) => match e.kind() {
                 std::io::ErrorKind::WouldBlock => continue,
@@ -85,6 +100,7 @@ fn run_udp_receiver_thread(
                 }
             },
         };
+        sender_addr = Some(addr);
 
         if packets_tx
             .send(Bytes::copy_from_slice(&buffer[..received_bytes]))
@@ -4,12 +4,16 @@ use compositor_render::{
 use crossbeam_channel::{bounded, Receiver, Sender};
 use mp4::{Mp4FileWriter, Mp4OutputOptions};
 
-use crate::{audio_mixer::OutputSamples, error::RegisterOutputError, queue::PipelineEvent};
+use crate::{
+    audio_mixer::OutputSamples,
+    error::{RegisterOutputError, UpdateEncoderError},
+    queue::PipelineEvent,
+};
 
 use self::rtp::{RtpSender, RtpSenderOptions};
 
 use super::{
-    encoder::{AudioEncoderOptions, Encoder, EncoderOptions, VideoEncoderOptions},
+    encoder::{AudioEncoderOptions, Encoder, EncoderOptions, VideoEncoder, VideoEncoderOptions},
     types::EncoderOutputEvent,
     PipelineCtx, Port, RawDataReceiver,
 };
@@ -215,6 +219,33 @@ impl Output {
         Ok(())
     }
 
+    /// Returns video encoder if its configuration can be updated with
+    /// a provided resolution.
+    pub(super) fn video_encoder_for_update(
+        &mut self,
+        output_id: &OutputId,
+        resolution: Option<Resolution>,
+    ) -> Result<&mut VideoEncoder, UpdateEncoderError> {
+        let encoder = match self {
+            Output::Rtp { encoder, .. } => encoder,
+            Output::EncodedData { encoder } => encoder,
+            Output::Mp4 { encoder, .. } => {
+                if resolution.is_some() {
+                    return Err(UpdateEncoderError::ResolutionChangeNotSupported(
+                        output_id.clone(),
+                    ));
+                }
+                encoder
+            }
+            Output::RawData { .. } => return Err(UpdateEncoderError::RawOutput(output_id.clone())),
+        };
+
+        encoder
+            .video
+            .as_mut()
+            .ok_or_else(|| UpdateEncoderError::NoVideoOutput(output_id.clone()))
+    }
+
     pub(super) fn output_frame_format(&self) -> Option<OutputFrameFormat> {
         match &self {
             Output::Rtp { encoder, .. } => encoder
@@ -27,6 +27,7 @@ pub struct PipelineOutput {
     pub output: output::Output,
     pub video_end_condition: Option<PipelineOutputEndConditionState>,
     pub audio_end_condition: Option<PipelineOutputEndConditionState>,
+    pub captions_source: Option<InputId>,
 }
 
 pub(super) enum OutputSender<T> {
@@ -51,6 +52,18 @@ impl Pipeline {
             return Err(RegisterOutputError::AlreadyRegistered(output_id));
         }
 
+        let captions_source = video
+            .as_ref()
+            .and_then(|video| video.captions_source.clone());
+        if let Some(ref input_id) = captions_source {
+            if !self.inputs.contains_key(input_id) {
+                return Err(RegisterOutputError::UnknownCaptionsSource(
+                    output_id,
+                    input_id.clone(),
+                ));
+            }
+        }
+
         let (output, output_result) = output_options.new_output(&output_id, &self.ctx)?;
 
         let output = PipelineOutput {
@@ -67,6 +80,7 @@ impl Pipeline {
                     &self.inputs,
                 )
             }),
+            captions_source: captions_source.clone(),
         };
 
         if let (Some(video_opts), Some(resolution), Some(format)) = (
@@ -96,6 +110,12 @@ impl Pipeline {
             );
         }
 
+        if let Some(input_id) = captions_source {
+            self.captions_sources
+                .write()
+                .unwrap()
+                .insert(output_id.clone(), input_id);
+        }
         self.outputs.insert(output_id.clone(), output);
 
         Ok(output_result)
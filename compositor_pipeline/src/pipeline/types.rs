@@ -60,6 +60,18 @@ pub enum VideoDecoder {
     FFmpegH264,
     #[cfg(feature = "vk-video")]
     VulkanVideoH264,
+    /// Try decoders from [`VideoDecoder::AUTO_FALLBACK_ORDER`] and use the first one
+    /// that can be initialized.
+    Auto,
+}
+
+impl VideoDecoder {
+    /// Order in which decoders are tried when [`VideoDecoder::Auto`] is selected.
+    pub const AUTO_FALLBACK_ORDER: &'static [VideoDecoder] = &[
+        #[cfg(feature = "vk-video")]
+        VideoDecoder::VulkanVideoH264,
+        VideoDecoder::FFmpegH264,
+    ];
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -174,11 +174,11 @@ impl VideoQueueInput {
             // if stream should not start yet, do not send any frames
             Some(offset) if offset > buffer_pts => None,
             // if stream is started then take the frames
-            Some(offset) => self.queue.front().cloned().map(|mut frame| {
+            Some(offset) => take_front_frame(&mut self.queue).map(|mut frame| {
                 frame.pts += offset;
                 frame
             }),
-            None => self.queue.front().cloned().map(|mut frame| {
+            None => take_front_frame(&mut self.queue).map(|mut frame| {
                 frame.pts = (input_start_time + frame.pts).duration_since(queue_start);
                 frame
             }),
@@ -278,7 +278,14 @@ impl VideoQueueInput {
                 .map(|(index, _frame)| index);
 
             if let Some(index) = closest_diff_frame_index {
-                self.queue.drain(0..index);
+                let dropped_captions: Vec<_> = self
+                    .queue
+                    .drain(0..index)
+                    .filter_map(|frame| frame.captions)
+                    .collect();
+                if let Some(frame) = self.queue.front_mut() {
+                    prepend_captions(frame, dropped_captions);
+                }
             }
 
             if !self.queue.is_empty() {
@@ -365,3 +372,105 @@ impl VideoQueueInput {
         Ok(())
     }
 }
+
+/// Returns a copy of the oldest frame in the queue. The same frame can be returned for
+/// multiple batches, so captions are moved out of the queued frame to pass them only once.
+fn take_front_frame(queue: &mut VecDeque<Frame>) -> Option<Frame> {
+    let frame = queue.front_mut()?;
+    let mut result = frame.clone();
+    result.captions = frame.captions.take();
+    Some(result)
+}
+
+/// Captions from frames that were dropped by the queue are attached to the next frame, so
+/// they are not lost when the input framerate is higher than the output framerate.
+fn prepend_captions(frame: &mut Frame, dropped_captions: Vec<bytes::Bytes>) {
+    if dropped_captions.is_empty() {
+        return;
+    }
+    let captions: bytes::BytesMut = dropped_captions
+        .iter()
+        .chain(frame.captions.as_ref())
+        .flat_map(|captions| captions.iter().copied())
+        .collect();
+    frame.captions = Some(captions.freeze());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, time::Duration};
+
+    use bytes::Bytes;
+    use compositor_render::{Frame, FrameData, Resolution};
+
+    use super::{prepend_captions, take_front_frame};
+
+    fn frame(captions: Option<&'static [u8]>) -> Frame {
+        Frame {
+            data: FrameData::InterleavedYuv422(Bytes::new()),
+            resolution: Resolution {
+                width: 0,
+                height: 0,
+            },
+            pts: Duration::ZERO,
+            captions: captions.map(Bytes::from_static),
+        }
+    }
+
+    #[test]
+    fn frame_captions_are_kept_if_nothing_was_dropped() {
+        let mut with_captions = frame(Some(&[1, 2, 3]));
+        prepend_captions(&mut with_captions, vec![]);
+        assert_eq!(
+            with_captions.captions.as_deref(),
+            Some([1, 2, 3].as_slice())
+        );
+
+        let mut without_captions = frame(None);
+        prepend_captions(&mut without_captions, vec![]);
+        assert_eq!(without_captions.captions, None);
+    }
+
+    #[test]
+    fn dropped_captions_are_placed_before_frame_captions() {
+        let mut frame = frame(Some(&[7, 8, 9]));
+        prepend_captions(
+            &mut frame,
+            vec![
+                Bytes::from_static(&[1, 2, 3]),
+                Bytes::from_static(&[4, 5, 6]),
+            ],
+        );
+        assert_eq!(
+            frame.captions.as_deref(),
+            Some([1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice())
+        );
+    }
+
+    #[test]
+    fn dropped_captions_are_attached_to_frame_without_captions() {
+        let mut frame = frame(None);
+        prepend_captions(&mut frame, vec![Bytes::from_static(&[1, 2, 3])]);
+        assert_eq!(frame.captions.as_deref(), Some([1, 2, 3].as_slice()));
+    }
+
+    #[test]
+    fn captions_are_taken_only_once() {
+        let mut queue = VecDeque::from([frame(Some(&[1, 2, 3])), frame(Some(&[4, 5, 6]))]);
+
+        let first = take_front_frame(&mut queue).unwrap();
+        assert_eq!(first.captions.as_deref(), Some([1, 2, 3].as_slice()));
+        let repeated = take_front_frame(&mut queue).unwrap();
+        assert_eq!(repeated.captions, None);
+        assert_eq!(queue.len(), 2);
+
+        queue.pop_front();
+        let next = take_front_frame(&mut queue).unwrap();
+        assert_eq!(next.captions.as_deref(), Some([4, 5, 6].as_slice()));
+    }
+
+    #[test]
+    fn empty_queue_has_no_front_frame() {
+        assert!(take_front_frame(&mut VecDeque::new()).is_none());
+    }
+}
@@ -0,0 +1,51 @@
+use std::{fs, io};
+
+use bytes::Bytes;
+
+/// Location of a file registered as a renderer or a font, e.g. an image, a LUT
+/// or a subtitles track.
+#[derive(Debug, Clone)]
+pub enum AssetSource {
+    Url { url: String },
+    LocalPath { path: String },
+    Bytes { bytes: Bytes },
+}
+
+impl AssetSource {
+    /// Reads the content of the file. Downloading from a URL blocks the current thread.
+    pub(crate) fn load(&self) -> Result<Bytes, AssetLoadError> {
+        match self {
+            AssetSource::Url { url } => {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    let _ = url;
+                    return Err(AssetLoadError::UrlNotSupported);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let response = reqwest::blocking::get(url)?;
+                    let response = response.error_for_status()?;
+                    Ok(response.bytes()?)
+                }
+            }
+            AssetSource::LocalPath { path } => {
+                let file = fs::read(path)?;
+                Ok(Bytes::from(file))
+            }
+            AssetSource::Bytes { bytes } => Ok(bytes.clone()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AssetLoadError {
+    #[error("Failed to download the file: {0}")]
+    Download(#[from] reqwest::Error),
+
+    #[error("Failed to read the file from disk: {0}")]
+    DiskReadError(#[from] io::Error),
+
+    #[error("Providing URL as a source is not supported on wasm platform")]
+    UrlNotSupported,
+}
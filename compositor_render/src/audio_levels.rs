@@ -0,0 +1,240 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::InputId;
+
+/// Levels older than that are removed, limits the duration of the waveform.
+const HISTORY_DURATION: Duration = Duration::from_secs(30);
+
+/// Period of samples used to calculate the current level of an input.
+pub(crate) const LEVEL_WINDOW: Duration = Duration::from_millis(100);
+
+/// Loudness of an input calculated from a batch of audio samples. Values are linear,
+/// `1.0` represents a full scale signal.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AudioLevel {
+    pub rms: f32,
+    pub peak: f32,
+}
+
+impl AudioLevel {
+    pub(crate) fn rms_db(&self) -> f32 {
+        to_db(self.rms)
+    }
+
+    pub(crate) fn peak_db(&self) -> f32 {
+        to_db(self.peak)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LevelsBatch {
+    start_pts: Duration,
+    end_pts: Duration,
+    level: AudioLevel,
+}
+
+/// Recent levels of all inputs, shared between the audio mixer that produces them and
+/// the components that display them.
+#[derive(Debug, Clone, Default)]
+pub struct AudioLevels(Arc<Mutex<HashMap<InputId, VecDeque<LevelsBatch>>>>);
+
+impl AudioLevels {
+    /// Levels of samples between `start_pts` and `end_pts`. Inputs without samples in that
+    /// range should not be included.
+    pub fn push(
+        &self,
+        start_pts: Duration,
+        end_pts: Duration,
+        levels: impl IntoIterator<Item = (InputId, AudioLevel)>,
+    ) {
+        let mut guard = self.0.lock().unwrap();
+        for (input_id, level) in levels {
+            guard.entry(input_id).or_default().push_back(LevelsBatch {
+                start_pts,
+                end_pts,
+                level,
+            });
+        }
+        let min_pts = end_pts.saturating_sub(HISTORY_DURATION);
+        for batches in guard.values_mut() {
+            while batches.front().is_some_and(|batch| batch.end_pts < min_pts) {
+                batches.pop_front();
+            }
+        }
+        guard.retain(|_, batches| !batches.is_empty());
+    }
+
+    pub(crate) fn remove_input(&self, input_id: &InputId) {
+        self.0.lock().unwrap().remove(input_id);
+    }
+
+    /// Level of the samples from the `window` before `pts`.
+    pub(crate) fn level(&self, input_id: &InputId, pts: Duration, window: Duration) -> AudioLevel {
+        self.history(input_id, pts, window, 1)
+            .pop()
+            .unwrap_or_default()
+    }
+
+    /// Levels of the samples from the `duration` before `pts` split into `count` equal
+    /// periods, ordered from the oldest one.
+    pub(crate) fn history(
+        &self,
+        input_id: &InputId,
+        pts: Duration,
+        duration: Duration,
+        count: usize,
+    ) -> Vec<AudioLevel> {
+        let mut periods = vec![PeriodLevels::default(); count];
+        let start = pts.as_secs_f64() - duration.as_secs_f64();
+        let end = pts.as_secs_f64();
+        let period = duration.as_secs_f64() / count.max(1) as f64;
+
+        let guard = self.0.lock().unwrap();
+        if let Some(batches) = guard.get(input_id).filter(|_| period > 0.0) {
+            // Batches are pushed in order, so only the ones inside of the window are visited.
+            let first = batches.partition_point(|batch| batch.end_pts.as_secs_f64() <= start);
+            let window_batches = batches
+                .range(first..)
+                .take_while(|batch| batch.start_pts.as_secs_f64() < end);
+            for batch in window_batches {
+                let batch_start = f64::max(batch.start_pts.as_secs_f64(), start);
+                let batch_end = f64::min(batch.end_pts.as_secs_f64(), end);
+                let first_period = ((batch_start - start) / period).floor() as usize;
+                let last_period = usize::min(((batch_end - start) / period).ceil() as usize, count);
+                for (index, levels) in periods
+                    .iter_mut()
+                    .enumerate()
+                    .take(last_period)
+                    .skip(first_period)
+                {
+                    let period_start = start + index as f64 * period;
+                    let overlap = f64::min(batch_end, period_start + period)
+                        - f64::max(batch_start, period_start);
+                    levels.add(batch.level, overlap);
+                }
+            }
+        }
+        drop(guard);
+
+        periods.iter().map(PeriodLevels::level).collect()
+    }
+}
+
+/// Levels of the batches that overlap a period of the history.
+#[derive(Debug, Clone, Copy, Default)]
+struct PeriodLevels {
+    peak: f32,
+    squares_sum: f64,
+    covered: f64,
+}
+
+impl PeriodLevels {
+    fn add(&mut self, level: AudioLevel, overlap: f64) {
+        if overlap <= 0.0 {
+            return;
+        }
+        self.peak = self.peak.max(level.peak);
+        self.squares_sum += (level.rms as f64).powi(2) * overlap;
+        self.covered += overlap;
+    }
+
+    /// Peak is the highest peak of the batches, RMS is averaged over the part of
+    /// the period that is covered by the batches.
+    fn level(&self) -> AudioLevel {
+        let rms = match self.covered > 0.0 {
+            true => (self.squares_sum / self.covered).sqrt() as f32,
+            false => 0.0,
+        };
+        AudioLevel {
+            rms,
+            peak: self.peak,
+        }
+    }
+}
+
+/// Silence is represented as `f32::NEG_INFINITY`.
+fn to_db(value: f32) -> f32 {
+    20.0 * value.log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::InputId;
+
+    use super::{AudioLevel, AudioLevels};
+
+    fn level(value: f32) -> AudioLevel {
+        AudioLevel {
+            rms: value,
+            peak: value,
+        }
+    }
+
+    /// Batches of 250 ms, so boundaries of the periods are exact.
+    fn push_batches(levels: &AudioLevels, input_id: &InputId, values: &[f32]) {
+        for (index, value) in values.iter().enumerate() {
+            levels.push(
+                Duration::from_millis(index as u64 * 250),
+                Duration::from_millis((index as u64 + 1) * 250),
+                [(input_id.clone(), level(*value))],
+            );
+        }
+    }
+
+    #[test]
+    fn history_splits_window_into_periods() {
+        let levels = AudioLevels::default();
+        let input_id = InputId("input".into());
+        push_batches(&levels, &input_id, &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+
+        let history = levels.history(
+            &input_id,
+            Duration::from_millis(1500),
+            Duration::from_millis(1000),
+            2,
+        );
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].peak, 0.4);
+        assert_eq!(history[1].peak, 0.6);
+        let expected_rms = ((0.5f64.powi(2) + 0.6f64.powi(2)) / 2.0).sqrt() as f32;
+        assert!((history[1].rms - expected_rms).abs() < 1e-6);
+    }
+
+    #[test]
+    fn history_without_samples_is_silent() {
+        let levels = AudioLevels::default();
+        let input_id = InputId("input".into());
+        push_batches(&levels, &input_id, &[0.5, 0.5]);
+
+        // first period is before the first batch, last one is after the last batch
+        let history = levels.history(
+            &input_id,
+            Duration::from_millis(1000),
+            Duration::from_millis(1500),
+            3,
+        );
+        assert_eq!(
+            history,
+            vec![AudioLevel::default(), level(0.5), AudioLevel::default()]
+        );
+        assert_eq!(
+            levels.history(
+                &InputId("other".into()),
+                Duration::from_millis(300),
+                Duration::from_millis(300),
+                2
+            ),
+            vec![AudioLevel::default(); 2]
+        );
+        assert!(levels
+            .history(&input_id, Duration::from_millis(300), Duration::ZERO, 1)
+            .iter()
+            .all(|level| *level == AudioLevel::default()));
+    }
+}
@@ -2,10 +2,13 @@ use crate::transformations::web_renderer::CreateWebRendererError;
 use crate::wgpu::common_pipeline::CreateShaderError;
 use crate::wgpu::CreateWgpuCtxError;
 use crate::{
+    asset::AssetLoadError,
     registry,
     scene::SceneError,
     transformations::{
-        image_renderer::ImageError, web_renderer::chromium_context::WebRendererContextError,
+        color_correction_renderer::lut::LutError, image_renderer::ImageError,
+        text_renderer::subtitles::SubtitlesError,
+        web_renderer::chromium_context::WebRendererContextError,
     },
 };
 use crate::{OutputId, RendererId};
@@ -23,6 +26,18 @@ pub enum InitRendererEngineError {
 
     #[error("Failed to initialize apply_layout transformation.")]
     LayoutTransformationsInitError(#[source] CreateShaderError),
+
+    #[error("Failed to initialize apply_mask transformation.")]
+    MaskTransformationInitError(#[source] CreateShaderError),
+
+    #[error("Failed to initialize chroma_key transformation.")]
+    ChromaKeyTransformationInitError(#[source] CreateShaderError),
+
+    #[error("Failed to initialize color_correction transformation.")]
+    ColorCorrectionTransformationInitError(#[source] CreateShaderError),
+
+    #[error("Failed to initialize text effects transformation.")]
+    TextEffectsTransformationInitError(#[source] CreateShaderError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -36,6 +51,12 @@ pub enum RegisterRendererError {
     #[error("Failed to register image \"{1}\".")]
     Image(#[source] ImageError, RendererId),
 
+    #[error("Failed to register LUT \"{1}\".")]
+    Lut(#[source] LutError, RendererId),
+
+    #[error("Failed to register subtitles \"{1}\".")]
+    Subtitles(#[source] SubtitlesError, RendererId),
+
     #[error("Failed to register web renderer \"{1}\".")]
     Web(#[source] CreateWebRendererError, RendererId),
 }
@@ -46,6 +67,27 @@ pub enum UnregisterRendererError {
     RendererRegistry(#[from] registry::UnregisterError),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum AddSubtitleCueError {
+    #[error("Subtitles \"{0}\" are not registered.")]
+    NotFound(RendererId),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegisterFontError {
+    #[error("Failed to load font file: {0}")]
+    FontLoad(#[from] AssetLoadError),
+
+    #[error("Provided file does not contain any supported fonts.")]
+    NoFontFaces,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UnregisterFontError {
+    #[error("Font family \"{0}\" is not registered. Only fonts registered through the API can be unregistered.")]
+    NotRegistered(String),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum RenderSceneError {
     #[error(transparent)]
@@ -1,3 +1,4 @@
+pub mod asset;
 pub mod error;
 pub mod event_handler;
 pub mod scene;
@@ -7,10 +8,12 @@ pub(crate) mod transformations;
 pub(crate) mod utils;
 pub(crate) mod wgpu;
 
+mod audio_levels;
 mod event_loop;
 mod state;
 mod types;
 
+pub use audio_levels::{AudioLevel, AudioLevels};
 pub use event_loop::EventLoop;
 pub use types::*;
 
@@ -23,13 +26,21 @@ pub use wgpu::WgpuFeatures;
 pub use wgpu::{create_wgpu_ctx, required_wgpu_features, set_required_wgpu_limits, WgpuComponents};
 
 pub mod image {
-    pub use crate::transformations::image_renderer::{ImageSource, ImageSpec, ImageType};
+    pub use crate::transformations::image_renderer::{ImageSpec, ImageType};
+}
+
+pub mod lut {
+    pub use crate::transformations::color_correction_renderer::lut::LutSpec;
 }
 
 pub mod shader {
     pub use crate::transformations::shader::ShaderSpec;
 }
 
+pub mod subtitles {
+    pub use crate::transformations::text_renderer::subtitles::{SubtitleCue, SubtitlesSpec};
+}
+
 pub mod web_renderer {
     pub use crate::transformations::web_renderer::{
         WebEmbeddingMethod, WebRendererInitOptions, WebRendererSpec, EMBED_SOURCE_FRAMES_MESSAGE,
@@ -23,6 +23,8 @@ pub enum RegistryType {
     Shader,
     WebRenderer,
     Image,
+    Lut,
+    Subtitles,
 }
 
 impl RegistryType {
@@ -31,6 +33,8 @@ impl RegistryType {
             RegistryType::Shader => "shader",
             RegistryType::WebRenderer => "web renderer instance",
             RegistryType::Image => "image",
+            RegistryType::Lut => "LUT",
+            RegistryType::Subtitles => "subtitles",
         }
     }
 }
@@ -1,36 +1,52 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::transformations::image_renderer::Image;
+use crate::transformations::image_renderer::{Image, SvgError};
 use crate::transformations::shader::validation::error::ParametersValidationError;
 use crate::transformations::shader::Shader;
 use crate::transformations::text_renderer::TextRenderParams;
 use crate::transformations::web_renderer::WebRenderer;
 use crate::{InputId, OutputId, RendererId, Resolution};
 
+use self::chroma_key_component::StatefulChromaKeyComponent;
+use self::color_correction_component::StatefulColorCorrectionComponent;
 use self::image_component::StatefulImageComponent;
 use self::input_stream_component::StatefulInputStreamComponent;
 use self::layout::StatefulLayoutComponent;
+use self::mask_component::StatefulMaskComponent;
 use self::scene_state::{BuildStateTreeCtx, IntermediateNode};
 use self::shader_component::StatefulShaderComponent;
 use self::text_component::StatefulTextComponent;
 use self::web_view_component::StatefulWebViewComponent;
 
+pub(crate) use chroma_key_component::{ChromaKeyComponentParams, ChromaKeyParams};
+pub(crate) use color_correction_component::{
+    ColorCorrectionComponentParams, ColorCorrectionParams,
+};
 pub(crate) use layout::LayoutNode;
+pub(crate) use mask_component::MaskComponentParams;
 pub(crate) use scene_state::{OutputNode, SceneState};
 pub(crate) use shader_component::ShaderComponentParams;
+pub(crate) use text_component::DynamicTextParams;
 
 pub use components::*;
 pub use types::*;
 
+mod animation;
+mod audio_meter_component;
+mod chroma_key_component;
+mod color_correction_component;
 mod components;
+mod grid_component;
 mod image_component;
 mod input_stream_component;
 mod layout;
+mod mask_component;
 mod rescaler_component;
 mod scene_state;
 mod shader_component;
 mod text_component;
+mod ticker_component;
 mod tiles_component;
 mod transition;
 mod types;
@@ -55,6 +71,13 @@ pub enum Component {
     View(ViewComponent),
     Tiles(TilesComponent),
     Rescaler(RescalerComponent),
+    Grid(GridComponent),
+    Mask(MaskComponent),
+    ChromaKey(ChromaKeyComponent),
+    ColorCorrection(ColorCorrectionComponent),
+    Ticker(TickerComponent),
+    Subtitles(SubtitlesComponent),
+    AudioMeter(AudioMeterComponent),
 }
 
 /// Stateful version of a `Component`. Represents the same element as
@@ -68,6 +91,9 @@ enum StatefulComponent {
     WebView(StatefulWebViewComponent),
     Image(StatefulImageComponent),
     Text(StatefulTextComponent),
+    Mask(StatefulMaskComponent),
+    ChromaKey(StatefulChromaKeyComponent),
+    ColorCorrection(StatefulColorCorrectionComponent),
     Layout(StatefulLayoutComponent),
 }
 
@@ -94,6 +120,9 @@ pub(crate) enum NodeParams {
     Web(Vec<ComponentId>, Arc<WebRenderer>),
     Image(Image),
     Text(TextRenderParams),
+    Mask(MaskComponentParams),
+    ChromaKey(ChromaKeyComponentParams),
+    ColorCorrection(ColorCorrectionComponentParams),
     Layout(LayoutNode),
 }
 
@@ -105,6 +134,11 @@ impl StatefulComponent {
             StatefulComponent::WebView(web) => Some(web.size().width),
             StatefulComponent::Image(image) => Some(image.size().width),
             StatefulComponent::Text(text) => Some(text.width()),
+            StatefulComponent::Mask(mask) => Some(mask.component.size.width),
+            StatefulComponent::ChromaKey(chroma_key) => Some(chroma_key.component.size.width),
+            StatefulComponent::ColorCorrection(color_correction) => {
+                Some(color_correction.component.size.width)
+            }
             StatefulComponent::Layout(layout) => match layout.position(pts) {
                 Position::Static { width, .. } => width,
                 Position::Absolute(position) => position.width,
@@ -119,6 +153,11 @@ impl StatefulComponent {
             StatefulComponent::WebView(web) => Some(web.size().height),
             StatefulComponent::Image(image) => Some(image.size().height),
             StatefulComponent::Text(text) => Some(text.height()),
+            StatefulComponent::Mask(mask) => Some(mask.component.size.height),
+            StatefulComponent::ChromaKey(chroma_key) => Some(chroma_key.component.size.height),
+            StatefulComponent::ColorCorrection(color_correction) => {
+                Some(color_correction.component.size.height)
+            }
             StatefulComponent::Layout(layout) => match layout.position(pts) {
                 Position::Static { height, .. } => height,
                 Position::Absolute(position) => position.height,
@@ -133,14 +172,38 @@ impl StatefulComponent {
             StatefulComponent::WebView(web) => web.intermediate_node(),
             StatefulComponent::Image(image) => image.intermediate_node(),
             StatefulComponent::Text(text) => text.intermediate_node(),
+            StatefulComponent::Mask(mask) => mask.intermediate_node(),
+            StatefulComponent::ChromaKey(chroma_key) => chroma_key.intermediate_node(),
+            StatefulComponent::ColorCorrection(color_correction) => {
+                color_correction.intermediate_node()
+            }
             StatefulComponent::Layout(layout) => match layout {
                 StatefulLayoutComponent::View(view) => view.intermediate_node(),
                 StatefulLayoutComponent::Tiles(tiles) => tiles.intermediate_node(),
                 StatefulLayoutComponent::Rescaler(rescaler) => rescaler.intermediate_node(),
+                StatefulLayoutComponent::Grid(grid) => grid.intermediate_node(),
+                StatefulLayoutComponent::Ticker(ticker) => ticker.intermediate_node(),
+                StatefulLayoutComponent::AudioMeter(meter) => meter.intermediate_node(),
             },
         }
     }
 
+    fn children(&self) -> Vec<&StatefulComponent> {
+        match self {
+            StatefulComponent::InputStream(_) => vec![],
+            StatefulComponent::Shader(shader) => shader.children.iter().collect(),
+            StatefulComponent::WebView(web) => web.children.iter().collect(),
+            StatefulComponent::Image(_) => vec![],
+            StatefulComponent::Text(_) => vec![],
+            StatefulComponent::Mask(mask) => mask.children(),
+            StatefulComponent::ChromaKey(chroma_key) => vec![&*chroma_key.child],
+            StatefulComponent::ColorCorrection(color_correction) => {
+                vec![&*color_correction.child]
+            }
+            StatefulComponent::Layout(layout) => layout.children(),
+        }
+    }
+
     fn children_mut(&mut self) -> Vec<&mut StatefulComponent> {
         match self {
             StatefulComponent::InputStream(_) => vec![],
@@ -148,6 +211,11 @@ impl StatefulComponent {
             StatefulComponent::WebView(web) => web.children.iter_mut().collect(),
             StatefulComponent::Image(_) => vec![],
             StatefulComponent::Text(_) => vec![],
+            StatefulComponent::Mask(mask) => mask.children_mut(),
+            StatefulComponent::ChromaKey(chroma_key) => vec![&mut *chroma_key.child],
+            StatefulComponent::ColorCorrection(color_correction) => {
+                vec![&mut *color_correction.child]
+            }
             StatefulComponent::Layout(layout) => layout.children_mut(),
         }
     }
@@ -159,6 +227,9 @@ impl StatefulComponent {
             StatefulComponent::WebView(web) => web.component_id(),
             StatefulComponent::Image(image) => image.component_id(),
             StatefulComponent::Text(text) => text.component_id(),
+            StatefulComponent::Mask(mask) => mask.component_id(),
+            StatefulComponent::ChromaKey(chroma_key) => chroma_key.component_id(),
+            StatefulComponent::ColorCorrection(color_correction) => color_correction.component_id(),
             StatefulComponent::Layout(layout) => layout.component_id(),
         }
     }
@@ -180,6 +251,15 @@ impl Component {
             Component::View(view) => view.stateful_component(ctx),
             Component::Tiles(tiles) => tiles.stateful_component(ctx),
             Component::Rescaler(rescaler) => rescaler.stateful_component(ctx),
+            Component::Grid(grid) => grid.stateful_component(ctx),
+            Component::Mask(mask) => mask.stateful_component(ctx),
+            Component::ChromaKey(chroma_key) => chroma_key.stateful_component(ctx),
+            Component::ColorCorrection(color_correction) => {
+                color_correction.stateful_component(ctx)
+            }
+            Component::Ticker(ticker) => ticker.stateful_component(ctx),
+            Component::Subtitles(subtitles) => subtitles.stateful_component(ctx),
+            Component::AudioMeter(audio_meter) => audio_meter.stateful_component(ctx),
         }
     }
 }
@@ -198,6 +278,12 @@ pub enum SceneError {
     #[error("Shader \"{0}\" does not exist. You have to register it first before using it in the scene definition.")]
     ShaderNotFound(RendererId),
 
+    #[error("LUT \"{0}\" does not exist. You have to register it first before using it in the scene definition.")]
+    LutNotFound(RendererId),
+
+    #[error("Subtitles \"{0}\" do not exist. You have to register them first before using them in the scene definition.")]
+    SubtitlesNotFound(RendererId),
+
     #[error("Instance of web renderer \"{0}\" does not exist. You have to register it first before using it in the scene definition.")]
     WebRendererNotFound(RendererId),
 
@@ -212,6 +298,21 @@ pub enum SceneError {
     #[error("Invalid parameter passed to \"{1}\" shader.")]
     ShaderNodeParametersValidationError(#[source] ParametersValidationError, RendererId),
 
+    #[error("Failed to render a path of the \"Mask\" component.")]
+    InvalidMaskPath(#[source] SvgError),
+
+    #[error("Invalid clock format \"{0}\" in a \"Text\" component.")]
+    InvalidClockFormat(Arc<str>),
+
+    #[error("\"Text\" component with a \"color_gradient\" has to be a child of a layout component, e.g. \"View\" or \"Rescaler\".")]
+    TextGradientWithoutLayoutParent,
+
     #[error("More than one component has an id \"{0}\". Component IDs in scene definition need to be unique.")]
     DuplicateComponentId(ComponentId),
+
+    #[error(
+        "Component{} defines \"grid_placement\", but it is not a direct child of a \"Grid\" component.",
+        .0.as_ref().map(|id| format!(" \"{id}\"")).unwrap_or_default()
+    )]
+    GridPlacementOutsideOfGrid(Option<ComponentId>),
 }
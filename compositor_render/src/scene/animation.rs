@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use super::{
+    types::interpolation::{ContinuousValue, InterpolationState},
+    AnimatedProperty, AnimationDirection, HorizontalPosition, Keyframe, KeyframeAnimation,
+    KeyframeValue, Position, VerticalPosition,
+};
+
+/// Keyframe animation together with a PTS of the scene update that started it.
+#[derive(Debug, Clone)]
+pub(super) struct KeyframeAnimationState {
+    animation: KeyframeAnimation,
+    start_pts: Duration,
+}
+
+impl KeyframeAnimationState {
+    /// Animations that were already defined in the previous scene (with exactly the
+    /// same parameters) continue where they were, new ones start at `last_pts`.
+    pub fn new_list(
+        animations: Vec<KeyframeAnimation>,
+        previous: &[KeyframeAnimationState],
+        last_pts: Duration,
+    ) -> Vec<Self> {
+        animations
+            .into_iter()
+            .map(|animation| {
+                let start_pts = previous
+                    .iter()
+                    .find(|state| state.animation == animation)
+                    .map(|state| state.start_pts)
+                    .unwrap_or(last_pts);
+                Self {
+                    animation,
+                    start_pts,
+                }
+            })
+            .collect()
+    }
+
+    pub fn property(&self) -> AnimatedProperty {
+        self.animation.property
+    }
+
+    /// Value of the animated property. Returns `None` if the animation did not start
+    /// yet. After the last iteration, the final value is preserved.
+    pub fn value(&self, pts: Duration) -> Option<KeyframeValue> {
+        let animation = &self.animation;
+        let elapsed = pts.checked_sub(self.start_pts + animation.delay)?;
+        let elapsed = match animation.duration.is_zero() {
+            true => f64::INFINITY,
+            false => elapsed.as_secs_f64() / animation.duration.as_secs_f64(),
+        };
+
+        let (iteration, progress) = match animation.iterations {
+            Some(iterations) if elapsed >= iterations as f64 => {
+                (iterations.saturating_sub(1) as u64, 1.0)
+            }
+            None if elapsed.is_infinite() => (0, 1.0),
+            _ => (elapsed.floor() as u64, elapsed.fract()),
+        };
+        let is_reversed = match animation.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2 == 1,
+            AnimationDirection::AlternateReverse => iteration % 2 == 0,
+        };
+        let progress = match is_reversed {
+            true => 1.0 - progress,
+            false => progress,
+        };
+        keyframes_value(&animation.keyframes, progress, animation.duration)
+    }
+}
+
+fn keyframes_value(
+    keyframes: &[Keyframe],
+    progress: f64,
+    duration: Duration,
+) -> Option<KeyframeValue> {
+    let next_index = keyframes
+        .iter()
+        .position(|keyframe| keyframe.offset > progress);
+    match next_index {
+        None => keyframes.last().map(|keyframe| keyframe.value),
+        Some(0) => Some(keyframes[0].value),
+        Some(index) => {
+            let (start, end) = (&keyframes[index - 1], &keyframes[index]);
+            let keyframe_progress = (progress - start.offset) / (end.offset - start.offset);
+            let keyframe_duration = duration.mul_f64(end.offset - start.offset);
+            let state = start
+                .interpolation_kind
+                .state(keyframe_progress, keyframe_duration);
+            Some(ContinuousValue::interpolate(
+                &start.value,
+                &end.value,
+                state,
+            ))
+        }
+    }
+}
+
+impl ContinuousValue for KeyframeValue {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        match (start, end) {
+            (KeyframeValue::Number(start), KeyframeValue::Number(end)) => {
+                KeyframeValue::Number(ContinuousValue::interpolate(start, end, state))
+            }
+            (KeyframeValue::Color(start), KeyframeValue::Color(end)) => {
+                KeyframeValue::Color(ContinuousValue::interpolate(start, end, state))
+            }
+            (_, end) => *end,
+        }
+    }
+}
+
+/// Update position of a component with a value of an animated property. Vertical and
+/// horizontal offsets and rotation are only applied to absolutely positioned components.
+pub(super) fn animate_position(position: &mut Position, property: AnimatedProperty, value: f32) {
+    match position {
+        Position::Static { width, height } => match property {
+            AnimatedProperty::Width => *width = Some(value),
+            AnimatedProperty::Height => *height = Some(value),
+            _ => (),
+        },
+        Position::Absolute(position) => match property {
+            AnimatedProperty::Width => position.width = Some(value),
+            AnimatedProperty::Height => position.height = Some(value),
+            AnimatedProperty::Top => {
+                position.position_vertical = VerticalPosition::TopOffset(value)
+            }
+            AnimatedProperty::Bottom => {
+                position.position_vertical = VerticalPosition::BottomOffset(value)
+            }
+            AnimatedProperty::Left => {
+                position.position_horizontal = HorizontalPosition::LeftOffset(value)
+            }
+            AnimatedProperty::Right => {
+                position.position_horizontal = HorizontalPosition::RightOffset(value)
+            }
+            AnimatedProperty::Rotation => position.rotation_degrees = value,
+            _ => (),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::scene::{
+        AnimatedProperty, AnimationDirection, InterpolationKind, Keyframe, KeyframeAnimation,
+        KeyframeValue,
+    };
+
+    use super::KeyframeAnimationState;
+
+    fn animation(iterations: Option<u32>, direction: AnimationDirection) -> KeyframeAnimationState {
+        let keyframe = |offset, value| Keyframe {
+            offset,
+            value: KeyframeValue::Number(value),
+            interpolation_kind: InterpolationKind::Linear,
+        };
+        KeyframeAnimationState {
+            animation: KeyframeAnimation {
+                property: AnimatedProperty::Opacity,
+                keyframes: vec![keyframe(0.0, 0.0), keyframe(0.5, 1.0), keyframe(1.0, 1.0)],
+                duration: Duration::from_secs(2),
+                delay: Duration::from_secs(1),
+                iterations,
+                direction,
+            },
+            start_pts: Duration::ZERO,
+        }
+    }
+
+    fn value_at(animation: &KeyframeAnimationState, secs: f64) -> Option<f32> {
+        match animation.value(Duration::from_secs_f64(secs)) {
+            Some(KeyframeValue::Number(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn keyframes_with_delay() {
+        let animation = animation(Some(1), AnimationDirection::Normal);
+        assert_eq!(value_at(&animation, 0.5), None);
+        assert_eq!(value_at(&animation, 1.5), Some(0.5));
+        assert_eq!(value_at(&animation, 2.5), Some(1.0));
+        assert_eq!(value_at(&animation, 10.0), Some(1.0));
+    }
+
+    #[test]
+    fn alternate_direction() {
+        let animation = animation(None, AnimationDirection::Alternate);
+        assert_eq!(value_at(&animation, 1.5), Some(0.5));
+        assert_eq!(value_at(&animation, 3.5), Some(1.0));
+        assert_eq!(value_at(&animation, 4.5), Some(0.5));
+        assert_eq!(value_at(&animation, 5.5), Some(0.5));
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use crate::{
+    audio_levels::{AudioLevel, LEVEL_WINDOW},
+    transformations::layout::{LayoutContent, NestedLayout},
+    AudioLevels,
+};
+
+use super::{
+    layout::StatefulLayoutComponent, scene_state::BuildStateTreeCtx, AudioMeterComponent,
+    AudioMeterOrientation, AudioMeterStyle, BlendMode, BorderRadius, ComponentId, IntermediateNode,
+    Position, RGBAColor, SceneError, Size, StatefulComponent,
+};
+
+/// Gap between segments and waveform bars as a fraction of their size.
+const GAP: f32 = 0.2;
+
+/// Thickness of the peak marker in pixels.
+const PEAK_MARKER_SIZE: f32 = 2.0;
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulAudioMeterComponent {
+    component: AudioMeterComponent,
+    audio_levels: AudioLevels,
+}
+
+impl StatefulAudioMeterComponent {
+    pub(super) fn children(&self) -> Vec<&StatefulComponent> {
+        vec![]
+    }
+
+    pub(super) fn children_mut(&mut self) -> Vec<&mut StatefulComponent> {
+        vec![]
+    }
+
+    pub(super) fn position(&self, _pts: Duration) -> Position {
+        self.component.position
+    }
+
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        IntermediateNode::Layout {
+            root: StatefulLayoutComponent::AudioMeter(self.clone()),
+            children: vec![],
+        }
+    }
+
+    pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
+        let input_id = &self.component.input_id;
+        let children = match self.component.style {
+            AudioMeterStyle::Bar => {
+                let level = self.audio_levels.level(input_id, pts, LEVEL_WINDOW);
+                self.bar_layouts(size, level)
+            }
+            AudioMeterStyle::Segmented { segments } => {
+                let level = self.audio_levels.level(input_id, pts, LEVEL_WINDOW);
+                self.segment_layouts(size, level, segments.max(1))
+            }
+            AudioMeterStyle::Waveform { duration, bars } => {
+                let history =
+                    self.audio_levels
+                        .history(input_id, pts, duration, bars.max(1) as usize);
+                self.waveform_layouts(size, &history)
+            }
+        };
+
+        NestedLayout {
+            content: LayoutContent::Color(self.component.background_color),
+            children,
+            ..color_layout(0.0, 0.0, size.width, size.height, RGBAColor(0, 0, 0, 0))
+        }
+    }
+
+    /// RMS level split into ranges with different colors and a marker on the peak level.
+    fn bar_layouts(&self, size: Size, level: AudioLevel) -> Vec<NestedLayout> {
+        let rms = self.fraction(level.rms_db());
+        let peak = self.fraction(level.peak_db());
+        let warning = self.fraction(self.component.warning_db);
+        let clip = self.fraction(self.component.clip_db);
+
+        let ranges = [
+            (0.0, warning, self.component.color),
+            (warning, clip, self.component.warning_color),
+            (clip, 1.0, self.component.clip_color),
+        ];
+        let mut layouts: Vec<_> = ranges
+            .into_iter()
+            .filter_map(|(start, end, color)| {
+                let end = f32::min(end, rms);
+                (end > start).then(|| self.meter_layout(size, start, end, 0.0, 1.0, color))
+            })
+            .collect();
+
+        if peak > 0.0 {
+            let length = self.length(size);
+            let marker = f32::min(PEAK_MARKER_SIZE / length, peak);
+            let color = self.level_color(level.peak_db());
+            layouts.push(self.meter_layout(size, peak - marker, peak, 0.0, 1.0, color));
+        }
+        layouts
+    }
+
+    /// Segments that are lit up to the RMS level, each colored based on its upper edge.
+    fn segment_layouts(&self, size: Size, level: AudioLevel, segments: u32) -> Vec<NestedLayout> {
+        let rms = self.fraction(level.rms_db());
+        let segment_size = 1.0 / segments as f32;
+        (0..segments)
+            .map(|index| index as f32 * segment_size)
+            .take_while(|start| *start < rms)
+            .map(|start| {
+                let end = start + segment_size;
+                let db = self.component.min_db * (1.0 - end);
+                self.meter_layout(
+                    size,
+                    start + segment_size * GAP / 2.0,
+                    end - segment_size * GAP / 2.0,
+                    0.0,
+                    1.0,
+                    self.level_color(db),
+                )
+            })
+            .collect()
+    }
+
+    /// Peak levels mirrored around the middle of the component, the newest level is
+    /// displayed at the end of the meter.
+    fn waveform_layouts(&self, size: Size, history: &[AudioLevel]) -> Vec<NestedLayout> {
+        let bar_size = 1.0 / history.len() as f32;
+        history
+            .iter()
+            .enumerate()
+            .filter_map(|(index, level)| {
+                let peak = self.fraction(level.peak_db());
+                if peak <= 0.0 {
+                    return None;
+                }
+                let start = index as f32 * bar_size;
+                Some(self.meter_layout(
+                    size,
+                    start + bar_size * GAP / 2.0,
+                    start + bar_size * (1.0 - GAP / 2.0),
+                    0.5 - peak / 2.0,
+                    0.5 + peak / 2.0,
+                    self.level_color(level.peak_db()),
+                ))
+            })
+            .collect()
+    }
+
+    /// Rectangle defined by fractions of the meter length (measured from the start of
+    /// the meter) and of its thickness.
+    fn meter_layout(
+        &self,
+        size: Size,
+        start: f32,
+        end: f32,
+        cross_start: f32,
+        cross_end: f32,
+        color: RGBAColor,
+    ) -> NestedLayout {
+        match self.component.orientation {
+            AudioMeterOrientation::Horizontal => color_layout(
+                size.height * cross_start,
+                size.width * start,
+                size.width * (end - start),
+                size.height * (cross_end - cross_start),
+                color,
+            ),
+            AudioMeterOrientation::Vertical => color_layout(
+                size.height * (1.0 - end),
+                size.width * cross_start,
+                size.width * (cross_end - cross_start),
+                size.height * (end - start),
+                color,
+            ),
+        }
+    }
+
+    fn length(&self, size: Size) -> f32 {
+        match self.component.orientation {
+            AudioMeterOrientation::Horizontal => size.width,
+            AudioMeterOrientation::Vertical => size.height,
+        }
+    }
+
+    /// Position of the level on the meter between 0.0 (`min_db`) and 1.0 (0 dBFS).
+    fn fraction(&self, db: f32) -> f32 {
+        if self.component.min_db >= 0.0 {
+            return 0.0;
+        }
+        ((db - self.component.min_db) / -self.component.min_db).clamp(0.0, 1.0)
+    }
+
+    fn level_color(&self, db: f32) -> RGBAColor {
+        if db > self.component.clip_db {
+            self.component.clip_color
+        } else if db > self.component.warning_db {
+            self.component.warning_color
+        } else {
+            self.component.color
+        }
+    }
+}
+
+fn color_layout(top: f32, left: f32, width: f32, height: f32, color: RGBAColor) -> NestedLayout {
+    NestedLayout {
+        top,
+        left,
+        width,
+        height,
+        rotation_degrees: 0.0,
+        scale_x: 1.0,
+        scale_y: 1.0,
+        crop: None,
+        mask: None,
+        content: LayoutContent::Color(color),
+        child_nodes_count: 0,
+        children: vec![],
+        border_width: 0.0,
+        border_color: RGBAColor(0, 0, 0, 0),
+        border_gradient: None,
+        border_radius: BorderRadius::ZERO,
+        box_shadow: vec![],
+        blend_mode: BlendMode::Normal,
+        opacity: 1.0,
+        blur_radius: 0.0,
+        backdrop_blur: 0.0,
+    }
+}
+
+impl AudioMeterComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        Ok(StatefulComponent::Layout(
+            StatefulLayoutComponent::AudioMeter(StatefulAudioMeterComponent {
+                component: self,
+                audio_levels: ctx.renderers.audio_levels.clone(),
+            }),
+        ))
+    }
+}
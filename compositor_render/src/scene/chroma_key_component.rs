@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use super::{
+    animation::KeyframeAnimationState,
+    scene_state::BuildStateTreeCtx,
+    transition::{velocity_scale, TransitionOptions, TransitionState},
+    types::interpolation::{ContinuousValue, InterpolationState},
+    AnimatedProperty, ChromaKeyComponent, Component, ComponentId, IntermediateNode, KeyframeValue,
+    RGBAColor, SceneError, Size, StatefulComponent,
+};
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulChromaKeyComponent {
+    pub(super) component: ChromaKeyComponentParams,
+    pub(super) child: Box<StatefulComponent>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ChromaKeyComponentParams {
+    pub(crate) id: Option<ComponentId>,
+    pub(crate) size: Size,
+    start: Option<ChromaKeyParams>,
+    end: ChromaKeyParams,
+    transition: Option<TransitionState>,
+    animations: Vec<KeyframeAnimationState>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChromaKeyParams {
+    pub(crate) key_color: RGBAColor,
+    pub(crate) similarity: f32,
+    pub(crate) smoothness: f32,
+    pub(crate) spill: f32,
+}
+
+impl ChromaKeyComponentParams {
+    pub(crate) fn params(&self, pts: Duration) -> ChromaKeyParams {
+        let mut params = match (&self.transition, &self.start) {
+            (Some(transition), Some(start)) => {
+                ContinuousValue::interpolate(start, &self.end, transition.state(pts))
+            }
+            _ => self.end,
+        };
+        for animation in self.animations.iter() {
+            if let Some(value) = animation.value(pts) {
+                params.apply_animated_value(animation.property(), value);
+            }
+        }
+        params
+    }
+}
+
+impl ContinuousValue for ChromaKeyParams {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            key_color: ContinuousValue::interpolate(&start.key_color, &end.key_color, state),
+            similarity: ContinuousValue::interpolate(&start.similarity, &end.similarity, state),
+            smoothness: ContinuousValue::interpolate(&start.smoothness, &end.smoothness, state),
+            spill: ContinuousValue::interpolate(&start.spill, &end.spill, state),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        self.key_color.push_coordinates(coordinates);
+        coordinates.extend([self.similarity, self.smoothness, self.spill].map(f64::from));
+    }
+}
+
+impl ChromaKeyParams {
+    fn apply_animated_value(&mut self, property: AnimatedProperty, value: KeyframeValue) {
+        match (property, value) {
+            (AnimatedProperty::KeyColor, KeyframeValue::Color(color)) => self.key_color = color,
+            (AnimatedProperty::Similarity, KeyframeValue::Number(similarity)) => {
+                self.similarity = f32::max(similarity, 0.0)
+            }
+            (AnimatedProperty::Smoothness, KeyframeValue::Number(smoothness)) => {
+                self.smoothness = f32::max(smoothness, 0.0)
+            }
+            (AnimatedProperty::Spill, KeyframeValue::Number(spill)) => {
+                self.spill = spill.clamp(0.0, 1.0)
+            }
+            _ => (),
+        }
+    }
+}
+
+impl StatefulChromaKeyComponent {
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        IntermediateNode::ChromaKey {
+            chroma_key: self.clone(),
+            children: vec![self.child.intermediate_node()],
+        }
+    }
+}
+
+impl ChromaKeyComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let previous_state = self
+            .id
+            .as_ref()
+            .and_then(|id| ctx.prev_state.get(id))
+            .and_then(|component| match component {
+                StatefulComponent::ChromaKey(chroma_key) => Some(&chroma_key.component),
+                _ => None,
+            });
+
+        let end = ChromaKeyParams {
+            key_color: self.key_color,
+            similarity: self.similarity,
+            smoothness: self.smoothness,
+            spill: self.spill,
+        };
+        let start = previous_state.map(|state| state.params(ctx.last_render_pts));
+        let velocity_scale = previous_state
+            .zip(start.as_ref())
+            .and_then(|(state, start)| {
+                velocity_scale((state.start.as_ref()?, &state.end), (start, &end))
+            });
+        let transition = TransitionState::new(
+            self.transition.map(|transition| TransitionOptions {
+                duration: transition.duration,
+                interpolation_kind: transition.interpolation_kind,
+            }),
+            previous_state.and_then(|state| state.transition.clone()),
+            velocity_scale,
+            ctx.last_render_pts,
+        );
+
+        Ok(StatefulComponent::ChromaKey(StatefulChromaKeyComponent {
+            component: ChromaKeyComponentParams {
+                id: self.id,
+                size: self.size,
+                start,
+                end,
+                transition,
+                animations: KeyframeAnimationState::new_list(
+                    self.animations,
+                    previous_state
+                        .map(|state| state.animations.as_slice())
+                        .unwrap_or_default(),
+                    ctx.last_render_pts,
+                ),
+            },
+            child: Box::new(Component::stateful_component(*self.child, ctx)?),
+        }))
+    }
+}
@@ -0,0 +1,233 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::transformations::color_correction_renderer::lut::Lut;
+
+use super::{
+    animation::KeyframeAnimationState,
+    scene_state::BuildStateTreeCtx,
+    transition::{velocity_scale, TransitionOptions, TransitionState},
+    types::interpolation::{ContinuousValue, InterpolationState},
+    AnimatedProperty, ColorChannels, ColorCorrectionComponent, Component, ComponentId,
+    IntermediateNode, KeyframeValue, LiftGammaGain, SceneError, Size, StatefulComponent,
+};
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulColorCorrectionComponent {
+    pub(super) component: ColorCorrectionComponentParams,
+    pub(super) child: Box<StatefulComponent>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ColorCorrectionComponentParams {
+    pub(crate) id: Option<ComponentId>,
+    pub(crate) size: Size,
+    pub(crate) lut: Option<Arc<Lut>>,
+    start: Option<ColorCorrectionParams>,
+    end: ColorCorrectionParams,
+    transition: Option<TransitionState>,
+    animations: Vec<KeyframeAnimationState>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ColorCorrectionParams {
+    pub(crate) brightness: f32,
+    pub(crate) contrast: f32,
+    pub(crate) saturation: f32,
+    pub(crate) gamma: f32,
+    pub(crate) temperature: f32,
+    pub(crate) tint: f32,
+    pub(crate) lift_gamma_gain: LiftGammaGain,
+    pub(crate) lut_strength: f32,
+}
+
+impl ColorCorrectionComponentParams {
+    pub(crate) fn params(&self, pts: Duration) -> ColorCorrectionParams {
+        let mut params = match (&self.transition, &self.start) {
+            (Some(transition), Some(start)) => {
+                ContinuousValue::interpolate(start, &self.end, transition.state(pts))
+            }
+            _ => self.end,
+        };
+        for animation in self.animations.iter() {
+            if let Some(value) = animation.value(pts) {
+                params.apply_animated_value(animation.property(), value);
+            }
+        }
+        params
+    }
+}
+
+impl ContinuousValue for ColorCorrectionParams {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            brightness: ContinuousValue::interpolate(&start.brightness, &end.brightness, state),
+            contrast: ContinuousValue::interpolate(&start.contrast, &end.contrast, state),
+            saturation: ContinuousValue::interpolate(&start.saturation, &end.saturation, state),
+            gamma: ContinuousValue::interpolate(&start.gamma, &end.gamma, state),
+            temperature: ContinuousValue::interpolate(&start.temperature, &end.temperature, state),
+            tint: ContinuousValue::interpolate(&start.tint, &end.tint, state),
+            lift_gamma_gain: ContinuousValue::interpolate(
+                &start.lift_gamma_gain,
+                &end.lift_gamma_gain,
+                state,
+            ),
+            lut_strength: ContinuousValue::interpolate(
+                &start.lut_strength,
+                &end.lut_strength,
+                state,
+            ),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend(
+            [
+                self.brightness,
+                self.contrast,
+                self.saturation,
+                self.gamma,
+                self.temperature,
+                self.tint,
+            ]
+            .map(f64::from),
+        );
+        self.lift_gamma_gain.push_coordinates(coordinates);
+        self.lut_strength.push_coordinates(coordinates);
+    }
+}
+
+impl ContinuousValue for LiftGammaGain {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            lift: ContinuousValue::interpolate(&start.lift, &end.lift, state),
+            gamma: ContinuousValue::interpolate(&start.gamma, &end.gamma, state),
+            gain: ContinuousValue::interpolate(&start.gain, &end.gain, state),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        self.lift.push_coordinates(coordinates);
+        self.gamma.push_coordinates(coordinates);
+        self.gain.push_coordinates(coordinates);
+    }
+}
+
+impl ContinuousValue for ColorChannels {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            red: ContinuousValue::interpolate(&start.red, &end.red, state),
+            green: ContinuousValue::interpolate(&start.green, &end.green, state),
+            blue: ContinuousValue::interpolate(&start.blue, &end.blue, state),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend([self.red, self.green, self.blue].map(f64::from))
+    }
+}
+
+impl ColorCorrectionParams {
+    fn apply_animated_value(&mut self, property: AnimatedProperty, value: KeyframeValue) {
+        let KeyframeValue::Number(value) = value else {
+            return;
+        };
+        match property {
+            AnimatedProperty::Brightness => self.brightness = value,
+            AnimatedProperty::Contrast => self.contrast = f32::max(value, 0.0),
+            AnimatedProperty::Saturation => self.saturation = f32::max(value, 0.0),
+            AnimatedProperty::Gamma => self.gamma = f32::max(value, 0.01),
+            AnimatedProperty::Temperature => self.temperature = value.clamp(-1.0, 1.0),
+            AnimatedProperty::Tint => self.tint = value.clamp(-1.0, 1.0),
+            AnimatedProperty::LutStrength => self.lut_strength = value.clamp(0.0, 1.0),
+            _ => (),
+        }
+    }
+}
+
+impl StatefulColorCorrectionComponent {
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        IntermediateNode::ColorCorrection {
+            color_correction: self.clone(),
+            children: vec![self.child.intermediate_node()],
+        }
+    }
+}
+
+impl ColorCorrectionComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let lut = self
+            .lut_id
+            .map(|lut_id| {
+                ctx.renderers
+                    .luts
+                    .get(&lut_id)
+                    .ok_or(SceneError::LutNotFound(lut_id))
+            })
+            .transpose()?;
+
+        let previous_state = self
+            .id
+            .as_ref()
+            .and_then(|id| ctx.prev_state.get(id))
+            .and_then(|component| match component {
+                StatefulComponent::ColorCorrection(color_correction) => {
+                    Some(&color_correction.component)
+                }
+                _ => None,
+            });
+
+        let end = ColorCorrectionParams {
+            brightness: self.brightness,
+            contrast: self.contrast,
+            saturation: self.saturation,
+            gamma: self.gamma,
+            temperature: self.temperature,
+            tint: self.tint,
+            lift_gamma_gain: self.lift_gamma_gain,
+            lut_strength: self.lut_strength,
+        };
+        let start = previous_state.map(|state| state.params(ctx.last_render_pts));
+        let velocity_scale = previous_state
+            .zip(start.as_ref())
+            .and_then(|(state, start)| {
+                velocity_scale((state.start.as_ref()?, &state.end), (start, &end))
+            });
+        let transition = TransitionState::new(
+            self.transition.map(|transition| TransitionOptions {
+                duration: transition.duration,
+                interpolation_kind: transition.interpolation_kind,
+            }),
+            previous_state.and_then(|state| state.transition.clone()),
+            velocity_scale,
+            ctx.last_render_pts,
+        );
+
+        Ok(StatefulComponent::ColorCorrection(
+            StatefulColorCorrectionComponent {
+                component: ColorCorrectionComponentParams {
+                    id: self.id,
+                    size: self.size,
+                    lut,
+                    start,
+                    end,
+                    transition,
+                    animations: KeyframeAnimationState::new_list(
+                        self.animations,
+                        previous_state
+                            .map(|state| state.animations.as_slice())
+                            .unwrap_or_default(),
+                        ctx.last_render_pts,
+                    ),
+                },
+                child: Box::new(Component::stateful_component(*self.child, ctx)?),
+            },
+        ))
+    }
+}
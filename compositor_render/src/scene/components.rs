@@ -1,10 +1,14 @@
-use std::{fmt::Display, sync::Arc, time::Duration};
+use std::{
+    fmt::Display,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
-use crate::{InputId, RendererId};
+use crate::{Framerate, InputId, RendererId};
 
 use super::{
-    AbsolutePosition, BorderRadius, BoxShadow, Component, HorizontalAlign, InterpolationKind,
-    RGBAColor, Size, VerticalAlign,
+    AbsolutePosition, BorderRadius, BoxShadow, Component, Gradient, HorizontalAlign,
+    InterpolationKind, RGBAColor, Size, VerticalAlign,
 };
 
 mod interpolation;
@@ -32,10 +36,121 @@ pub struct ShaderComponent {
 
     pub shader_id: RendererId,
     pub shader_param: Option<ShaderParam>,
+    /// Inputs which audio levels are available in the shader.
+    pub audio_inputs: Vec<InputId>,
 
     pub size: Size,
 }
 
+#[derive(Debug, Clone)]
+pub struct MaskComponent {
+    pub id: Option<ComponentId>,
+    pub child: Box<Component>,
+
+    pub source: MaskSource,
+    pub mode: MaskMode,
+
+    pub size: Size,
+}
+
+#[derive(Debug, Clone)]
+pub enum MaskSource {
+    /// Output of a component is used as a mask.
+    Component(Box<Component>),
+    /// SVG path data in pixel coordinates of the mask component.
+    Path(Arc<str>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskMode {
+    /// Alpha channel of the mask defines visibility.
+    Alpha,
+    /// Luminance of the mask (multiplied by its alpha) defines visibility.
+    Luminance,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChromaKeyComponent {
+    pub id: Option<ComponentId>,
+    pub child: Box<Component>,
+
+    pub key_color: RGBAColor,
+    /// Distance from the key color (in linear RGB) below which pixels are fully transparent.
+    pub similarity: f32,
+    /// Width of the range above `similarity` where transparency fades out.
+    pub smoothness: f32,
+    /// Strength of the key color removal from the remaining pixels, between 0.0 and 1.0.
+    pub spill: f32,
+
+    pub transition: Option<Transition>,
+    pub animations: Vec<KeyframeAnimation>,
+
+    pub size: Size,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColorCorrectionComponent {
+    pub id: Option<ComponentId>,
+    pub child: Box<Component>,
+
+    /// Value added to all channels, 0.0 means no change.
+    pub brightness: f32,
+    /// Multiplier of the distance from mid-gray, 1.0 means no change.
+    pub contrast: f32,
+    /// 0.0 produces grayscale image, 1.0 means no change.
+    pub saturation: f32,
+    /// Gamma exponent applied to all channels, 1.0 means no change.
+    pub gamma: f32,
+    /// White balance shift towards blue (negative) or orange (positive), between -1.0 and 1.0.
+    pub temperature: f32,
+    /// White balance shift towards green (negative) or magenta (positive), between -1.0 and 1.0.
+    pub tint: f32,
+    pub lift_gamma_gain: LiftGammaGain,
+
+    /// LUT registered with `RendererSpec::Lut`, applied after all other corrections.
+    pub lut_id: Option<RendererId>,
+    /// Mix between the image before (0.0) and after (1.0) applying the LUT.
+    pub lut_strength: f32,
+
+    pub transition: Option<Transition>,
+    pub animations: Vec<KeyframeAnimation>,
+
+    pub size: Size,
+}
+
+/// Per-channel adjustment of shadows (lift), midtones (gamma) and highlights (gain).
+#[derive(Debug, Clone, Copy)]
+pub struct LiftGammaGain {
+    pub lift: ColorChannels,
+    pub gamma: ColorChannels,
+    pub gain: ColorChannels,
+}
+
+impl LiftGammaGain {
+    pub const IDENTITY: Self = Self {
+        lift: ColorChannels::splat(0.0),
+        gamma: ColorChannels::splat(1.0),
+        gain: ColorChannels::splat(1.0),
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorChannels {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl ColorChannels {
+    pub const fn splat(value: f32) -> Self {
+        Self {
+            red: value,
+            green: value,
+            blue: value,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ShaderParam {
     F32(f32),
@@ -68,7 +183,9 @@ pub struct ImageComponent {
 #[derive(Debug, Clone)]
 pub struct TextComponent {
     pub id: Option<ComponentId>,
-    pub text: Arc<str>,
+    /// Fragments of the text, each with its own styling. Properties that are
+    /// not defined on a span are inherited from the component.
+    pub spans: Vec<TextSpan>,
     /// in pixels
     pub font_size: f32,
     /// in pixels, default: same as font_size
@@ -82,9 +199,95 @@ pub struct TextComponent {
     pub weight: TextWeight,
     pub wrap: TextWrap,
     pub background_color: RGBAColor,
+    /// Replaces `color` if defined. Gradient is only applied if the text is
+    /// a child of a layout component.
+    pub color_gradient: Option<Gradient>,
+    pub stroke: Option<TextStroke>,
+    pub shadow: Option<TextShadow>,
+    /// Replaces `spans` if defined. Content is generated for every frame.
+    pub dynamic: Option<DynamicText>,
     pub dimensions: TextDimensions,
 }
 
+#[derive(Debug, Clone)]
+pub struct TextSpan {
+    pub text: Arc<str>,
+    pub font_family: Option<Arc<str>>,
+    /// in pixels
+    pub font_size: Option<f32>,
+    pub color: Option<RGBAColor>,
+    pub style: Option<TextStyle>,
+    pub weight: Option<TextWeight>,
+}
+
+/// Outline around the glyphs, rendered outside of the glyphs.
+#[derive(Debug, Clone)]
+pub struct TextStroke {
+    /// in pixels, at most `TextStroke::MAX_WIDTH`
+    pub width: f32,
+    pub color: RGBAColor,
+}
+
+impl TextStroke {
+    /// Distance to the glyphs is searched up to this width, so every pixel
+    /// of the stroke samples `O(width)` pixels.
+    pub const MAX_WIDTH: f32 = 20.0;
+}
+
+/// Copy of the text (including a stroke) rendered below it.
+#[derive(Debug, Clone)]
+pub struct TextShadow {
+    /// in pixels
+    pub offset_x: f32,
+    /// in pixels
+    pub offset_y: f32,
+    pub color: RGBAColor,
+}
+
+/// Content of a text that changes over time without scene updates.
+#[derive(Debug, Clone)]
+pub enum DynamicText {
+    /// Current time of the system clock.
+    Clock {
+        /// strftime-like format, see `chrono::format::strftime`.
+        format: Arc<str>,
+        timezone: ClockTimezone,
+    },
+    /// PTS of the output frame formatted as a SMPTE timecode (`HH:MM:SS:FF`).
+    Timecode {
+        framerate: Framerate,
+        /// Skip frame numbers to keep the timecode in sync with the wall clock
+        /// for NTSC framerates (29.97 and 59.94). Frames are separated with `;`.
+        drop_frame: bool,
+    },
+    /// Time left until `end` (based on the system clock) in `HH:MM:SS` format.
+    Countdown { end: SystemTime },
+    /// Number that is increased by `step` every `interval`. Counting starts
+    /// when the component is added to the scene.
+    Counter {
+        start: i64,
+        step: i64,
+        interval: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ClockTimezone {
+    Utc,
+    Local,
+    /// Offset from UTC in seconds.
+    Offset(i32),
+    /// Timezone from the IANA database, follows daylight saving time changes.
+    Named(chrono_tz::Tz),
+}
+
+impl ClockTimezone {
+    /// Parses an IANA timezone name, e.g. `"Europe/Warsaw"`.
+    pub fn named(name: &str) -> Option<Self> {
+        name.parse().ok().map(Self::Named)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TextStyle {
     Normal,
@@ -131,6 +334,85 @@ pub enum TextDimensions {
     },
 }
 
+/// Text of the subtitle cues displayed at the PTS of the output frame.
+#[derive(Debug, Clone)]
+pub struct SubtitlesComponent {
+    pub id: Option<ComponentId>,
+    /// Subtitles registered with `RendererSpec::Subtitles`.
+    pub subtitles_id: RendererId,
+    /// in milliseconds, positive values display cues later
+    pub offset_ms: i64,
+    /// Styling of the cues, `spans` and `dynamic` are ignored.
+    pub text: TextComponent,
+    pub width: f32,
+    pub height: f32,
+    /// Position of the cue text inside the component.
+    pub vertical_align: VerticalAlign,
+}
+
+/// Text scrolled in a loop with a constant speed.
+#[derive(Debug, Clone)]
+pub struct TickerComponent {
+    pub id: Option<ComponentId>,
+    pub position: Position,
+    /// Content of a single iteration of the loop.
+    pub text: TextComponent,
+    /// Rendered after each iteration, uses the same style as `text`.
+    pub separator: Arc<str>,
+    /// in pixels per second
+    pub speed: f32,
+    pub direction: TickerDirection,
+    pub background_color: RGBAColor,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TickerDirection {
+    /// Text enters from the right edge and moves to the left.
+    Left,
+    /// Text enters from the left edge and moves to the right.
+    Right,
+}
+
+/// Audio level of an input stream. Levels are displayed on a dBFS scale from `min_db`
+/// to 0.
+#[derive(Debug, Clone)]
+pub struct AudioMeterComponent {
+    pub id: Option<ComponentId>,
+    pub input_id: InputId,
+    pub position: Position,
+    pub style: AudioMeterStyle,
+    pub orientation: AudioMeterOrientation,
+    /// in dBFS, quieter levels are displayed as silence
+    pub min_db: f32,
+    /// in dBFS, levels above are displayed with `warning_color`
+    pub warning_db: f32,
+    /// in dBFS, levels above are displayed with `clip_color`
+    pub clip_db: f32,
+    pub color: RGBAColor,
+    pub warning_color: RGBAColor,
+    pub clip_color: RGBAColor,
+    pub background_color: RGBAColor,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AudioMeterStyle {
+    /// Continuous bar that shows the RMS level with a marker on the peak level.
+    Bar,
+    /// Bar split into segments that are lit up to the RMS level.
+    Segmented { segments: u32 },
+    /// Peak levels from the last `duration` displayed as `bars`, the newest at the end
+    /// of the component.
+    Waveform { duration: Duration, bars: u32 },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AudioMeterOrientation {
+    /// Meter fills from the bottom, waveform scrolls down.
+    Vertical,
+    /// Meter fills from the left, waveform scrolls to the left.
+    Horizontal,
+}
+
 #[derive(Debug, Clone)]
 pub struct ViewComponent {
     pub id: Option<ComponentId>,
@@ -139,15 +421,46 @@ pub struct ViewComponent {
     pub direction: ViewChildrenDirection,
     pub position: Position,
     pub transition: Option<Transition>,
+    pub enter_transition: Option<EnterExitTransition>,
+    pub exit_transition: Option<EnterExitTransition>,
+    pub animations: Vec<KeyframeAnimation>,
     pub overflow: Overflow,
 
     pub background_color: RGBAColor,
+    /// Replaces `background_color` if defined.
+    pub background_gradient: Option<Gradient>,
 
     pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: RGBAColor,
+    /// Replaces `border_color` if defined.
+    pub border_gradient: Option<Gradient>,
 
     pub box_shadow: Vec<BoxShadow>,
+
+    pub opacity: f32,
+    pub blend_mode: BlendMode,
+    /// Blur applied to the component and all of its children.
+    pub blur_radius: f32,
+    /// Blur applied to everything rendered behind the component.
+    pub backdrop_blur: f32,
+
+    pub padding: Padding,
+    /// Space between statically positioned children.
+    pub gap: f32,
+    pub justify_content: JustifyContent,
+    pub align_items: AlignItems,
+
+    /// Share of the free space in the parent View that is added to this component.
+    pub flex_grow: f32,
+    /// Share of the overflowing space in the parent View that is removed from this
+    /// component (weighted by its size).
+    pub flex_shrink: f32,
+
+    pub grid_placement: GridPlacement,
+    /// Places the component in the spotlight (or first in other modes) if it is a child
+    /// of a Tiles component.
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -157,12 +470,121 @@ pub enum Overflow {
     Fit,
 }
 
+/// Defines how colors of a component are combined with colors of everything
+/// rendered below it. Alpha is always composed as with `Normal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Add,
+    Difference,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Transition {
     pub duration: Duration,
     pub interpolation_kind: InterpolationKind,
 }
 
+/// Animation of a single component property defined by a list of keyframes. It starts
+/// when the component with this animation is added to the scene.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyframeAnimation {
+    pub property: AnimatedProperty,
+    /// Sorted by offset. Contains at least one element.
+    pub keyframes: Vec<Keyframe>,
+    /// Duration of a single iteration.
+    pub duration: Duration,
+    pub delay: Duration,
+    /// `None` means that animation repeats infinitely.
+    pub iterations: Option<u32>,
+    pub direction: AnimationDirection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    /// Value in range [0, 1] that represents a point within an iteration.
+    pub offset: f64,
+    pub value: KeyframeValue,
+    /// Interpolation between this keyframe and the next one.
+    pub interpolation_kind: InterpolationKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyframeValue {
+    Number(f32),
+    Color(RGBAColor),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimatedProperty {
+    Top,
+    Left,
+    Bottom,
+    Right,
+    Width,
+    Height,
+    Rotation,
+    Opacity,
+    BorderWidth,
+    BorderRadius,
+    BorderColor,
+    BackgroundColor,
+    CropTop,
+    CropLeft,
+    CropWidth,
+    CropHeight,
+    KeyColor,
+    Similarity,
+    Smoothness,
+    Spill,
+    Brightness,
+    Contrast,
+    Saturation,
+    Gamma,
+    Temperature,
+    Tint,
+    LutStrength,
+    BlurRadius,
+    BackdropBlur,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+/// Animation applied when a component is added to or removed from the scene.
+#[derive(Debug, Clone, Copy)]
+pub struct EnterExitTransition {
+    pub effect: EnterExitEffect,
+    pub transition: Transition,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum EnterExitEffect {
+    Fade,
+    /// Move a component by its own width/height from (or to) a specified edge.
+    Slide {
+        from: SlideEdge,
+    },
+    /// Scale a component around its center.
+    Scale,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SlideEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Position {
     Static {
@@ -178,6 +600,46 @@ pub enum ViewChildrenDirection {
     Column,
 }
 
+/// Space between the border of a View and its statically positioned children.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Padding {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Padding {
+    pub const ZERO: Padding = Padding {
+        top: 0.0,
+        right: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+    };
+}
+
+/// Alignment of static children along the main axis (defined by `ViewChildrenDirection`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JustifyContent {
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+/// Alignment of static children along the cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignItems {
+    Start,
+    End,
+    Center,
+    /// Children without explicit size are stretched to fill the View, the ones with
+    /// explicit size are aligned to the start.
+    Stretch,
+}
+
 #[derive(Debug, Clone)]
 pub struct RescalerComponent {
     pub id: Option<ComponentId>,
@@ -185,6 +647,9 @@ pub struct RescalerComponent {
 
     pub position: Position,
     pub transition: Option<Transition>,
+    pub enter_transition: Option<EnterExitTransition>,
+    pub exit_transition: Option<EnterExitTransition>,
+    pub animations: Vec<KeyframeAnimation>,
 
     pub mode: RescaleMode,
     pub horizontal_align: HorizontalAlign,
@@ -195,6 +660,19 @@ pub struct RescalerComponent {
     pub border_color: RGBAColor,
 
     pub box_shadow: Vec<BoxShadow>,
+
+    pub opacity: f32,
+    pub blend_mode: BlendMode,
+    /// Blur applied to the component and its child.
+    pub blur_radius: f32,
+
+    pub flex_grow: f32,
+    pub flex_shrink: f32,
+
+    pub grid_placement: GridPlacement,
+    pub pinned: bool,
+
+    pub crop: Option<RescalerCrop>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -203,6 +681,24 @@ pub enum RescaleMode {
     Fill,
 }
 
+/// Part of the child (source rectangle) that is rendered by the Rescaler.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RescalerCrop {
+    pub top: f32,
+    pub left: f32,
+    pub width: f32,
+    pub height: f32,
+    pub unit: CropUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CropUnit {
+    /// Values in pixels of the child component.
+    Pixels,
+    /// Values relative to the child size, `1.0` represents the entire width or height.
+    Normalized,
+}
+
 #[derive(Debug, Clone)]
 pub struct TilesComponent {
     pub id: Option<ComponentId>,
@@ -217,6 +713,78 @@ pub struct TilesComponent {
     pub padding: f32,
     pub horizontal_align: HorizontalAlign,
     pub vertical_align: VerticalAlign,
+    pub opacity: f32,
+
+    pub layout_mode: TilesLayoutMode,
+    /// Ids of children that should be placed first (in that order). Remaining children
+    /// keep their order.
+    pub order: Vec<ComponentId>,
+
+    pub transition: Option<Transition>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TilesLayoutMode {
+    /// All tiles have the same size.
+    Grid,
+    /// Pinned tiles are large and placed on the left, remaining tiles form a column on the right.
+    SpotlightLeft,
+    /// Pinned tiles are large and placed on the top, remaining tiles form a row at the bottom.
+    SpotlightTop,
+    /// All tiles are placed in a single row.
+    Filmstrip,
+}
+
+#[derive(Debug, Clone)]
+pub struct GridComponent {
+    pub id: Option<ComponentId>,
+    pub children: Vec<Component>,
+
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+
+    /// Sizes of columns. Empty list is treated as a single `GridTrack::Fraction(1.0)`.
+    pub columns: Vec<GridTrack>,
+    /// Sizes of rows. If children do not fit, additional `GridTrack::Auto` rows are added.
+    pub rows: Vec<GridTrack>,
+    pub column_gap: f32,
+    pub row_gap: f32,
+
+    pub background_color: RGBAColor,
+    pub opacity: f32,
 
     pub transition: Option<Transition>,
 }
+
+/// Size of a single row or column of a Grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// Size in pixels.
+    Pixels(f32),
+    /// Share of the space left after sizing all pixel and auto tracks.
+    Fraction(f32),
+    /// Largest known size of children placed only in this track. If there are no
+    /// fraction tracks, auto tracks share the remaining space.
+    Auto,
+}
+
+/// Position of a child inside a Grid. Row and column indexes start from 0. Children that
+/// do not define both `row` and `column` are placed in the first free cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridPlacement {
+    pub row: Option<u32>,
+    pub column: Option<u32>,
+    pub row_span: u32,
+    pub column_span: u32,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            row: None,
+            column: None,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::scene::{
     types::interpolation::{ContinuousValue, InterpolationState},
-    BorderRadius, BoxShadow,
+    BorderRadius, BoxShadow, Padding,
 };
 
 use super::{AbsolutePosition, Position};
@@ -24,6 +24,16 @@ impl ContinuousValue for Position {
             (_, end) => *end,
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        match self {
+            Position::Static { width, height } => {
+                width.push_coordinates(coordinates);
+                height.push_coordinates(coordinates);
+            }
+            Position::Absolute(position) => position.push_coordinates(coordinates),
+        }
+    }
 }
 
 impl ContinuousValue for AbsolutePosition {
@@ -48,6 +58,14 @@ impl ContinuousValue for AbsolutePosition {
             ),
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        self.width.push_coordinates(coordinates);
+        self.height.push_coordinates(coordinates);
+        self.position_horizontal.push_coordinates(coordinates);
+        self.position_vertical.push_coordinates(coordinates);
+        self.rotation_degrees.push_coordinates(coordinates);
+    }
 }
 
 impl ContinuousValue for BorderRadius {
@@ -63,6 +81,33 @@ impl ContinuousValue for BorderRadius {
             bottom_left: ContinuousValue::interpolate(&start.bottom_left, &end.bottom_left, state),
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend(
+            [
+                self.top_left,
+                self.top_right,
+                self.bottom_right,
+                self.bottom_left,
+            ]
+            .map(f64::from),
+        )
+    }
+}
+
+impl ContinuousValue for Padding {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            top: ContinuousValue::interpolate(&start.top, &end.top, state),
+            right: ContinuousValue::interpolate(&start.right, &end.right, state),
+            bottom: ContinuousValue::interpolate(&start.bottom, &end.bottom, state),
+            left: ContinuousValue::interpolate(&start.left, &end.left, state),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend([self.top, self.right, self.bottom, self.left].map(f64::from))
+    }
 }
 
 impl ContinuousValue for Vec<BoxShadow> {
@@ -76,6 +121,12 @@ impl ContinuousValue for Vec<BoxShadow> {
             .chain(end.iter().skip(usize::min(start.len(), end.len())).copied())
             .collect()
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        for shadow in self {
+            shadow.push_coordinates(coordinates)
+        }
+    }
 }
 
 impl ContinuousValue for BoxShadow {
@@ -87,4 +138,8 @@ impl ContinuousValue for BoxShadow {
             color: end.color,
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend([self.offset_x, self.offset_y, self.blur_radius].map(f64::from))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::scene::AbsolutePosition;
+use crate::scene::{AbsolutePosition, Padding};
 
 use super::Position;
 
@@ -24,4 +24,20 @@ impl Position {
             }),
         }
     }
+
+    pub(crate) fn with_padding(self, padding: Padding) -> Self {
+        let horizontal = padding.left + padding.right;
+        let vertical = padding.top + padding.bottom;
+        match self {
+            Position::Static { width, height } => Self::Static {
+                width: width.map(|w| w + horizontal),
+                height: height.map(|h| h + vertical),
+            },
+            Position::Absolute(position) => Self::Absolute(AbsolutePosition {
+                width: position.width.map(|w| w + horizontal),
+                height: position.height.map(|h| h + vertical),
+                ..position
+            }),
+        }
+    }
 }
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use crate::transformations::layout::NestedLayout;
+
+use self::{
+    cells::GridCell,
+    layout::{layout_grid, resize_cells},
+};
+
+use super::{
+    layout::StatefulLayoutComponent,
+    scene_state::BuildStateTreeCtx,
+    transition::{TransitionOptions, TransitionState},
+    types::interpolation::ContinuousValue,
+    Component, ComponentId, GridComponent, GridTrack, IntermediateNode, Position, RGBAColor,
+    SceneError, Size, StatefulComponent,
+};
+
+mod cells;
+mod interpolation;
+mod layout;
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulGridComponent {
+    /// Initial state for transition. To calculate scene at specific PTS you need
+    /// to interpolate between this state and `self.component.cells(...)`.
+    start: Option<(Vec<GridCell>, Size)>,
+    /// Initial opacity for transition.
+    start_opacity: Option<f32>,
+
+    /// Cell positions from last layout call. This field is a source for
+    /// `start` value after scene update.
+    last_layout: Option<(Vec<GridCell>, Size)>,
+
+    transition: Option<TransitionState>,
+
+    component: GridComponentParams,
+    children: Vec<StatefulComponent>,
+}
+
+#[derive(Debug, Clone)]
+struct GridComponentParams {
+    id: Option<ComponentId>,
+
+    width: Option<f32>,
+    height: Option<f32>,
+
+    columns: Vec<GridTrack>,
+    rows: Vec<GridTrack>,
+    column_gap: f32,
+    row_gap: f32,
+
+    background_color: RGBAColor,
+    opacity: f32,
+}
+
+impl StatefulGridComponent {
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    fn cells(&self, size: Size, pts: Duration) -> Vec<GridCell> {
+        let end = self.component.cells(size, &self.children, pts);
+        let (Some((start, start_size)), Some(transition)) = (&self.start, &self.transition) else {
+            return end;
+        };
+        let start = resize_cells(start, start_size, &size);
+        let interpolation_progress = transition.state(pts);
+        ContinuousValue::interpolate(&start, &end, interpolation_progress)
+    }
+
+    fn opacity(&self, pts: Duration) -> f32 {
+        let (Some(start), Some(transition)) = (self.start_opacity, &self.transition) else {
+            return self.component.opacity;
+        };
+        ContinuousValue::interpolate(&start, &self.component.opacity, transition.state(pts))
+    }
+
+    pub(super) fn position(&self, _pts: Duration) -> Position {
+        Position::Static {
+            width: self.component.width,
+            height: self.component.height,
+        }
+    }
+
+    pub(super) fn children(&self) -> Vec<&StatefulComponent> {
+        self.children.iter().collect()
+    }
+
+    pub(super) fn children_mut(&mut self) -> Vec<&mut StatefulComponent> {
+        self.children.iter_mut().collect()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        let children = self
+            .children
+            .iter()
+            .flat_map(|component| {
+                let node = component.intermediate_node();
+                match node {
+                    IntermediateNode::Layout { root: _, children } => children,
+                    _ => vec![node],
+                }
+            })
+            .collect();
+
+        IntermediateNode::Layout {
+            root: StatefulLayoutComponent::Grid(self.clone()),
+            children,
+        }
+    }
+
+    pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
+        let removed = StatefulLayoutComponent::take_removed_children(&mut self.children, pts);
+        let cells = self.cells(size, pts);
+        let mut layout = layout_grid(
+            &cells,
+            size,
+            &mut self.children,
+            pts,
+            self.component.background_color,
+            self.opacity(pts),
+        );
+        StatefulLayoutComponent::restore_removed_children(&mut self.children, removed, &mut layout);
+        self.last_layout = Some((cells, size));
+        layout
+    }
+}
+
+impl GridComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let previous_state = self
+            .id
+            .as_ref()
+            .and_then(|id| ctx.prev_state.get(id))
+            .and_then(|component| match component {
+                StatefulComponent::Layout(StatefulLayoutComponent::Grid(grid_state)) => {
+                    Some(grid_state)
+                }
+                _ => None,
+            });
+
+        let start = previous_state.and_then(|state| state.last_layout.clone());
+        let start_opacity = previous_state.map(|state| state.opacity(ctx.last_render_pts));
+        let transition = TransitionState::new(
+            self.transition.map(|transition| TransitionOptions {
+                duration: transition.duration,
+                interpolation_kind: transition.interpolation_kind,
+            }),
+            previous_state.and_then(|s| s.transition.clone()),
+            // Target layout is not known until the component is laid out.
+            None,
+            ctx.last_render_pts,
+        );
+
+        let mut children = self
+            .children
+            .into_iter()
+            .map(|c| Component::stateful_component(c, ctx))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(previous_state) = previous_state {
+            ctx.retain_exiting_children(&previous_state.children, &mut children);
+        }
+
+        let grid = StatefulGridComponent {
+            start,
+            start_opacity,
+            last_layout: previous_state.and_then(|state| state.last_layout.clone()),
+            component: GridComponentParams {
+                id: self.id,
+                width: self.width,
+                height: self.height,
+                columns: self.columns,
+                rows: self.rows,
+                column_gap: self.column_gap,
+                row_gap: self.row_gap,
+                background_color: self.background_color,
+                opacity: self.opacity,
+            },
+            transition,
+            children,
+        };
+
+        Ok(StatefulComponent::Layout(StatefulLayoutComponent::Grid(
+            grid,
+        )))
+    }
+}
@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use crate::scene::{GridPlacement, GridTrack, Size, StatefulComponent};
+
+use super::{interpolation::CellId, GridComponentParams};
+
+#[derive(Debug, Clone)]
+pub(super) struct GridCell {
+    pub id: CellId,
+    pub top: f32,
+    pub left: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Rows and columns occupied by a child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellArea {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+impl GridComponentParams {
+    pub(super) fn cells(
+        &self,
+        size: Size,
+        children: &[StatefulComponent],
+        pts: Duration,
+    ) -> Vec<GridCell> {
+        let columns = match self.columns.is_empty() {
+            true => vec![GridTrack::Fraction(1.0)],
+            false => self.columns.clone(),
+        };
+        let placements: Vec<_> = children
+            .iter()
+            .map(|child| match child {
+                StatefulComponent::Layout(layout) => layout.grid_placement(),
+                _ => GridPlacement::default(),
+            })
+            .collect();
+        let areas = place_children(columns.len(), &placements);
+
+        let rows_count = areas
+            .iter()
+            .map(|area| area.row + area.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.rows.len());
+        let mut rows = self.rows.clone();
+        rows.resize(rows_count, GridTrack::Auto);
+
+        let column_sizes = track_sizes(
+            &columns,
+            size.width,
+            self.column_gap,
+            children
+                .iter()
+                .zip(areas.iter())
+                .map(|(child, area)| (area.column, area.column_span, child.width(pts))),
+        );
+        let row_sizes = track_sizes(
+            &rows,
+            size.height,
+            self.row_gap,
+            children
+                .iter()
+                .zip(areas.iter())
+                .map(|(child, area)| (area.row, area.row_span, child.height(pts))),
+        );
+
+        let mut index = 0;
+        children
+            .iter()
+            .zip(areas)
+            .map(|(child, area)| GridCell {
+                id: match child.component_id() {
+                    Some(id) => CellId::ComponentId(id.clone()),
+                    None => {
+                        let id = CellId::Index(index);
+                        index += 1;
+                        id
+                    }
+                },
+                top: track_offset(&row_sizes, area.row, self.row_gap),
+                left: track_offset(&column_sizes, area.column, self.column_gap),
+                width: span_size(
+                    &column_sizes,
+                    area.column,
+                    area.column_span,
+                    self.column_gap,
+                ),
+                height: span_size(&row_sizes, area.row, area.row_span, self.row_gap),
+            })
+            .collect()
+    }
+}
+
+/// Assign cells to children. Children with both row and column defined are placed first,
+/// the rest is placed in the first free area (in row-major order) that matches their
+/// explicit row or column. Column spans are limited to the number of columns, additional
+/// rows are added if needed.
+fn place_children(columns_count: usize, placements: &[GridPlacement]) -> Vec<CellArea> {
+    let mut occupied: Vec<Vec<bool>> = vec![];
+    let is_free = |occupied: &Vec<Vec<bool>>, area: &CellArea| {
+        (area.row..area.row + area.row_span).all(|row| {
+            (area.column..area.column + area.column_span)
+                .all(|column| !occupied.get(row).is_some_and(|row| row[column]))
+        })
+    };
+    let mark_occupied = |occupied: &mut Vec<Vec<bool>>, area: CellArea| {
+        if occupied.len() < area.row + area.row_span {
+            occupied.resize(area.row + area.row_span, vec![false; columns_count]);
+        }
+        for row in occupied.iter_mut().skip(area.row).take(area.row_span) {
+            for cell in row.iter_mut().skip(area.column).take(area.column_span) {
+                *cell = true;
+            }
+        }
+    };
+    let new_area = |row: usize, column: usize, placement: &GridPlacement| {
+        let column = usize::min(column, columns_count - 1);
+        CellArea {
+            row,
+            column,
+            row_span: usize::max(placement.row_span as usize, 1),
+            column_span: (placement.column_span as usize).clamp(1, columns_count - column),
+        }
+    };
+
+    let mut areas: Vec<Option<CellArea>> = vec![None; placements.len()];
+    for (placement, result) in placements.iter().zip(areas.iter_mut()) {
+        if let (Some(row), Some(column)) = (placement.row, placement.column) {
+            let area = new_area(row as usize, column as usize, placement);
+            mark_occupied(&mut occupied, area);
+            *result = Some(area);
+        }
+    }
+    for (placement, result) in placements.iter().zip(areas.iter_mut()) {
+        if result.is_some() {
+            continue;
+        }
+        // There is always a free area in the first row after the occupied ones.
+        let rows = match placement.row {
+            Some(row) => row as usize..=row as usize,
+            None => 0..=occupied.len(),
+        };
+        let columns = match placement.column {
+            Some(column) => column as usize..=column as usize,
+            None => 0..=columns_count - 1,
+        };
+        let first_free_area = rows
+            .clone()
+            .flat_map(|row| columns.clone().map(move |column| (row, column)))
+            .map(|(row, column)| new_area(row, column, placement))
+            .find(|area| is_free(&occupied, area));
+        let area =
+            first_free_area.unwrap_or_else(|| new_area(*rows.end(), *columns.start(), placement));
+        mark_occupied(&mut occupied, area);
+        *result = Some(area);
+    }
+    areas.into_iter().flatten().collect()
+}
+
+/// Calculate sizes of tracks (rows or columns). `children` contains the index of
+/// the first track, the span, and the known size of every child.
+fn track_sizes(
+    tracks: &[GridTrack],
+    available_size: f32,
+    gap: f32,
+    children: impl Iterator<Item = (usize, usize, Option<f32>)>,
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            GridTrack::Pixels(size) => *size,
+            GridTrack::Fraction(_) | GridTrack::Auto => 0.0,
+        })
+        .collect();
+    for (track, span, size) in children {
+        if let (1, Some(size), Some(GridTrack::Auto)) = (span, size, tracks.get(track)) {
+            sizes[track] = f32::max(sizes[track], size);
+        }
+    }
+
+    let gaps_size = gap * tracks.len().saturating_sub(1) as f32;
+    let free_space = f32::max(available_size - gaps_size - sizes.iter().sum::<f32>(), 0.0);
+    let fractions_sum: f32 = tracks
+        .iter()
+        .map(|track| match track {
+            GridTrack::Fraction(fraction) => *fraction,
+            _ => 0.0,
+        })
+        .sum();
+    let auto_count = tracks
+        .iter()
+        .filter(|track| **track == GridTrack::Auto)
+        .count();
+    for (size, track) in sizes.iter_mut().zip(tracks) {
+        match track {
+            GridTrack::Fraction(fraction) if fractions_sum > 0.0 => {
+                *size = free_space * fraction / fractions_sum
+            }
+            // If there are no fraction tracks, free space is distributed between auto tracks.
+            GridTrack::Auto if fractions_sum <= 0.0 => *size += free_space / auto_count as f32,
+            _ => (),
+        }
+    }
+    sizes
+}
+
+fn track_offset(sizes: &[f32], track: usize, gap: f32) -> f32 {
+    sizes[..track].iter().sum::<f32>() + gap * track as f32
+}
+
+fn span_size(sizes: &[f32], track: usize, span: usize, gap: f32) -> f32 {
+    sizes[track..track + span].iter().sum::<f32>() + gap * (span - 1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scene::{GridPlacement, GridTrack};
+
+    use super::{place_children, track_sizes, CellArea};
+
+    fn area(row: usize, column: usize, row_span: usize, column_span: usize) -> CellArea {
+        CellArea {
+            row,
+            column,
+            row_span,
+            column_span,
+        }
+    }
+
+    #[test]
+    fn auto_placement_around_spanning_child() {
+        let spanning = GridPlacement {
+            row: Some(0),
+            column: Some(0),
+            column_span: 2,
+            ..Default::default()
+        };
+        let placements = [
+            GridPlacement::default(),
+            spanning,
+            GridPlacement::default(),
+            GridPlacement::default(),
+        ];
+        assert_eq!(
+            place_children(2, &placements),
+            vec![
+                area(1, 0, 1, 1),
+                area(0, 0, 1, 2),
+                area(1, 1, 1, 1),
+                area(2, 0, 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn fraction_pixel_and_auto_tracks() {
+        let tracks = [
+            GridTrack::Pixels(100.0),
+            GridTrack::Fraction(1.0),
+            GridTrack::Auto,
+            GridTrack::Fraction(3.0),
+        ];
+        let children = [(2, 1, Some(50.0)), (0, 2, Some(500.0))].into_iter();
+        assert_eq!(
+            track_sizes(&tracks, 600.0, 10.0, children),
+            vec![100.0, 105.0, 50.0, 315.0]
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::scene::{
+    types::interpolation::{ContinuousValue, InterpolationState},
+    ComponentId,
+};
+
+use super::cells::GridCell;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub(super) enum CellId {
+    ComponentId(ComponentId),
+    Index(usize),
+}
+
+impl ContinuousValue for Vec<GridCell> {
+    /// Cells are matched by id. Children that did not exist in the previous
+    /// scene are rendered in their final cells from the start of a transition.
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        if state.0 >= 1.0 {
+            return end.clone();
+        };
+
+        end.iter()
+            .map(|cell| {
+                start
+                    .iter()
+                    .find(|start_cell| start_cell.id == cell.id)
+                    .map(|start_cell| ContinuousValue::interpolate(start_cell, cell, state))
+                    .unwrap_or_else(|| cell.clone())
+            })
+            .collect()
+    }
+}
+
+impl ContinuousValue for GridCell {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            id: end.id.clone(),
+            top: ContinuousValue::interpolate(&start.top, &end.top, state),
+            left: ContinuousValue::interpolate(&start.left, &end.left, state),
+            width: ContinuousValue::interpolate(&start.width, &end.width, state),
+            height: ContinuousValue::interpolate(&start.height, &end.height, state),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use crate::{
+    scene::{
+        layout::StatefulLayoutComponent, BlendMode, BorderRadius, RGBAColor, Size,
+        StatefulComponent,
+    },
+    transformations::layout::{LayoutContent, NestedLayout},
+};
+
+use super::cells::GridCell;
+
+pub(super) fn layout_grid(
+    cells: &[GridCell],
+    size: Size,
+    children: &mut [StatefulComponent],
+    pts: Duration,
+    background_color: RGBAColor,
+    opacity: f32,
+) -> NestedLayout {
+    let children = children
+        .iter_mut()
+        .zip(cells)
+        .map(|(component, cell)| layout_child(component, cell.clone(), pts))
+        .collect::<Vec<_>>();
+
+    NestedLayout {
+        top: 0.0,
+        left: 0.0,
+        width: size.width,
+        height: size.height,
+        rotation_degrees: 0.0,
+        scale_x: 1.0,
+        scale_y: 1.0,
+        crop: None,
+        mask: None,
+        content: LayoutContent::Color(background_color),
+        child_nodes_count: children.iter().map(|l| l.child_nodes_count).sum(),
+        children,
+        border_width: 0.0,
+        border_color: RGBAColor(0, 0, 0, 0),
+        border_gradient: None,
+        border_radius: BorderRadius::ZERO,
+        box_shadow: vec![],
+        blend_mode: BlendMode::Normal,
+        opacity,
+        blur_radius: 0.0,
+        backdrop_blur: 0.0,
+    }
+}
+
+fn layout_child(child: &mut StatefulComponent, cell: GridCell, pts: Duration) -> NestedLayout {
+    match child {
+        StatefulComponent::Layout(layout_component) => {
+            let children_layouts = layout_component.layout(
+                Size {
+                    width: cell.width,
+                    height: cell.height,
+                },
+                pts,
+            );
+            NestedLayout {
+                top: cell.top,
+                left: cell.left,
+                width: cell.width,
+                height: cell.height,
+                rotation_degrees: 0.0,
+                scale_x: 1.0,
+                scale_y: 1.0,
+                crop: None,
+                mask: None,
+                content: LayoutContent::None,
+                child_nodes_count: children_layouts.child_nodes_count,
+                children: vec![children_layouts],
+                border_width: 0.0,
+                border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
+                border_radius: BorderRadius::ZERO,
+                box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
+            }
+        }
+        _ => {
+            let fitted = fit_into_cell(cell, child, pts);
+            NestedLayout {
+                top: fitted.top,
+                left: fitted.left,
+                width: fitted.width,
+                height: fitted.height,
+                rotation_degrees: 0.0,
+                scale_x: 1.0,
+                scale_y: 1.0,
+                crop: None,
+                mask: None,
+                content: StatefulLayoutComponent::layout_content(child, 0),
+                child_nodes_count: 1,
+                children: vec![],
+                border_width: 0.0,
+                border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
+                border_radius: BorderRadius::ZERO,
+                box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
+            }
+        }
+    }
+}
+
+/// Non-layout components are scaled to fit into the cell (preserving aspect ratio)
+/// and centered inside it.
+fn fit_into_cell(cell: GridCell, component: &StatefulComponent, pts: Duration) -> GridCell {
+    let (Some(width), Some(height)) = (component.width(pts), component.height(pts)) else {
+        return cell;
+    };
+    if width <= 0.0 || height <= 0.0 {
+        return cell;
+    }
+    let scale_factor = f32::min(cell.width / width, cell.height / height);
+
+    GridCell {
+        top: cell.top + (cell.height - scale_factor * height) / 2.0,
+        left: cell.left + (cell.width - scale_factor * width) / 2.0,
+        width: scale_factor * width,
+        height: scale_factor * height,
+        id: cell.id,
+    }
+}
+
+pub(super) fn resize_cells(
+    cells: &[GridCell],
+    original_size: &Size,
+    desired_size: &Size,
+) -> Vec<GridCell> {
+    // All cells of an empty grid have zero size, so they start collapsed at the edge.
+    let scale = |desired: f32, original: f32| match original > 0.0 {
+        true => desired / original,
+        false => 0.0,
+    };
+    let scale_x = scale(desired_size.width, original_size.width);
+    let scale_y = scale(desired_size.height, original_size.height);
+    cells
+        .iter()
+        .map(|cell| GridCell {
+            id: cell.id.clone(),
+            top: cell.top * scale_y,
+            left: cell.left * scale_x,
+            width: cell.width * scale_x,
+            height: cell.height * scale_y,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scene::{grid_component::interpolation::CellId, Size};
+
+    use super::{resize_cells, GridCell};
+
+    #[test]
+    fn resize_cells_of_empty_grid() {
+        let cells = [GridCell {
+            id: CellId::Index(0),
+            top: 10.0,
+            left: 0.0,
+            width: 0.0,
+            height: 20.0,
+        }];
+        let original_size = Size {
+            width: 0.0,
+            height: 40.0,
+        };
+        let desired_size = Size {
+            width: 100.0,
+            height: 80.0,
+        };
+        let resized = resize_cells(&cells, &original_size, &desired_size);
+        assert_eq!(
+            (
+                resized[0].top,
+                resized[0].left,
+                resized[0].width,
+                resized[0].height
+            ),
+            (20.0, 0.0, 0.0, 40.0)
+        );
+    }
+}
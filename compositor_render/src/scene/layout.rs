@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{mem, time::Duration};
 
 use crate::{
     transformations::layout::{self, LayoutContent, NestedLayout},
@@ -6,9 +6,11 @@ use crate::{
 };
 
 use super::{
-    rescaler_component::StatefulRescalerComponent, tiles_component::StatefulTilesComponent,
-    view_component::StatefulViewComponent, AbsolutePosition, BorderRadius, ComponentId,
-    HorizontalPosition, Position, RGBAColor, Size, StatefulComponent, VerticalPosition,
+    audio_meter_component::StatefulAudioMeterComponent, grid_component::StatefulGridComponent,
+    rescaler_component::StatefulRescalerComponent, ticker_component::StatefulTickerComponent,
+    tiles_component::StatefulTilesComponent, view_component::StatefulViewComponent,
+    AbsolutePosition, BlendMode, BorderRadius, ComponentId, GridPlacement, HorizontalPosition,
+    Position, RGBAColor, Size, StatefulComponent, VerticalPosition,
 };
 
 #[derive(Debug, Clone)]
@@ -16,6 +18,9 @@ pub(super) enum StatefulLayoutComponent {
     View(StatefulViewComponent),
     Tiles(StatefulTilesComponent),
     Rescaler(StatefulRescalerComponent),
+    Grid(StatefulGridComponent),
+    Ticker(StatefulTickerComponent),
+    AudioMeter(StatefulAudioMeterComponent),
 }
 
 #[derive(Debug)]
@@ -46,6 +51,9 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(view) => view.layout(size, pts),
             StatefulLayoutComponent::Tiles(tiles) => tiles.layout(size, pts),
             StatefulLayoutComponent::Rescaler(rescaler) => rescaler.layout(size, pts),
+            StatefulLayoutComponent::Grid(grid) => grid.layout(size, pts),
+            StatefulLayoutComponent::Ticker(ticker) => ticker.layout(size, pts),
+            StatefulLayoutComponent::AudioMeter(meter) => meter.layout(size, pts),
         }
     }
 
@@ -55,6 +63,45 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(view) => view.position(pts),
             StatefulLayoutComponent::Tiles(tiles) => tiles.position(pts),
             StatefulLayoutComponent::Rescaler(rescaler) => rescaler.position(pts),
+            StatefulLayoutComponent::Grid(grid) => grid.position(pts),
+            StatefulLayoutComponent::Ticker(ticker) => ticker.position(pts),
+            StatefulLayoutComponent::AudioMeter(meter) => meter.position(pts),
+        }
+    }
+
+    /// Flex grow and flex shrink factors used if the component is a static child of a View.
+    pub(super) fn flex_factors(&self, pts: Duration) -> (f32, f32) {
+        match self {
+            StatefulLayoutComponent::View(view) => view.flex_factors(pts),
+            StatefulLayoutComponent::Tiles(_) => (0.0, 0.0),
+            StatefulLayoutComponent::Rescaler(rescaler) => rescaler.flex_factors(pts),
+            StatefulLayoutComponent::Grid(_) => (0.0, 0.0),
+            StatefulLayoutComponent::Ticker(_) => (0.0, 0.0),
+            StatefulLayoutComponent::AudioMeter(_) => (0.0, 0.0),
+        }
+    }
+
+    /// Placement of the component if it is a child of a Grid.
+    pub(super) fn grid_placement(&self) -> GridPlacement {
+        match self {
+            StatefulLayoutComponent::View(view) => view.grid_placement(),
+            StatefulLayoutComponent::Rescaler(rescaler) => rescaler.grid_placement(),
+            StatefulLayoutComponent::Tiles(_)
+            | StatefulLayoutComponent::Grid(_)
+            | StatefulLayoutComponent::Ticker(_)
+            | StatefulLayoutComponent::AudioMeter(_) => GridPlacement::default(),
+        }
+    }
+
+    /// Whether the component is pinned if it is a child of a Tiles.
+    pub(super) fn pinned(&self) -> bool {
+        match self {
+            StatefulLayoutComponent::View(view) => view.pinned(),
+            StatefulLayoutComponent::Rescaler(rescaler) => rescaler.pinned(),
+            StatefulLayoutComponent::Tiles(_)
+            | StatefulLayoutComponent::Grid(_)
+            | StatefulLayoutComponent::Ticker(_)
+            | StatefulLayoutComponent::AudioMeter(_) => false,
         }
     }
 
@@ -63,6 +110,9 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(view) => view.component_id(),
             StatefulLayoutComponent::Tiles(tiles) => tiles.component_id(),
             StatefulLayoutComponent::Rescaler(rescaler) => rescaler.component_id(),
+            StatefulLayoutComponent::Grid(grid) => grid.component_id(),
+            StatefulLayoutComponent::Ticker(ticker) => ticker.component_id(),
+            StatefulLayoutComponent::AudioMeter(meter) => meter.component_id(),
         }
     }
 
@@ -71,6 +121,80 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(_) => "View",
             StatefulLayoutComponent::Tiles(_) => "Tiles",
             StatefulLayoutComponent::Rescaler(_) => "Rescaler",
+            StatefulLayoutComponent::Grid(_) => "Grid",
+            StatefulLayoutComponent::Ticker(_) => "Ticker",
+            StatefulLayoutComponent::AudioMeter(_) => "AudioMeter",
+        }
+    }
+
+    /// Returns true if the component was removed from the scene and its exit transition
+    /// already finished at `pts`.
+    pub(super) fn is_exit_finished(&self, pts: Duration) -> bool {
+        match self {
+            StatefulLayoutComponent::View(view) => view.is_exit_finished(pts),
+            StatefulLayoutComponent::Rescaler(rescaler) => rescaler.is_exit_finished(pts),
+            StatefulLayoutComponent::Tiles(_)
+            | StatefulLayoutComponent::Grid(_)
+            | StatefulLayoutComponent::Ticker(_)
+            | StatefulLayoutComponent::AudioMeter(_) => false,
+        }
+    }
+
+    /// Exiting children are kept in the scene until the next scene update, even after their
+    /// exit transition finishes. Such children are taken out before the layout is calculated,
+    /// so they don't take any space. They need to be put back with `restore_removed_children`.
+    pub(super) fn take_removed_children(
+        children: &mut Vec<StatefulComponent>,
+        pts: Duration,
+    ) -> Vec<(usize, StatefulComponent)> {
+        let (removed, kept): (Vec<_>, Vec<_>) = mem::take(children)
+            .into_iter()
+            .enumerate()
+            .partition(|(_, child)| match child {
+                StatefulComponent::Layout(layout) => layout.is_exit_finished(pts),
+                _ => false,
+            });
+        *children = kept.into_iter().map(|(_, child)| child).collect();
+        removed
+    }
+
+    /// Puts back children taken out by `take_removed_children`. Their layouts are
+    /// placeholders, so child node indexes are still calculated correctly.
+    pub(super) fn restore_removed_children(
+        children: &mut Vec<StatefulComponent>,
+        removed: Vec<(usize, StatefulComponent)>,
+        layout: &mut NestedLayout,
+    ) {
+        for (index, child) in removed {
+            let child_nodes_count = match &child {
+                StatefulComponent::Layout(layout_component) => {
+                    layout_component.node_children().len()
+                }
+                _ => 1,
+            };
+            layout.children.insert(
+                index,
+                NestedLayout::child_nodes_placeholder(child_nodes_count),
+            );
+            layout.child_nodes_count += child_nodes_count;
+            children.insert(index, child);
+        }
+    }
+
+    /// Copy of a component that was removed from the scene, but should still be rendered
+    /// until its exit transition finishes.
+    pub(super) fn exiting(&self, last_pts: Duration) -> Option<Self> {
+        match self {
+            StatefulLayoutComponent::View(view) => {
+                view.exiting(last_pts).map(StatefulLayoutComponent::View)
+            }
+            StatefulLayoutComponent::Rescaler(rescaler) => rescaler
+                .exiting(last_pts)
+                .map(StatefulLayoutComponent::Rescaler),
+            StatefulLayoutComponent::Tiles(_) => None,
+            StatefulLayoutComponent::Grid(_) => None,
+            StatefulLayoutComponent::Ticker(_) => None,
+            StatefulLayoutComponent::AudioMeter(_) => None,
         }
     }
 
@@ -79,6 +203,9 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(view) => view.children(),
             StatefulLayoutComponent::Tiles(tiles) => tiles.children(),
             StatefulLayoutComponent::Rescaler(rescaler) => rescaler.children(),
+            StatefulLayoutComponent::Grid(grid) => grid.children(),
+            StatefulLayoutComponent::Ticker(ticker) => ticker.children(),
+            StatefulLayoutComponent::AudioMeter(meter) => meter.children(),
         }
     }
 
@@ -87,6 +214,9 @@ impl StatefulLayoutComponent {
             StatefulLayoutComponent::View(view) => view.children_mut(),
             StatefulLayoutComponent::Tiles(tiles) => tiles.children_mut(),
             StatefulLayoutComponent::Rescaler(rescaler) => rescaler.children_mut(),
+            StatefulLayoutComponent::Grid(grid) => grid.children_mut(),
+            StatefulLayoutComponent::Ticker(ticker) => ticker.children_mut(),
+            StatefulLayoutComponent::AudioMeter(meter) => meter.children_mut(),
         }
     }
 
@@ -115,6 +245,9 @@ impl StatefulLayoutComponent {
                     child_index_offset += 1;
                 }
                 StatefulComponent::Shader(_)
+                | StatefulComponent::Mask(_)
+                | StatefulComponent::ChromaKey(_)
+                | StatefulComponent::ColorCorrection(_)
                 | StatefulComponent::Image(_)
                 | StatefulComponent::Text(_)
                 | StatefulComponent::WebView(_) => {
@@ -137,22 +270,42 @@ impl StatefulLayoutComponent {
             StatefulComponent::InputStream(input) => LayoutContent::ChildNode {
                 index,
                 size: input.size,
+                gradient_fill: None,
             },
             StatefulComponent::Shader(shader) => LayoutContent::ChildNode {
                 index,
                 size: shader.component.size,
+                gradient_fill: None,
             },
             StatefulComponent::WebView(web) => LayoutContent::ChildNode {
                 index,
                 size: web.size(),
+                gradient_fill: None,
             },
             StatefulComponent::Image(image) => LayoutContent::ChildNode {
                 index,
                 size: image.size(),
+                gradient_fill: None,
             },
             StatefulComponent::Text(text) => LayoutContent::ChildNode {
                 index,
                 size: text.size(),
+                gradient_fill: text.gradient_fill(),
+            },
+            StatefulComponent::Mask(mask) => LayoutContent::ChildNode {
+                index,
+                size: mask.component.size,
+                gradient_fill: None,
+            },
+            StatefulComponent::ChromaKey(chroma_key) => LayoutContent::ChildNode {
+                index,
+                size: chroma_key.component.size,
+                gradient_fill: None,
+            },
+            StatefulComponent::ColorCorrection(color_correction) => LayoutContent::ChildNode {
+                index,
+                size: color_correction.component.size,
+                gradient_fill: None,
             },
         }
     }
@@ -203,8 +356,13 @@ impl StatefulLayoutComponent {
                     children: vec![children_layouts],
                     border_width: 0.0,
                     border_color: RGBAColor(0, 0, 0, 0),
+                    border_gradient: None,
                     border_radius: BorderRadius::ZERO,
                     box_shadow: vec![],
+                    blend_mode: BlendMode::Normal,
+                    opacity: 1.0,
+                    blur_radius: 0.0,
+                    backdrop_blur: 0.0,
                 }
             }
             _non_layout_components => {
@@ -229,8 +387,13 @@ impl StatefulLayoutComponent {
                     children: vec![],
                     border_width: 0.0,
                     border_color: RGBAColor(0, 0, 0, 0),
+                    border_gradient: None,
                     border_radius: BorderRadius::ZERO,
                     box_shadow: vec![],
+                    blend_mode: BlendMode::Normal,
+                    opacity: 1.0,
+                    blur_radius: 0.0,
+                    backdrop_blur: 0.0,
                 }
             }
         }
@@ -0,0 +1,89 @@
+use crate::transformations::mask_renderer::MaskPathBitmap;
+
+use super::{
+    scene_state::BuildStateTreeCtx, Component, ComponentId, IntermediateNode, MaskComponent,
+    MaskMode, MaskSource, SceneError, Size, StatefulComponent,
+};
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulMaskComponent {
+    pub(super) component: MaskComponentParams,
+    pub(super) child: Box<StatefulComponent>,
+    /// Component used as a mask. `None` if the mask is defined with a path.
+    pub(super) source: Option<Box<StatefulComponent>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MaskComponentParams {
+    pub(crate) id: Option<ComponentId>,
+    pub(crate) mode: MaskMode,
+    pub(crate) path: Option<MaskPathBitmap>,
+    pub(crate) size: Size,
+}
+
+impl StatefulMaskComponent {
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    pub(super) fn children(&self) -> Vec<&StatefulComponent> {
+        [Some(&self.child), self.source.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|child| &**child)
+            .collect()
+    }
+
+    pub(super) fn children_mut(&mut self) -> Vec<&mut StatefulComponent> {
+        [Some(&mut self.child), self.source.as_mut()]
+            .into_iter()
+            .flatten()
+            .map(|child| &mut **child)
+            .collect()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        let children = self
+            .children()
+            .into_iter()
+            .map(StatefulComponent::intermediate_node)
+            .collect();
+
+        IntermediateNode::Mask {
+            mask: self.clone(),
+            children,
+        }
+    }
+}
+
+impl MaskComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let (source, path) = match self.source {
+            MaskSource::Component(component) => (
+                Some(Box::new(Component::stateful_component(*component, ctx)?)),
+                None,
+            ),
+            MaskSource::Path(path) => {
+                let bitmap = ctx
+                    .mask_paths
+                    .bitmap(path, self.size.into())
+                    .map_err(SceneError::InvalidMaskPath)?;
+                (None, Some(bitmap))
+            }
+        };
+
+        Ok(StatefulComponent::Mask(StatefulMaskComponent {
+            component: MaskComponentParams {
+                id: self.id,
+                mode: self.mode,
+                path,
+                size: self.size,
+            },
+            child: Box::new(Component::stateful_component(*self.child, ctx)?),
+            source,
+        }))
+    }
+}
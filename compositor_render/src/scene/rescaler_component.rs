@@ -3,13 +3,15 @@ use std::time::Duration;
 use crate::transformations::layout::NestedLayout;
 
 use super::{
+    animation::KeyframeAnimationState,
     components::RescalerComponent,
     layout::StatefulLayoutComponent,
     scene_state::BuildStateTreeCtx,
-    transition::{TransitionOptions, TransitionState},
+    transition::{velocity_scale, EnterExitState, TransitionOptions, TransitionState},
     types::interpolation::ContinuousValue,
-    BorderRadius, BoxShadow, Component, ComponentId, HorizontalAlign, IntermediateNode, Position,
-    RGBAColor, RescaleMode, SceneError, Size, StatefulComponent, VerticalAlign,
+    BlendMode, BorderRadius, BoxShadow, Component, ComponentId, EnterExitTransition, GridPlacement,
+    HorizontalAlign, IntermediateNode, Position, RGBAColor, RescaleMode, RescalerCrop, SceneError,
+    Size, StatefulComponent, VerticalAlign,
 };
 
 mod interpolation;
@@ -20,6 +22,9 @@ pub(super) struct StatefulRescalerComponent {
     start: Option<RescalerComponentParam>,
     end: RescalerComponentParam,
     transition: Option<TransitionState>,
+    enter_exit: Option<EnterExitState>,
+    exit_transition: Option<EnterExitTransition>,
+    animations: Vec<KeyframeAnimationState>,
     child: Box<StatefulComponent>,
 }
 
@@ -37,16 +42,64 @@ struct RescalerComponentParam {
     border_color: RGBAColor,
 
     box_shadow: Vec<BoxShadow>,
+    opacity: f32,
+    blend_mode: BlendMode,
+    blur_radius: f32,
+
+    flex_grow: f32,
+    flex_shrink: f32,
+
+    grid_placement: GridPlacement,
+    pinned: bool,
+
+    crop: Option<RescalerCrop>,
+}
+
+/// Defaults of the optional fields in the API, tests only override what they need.
+#[cfg(test)]
+impl Default for RescalerComponentParam {
+    fn default() -> Self {
+        Self {
+            id: None,
+            position: Position::Static {
+                width: None,
+                height: None,
+            },
+            mode: RescaleMode::Fit,
+            horizontal_align: HorizontalAlign::Center,
+            vertical_align: VerticalAlign::Center,
+            border_radius: BorderRadius::ZERO,
+            border_width: 0.0,
+            border_color: RGBAColor(0, 0, 0, 0),
+            box_shadow: vec![],
+            opacity: 1.0,
+            blend_mode: BlendMode::Normal,
+            blur_radius: 0.0,
+            flex_grow: 0.0,
+            flex_shrink: 0.0,
+            grid_placement: GridPlacement::default(),
+            pinned: false,
+            crop: None,
+        }
+    }
 }
 
 impl StatefulRescalerComponent {
     /// Generate state of the component for particular pts value.
     fn transition_snapshot(&self, pts: Duration) -> RescalerComponentParam {
-        let (Some(transition), Some(start)) = (&self.transition, &self.start) else {
-            return self.end.clone();
+        let mut rescaler = match (&self.transition, &self.start) {
+            (Some(transition), Some(start)) => {
+                let interpolation_progress = transition.state(pts);
+                ContinuousValue::interpolate(start, &self.end, interpolation_progress)
+            }
+            _ => self.end.clone(),
         };
-        let interpolation_progress = transition.state(pts);
-        ContinuousValue::interpolate(start, &self.end, interpolation_progress)
+        for animation in self.animations.iter() {
+            if let Some(value) = animation.value(pts) {
+                rescaler.apply_animated_value(animation.property(), value);
+            }
+        }
+        rescaler
     }
 
     pub(super) fn children(&self) -> Vec<&StatefulComponent> {
@@ -62,6 +115,20 @@ impl StatefulRescalerComponent {
         rescaler.position.with_border(rescaler.border_width)
     }
 
+    /// Flex grow and flex shrink factors used if the component is a static child of a View.
+    pub(super) fn flex_factors(&self, pts: Duration) -> (f32, f32) {
+        let rescaler = self.transition_snapshot(pts);
+        (rescaler.flex_grow, rescaler.flex_shrink)
+    }
+
+    pub(super) fn grid_placement(&self) -> GridPlacement {
+        self.end.grid_placement
+    }
+
+    pub(super) fn pinned(&self) -> bool {
+        self.end.pinned
+    }
+
     pub(super) fn component_id(&self) -> Option<&ComponentId> {
         self.end.id.as_ref()
     }
@@ -82,8 +149,30 @@ impl StatefulRescalerComponent {
     }
 
     pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
-        self.transition_snapshot(pts)
-            .layout(size, &mut self.child, pts)
+        let layout = self
+            .transition_snapshot(pts)
+            .layout(size, &mut self.child, pts);
+        match &self.enter_exit {
+            Some(enter_exit) => enter_exit.apply(layout, pts),
+            None => layout,
+        }
+    }
+
+    pub(super) fn is_exit_finished(&self, pts: Duration) -> bool {
+        self.enter_exit
+            .as_ref()
+            .is_some_and(|enter_exit| enter_exit.is_exit_finished(pts))
+    }
+
+    /// Returns copy of this component that should be kept in the scene after it was
+    /// removed, or `None` if it should be removed immediately.
+    pub(super) fn exiting(&self, last_pts: Duration) -> Option<Self> {
+        let enter_exit =
+            EnterExitState::on_remove(self.exit_transition, self.enter_exit.as_ref(), last_pts)?;
+        Some(Self {
+            enter_exit: Some(enter_exit),
+            ..self.clone()
+        })
     }
 }
 
@@ -103,31 +192,60 @@ impl RescalerComponent {
                 _ => None,
             });
 
+        let end = RescalerComponentParam {
+            id: self.id,
+            position: self.position,
+            mode: self.mode,
+            horizontal_align: self.horizontal_align,
+            vertical_align: self.vertical_align,
+            border_radius: self.border_radius,
+            border_width: self.border_width,
+            border_color: self.border_color,
+            box_shadow: self.box_shadow,
+            opacity: self.opacity,
+            blend_mode: self.blend_mode,
+            blur_radius: self.blur_radius,
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            grid_placement: self.grid_placement,
+            pinned: self.pinned,
+            crop: self.crop,
+        };
         // TODO: to handle cases like transition from top to bottom this view needs
         // to be further processed to use the same type of coordinates as end
         let start = previous_state.map(|state| state.transition_snapshot(ctx.last_render_pts));
+        let velocity_scale = previous_state
+            .zip(start.as_ref())
+            .and_then(|(state, start)| {
+                velocity_scale((state.start.as_ref()?, &state.end), (start, &end))
+            });
         let transition = TransitionState::new(
             self.transition.map(|transition| TransitionOptions {
                 duration: transition.duration,
                 interpolation_kind: transition.interpolation_kind,
             }),
             previous_state.and_then(|s| s.transition.clone()),
+            velocity_scale,
+            ctx.last_render_pts,
+        );
+        let enter_exit = EnterExitState::on_scene_update(
+            self.enter_transition,
+            previous_state.map(|s| s.enter_exit.as_ref()),
             ctx.last_render_pts,
         );
         let rescaler = StatefulRescalerComponent {
             start,
-            end: RescalerComponentParam {
-                id: self.id,
-                position: self.position,
-                mode: self.mode,
-                horizontal_align: self.horizontal_align,
-                vertical_align: self.vertical_align,
-                border_radius: self.border_radius,
-                border_width: self.border_width,
-                border_color: self.border_color,
-                box_shadow: self.box_shadow,
-            },
+            end,
             transition,
+            enter_exit,
+            exit_transition: self.exit_transition,
+            animations: KeyframeAnimationState::new_list(
+                self.animations,
+                previous_state
+                    .map(|state| state.animations.as_slice())
+                    .unwrap_or_default(),
+                ctx.last_render_pts,
+            ),
             child: Box::new(Component::stateful_component(*self.child, ctx)?),
         };
         Ok(StatefulComponent::Layout(
@@ -1,7 +1,15 @@
-use crate::scene::types::interpolation::{ContinuousValue, InterpolationState};
+use crate::scene::{
+    animation::animate_position,
+    types::interpolation::{ContinuousValue, InterpolationState},
+    AnimatedProperty, BorderRadius, KeyframeValue, RescalerCrop,
+};
 
 use super::RescalerComponentParam;
 
+/// Smallest animated crop size. The scale of the cropped child is calculated by dividing
+/// by the crop size, so it can't be zero.
+const MIN_CROP_SIZE: f32 = 0.0001;
+
 impl ContinuousValue for RescalerComponentParam {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
         Self {
@@ -22,6 +30,96 @@ impl ContinuousValue for RescalerComponentParam {
             ),
             border_color: end.border_color,
             box_shadow: ContinuousValue::interpolate(&start.box_shadow, &end.box_shadow, state),
+            opacity: ContinuousValue::interpolate(&start.opacity, &end.opacity, state),
+            blend_mode: end.blend_mode,
+            blur_radius: ContinuousValue::interpolate(&start.blur_radius, &end.blur_radius, state),
+            flex_grow: ContinuousValue::interpolate(&start.flex_grow, &end.flex_grow, state),
+            flex_shrink: ContinuousValue::interpolate(&start.flex_shrink, &end.flex_shrink, state),
+            grid_placement: end.grid_placement,
+            pinned: end.pinned,
+            crop: ContinuousValue::interpolate(&start.crop, &end.crop, state),
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        self.position.push_coordinates(coordinates);
+        self.border_radius.push_coordinates(coordinates);
+        self.border_width.push_coordinates(coordinates);
+        self.box_shadow.push_coordinates(coordinates);
+        self.opacity.push_coordinates(coordinates);
+        self.blur_radius.push_coordinates(coordinates);
+        self.flex_grow.push_coordinates(coordinates);
+        self.flex_shrink.push_coordinates(coordinates);
+        self.crop.push_coordinates(coordinates);
+    }
+}
+
+impl ContinuousValue for RescalerCrop {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        if start.unit != end.unit {
+            return *end;
+        }
+        Self {
+            top: ContinuousValue::interpolate(&start.top, &end.top, state),
+            left: ContinuousValue::interpolate(&start.left, &end.left, state),
+            width: ContinuousValue::interpolate(&start.width, &end.width, state),
+            height: ContinuousValue::interpolate(&start.height, &end.height, state),
+            unit: end.unit,
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend([self.top, self.left, self.width, self.height].map(f64::from))
+    }
+}
+
+impl RescalerComponentParam {
+    pub(super) fn apply_animated_value(
+        &mut self,
+        property: AnimatedProperty,
+        value: KeyframeValue,
+    ) {
+        match (property, value) {
+            (AnimatedProperty::Opacity, KeyframeValue::Number(opacity)) => {
+                self.opacity = opacity.clamp(0.0, 1.0)
+            }
+            (AnimatedProperty::BorderWidth, KeyframeValue::Number(width)) => {
+                self.border_width = f32::max(width, 0.0)
+            }
+            (AnimatedProperty::BorderRadius, KeyframeValue::Number(radius)) => {
+                self.border_radius = BorderRadius::new_with_radius(radius)
+            }
+            (AnimatedProperty::BorderColor, KeyframeValue::Color(color)) => {
+                self.border_color = color
+            }
+            (AnimatedProperty::BlurRadius, KeyframeValue::Number(radius)) => {
+                self.blur_radius = f32::max(radius, 0.0)
+            }
+            // Crop properties are only animated if the crop is defined on the component.
+            (AnimatedProperty::CropTop, KeyframeValue::Number(top)) => {
+                if let Some(crop) = &mut self.crop {
+                    crop.top = top
+                }
+            }
+            (AnimatedProperty::CropLeft, KeyframeValue::Number(left)) => {
+                if let Some(crop) = &mut self.crop {
+                    crop.left = left
+                }
+            }
+            (AnimatedProperty::CropWidth, KeyframeValue::Number(width)) => {
+                if let Some(crop) = &mut self.crop {
+                    crop.width = f32::max(width, MIN_CROP_SIZE)
+                }
+            }
+            (AnimatedProperty::CropHeight, KeyframeValue::Number(height)) => {
+                if let Some(crop) = &mut self.crop {
+                    crop.height = f32::max(height, MIN_CROP_SIZE)
+                }
+            }
+            (property, KeyframeValue::Number(value)) => {
+                animate_position(&mut self.position, property, value)
+            }
+            (_, KeyframeValue::Color(_)) => (),
         }
     }
 }
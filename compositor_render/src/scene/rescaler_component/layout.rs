@@ -2,10 +2,10 @@ use std::time::Duration;
 
 use crate::{
     scene::{
-        layout::StatefulLayoutComponent, BorderRadius, HorizontalAlign, RGBAColor, RescaleMode,
-        Size, StatefulComponent, VerticalAlign,
+        layout::StatefulLayoutComponent, BlendMode, BorderRadius, CropUnit, HorizontalAlign,
+        RGBAColor, RescaleMode, RescalerCrop, Size, StatefulComponent, VerticalAlign,
     },
-    transformations::layout::{LayoutContent, Mask, NestedLayout},
+    transformations::layout::{Crop, LayoutContent, Mask, NestedLayout},
 };
 
 use super::RescalerComponentParam;
@@ -21,30 +21,47 @@ impl RescalerComponentParam {
             width: f32::max(size.width - (2.0 * self.border_width), 0.0),
             height: f32::max(size.height - (2.0 * self.border_width), 0.0),
         };
-        let child_width = child.width(pts);
-        let child_height = child.height(pts);
-        match (child_width, child_height) {
-            (None, None) => self.layout_with_scale(content_size, child, pts, 1.0),
-            (None, Some(child_height)) => {
-                self.layout_with_scale(content_size, child, pts, content_size.height / child_height)
-            }
-            (Some(child_width), None) => {
-                self.layout_with_scale(content_size, child, pts, content_size.width / child_width)
-            }
-            (Some(child_width), Some(child_height)) => {
-                let scale = match self.mode {
-                    RescaleMode::Fit => f32::min(
-                        content_size.width / child_width,
-                        content_size.height / child_height,
-                    ),
-                    RescaleMode::Fill => f32::max(
-                        content_size.width / child_width,
-                        content_size.height / child_height,
-                    ),
-                };
-                self.layout_with_scale(content_size, child, pts, scale)
-            }
-        }
+        let crop = self.crop.and_then(|crop| {
+            // Size of non-layout children (e.g. resolution of an input stream) is always
+            // known, so the crop is relative to it. If size of a layout child is not known,
+            // it will be rendered with the size of the Rescaler content.
+            let child_size = Size {
+                width: child.width(pts).unwrap_or(content_size.width),
+                height: child.height(pts).unwrap_or(content_size.height),
+            };
+            let crop = crop_rect(crop, child_size);
+            // e.g. an input that did not receive any frames yet
+            let is_empty = crop.width <= 0.0 || crop.height <= 0.0;
+            (!is_empty).then_some((crop, child_size))
+        });
+        // Size of the part of the child that is rescaled.
+        let (source_width, source_height) = match &crop {
+            Some((crop, _)) => (Some(crop.width), Some(crop.height)),
+            None => (child.width(pts), child.height(pts)),
+        };
+        let scale = match (source_width, source_height) {
+            (None, None) => 1.0,
+            (None, Some(source_height)) => content_size.height / source_height,
+            (Some(source_width), None) => content_size.width / source_width,
+            (Some(source_width), Some(source_height)) => match self.mode {
+                RescaleMode::Fit => f32::min(
+                    content_size.width / source_width,
+                    content_size.height / source_height,
+                ),
+                RescaleMode::Fill => f32::max(
+                    content_size.width / source_width,
+                    content_size.height / source_height,
+                ),
+            },
+        };
+        self.layout_with_scale(
+            content_size,
+            child,
+            pts,
+            scale,
+            (source_width, source_height),
+            crop,
+        )
     }
 
     fn layout_with_scale(
@@ -53,15 +70,20 @@ impl RescalerComponentParam {
         child: &mut StatefulComponent,
         pts: Duration,
         scale: f32,
+        (source_width, source_height): (Option<f32>, Option<f32>),
+        crop: Option<(Crop, Size)>,
     ) -> NestedLayout {
         let child_width = child.width(pts);
         let child_height = child.height(pts);
         let (content, children, child_nodes_count) = match child {
             StatefulComponent::Layout(layout_component) => {
                 let children_layout = layout_component.layout(
-                    Size {
-                        width: child_width.unwrap_or(max_size.width / scale),
-                        height: child_height.unwrap_or(max_size.height / scale),
+                    match &crop {
+                        Some((_, child_size)) => *child_size,
+                        None => Size {
+                            width: child_width.unwrap_or(max_size.width / scale),
+                            height: child_height.unwrap_or(max_size.height / scale),
+                        },
                     },
                     pts,
                 );
@@ -77,36 +99,64 @@ impl RescalerComponentParam {
 
         let top = match self.vertical_align {
             VerticalAlign::Top => 0.0,
-            VerticalAlign::Bottom => child
-                .height(pts)
+            VerticalAlign::Bottom => source_height
                 .map(|height| max_size.height - (height * scale))
                 .unwrap_or(0.0),
-            VerticalAlign::Center | VerticalAlign::Justified => child
-                .height(pts)
+            VerticalAlign::Center | VerticalAlign::Justified => source_height
                 .map(|height| (max_size.height - (height * scale)) / 2.0)
                 .unwrap_or(0.0),
         };
         let left = match self.horizontal_align {
             HorizontalAlign::Left => 0.0,
-            HorizontalAlign::Right => child
-                .width(pts)
+            HorizontalAlign::Right => source_width
                 .map(|width| (max_size.width - (width * scale)))
                 .unwrap_or(0.0),
-            HorizontalAlign::Center | HorizontalAlign::Justified => child
-                .width(pts)
+            HorizontalAlign::Center | HorizontalAlign::Justified => source_width
                 .map(|width| (max_size.width - (width * scale)) / (2.0))
                 .unwrap_or(0.0),
         };
 
-        let width = child
-            .width(pts)
-            .map(|child_width| child_width * scale)
+        let width = source_width
+            .map(|source_width| source_width * scale)
             .unwrap_or(max_size.width);
-        let height = child
-            .height(pts)
-            .map(|child_height| child_height * scale)
+        let height = source_height
+            .map(|source_height| source_height * scale)
             .unwrap_or(max_size.height);
 
+        // Crop is applied to children of a layout, so if it is defined, the child is
+        // wrapped in an additional layout. It does not require an additional render pass,
+        // the cropped part of the texture is sampled directly.
+        let (crop, content, children) = match crop {
+            Some((crop, child_size)) => (
+                Some(crop),
+                LayoutContent::None,
+                vec![NestedLayout {
+                    top: 0.0,
+                    left: 0.0,
+                    width: child_size.width,
+                    height: child_size.height,
+                    rotation_degrees: 0.0,
+                    scale_x: 1.0,
+                    scale_y: 1.0,
+                    crop: None,
+                    mask: None,
+                    content,
+                    child_nodes_count,
+                    children,
+                    border_width: 0.0,
+                    border_color: RGBAColor(0, 0, 0, 0),
+                    border_gradient: None,
+                    border_radius: BorderRadius::ZERO,
+                    box_shadow: vec![],
+                    blend_mode: BlendMode::Normal,
+                    opacity: 1.0,
+                    blur_radius: 0.0,
+                    backdrop_blur: 0.0,
+                }],
+            ),
+            None => (None, content, children),
+        };
+
         NestedLayout {
             top: 0.0,
             left: 0.0,
@@ -132,21 +182,153 @@ impl RescalerComponentParam {
                 rotation_degrees: 0.0,
                 scale_x: scale,
                 scale_y: scale,
-                crop: None,
+                crop,
                 mask: None,
                 content,
                 child_nodes_count,
                 children,
                 border_width: 0.0,
                 border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
                 border_radius: BorderRadius::ZERO,
                 box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
             }],
             child_nodes_count,
             border_width: self.border_width,
             border_color: self.border_color,
+            border_gradient: None,
             border_radius: self.border_radius,
             box_shadow: self.box_shadow.clone(),
+            blend_mode: self.blend_mode,
+            opacity: self.opacity,
+            blur_radius: self.blur_radius,
+            backdrop_blur: 0.0,
+        }
+    }
+}
+
+/// Source rectangle in pixels of the child.
+fn crop_rect(crop: RescalerCrop, child_size: Size) -> Crop {
+    match crop.unit {
+        CropUnit::Pixels => Crop {
+            top: crop.top,
+            left: crop.left,
+            width: crop.width,
+            height: crop.height,
+        },
+        CropUnit::Normalized => Crop {
+            top: crop.top * child_size.height,
+            left: crop.left * child_size.width,
+            width: crop.width * child_size.width,
+            height: crop.height * child_size.height,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::{
+        scene::{
+            input_stream_component::StatefulInputStreamComponent, CropUnit, HorizontalAlign,
+            InputStreamComponent, RescalerCrop, Size, StatefulComponent, VerticalAlign,
+        },
+        transformations::layout::NestedLayout,
+        InputId,
+    };
+
+    use super::RescalerComponentParam;
+
+    const SIZE: Size = Size {
+        width: 400.0,
+        height: 200.0,
+    };
+
+    fn rescaler(crop: RescalerCrop) -> RescalerComponentParam {
+        RescalerComponentParam {
+            horizontal_align: HorizontalAlign::Left,
+            vertical_align: VerticalAlign::Top,
+            crop: Some(crop),
+            ..Default::default()
+        }
+    }
+
+    fn crop(width: f32, height: f32, unit: CropUnit) -> RescalerCrop {
+        RescalerCrop {
+            top: 10.0,
+            left: 20.0,
+            width,
+            height,
+            unit,
         }
     }
+
+    fn input(width: f32, height: f32) -> StatefulComponent {
+        StatefulComponent::InputStream(StatefulInputStreamComponent {
+            component: InputStreamComponent {
+                id: None,
+                input_id: InputId(Arc::from("input")),
+            },
+            size: Size { width, height },
+        })
+    }
+
+    /// Layout of the rescaled child.
+    fn child_layout(
+        rescaler: &RescalerComponentParam,
+        child: &mut StatefulComponent,
+    ) -> NestedLayout {
+        let mut layout = rescaler.layout(SIZE, child, Duration::ZERO);
+        layout.children.remove(0)
+    }
+
+    #[test]
+    fn pixel_crop_is_relative_to_input_resolution() {
+        let rescaler = rescaler(crop(100.0, 100.0, CropUnit::Pixels));
+        let layout = child_layout(&rescaler, &mut input(1920.0, 1080.0));
+
+        let crop = layout.crop.unwrap();
+        assert_eq!(
+            (crop.top, crop.left, crop.width, crop.height),
+            (10.0, 20.0, 100.0, 100.0)
+        );
+        assert_eq!((layout.width, layout.height), (200.0, 200.0));
+        assert_eq!((layout.scale_x, layout.scale_y), (2.0, 2.0));
+        // cropped content is rendered with the size of the input
+        assert_eq!(
+            (layout.children[0].width, layout.children[0].height),
+            (1920.0, 1080.0)
+        );
+    }
+
+    #[test]
+    fn normalized_crop_is_relative_to_input_resolution() {
+        let rescaler = rescaler(RescalerCrop {
+            top: 0.5,
+            left: 0.25,
+            ..crop(0.5, 0.5, CropUnit::Normalized)
+        });
+        let layout = child_layout(&rescaler, &mut input(800.0, 400.0));
+
+        let crop = layout.crop.unwrap();
+        assert_eq!(
+            (crop.top, crop.left, crop.width, crop.height),
+            (200.0, 200.0, 400.0, 200.0)
+        );
+        assert_eq!((layout.scale_x, layout.scale_y), (1.0, 1.0));
+    }
+
+    #[test]
+    fn empty_crop_is_ignored() {
+        let rescaler = rescaler(crop(0.5, 0.5, CropUnit::Normalized));
+        let layout = child_layout(&rescaler, &mut input(0.0, 0.0));
+
+        assert!(layout.crop.is_none());
+        assert!(layout.children.is_empty());
+    }
 }
@@ -1,31 +1,67 @@
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use log::error;
 
 use crate::{
-    state::renderers::Renderers, transformations::text_renderer::TextRendererCtx, InputId,
-    OutputId, Resolution,
+    state::renderers::Renderers,
+    transformations::{mask_renderer::MaskPathCache, text_renderer::TextRendererCtx},
+    InputId, OutputId, Resolution,
 };
 
 use super::{
+    chroma_key_component::StatefulChromaKeyComponent,
+    color_correction_component::StatefulColorCorrectionComponent,
     image_component::StatefulImageComponent,
     input_stream_component::StatefulInputStreamComponent,
     layout::{LayoutNode, SizedLayoutComponent, StatefulLayoutComponent},
+    mask_component::StatefulMaskComponent,
     shader_component::StatefulShaderComponent,
     text_component::StatefulTextComponent,
     validation::validate_scene_update,
     web_view_component::StatefulWebViewComponent,
-    ComponentId, Node, NodeParams, OutputScene, Position, SceneError, Size, StatefulComponent,
+    Component, ComponentId, Node, NodeParams, OutputScene, Position, SceneError, Size,
+    StatefulComponent,
 };
 
 pub(super) struct BuildStateTreeCtx<'a> {
     pub(super) prev_state: HashMap<ComponentId, &'a StatefulComponent>,
+    /// IDs of all components in the new scene.
+    pub(super) scene_component_ids: HashSet<ComponentId>,
+    /// Components removed in this scene update that are rendered until their exit
+    /// transition finishes. Layout parents put them back among their children.
+    pub(super) exiting_components: HashMap<ComponentId, StatefulLayoutComponent>,
+    /// Mask paths rasterized for the previous scene.
+    pub(super) mask_paths: MaskPathCache,
     pub(super) last_render_pts: Duration,
     pub(super) renderers: &'a Renderers,
     pub(super) text_renderer_ctx: &'a TextRendererCtx,
     pub(super) input_resolutions: &'a HashMap<InputId, Resolution>,
 }
 
+impl BuildStateTreeCtx<'_> {
+    /// Children that were removed in this scene update are kept until their exit
+    /// transition finishes. They are inserted at their previous positions.
+    pub(super) fn retain_exiting_children(
+        &self,
+        previous_children: &[StatefulComponent],
+        children: &mut Vec<StatefulComponent>,
+    ) {
+        for (index, child) in previous_children.iter().enumerate() {
+            let Some(exiting) = child
+                .component_id()
+                .and_then(|id| self.exiting_components.get(id))
+            else {
+                continue;
+            };
+            let index = usize::min(index, children.len());
+            children.insert(index, StatefulComponent::Layout(exiting.clone()));
+        }
+    }
+}
+
 pub(crate) struct SceneState {
     output_scenes: HashMap<OutputId, OutputScene>,
     output_states: HashMap<OutputId, OutputSceneState>,
@@ -66,11 +102,22 @@ impl SceneState {
         // TODO: pass input stream sizes and populate it in the ComponentState tree
     }
 
+    /// PTS of the last rendered frame.
+    pub(crate) fn last_pts(&self) -> Duration {
+        self.last_pts
+    }
+
     pub(crate) fn unregister_output(&mut self, output_id: &OutputId) {
         self.output_scenes.remove(output_id);
         self.output_states.remove(output_id);
     }
 
+    pub(crate) fn scene_root(&self, output_id: &OutputId) -> Option<&Component> {
+        self.output_scenes
+            .get(output_id)
+            .map(|output_scene| &output_scene.scene_root)
+    }
+
     pub(crate) fn update_scene(
         &mut self,
         output_scene: OutputScene,
@@ -89,16 +136,36 @@ impl SceneState {
             )
         }
 
+        let prev_state = self
+            .output_states
+            .get(&output_id)
+            .map(|o| {
+                let mut components = HashMap::new();
+                gather_components_with_id(&o.root, &mut components);
+                components
+            })
+            .unwrap_or_default();
+        let scene_component_ids = {
+            let mut ids = HashSet::new();
+            gather_scene_component_ids(&output_scene.scene_root, &mut ids);
+            ids
+        };
+        let exiting_components =
+            gather_exiting_components(&prev_state, &scene_component_ids, self.last_pts);
+        let mask_paths = self
+            .output_states
+            .get(&output_id)
+            .map(|o| {
+                let mut cache = MaskPathCache::default();
+                gather_mask_paths(&o.root, &mut cache);
+                cache
+            })
+            .unwrap_or_default();
         let ctx = BuildStateTreeCtx {
-            prev_state: self
-                .output_states
-                .get(&output_id)
-                .map(|o| {
-                    let mut components = HashMap::new();
-                    gather_components_with_id(&o.root, &mut components);
-                    components
-                })
-                .unwrap_or_default(),
+            prev_state,
+            scene_component_ids,
+            exiting_components,
+            mask_paths,
             last_render_pts: self.last_pts,
             input_resolutions: &self.input_resolutions,
             text_renderer_ctx,
@@ -139,6 +206,18 @@ pub(super) enum IntermediateNode {
     },
     Image(StatefulImageComponent),
     Text(StatefulTextComponent),
+    Mask {
+        mask: StatefulMaskComponent,
+        children: Vec<IntermediateNode>,
+    },
+    ChromaKey {
+        chroma_key: StatefulChromaKeyComponent,
+        children: Vec<IntermediateNode>,
+    },
+    ColorCorrection {
+        color_correction: StatefulColorCorrectionComponent,
+        children: Vec<IntermediateNode>,
+    },
     Layout {
         root: StatefulLayoutComponent,
         children: Vec<IntermediateNode>,
@@ -181,6 +260,10 @@ impl IntermediateNode {
                 }),
                 children: children
                     .into_iter()
+                    .map(|node| match node {
+                        IntermediateNode::Text(text) => IntermediateNode::Text(text.layout_child()),
+                        node => node,
+                    })
                     .map(|node| node.build_tree(None, pts))
                     .collect::<Result<_, _>>()?,
             }),
@@ -188,9 +271,41 @@ impl IntermediateNode {
                 params: NodeParams::Image(image.image),
                 children: vec![],
             }),
-            IntermediateNode::Text(text) => Ok(Node {
-                params: NodeParams::Text(text.params),
-                children: vec![],
+            IntermediateNode::Text(text) => {
+                if text.has_unapplied_gradient() {
+                    return Err(SceneError::TextGradientWithoutLayoutParent);
+                }
+                Ok(Node {
+                    params: NodeParams::Text(text.params),
+                    children: vec![],
+                })
+            }
+            IntermediateNode::Mask { mask, children } => Ok(Node {
+                params: NodeParams::Mask(mask.component),
+                children: children
+                    .into_iter()
+                    .map(|node| node.build_tree(None, pts))
+                    .collect::<Result<_, _>>()?,
+            }),
+            IntermediateNode::ChromaKey {
+                chroma_key,
+                children,
+            } => Ok(Node {
+                params: NodeParams::ChromaKey(chroma_key.component),
+                children: children
+                    .into_iter()
+                    .map(|node| node.build_tree(None, pts))
+                    .collect::<Result<_, _>>()?,
+            }),
+            IntermediateNode::ColorCorrection {
+                color_correction,
+                children,
+            } => Ok(Node {
+                params: NodeParams::ColorCorrection(color_correction.component),
+                children: children
+                    .into_iter()
+                    .map(|node| node.build_tree(None, pts))
+                    .collect::<Result<_, _>>()?,
             }),
         }
     }
@@ -205,6 +320,15 @@ impl IntermediateNode {
             IntermediateNode::WebView { web, children: _ } => Ok(web.size()),
             IntermediateNode::Image(image) => Ok(image.size()),
             IntermediateNode::Text(text) => Ok(text.size()),
+            IntermediateNode::Mask { mask, children: _ } => Ok(mask.component.size),
+            IntermediateNode::ChromaKey {
+                chroma_key,
+                children: _,
+            } => Ok(chroma_key.component.size),
+            IntermediateNode::ColorCorrection {
+                color_correction,
+                children: _,
+            } => Ok(color_correction.component.size),
             IntermediateNode::Layout { root, children: _ } => {
                 let (width, height) = match root.position(pts) {
                     Position::Static { width, height } => (width, height),
@@ -297,6 +421,26 @@ fn gather_components_with_id<'a>(
                 components.insert(id.clone(), component);
             }
         }
+        StatefulComponent::Mask(mask) => {
+            if let Some(id) = mask.component_id() {
+                components.insert(id.clone(), component);
+            }
+            for child in mask.children() {
+                gather_components_with_id(child, components);
+            }
+        }
+        StatefulComponent::ChromaKey(chroma_key) => {
+            if let Some(id) = chroma_key.component_id() {
+                components.insert(id.clone(), component);
+            }
+            gather_components_with_id(&chroma_key.child, components);
+        }
+        StatefulComponent::ColorCorrection(color_correction) => {
+            if let Some(id) = color_correction.component_id() {
+                components.insert(id.clone(), component);
+            }
+            gather_components_with_id(&color_correction.child, components);
+        }
         StatefulComponent::Layout(layout) => {
             if let Some(id) = layout.component_id() {
                 components.insert(id.clone(), component);
@@ -307,3 +451,42 @@ fn gather_components_with_id<'a>(
         }
     }
 }
+
+fn gather_mask_paths(component: &StatefulComponent, cache: &mut MaskPathCache) {
+    if let StatefulComponent::Mask(mask) = component {
+        if let Some(bitmap) = &mask.component.path {
+            cache.insert(bitmap.clone());
+        }
+    }
+    for child in component.children() {
+        gather_mask_paths(child, cache);
+    }
+}
+
+/// Removed components that define an exit transition (or were already exiting
+/// before this update and did not finish yet).
+fn gather_exiting_components(
+    prev_state: &HashMap<ComponentId, &StatefulComponent>,
+    scene_component_ids: &HashSet<ComponentId>,
+    last_pts: Duration,
+) -> HashMap<ComponentId, StatefulLayoutComponent> {
+    prev_state
+        .iter()
+        .filter(|(id, _)| !scene_component_ids.contains(*id))
+        .filter_map(|(id, component)| match component {
+            StatefulComponent::Layout(layout) => {
+                layout.exiting(last_pts).map(|layout| (id.clone(), layout))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn gather_scene_component_ids(component: &Component, ids: &mut HashSet<ComponentId>) {
+    if let Some(id) = component.component_id() {
+        ids.insert(id.clone());
+    }
+    for child in component.children() {
+        gather_scene_component_ids(child, ids);
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::transformations::shader::Shader;
+use crate::{transformations::shader::Shader, InputId};
 
 use super::{
     scene_state::BuildStateTreeCtx, Component, ComponentId, IntermediateNode, SceneError,
@@ -18,6 +18,7 @@ pub(super) struct StatefulShaderComponent {
 pub(crate) struct ShaderComponentParams {
     pub(crate) id: Option<ComponentId>,
     pub(crate) shader_param: Option<ShaderParam>,
+    pub(crate) audio_inputs: Vec<InputId>,
     pub(crate) size: Size,
 }
 
@@ -65,6 +66,7 @@ impl ShaderComponent {
             component: ShaderComponentParams {
                 id: self.id,
                 shader_param: self.shader_param,
+                audio_inputs: self.audio_inputs,
                 size: self.size,
             },
             shader,
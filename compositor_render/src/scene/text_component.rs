@@ -1,14 +1,60 @@
-use crate::transformations::text_renderer::TextRenderParams;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, FixedOffset, Local, Utc,
+};
+
+use crate::{
+    transformations::{
+        layout::GradientFill,
+        text_renderer::{
+            subtitles::{Subtitles, SubtitlesCursor},
+            TextBuffer, TextRenderParams, TextRendererCtx,
+        },
+    },
+    Framerate, Resolution,
+};
 
 use super::{
-    scene_state::BuildStateTreeCtx, ComponentId, IntermediateNode, SceneError, Size,
-    StatefulComponent, TextComponent,
+    scene_state::BuildStateTreeCtx, ClockTimezone, ComponentId, DynamicText, Gradient,
+    IntermediateNode, SceneError, Size, StatefulComponent, SubtitlesComponent, TextComponent,
+    TextDimensions, TextSpan, VerticalAlign,
 };
 
 #[derive(Debug, Clone)]
 pub(super) struct StatefulTextComponent {
     id: Option<ComponentId>,
     pub(super) params: TextRenderParams,
+    color_gradient: Option<Gradient>,
+}
+
+/// Text that is generated and shaped again by the renderer for every frame
+/// (if it changed since the previous one).
+#[derive(Debug, Clone)]
+pub(crate) struct DynamicTextParams {
+    source: DynamicTextSource,
+    /// PTS of the scene update that added the component.
+    start_pts: Duration,
+    /// Styling of the generated text, `spans` are ignored.
+    component: TextComponent,
+    /// Size of the texture is calculated during the scene update and does not
+    /// change when the content changes.
+    resolution: Resolution,
+}
+
+#[derive(Debug, Clone)]
+enum DynamicTextSource {
+    Text(DynamicText),
+    Subtitles {
+        subtitles: Arc<Subtitles>,
+        offset_ms: i64,
+        vertical_align: VerticalAlign,
+        cursor: SubtitlesCursor,
+    },
 }
 
 impl StatefulTextComponent {
@@ -28,26 +74,372 @@ impl StatefulTextComponent {
         self.params.resolution.into()
     }
 
+    /// Gradient applied by the parent layout, see `layout_child`.
+    pub(super) fn gradient_fill(&self) -> Option<GradientFill> {
+        self.color_gradient.clone().map(|gradient| GradientFill {
+            gradient,
+            background_color: self.params.background_color,
+        })
+    }
+
+    /// Text rendered as a child of a layout. If there is a gradient, the text
+    /// is rendered without colors and the layout fills it.
+    pub(super) fn layout_child(mut self) -> Self {
+        self.params.gradient_fill = self.color_gradient.is_some();
+        self
+    }
+
+    /// Gradient can only be applied by a parent layout.
+    pub(super) fn has_unapplied_gradient(&self) -> bool {
+        self.color_gradient.is_some() && !self.params.gradient_fill
+    }
+
     pub(super) fn intermediate_node(&self) -> IntermediateNode {
         IntermediateNode::Text(self.clone())
     }
 }
 
+impl DynamicTextParams {
+    pub(crate) fn text(&mut self, pts: Duration) -> String {
+        let source = match &mut self.source {
+            DynamicTextSource::Text(source) => source,
+            DynamicTextSource::Subtitles {
+                subtitles,
+                offset_ms,
+                cursor,
+                ..
+            } => return subtitles.text(pts, *offset_ms, cursor),
+        };
+        match source {
+            DynamicText::Clock { format, timezone } => {
+                format_clock(SystemTime::now(), format, *timezone)
+            }
+            DynamicText::Timecode {
+                framerate,
+                drop_frame,
+            } => format_timecode(pts, *framerate, *drop_frame),
+            DynamicText::Countdown { end } => format_countdown(
+                end.duration_since(SystemTime::now())
+                    .unwrap_or(Duration::ZERO),
+            ),
+            DynamicText::Counter {
+                start,
+                step,
+                interval,
+            } => {
+                let elapsed = pts.saturating_sub(self.start_pts);
+                let ticks = match interval.is_zero() {
+                    true => 0,
+                    false => (elapsed.as_nanos() / interval.as_nanos()) as i64,
+                };
+                start.saturating_add(step.saturating_mul(ticks)).to_string()
+            }
+        }
+    }
+
+    /// Returns the shaped text and its vertical offset inside the texture.
+    pub(crate) fn layout(
+        &self,
+        text_renderer_ctx: &TextRendererCtx,
+        text: &str,
+    ) -> (TextBuffer, f32) {
+        let component = TextComponent {
+            spans: vec![plain_span(text.into())],
+            ..self.component.clone()
+        };
+        let width = self.resolution.width as f32;
+        let height = self.resolution.height as f32;
+        match &self.source {
+            DynamicTextSource::Text(_) => {
+                let dimensions = TextDimensions::Fixed { width, height };
+                let (buffer, _) = text_renderer_ctx.layout_text((&component).into(), dimensions);
+                (buffer, 0.0)
+            }
+            DynamicTextSource::Subtitles { vertical_align, .. } => {
+                let dimensions = TextDimensions::FittedColumn {
+                    width,
+                    max_height: height,
+                };
+                let (buffer, resolution) =
+                    text_renderer_ctx.layout_text((&component).into(), dimensions);
+                let free_space = f32::max(height - resolution.height as f32, 0.0);
+                let offset = match vertical_align {
+                    VerticalAlign::Top | VerticalAlign::Justified => 0.0,
+                    VerticalAlign::Center => (free_space / 2.0).floor(),
+                    VerticalAlign::Bottom => free_space,
+                };
+                (buffer, offset)
+            }
+        }
+    }
+}
+
 impl TextComponent {
     pub(super) fn stateful_component(
         self,
         ctx: &BuildStateTreeCtx,
     ) -> Result<StatefulComponent, SceneError> {
+        let previous_state = self
+            .id
+            .as_ref()
+            .and_then(|id| ctx.prev_state.get(id))
+            .and_then(|component| match component {
+                StatefulComponent::Text(text_state) => text_state.params.dynamic.as_ref(),
+                _ => None,
+            });
+
+        let (text, dynamic) = match self.dynamic.clone() {
+            Some(source) => {
+                if let DynamicText::Clock { format, .. } = &source {
+                    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+                        return Err(SceneError::InvalidClockFormat(format.clone()));
+                    }
+                }
+                // Counter keeps counting if it was already present in the previous scene.
+                let start_pts = match (&source, previous_state) {
+                    (
+                        DynamicText::Counter { .. },
+                        Some(DynamicTextParams {
+                            source: DynamicTextSource::Text(DynamicText::Counter { .. }),
+                            start_pts,
+                            ..
+                        }),
+                    ) => *start_pts,
+                    _ => ctx.last_render_pts,
+                };
+                let dynamic = DynamicTextParams {
+                    source: DynamicTextSource::Text(source),
+                    start_pts,
+                    component: self.clone(),
+                    resolution: Resolution {
+                        width: 0,
+                        height: 0,
+                    },
+                };
+                // Digits are replaced, so the size of the texture does not depend on
+                // the current value, e.g. "11:11" and "00:00" are usually not equally wide.
+                let sizing_text = dynamic
+                    .text(ctx.last_render_pts)
+                    .chars()
+                    .map(|c| if c.is_ascii_digit() { '0' } else { c })
+                    .collect::<String>();
+                let text = TextComponent {
+                    spans: vec![plain_span(sizing_text.into())],
+                    ..self
+                };
+                (text, Some(dynamic))
+            }
+            None => (self, None),
+        };
+
         let (buffer, resolution) = ctx
             .text_renderer_ctx
-            .layout_text((&self).into(), self.dimensions);
+            .layout_text((&text).into(), text.dimensions);
         Ok(StatefulComponent::Text(StatefulTextComponent {
+            id: text.id,
+            params: TextRenderParams {
+                buffer,
+                resolution,
+                color: text.color,
+                background_color: text.background_color,
+                stroke: text.stroke,
+                shadow: text.shadow,
+                gradient_fill: false,
+                dynamic: dynamic.map(|dynamic| DynamicTextParams {
+                    resolution,
+                    ..dynamic
+                }),
+            },
+            color_gradient: text.color_gradient,
+        }))
+    }
+}
+
+impl SubtitlesComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let subtitles = ctx
+            .renderers
+            .subtitles
+            .get(&self.subtitles_id)
+            .ok_or(SceneError::SubtitlesNotFound(self.subtitles_id))?;
+
+        let resolution = Resolution {
+            width: self.width as usize,
+            height: self.height as usize,
+        };
+        let text = TextComponent {
             id: self.id,
+            spans: vec![],
+            dynamic: None,
+            dimensions: TextDimensions::Fixed {
+                width: self.width,
+                height: self.height,
+            },
+            ..self.text
+        };
+        let dynamic = DynamicTextParams {
+            source: DynamicTextSource::Subtitles {
+                subtitles,
+                offset_ms: self.offset_ms,
+                vertical_align: self.vertical_align,
+                cursor: SubtitlesCursor::default(),
+            },
+            start_pts: ctx.last_render_pts,
+            component: text.clone(),
+            resolution,
+        };
+        // Cues are shaped by the renderer, the initial buffer is empty.
+        let (buffer, resolution) = ctx
+            .text_renderer_ctx
+            .layout_text((&text).into(), text.dimensions);
+
+        Ok(StatefulComponent::Text(StatefulTextComponent {
+            id: text.id,
             params: TextRenderParams {
                 buffer,
                 resolution,
-                background_color: self.background_color,
+                color: text.color,
+                background_color: text.background_color,
+                stroke: text.stroke,
+                shadow: text.shadow,
+                gradient_fill: false,
+                dynamic: Some(dynamic),
             },
+            color_gradient: text.color_gradient,
         }))
     }
 }
+
+fn plain_span(text: Arc<str>) -> TextSpan {
+    TextSpan {
+        text,
+        font_family: None,
+        font_size: None,
+        color: None,
+        style: None,
+        weight: None,
+    }
+}
+
+/// `format` needs to be validated first, otherwise formatting panics.
+fn format_clock(now: SystemTime, format: &str, timezone: ClockTimezone) -> String {
+    let now: DateTime<Utc> = now.into();
+    match timezone {
+        ClockTimezone::Utc => now.format(format).to_string(),
+        ClockTimezone::Local => now.with_timezone(&Local).format(format).to_string(),
+        ClockTimezone::Offset(seconds) => match FixedOffset::east_opt(seconds) {
+            Some(offset) => now.with_timezone(&offset).format(format).to_string(),
+            None => now.format(format).to_string(),
+        },
+        ClockTimezone::Named(timezone) => now.with_timezone(&timezone).format(format).to_string(),
+    }
+}
+
+fn format_timecode(pts: Duration, framerate: Framerate, drop_frame: bool) -> String {
+    let frame =
+        (pts.as_nanos() * framerate.num as u128 / (framerate.den as u128 * 1_000_000_000)) as u64;
+    let fps = u64::max(
+        (framerate.num as f64 / framerate.den as f64).round() as u64,
+        1,
+    );
+
+    let (frame, separator) = match drop_frame {
+        true => {
+            // Frame numbers 0 and 1 (0-3 for 59.94) are skipped at the start of each
+            // minute, except for every tenth minute.
+            let dropped = fps / 15;
+            let frames_per_minute = fps * 60 - dropped;
+            let frames_per_10_minutes = frames_per_minute * 10 + dropped;
+            let remainder = frame % frames_per_10_minutes;
+            let skipped_in_remainder = match remainder > dropped {
+                true => dropped * ((remainder - dropped) / frames_per_minute),
+                false => 0,
+            };
+            let skipped = 9 * dropped * (frame / frames_per_10_minutes) + skipped_in_remainder;
+            (frame + skipped, ';')
+        }
+        false => (frame, ':'),
+    };
+
+    let frames = frame % fps;
+    let seconds = (frame / fps) % 60;
+    let minutes = (frame / (fps * 60)) % 60;
+    let hours = (frame / (fps * 3600)) % 24;
+    format!("{hours:02}:{minutes:02}:{seconds:02}{separator}{frames:02}")
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    // Rounded up, so "00:00:00" is only displayed after the countdown ends.
+    let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame_pts(frame: u64, framerate: Framerate) -> Duration {
+        let nanos = frame * framerate.den as u64 * 1_000_000_000;
+        Duration::from_nanos(nanos.div_ceil(framerate.num as u64))
+    }
+
+    #[test]
+    fn test_timecode() {
+        let fps_25 = Framerate { num: 25, den: 1 };
+        assert_eq!(
+            format_timecode(frame_pts(0, fps_25), fps_25, false),
+            "00:00:00:00"
+        );
+        assert_eq!(
+            format_timecode(frame_pts(24, fps_25), fps_25, false),
+            "00:00:00:24"
+        );
+        assert_eq!(
+            format_timecode(frame_pts(90_025, fps_25), fps_25, false),
+            "01:00:01:00"
+        );
+    }
+
+    #[test]
+    fn test_drop_frame_timecode() {
+        let fps_29_97 = Framerate {
+            num: 30000,
+            den: 1001,
+        };
+        let timecode = |frame| format_timecode(frame_pts(frame, fps_29_97), fps_29_97, true);
+        assert_eq!(timecode(1799), "00:00:59;29");
+        assert_eq!(timecode(1800), "00:01:00;02");
+        assert_eq!(timecode(17_981), "00:09:59;29");
+        assert_eq!(timecode(17_982), "00:10:00;00");
+        assert_eq!(timecode(17_982 + 1800), "00:11:00;02");
+
+        let fps_59_94 = Framerate {
+            num: 60000,
+            den: 1001,
+        };
+        let timecode = |frame| format_timecode(frame_pts(frame, fps_59_94), fps_59_94, true);
+        assert_eq!(timecode(3599), "00:00:59;59");
+        assert_eq!(timecode(3600), "00:01:00;04");
+    }
+
+    #[test]
+    fn test_named_timezone_clock() {
+        let timezone = ClockTimezone::named("Europe/Warsaw").unwrap();
+        // 2024-01-15 12:00:00 UTC, standard time
+        let winter = SystemTime::UNIX_EPOCH + Duration::from_secs(1_705_320_000);
+        assert_eq!(format_clock(winter, "%H:%M %Z", timezone), "13:00 CET");
+        // 2024-07-15 12:00:00 UTC, daylight saving time
+        let summer = SystemTime::UNIX_EPOCH + Duration::from_secs(1_721_044_800);
+        assert_eq!(format_clock(summer, "%H:%M %Z", timezone), "14:00 CEST");
+
+        assert!(ClockTimezone::named("Europe/Unknown").is_none());
+    }
+}
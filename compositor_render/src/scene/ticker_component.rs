@@ -0,0 +1,330 @@
+use std::time::Duration;
+
+use crate::transformations::layout::{LayoutContent, Mask, NestedLayout};
+
+use super::{
+    layout::StatefulLayoutComponent, scene_state::BuildStateTreeCtx, BlendMode, BorderRadius,
+    ComponentId, IntermediateNode, Position, RGBAColor, SceneError, Size, StatefulComponent,
+    TextComponent, TextDimensions, TextSpan, TickerComponent, TickerDirection,
+};
+
+#[derive(Debug, Clone)]
+pub(super) struct StatefulTickerComponent {
+    component: TickerComponentParams,
+    /// Content of the ticker. Each scene update appends a new segment that starts
+    /// where the current iteration of the previous segment ends.
+    segments: Vec<TickerSegment>,
+    /// Offset of the strip at `start_pts`. Positions on the strip grow for as long
+    /// as the ticker is rendered, so they are stored as `f64` to keep sub-pixel precision.
+    start_offset: f64,
+    start_pts: Duration,
+    /// Width of the component calculated during the last layout, used to detect
+    /// segments that are no longer visible.
+    last_width: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+struct TickerComponentParams {
+    id: Option<ComponentId>,
+    position: Position,
+    speed: f32,
+    direction: TickerDirection,
+    background_color: RGBAColor,
+}
+
+/// Texts rendered in a loop starting from `start`. Positions on the strip are measured
+/// in pixels from the point where the first segment entered the component.
+#[derive(Debug, Clone)]
+struct TickerSegment {
+    start: f64,
+    content: StatefulComponent,
+    separator: StatefulComponent,
+}
+
+impl TickerSegment {
+    fn period(&self, pts: Duration) -> f64 {
+        self.content.width(pts).unwrap_or(0.0) as f64
+            + self.separator.width(pts).unwrap_or(0.0) as f64
+    }
+}
+
+impl StatefulTickerComponent {
+    /// How many pixels of the strip scrolled into the component at `pts`.
+    fn offset(&self, pts: Duration) -> f64 {
+        let elapsed = pts.saturating_sub(self.start_pts).as_secs_f64();
+        self.start_offset + self.component.speed as f64 * elapsed
+    }
+
+    pub(super) fn children(&self) -> Vec<&StatefulComponent> {
+        self.segments
+            .iter()
+            .flat_map(|segment| [&segment.content, &segment.separator])
+            .collect()
+    }
+
+    pub(super) fn children_mut(&mut self) -> Vec<&mut StatefulComponent> {
+        self.segments
+            .iter_mut()
+            .flat_map(|segment| [&mut segment.content, &mut segment.separator])
+            .collect()
+    }
+
+    pub(super) fn position(&self, _pts: Duration) -> Position {
+        self.component.position
+    }
+
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
+        self.component.id.as_ref()
+    }
+
+    pub(super) fn intermediate_node(&self) -> IntermediateNode {
+        let children = self
+            .children()
+            .into_iter()
+            .map(StatefulComponent::intermediate_node)
+            .collect();
+
+        IntermediateNode::Layout {
+            root: StatefulLayoutComponent::Ticker(self.clone()),
+            children,
+        }
+    }
+
+    pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
+        self.last_width = Some(size.width);
+        let offset = self.offset(pts);
+
+        let mut children = vec![];
+        for (index, segment) in self.segments.iter().enumerate() {
+            let period = segment.period(pts);
+            if period <= 0.0 {
+                continue;
+            }
+            let end = self
+                .segments
+                .get(index + 1)
+                .map(|next| next.start)
+                .unwrap_or(f64::INFINITY)
+                .min(offset);
+
+            // skip iterations that already scrolled out of the component
+            let first_visible = ((offset - size.width as f64 - segment.start) / period).floor();
+            let mut iteration_start = segment.start + f64::max(first_visible, 0.0) * period;
+            while iteration_start < end {
+                let content_end =
+                    iteration_start + segment.content.width(pts).unwrap_or(0.0) as f64;
+                children.push(self.layout_item(
+                    &segment.content,
+                    2 * index,
+                    iteration_start,
+                    offset,
+                    size,
+                    pts,
+                ));
+                children.push(self.layout_item(
+                    &segment.separator,
+                    2 * index + 1,
+                    content_end,
+                    offset,
+                    size,
+                    pts,
+                ));
+                iteration_start += period;
+            }
+        }
+
+        NestedLayout {
+            top: 0.0,
+            left: 0.0,
+            width: size.width,
+            height: size.height,
+            rotation_degrees: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            crop: None,
+            mask: Some(Mask {
+                radius: BorderRadius::ZERO,
+                top: 0.0,
+                left: 0.0,
+                width: size.width,
+                height: size.height,
+            }),
+            content: LayoutContent::Color(self.component.background_color),
+            // the same node can be rendered multiple times, so the count can't be
+            // calculated from the children
+            child_nodes_count: 2 * self.segments.len(),
+            children,
+            border_width: 0.0,
+            border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
+            border_radius: BorderRadius::ZERO,
+            box_shadow: vec![],
+            blend_mode: BlendMode::Normal,
+            opacity: 1.0,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+        }
+    }
+
+    /// Layout of a text that starts at `strip_position` on the strip.
+    fn layout_item(
+        &self,
+        item: &StatefulComponent,
+        index: usize,
+        strip_position: f64,
+        offset: f64,
+        size: Size,
+        pts: Duration,
+    ) -> NestedLayout {
+        let width = item.width(pts).unwrap_or(0.0);
+        let height = item.height(pts).unwrap_or(0.0);
+        // difference is calculated before the conversion, so the precision is not
+        // lost for long running tickers
+        let left = match self.component.direction {
+            TickerDirection::Left => size.width - (offset - strip_position) as f32,
+            TickerDirection::Right => (offset - strip_position) as f32 - width,
+        };
+
+        NestedLayout {
+            top: (size.height - height) / 2.0,
+            left,
+            width,
+            height,
+            rotation_degrees: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            crop: None,
+            mask: None,
+            content: StatefulLayoutComponent::layout_content(item, index),
+            child_nodes_count: 0,
+            children: vec![],
+            border_width: 0.0,
+            border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
+            border_radius: BorderRadius::ZERO,
+            box_shadow: vec![],
+            blend_mode: BlendMode::Normal,
+            opacity: 1.0,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+        }
+    }
+}
+
+impl TickerComponent {
+    pub(super) fn stateful_component(
+        self,
+        ctx: &BuildStateTreeCtx,
+    ) -> Result<StatefulComponent, SceneError> {
+        let previous_state = self
+            .id
+            .as_ref()
+            .and_then(|id| ctx.prev_state.get(id))
+            .and_then(|component| match component {
+                StatefulComponent::Layout(StatefulLayoutComponent::Ticker(ticker_state)) => {
+                    Some(ticker_state)
+                }
+                _ => None,
+            });
+
+        let separator = TextComponent {
+            id: None,
+            spans: vec![TextSpan {
+                text: self.separator.clone(),
+                font_family: None,
+                font_size: None,
+                color: None,
+                style: None,
+                weight: None,
+            }],
+            dynamic: None,
+            dimensions: separator_dimensions(self.text.dimensions),
+            ..self.text.clone()
+        };
+        let content = TextComponent {
+            id: None,
+            ..self.text
+        };
+        let pts = ctx.last_render_pts;
+
+        let (segments, start_offset) = match previous_state {
+            Some(state) => {
+                let offset = state.offset(pts);
+                let mut segments = state.segments.clone();
+                if let Some(width) = state.last_width {
+                    while segments.len() > 1 && segments[1].start <= offset - width as f64 {
+                        segments.remove(0);
+                    }
+                }
+                let start = match segments.last() {
+                    // last segment did not enter the component yet, so it can be replaced
+                    Some(last) if last.start >= offset => {
+                        segments.pop().map(|last| last.start).unwrap_or(offset)
+                    }
+                    Some(last) => {
+                        let period = last.period(pts);
+                        if period > 0.0 {
+                            let iterations = ((offset - last.start) / period).floor() + 1.0;
+                            last.start + iterations * period
+                        } else {
+                            offset
+                        }
+                    }
+                    None => offset,
+                };
+                segments.push(TickerSegment {
+                    start,
+                    content: content.stateful_component(ctx)?,
+                    separator: separator.stateful_component(ctx)?,
+                });
+                (segments, offset)
+            }
+            None => {
+                let segment = TickerSegment {
+                    start: 0.0,
+                    content: content.stateful_component(ctx)?,
+                    separator: separator.stateful_component(ctx)?,
+                };
+                (vec![segment], 0.0)
+            }
+        };
+
+        Ok(StatefulComponent::Layout(StatefulLayoutComponent::Ticker(
+            StatefulTickerComponent {
+                component: TickerComponentParams {
+                    id: self.id,
+                    position: self.position,
+                    speed: self.speed,
+                    direction: self.direction,
+                    background_color: self.background_color,
+                },
+                segments,
+                start_offset,
+                start_pts: pts,
+                last_width: previous_state.and_then(|state| state.last_width),
+            },
+        )))
+    }
+}
+
+/// Separator is always fitted to its content, otherwise the fixed width of
+/// the content would also be used for the separator.
+fn separator_dimensions(dimensions: TextDimensions) -> TextDimensions {
+    match dimensions {
+        TextDimensions::Fitted {
+            max_width,
+            max_height,
+        } => TextDimensions::Fitted {
+            max_width,
+            max_height,
+        },
+        TextDimensions::FittedColumn { width, max_height } => TextDimensions::Fitted {
+            max_width: width,
+            max_height,
+        },
+        TextDimensions::Fixed { width, height } => TextDimensions::Fitted {
+            max_width: width,
+            max_height: height,
+        },
+    }
+}
@@ -13,7 +13,7 @@ use super::{
     transition::{TransitionOptions, TransitionState},
     types::interpolation::ContinuousValue,
     Component, ComponentId, HorizontalAlign, IntermediateNode, Position, RGBAColor, SceneError,
-    Size, StatefulComponent, TilesComponent, VerticalAlign,
+    Size, StatefulComponent, TilesComponent, TilesLayoutMode, VerticalAlign,
 };
 
 mod interpolation;
@@ -25,6 +25,8 @@ pub(super) struct StatefulTilesComponent {
     /// Initial state for transition. To calculate scene at specific PTS you need
     /// to interpolate between this state and `self.component.tiles(...)`.
     start: Option<(Vec<Option<Tile>>, Size)>,
+    /// Initial opacity for transition.
+    start_opacity: Option<f32>,
 
     /// Tile positions from last layout call. This field is a source for
     /// `start` value after scene update.
@@ -49,6 +51,31 @@ struct TilesComponentParams {
     padding: f32,
     horizontal_align: HorizontalAlign,
     vertical_align: VerticalAlign,
+    opacity: f32,
+
+    layout_mode: TilesLayoutMode,
+    order: Vec<ComponentId>,
+}
+
+/// Defaults of the optional fields in the API, tests only override what they need.
+#[cfg(test)]
+impl Default for TilesComponentParams {
+    fn default() -> Self {
+        Self {
+            id: None,
+            width: None,
+            height: None,
+            background_color: RGBAColor(0, 0, 0, 0),
+            tile_aspect_ratio: (16, 9),
+            margin: 0.0,
+            padding: 0.0,
+            horizontal_align: HorizontalAlign::Center,
+            vertical_align: VerticalAlign::Center,
+            opacity: 1.0,
+            layout_mode: TilesLayoutMode::Grid,
+            order: vec![],
+        }
+    }
 }
 
 impl StatefulTilesComponent {
@@ -66,6 +93,13 @@ impl StatefulTilesComponent {
         ContinuousValue::interpolate(&start, &end, interpolation_progress)
     }
 
+    fn opacity(&self, pts: Duration) -> f32 {
+        let (Some(start), Some(transition)) = (self.start_opacity, &self.transition) else {
+            return self.component.opacity;
+        };
+        ContinuousValue::interpolate(&start, &self.component.opacity, transition.state(pts))
+    }
+
     pub(super) fn position(&self, _pts: Duration) -> Position {
         Position::Static {
             width: self.component.width,
@@ -101,14 +135,17 @@ impl StatefulTilesComponent {
     }
 
     pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
+        let removed = StatefulLayoutComponent::take_removed_children(&mut self.children, pts);
         let tiles = self.tiles(size, pts);
-        let layout = layout_tiles(
+        let mut layout = layout_tiles(
             &tiles,
             size,
             &mut self.children,
             pts,
             self.component.background_color,
+            self.opacity(pts),
         );
+        StatefulLayoutComponent::restore_removed_children(&mut self.children, removed, &mut layout);
         self.last_layout = Some((tiles, size));
         layout
     }
@@ -131,17 +168,30 @@ impl TilesComponent {
             });
 
         let start = previous_state.and_then(|state| state.last_layout.clone());
+        let start_opacity = previous_state.map(|state| state.opacity(ctx.last_render_pts));
         let transition = TransitionState::new(
             self.transition.map(|transition| TransitionOptions {
                 duration: transition.duration,
                 interpolation_kind: transition.interpolation_kind,
             }),
             previous_state.and_then(|s| s.transition.clone()),
+            // Target layout is not known until the component is laid out.
+            None,
             ctx.last_render_pts,
         );
 
+        let mut children = self
+            .children
+            .into_iter()
+            .map(|c| Component::stateful_component(c, ctx))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(previous_state) = previous_state {
+            ctx.retain_exiting_children(&previous_state.children, &mut children);
+        }
+
         let tiles = StatefulTilesComponent {
             start,
+            start_opacity,
             last_layout: previous_state.and_then(|state| state.last_layout.clone()),
             component: TilesComponentParams {
                 id: self.id,
@@ -153,13 +203,12 @@ impl TilesComponent {
                 padding: self.padding,
                 horizontal_align: self.horizontal_align,
                 vertical_align: self.vertical_align,
+                opacity: self.opacity,
+                layout_mode: self.layout_mode,
+                order: self.order,
             },
             transition,
-            children: self
-                .children
-                .into_iter()
-                .map(|c| Component::stateful_component(c, ctx))
-                .collect::<Result<_, _>>()?,
+            children,
         };
 
         Ok(StatefulComponent::Layout(StatefulLayoutComponent::Tiles(
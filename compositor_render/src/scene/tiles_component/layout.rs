@@ -1,7 +1,10 @@
 use std::time::Duration;
 
 use crate::{
-    scene::{layout::StatefulLayoutComponent, BorderRadius, RGBAColor, Size, StatefulComponent},
+    scene::{
+        layout::StatefulLayoutComponent, BlendMode, BorderRadius, RGBAColor, Size,
+        StatefulComponent,
+    },
     transformations::layout::{LayoutContent, NestedLayout},
 };
 
@@ -13,6 +16,7 @@ pub(super) fn layout_tiles(
     children: &mut [StatefulComponent],
     pts: Duration,
     background_color: RGBAColor,
+    opacity: f32,
 ) -> NestedLayout {
     let children = children
         .iter_mut()
@@ -35,8 +39,13 @@ pub(super) fn layout_tiles(
         children,
         border_width: 0.0,
         border_color: RGBAColor(0, 0, 0, 0),
+        border_gradient: None,
         border_radius: BorderRadius::ZERO,
         box_shadow: vec![],
+        blend_mode: BlendMode::Normal,
+        opacity,
+        blur_radius: 0.0,
+        backdrop_blur: 0.0,
     }
 }
 
@@ -75,8 +84,13 @@ fn layout_child(child: &mut StatefulComponent, tile: Option<Tile>, pts: Duration
                 children: vec![children_layouts],
                 border_width: 0.0,
                 border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
                 border_radius: BorderRadius::ZERO,
                 box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
             }
         }
         _ => {
@@ -97,8 +111,13 @@ fn layout_child(child: &mut StatefulComponent, tile: Option<Tile>, pts: Duration
                 children: vec![],
                 border_width: 0.0,
                 border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
                 border_radius: BorderRadius::ZERO,
                 box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
             }
         }
     }
@@ -1,4 +1,6 @@
-use crate::scene::{HorizontalAlign, Size, StatefulComponent, VerticalAlign};
+use crate::scene::{
+    ComponentId, HorizontalAlign, Size, StatefulComponent, TilesLayoutMode, VerticalAlign,
+};
 
 use super::{interpolation::TileId, TilesComponentParams};
 
@@ -25,31 +27,203 @@ struct TilePosition {
     height: f32,
 }
 
+/// Maximal size of a filmstrip in spotlight modes, relative to the size of the component
+/// (width for `SpotlightLeft` and height for `SpotlightTop`).
+const FILMSTRIP_MAX_SIZE_RATIO: f32 = 0.25;
+
 impl TilesComponentParams {
     pub(super) fn tiles(&self, size: Size, children: &[StatefulComponent]) -> Vec<Option<Tile>> {
-        let input_count = children.len() as u32;
-        let rows_cols = self.optimal_row_column_count(input_count, size);
-        let tile_size = self.tile_size(rows_cols, size);
-        let tiles = self.tiles_positions(input_count, rows_cols, tile_size, size);
         let mut index = 0;
+        let ids: Vec<TileId> = children
+            .iter()
+            .map(|child| match child.component_id() {
+                Some(id) => TileId::ComponentId(id.clone()),
+                None => {
+                    let id = TileId::Index(index);
+                    index += 1;
+                    id
+                }
+            })
+            .collect();
+
+        let child_ids: Vec<_> = children.iter().map(|child| child.component_id()).collect();
+        let pinned: Vec<_> = children
+            .iter()
+            .map(|child| match child {
+                StatefulComponent::Layout(layout) => layout.pinned(),
+                _ => false,
+            })
+            .collect();
+        let (spotlight, others) = self.ordered_children(&child_ids, &pinned);
+        let full_area = TilePosition {
+            top: 0.0,
+            left: 0.0,
+            width: size.width,
+            height: size.height,
+        };
+        let positions = match self.layout_mode {
+            TilesLayoutMode::Grid => self.positions_in_area(children.len() as u32, None, full_area),
+            TilesLayoutMode::Filmstrip => {
+                let rows_cols = RowsCols {
+                    rows: 1,
+                    columns: children.len() as u32,
+                };
+                self.positions_in_area(children.len() as u32, Some(rows_cols), full_area)
+            }
+            TilesLayoutMode::SpotlightLeft | TilesLayoutMode::SpotlightTop => {
+                self.spotlight_positions(spotlight.len() as u32, others.len() as u32, size)
+            }
+        };
+
+        let mut tiles = vec![None; children.len()];
+        for (position, index) in positions
+            .into_iter()
+            .zip(spotlight.into_iter().chain(others))
+        {
+            tiles[index] = Some(Tile {
+                top: position.top,
+                left: position.left,
+                width: position.width,
+                height: position.height,
+                id: ids[index].clone(),
+            })
+        }
         tiles
+    }
+
+    /// Returns indexes of children in the order in which they should be placed, split into
+    /// pinned and not pinned children. Children listed in `order` go first, the rest
+    /// keeps the order from the scene definition. In spotlight modes, if there are no pinned
+    /// children, the first child is placed in the spotlight.
+    fn ordered_children(
+        &self,
+        child_ids: &[Option<&ComponentId>],
+        is_pinned: &[bool],
+    ) -> (Vec<usize>, Vec<usize>) {
+        let order_position = |index: usize| {
+            child_ids[index]
+                .and_then(|id| self.order.iter().position(|ordered_id| ordered_id == id))
+                .unwrap_or(self.order.len())
+        };
+        let mut ordered: Vec<usize> = (0..child_ids.len()).collect();
+        // sort is stable, so children not listed in `order` keep their relative order
+        ordered.sort_by_key(|index| order_position(*index));
+
+        let (mut pinned, mut others): (Vec<_>, Vec<_>) =
+            ordered.into_iter().partition(|index| is_pinned[*index]);
+        let is_spotlight_mode = matches!(
+            self.layout_mode,
+            TilesLayoutMode::SpotlightLeft | TilesLayoutMode::SpotlightTop
+        );
+        if is_spotlight_mode && pinned.is_empty() && !others.is_empty() {
+            pinned.push(others.remove(0));
+        }
+        (pinned, others)
+    }
+
+    /// Spotlight tiles are placed in a grid, the rest of tiles is placed in a filmstrip
+    /// on the right (`SpotlightLeft`) or on the bottom (`SpotlightTop`).
+    fn spotlight_positions(
+        &self,
+        spotlight_count: u32,
+        filmstrip_count: u32,
+        size: Size,
+    ) -> Vec<TilePosition> {
+        let full_area = TilePosition {
+            top: 0.0,
+            left: 0.0,
+            width: size.width,
+            height: size.height,
+        };
+        if filmstrip_count == 0 {
+            return self.positions_in_area(spotlight_count, None, full_area);
+        }
+
+        let (filmstrip_rows_cols, spotlight_area, filmstrip_area) = match self.layout_mode {
+            TilesLayoutMode::SpotlightTop => {
+                let rows_cols = RowsCols {
+                    rows: 1,
+                    columns: filmstrip_count,
+                };
+                let max_size = Size {
+                    width: size.width,
+                    height: size.height * FILMSTRIP_MAX_SIZE_RATIO,
+                };
+                // padding and margin can be larger than the component, spotlight area
+                // can't have a negative size
+                let filmstrip_height = f32::min(
+                    self.tile_size(rows_cols, max_size).height + 2.0 * (self.padding + self.margin),
+                    size.height,
+                );
+                let spotlight_area = TilePosition {
+                    height: size.height - filmstrip_height,
+                    ..full_area
+                };
+                let filmstrip_area = TilePosition {
+                    top: size.height - filmstrip_height,
+                    height: filmstrip_height,
+                    ..full_area
+                };
+                (rows_cols, spotlight_area, filmstrip_area)
+            }
+            _ => {
+                let rows_cols = RowsCols {
+                    rows: filmstrip_count,
+                    columns: 1,
+                };
+                let max_size = Size {
+                    width: size.width * FILMSTRIP_MAX_SIZE_RATIO,
+                    height: size.height,
+                };
+                let filmstrip_width = f32::min(
+                    self.tile_size(rows_cols, max_size).width + 2.0 * (self.padding + self.margin),
+                    size.width,
+                );
+                let spotlight_area = TilePosition {
+                    width: size.width - filmstrip_width,
+                    ..full_area
+                };
+                let filmstrip_area = TilePosition {
+                    left: size.width - filmstrip_width,
+                    width: filmstrip_width,
+                    ..full_area
+                };
+                (rows_cols, spotlight_area, filmstrip_area)
+            }
+        };
+
+        let mut positions = self.positions_in_area(spotlight_count, None, spotlight_area);
+        positions.extend(self.positions_in_area(
+            filmstrip_count,
+            Some(filmstrip_rows_cols),
+            filmstrip_area,
+        ));
+        positions
+    }
+
+    /// Positions of `count` tiles inside `area`. If `rows_cols` is not defined, an optimal
+    /// number of rows and columns is calculated.
+    fn positions_in_area(
+        &self,
+        count: u32,
+        rows_cols: Option<RowsCols>,
+        area: TilePosition,
+    ) -> Vec<TilePosition> {
+        if count == 0 {
+            return vec![];
+        }
+        let size = Size {
+            width: area.width,
+            height: area.height,
+        };
+        let rows_cols = rows_cols.unwrap_or_else(|| self.optimal_row_column_count(count, size));
+        let tile_size = self.tile_size(rows_cols, size);
+        self.tiles_positions(count, rows_cols, tile_size, size)
             .into_iter()
-            .zip(children.iter())
-            .map(|(tile, child)| {
-                Some(Tile {
-                    top: tile.top,
-                    left: tile.left,
-                    width: tile.width,
-                    height: tile.height,
-                    id: match child.component_id() {
-                        Some(id) => TileId::ComponentId(id.clone()),
-                        None => {
-                            let id = TileId::Index(index);
-                            index += 1;
-                            id
-                        }
-                    },
-                })
+            .map(|position| TilePosition {
+                top: area.top + position.top,
+                left: area.left + position.left,
+                ..position
             })
             .collect()
     }
@@ -164,3 +338,117 @@ impl TilesComponentParams {
         layouts
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::scene::{ComponentId, Size, TilesLayoutMode};
+
+    use super::{super::TilesComponentParams, TilePosition};
+
+    fn tiles(layout_mode: TilesLayoutMode, order: &[&str]) -> TilesComponentParams {
+        TilesComponentParams {
+            layout_mode,
+            order: order.iter().map(|id| ComponentId(Arc::from(*id))).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn ids(ids: &[Option<&str>]) -> Vec<Option<ComponentId>> {
+        ids.iter()
+            .map(|id| id.map(|id| ComponentId(Arc::from(id))))
+            .collect()
+    }
+
+    fn ordered_children(
+        tiles: &TilesComponentParams,
+        child_ids: &[Option<ComponentId>],
+        pinned: &[bool],
+    ) -> (Vec<usize>, Vec<usize>) {
+        let child_ids: Vec<_> = child_ids.iter().map(Option::as_ref).collect();
+        tiles.ordered_children(&child_ids, pinned)
+    }
+
+    fn assert_position(position: TilePosition, expected: (f32, f32, f32, f32)) {
+        assert_eq!(
+            (position.top, position.left, position.width, position.height),
+            expected
+        );
+    }
+
+    #[test]
+    fn order_and_pinned_children() {
+        let child_ids = ids(&[Some("a"), None, Some("c"), Some("d")]);
+        let tiles = tiles(TilesLayoutMode::Grid, &["c", "missing", "a"]);
+
+        assert_eq!(
+            ordered_children(&tiles, &child_ids, &[false; 4]),
+            (vec![], vec![2, 0, 1, 3])
+        );
+        assert_eq!(
+            ordered_children(&tiles, &child_ids, &[false, true, false, true]),
+            (vec![1, 3], vec![2, 0])
+        );
+    }
+
+    #[test]
+    fn first_child_is_in_spotlight_if_none_is_pinned() {
+        let child_ids = ids(&[Some("a"), Some("b"), Some("c")]);
+        let tiles = tiles(TilesLayoutMode::SpotlightLeft, &["b"]);
+
+        assert_eq!(
+            ordered_children(&tiles, &child_ids, &[false; 3]),
+            (vec![1], vec![0, 2])
+        );
+        assert_eq!(
+            ordered_children(&tiles, &child_ids, &[false, false, true]),
+            (vec![2], vec![1, 0])
+        );
+        assert_eq!(ordered_children(&tiles, &[], &[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn spotlight_left_positions() {
+        let size = Size {
+            width: 1000.0,
+            height: 500.0,
+        };
+        let positions = tiles(TilesLayoutMode::SpotlightLeft, &[]).spotlight_positions(1, 3, size);
+
+        assert_eq!(positions.len(), 4);
+        assert_position(positions[0], (39.0625, 0.0, 750.0, 421.875));
+        assert_position(positions[1], (39.0625, 750.0, 250.0, 140.625));
+        assert_position(positions[2], (179.6875, 750.0, 250.0, 140.625));
+        assert_position(positions[3], (320.3125, 750.0, 250.0, 140.625));
+    }
+
+    #[test]
+    fn spotlight_top_without_filmstrip_uses_whole_area() {
+        let size = Size {
+            width: 1600.0,
+            height: 900.0,
+        };
+        let positions = tiles(TilesLayoutMode::SpotlightTop, &[]).spotlight_positions(1, 0, size);
+
+        assert_eq!(positions.len(), 1);
+        assert_position(positions[0], (0.0, 0.0, 1600.0, 900.0));
+    }
+
+    #[test]
+    fn spotlight_area_is_not_negative() {
+        let size = Size {
+            width: 100.0,
+            height: 100.0,
+        };
+        let tiles = TilesComponentParams {
+            margin: 300.0,
+            ..tiles(TilesLayoutMode::SpotlightTop, &[])
+        };
+        let positions = tiles.spotlight_positions(1, 1, size);
+
+        assert_eq!(positions.len(), 2);
+        assert_position(positions[0], (0.0, 50.0, 0.0, 0.0));
+        assert_position(positions[1], (50.0, 50.0, 0.0, 0.0));
+    }
+}
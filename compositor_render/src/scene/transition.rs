@@ -1,11 +1,18 @@
 use std::time::Duration;
 
-use self::{bounce::bounce_easing, cubic_bezier::cubic_bezier_easing};
+use self::{bounce::bounce_easing, cubic_bezier::cubic_bezier_easing, spring::spring_easing};
 
-use super::{types::interpolation::InterpolationState, InterpolationKind};
+use super::{
+    types::interpolation::{ContinuousValue, InterpolationState},
+    InterpolationKind,
+};
+
+pub(super) use enter_exit::EnterExitState;
 
 mod bounce;
 mod cubic_bezier;
+mod enter_exit;
+mod spring;
 
 /// Similar concept to InterpolationState, but it represents a time instead.
 /// Values between 0 and 1 represent transition and larger than 1 post transition.
@@ -27,6 +34,10 @@ pub(super) struct TransitionState {
     /// Duration of the transition.
     duration: Duration,
 
+    /// Duration of the entire interpolation curve. It is longer than `duration` if
+    /// the transition starts in the middle of the curve.
+    curve_duration: Duration,
+
     interpolation_kind: InterpolationKind,
 }
 
@@ -36,9 +47,13 @@ pub(super) struct TransitionOptions {
 }
 
 impl TransitionState {
+    /// * `velocity_scale` - converts velocity of the previous transition into velocity of
+    ///   the new one, see [`velocity_scale`]. If it is not known, the new transition is
+    ///   assumed to have the same target as the previous one.
     pub fn new(
         current_transition: Option<TransitionOptions>,
         previous_transition: Option<TransitionState>,
+        velocity_scale: Option<f64>,
         last_pts: Duration,
     ) -> Option<Self> {
         let previous_transition = previous_transition.and_then(|transition| {
@@ -59,20 +74,44 @@ impl TransitionState {
                 );
                 let state_offset = previous_transition
                     .interpolation_kind
-                    .state(progress_offset.0);
+                    .state(progress_offset.0, previous_transition.curve_duration);
                 Some(Self {
                     initial_offset: (progress_offset, state_offset),
                     start_pts: last_pts,
                     duration: remaining_duration,
+                    curve_duration: previous_transition.curve_duration,
                     interpolation_kind: previous_transition.interpolation_kind,
                 })
             }
-            (Some(current_transition), _) => Some(Self {
-                initial_offset: (TransitionProgress(0.0), InterpolationState(0.0)),
-                start_pts: last_pts,
-                duration: current_transition.duration,
-                interpolation_kind: current_transition.interpolation_kind,
-            }),
+            (Some(current_transition), previous_transition) => {
+                // Spring continues with the velocity of the interrupted transition.
+                let interpolation_kind =
+                    match (current_transition.interpolation_kind, previous_transition) {
+                        (
+                            InterpolationKind::Spring {
+                                stiffness,
+                                damping,
+                                mass,
+                                ..
+                            },
+                            Some(previous_transition),
+                        ) => InterpolationKind::Spring {
+                            stiffness,
+                            damping,
+                            mass,
+                            initial_velocity: previous_transition
+                                .initial_velocity(last_pts, velocity_scale),
+                        },
+                        (interpolation_kind, _) => interpolation_kind,
+                    };
+                Some(Self {
+                    initial_offset: (TransitionProgress(0.0), InterpolationState(0.0)),
+                    start_pts: last_pts,
+                    duration: current_transition.duration,
+                    curve_duration: current_transition.duration,
+                    interpolation_kind,
+                })
+            }
         }
     }
 
@@ -86,20 +125,185 @@ impl TransitionState {
         // Clamp just to handle a case where this function is called after transition is finished.
         let progress = f64::clamp(progress, 0.0, 1.0);
         // Value in range [initial_offset.1, 1] or [state(initial_offset.0), 1].
-        let state = self.interpolation_kind.state(progress);
+        let state = self.interpolation_kind.state(progress, self.curve_duration);
         // Value in range [0, 1].
         InterpolationState((state.0 - self.initial_offset.1 .0) / (1.0 - self.initial_offset.1 .0))
     }
+
+    /// Initial velocity of a transition that interrupts this one at `pts`, in units of
+    /// the distance the new transition needs to cover per second.
+    fn initial_velocity(&self, pts: Duration, velocity_scale: Option<f64>) -> f64 {
+        const DELTA: Duration = Duration::from_millis(1);
+        let state = self.state(pts).0;
+        let velocity = (self.state(pts + DELTA).0 - state) / DELTA.as_secs_f64();
+        match velocity_scale {
+            Some(scale) => velocity * scale,
+            // The new transition covers the remaining distance of this one.
+            None => {
+                let remaining_distance = 1.0 - state;
+                if remaining_distance.abs() < 1e-3 {
+                    return 0.0;
+                }
+                velocity / remaining_distance
+            }
+        }
+    }
+}
+
+/// Scale that converts velocity of the interrupted transition from `previous.0` to
+/// `previous.1` into velocity of the new transition from `next.0` to `next.1`. Velocity
+/// is projected on the direction of the new transition, so the value keeps moving with
+/// the same speed even if the target changed.
+///
+/// Returns `None` if the values can't be compared or the new transition doesn't move.
+pub(super) fn velocity_scale<T: ContinuousValue>(
+    previous: (&T, &T),
+    next: (&T, &T),
+) -> Option<f64> {
+    fn difference<T: ContinuousValue>((start, end): (&T, &T)) -> Option<Vec<f64>> {
+        let (mut start_coordinates, mut end_coordinates) = (Vec::new(), Vec::new());
+        start.push_coordinates(&mut start_coordinates);
+        end.push_coordinates(&mut end_coordinates);
+        if start_coordinates.len() != end_coordinates.len() {
+            return None;
+        }
+        Some(
+            start_coordinates
+                .into_iter()
+                .zip(end_coordinates)
+                .map(|(start, end)| end - start)
+                .collect(),
+        )
+    }
+    fn dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    let (previous, next) = (difference(previous)?, difference(next)?);
+    if previous.len() != next.len() {
+        return None;
+    }
+    let next_distance_squared = dot(&next, &next);
+    if next_distance_squared < 1e-9 {
+        return None;
+    }
+    Some(dot(&previous, &next) / next_distance_squared)
 }
 
 impl InterpolationKind {
-    fn state(&self, t: f64) -> InterpolationState {
+    /// * `t` - progress of the transition in range [0, 1]
+    /// * `duration` - duration of the entire transition, only physically based
+    ///   interpolations depend on it
+    pub(super) fn state(&self, t: f64, duration: Duration) -> InterpolationState {
         match self {
             InterpolationKind::Linear => InterpolationState(t),
             InterpolationKind::Bounce => InterpolationState(bounce_easing(t)),
             InterpolationKind::CubicBezier { x1, y1, x2, y2 } => {
                 InterpolationState(cubic_bezier_easing(t, *x1, *y1, *x2, *y2))
             }
+            // Spring might not settle within the transition duration, so the final
+            // value is forced at the end.
+            InterpolationKind::Spring { .. } if t >= 1.0 => InterpolationState(1.0),
+            InterpolationKind::Spring {
+                stiffness,
+                damping,
+                mass,
+                initial_velocity,
+            } => InterpolationState(spring_easing(
+                t * duration.as_secs_f64(),
+                *stiffness,
+                *damping,
+                *mass,
+                *initial_velocity,
+            )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::scene::{types::interpolation::ContinuousValue, InterpolationKind};
+
+    use super::{velocity_scale, TransitionOptions, TransitionState};
+
+    fn spring() -> Option<TransitionOptions> {
+        Some(TransitionOptions {
+            duration: Duration::from_secs(2),
+            interpolation_kind: InterpolationKind::Spring {
+                stiffness: 100.0,
+                damping: 10.0,
+                mass: 1.0,
+                initial_velocity: 0.0,
+            },
+        })
+    }
+
+    fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+
+    /// Value of a transition from `start` to `end` and its velocity in value units per second.
+    fn value_and_velocity(
+        transition: &TransitionState,
+        (start, end): (f64, f64),
+        pts: Duration,
+    ) -> (f64, f64) {
+        const DELTA: Duration = Duration::from_micros(100);
+        let value = |pts| ContinuousValue::interpolate(&start, &end, transition.state(pts));
+        let velocity = (value(pts + DELTA) - value(pts)) / DELTA.as_secs_f64();
+        (value(pts), velocity)
+    }
+
+    #[test]
+    fn interrupted_spring_keeps_velocity_when_retargeted() {
+        let previous = (0.0, 100.0);
+        let previous_transition = TransitionState::new(spring(), None, None, secs(0.0)).unwrap();
+        let (current, previous_velocity) =
+            value_and_velocity(&previous_transition, previous, secs(0.1));
+        assert!(previous_velocity > 100.0);
+
+        for target in [300.0, 50.0, -100.0] {
+            let next = (current, target);
+            let scale = velocity_scale((&previous.0, &previous.1), (&next.0, &next.1));
+            let transition = TransitionState::new(
+                spring(),
+                Some(previous_transition.clone()),
+                scale,
+                secs(0.1),
+            )
+            .unwrap();
+            let (value, velocity) = value_and_velocity(&transition, next, secs(0.1));
+            assert!((value - current).abs() < 1e-9);
+            assert!(
+                (velocity - previous_velocity).abs() < previous_velocity * 0.02,
+                "target {target}: velocity {velocity}, expected {previous_velocity}"
+            );
+        }
+    }
+
+    #[test]
+    fn interrupted_spring_without_scale_assumes_the_same_target() {
+        let previous = (0.0, 100.0);
+        let previous_transition = TransitionState::new(spring(), None, None, secs(0.0)).unwrap();
+        let (current, previous_velocity) =
+            value_and_velocity(&previous_transition, previous, secs(0.1));
+
+        let transition =
+            TransitionState::new(spring(), Some(previous_transition), None, secs(0.1)).unwrap();
+        let (_, velocity) = value_and_velocity(&transition, (current, previous.1), secs(0.1));
+        assert!((velocity - previous_velocity).abs() < previous_velocity * 0.02);
+    }
+
+    #[test]
+    fn velocity_scale_of_values_that_can_not_be_compared() {
+        let none: Option<f32> = None;
+        assert_eq!(
+            velocity_scale((&none, &Some(1.0)), (&none, &Some(2.0))),
+            None
+        );
+        assert_eq!(velocity_scale((&0.0, &1.0), (&1.0, &1.0)), None);
+        assert_eq!(velocity_scale((&0.0, &1.0), (&1.0, &3.0)), Some(0.5));
+    }
+}
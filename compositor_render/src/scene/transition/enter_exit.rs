@@ -0,0 +1,228 @@
+use std::time::Duration;
+
+use crate::{
+    scene::{
+        types::interpolation::InterpolationState, BlendMode, BorderRadius, EnterExitEffect,
+        EnterExitTransition, RGBAColor, SlideEdge,
+    },
+    transformations::layout::{LayoutContent, NestedLayout},
+};
+
+use super::{TransitionProgress, TransitionState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Enter,
+    Exit,
+}
+
+/// Animation of a component that was added to or removed from the scene.
+#[derive(Debug, Clone)]
+pub(crate) struct EnterExitState {
+    direction: Direction,
+    effect: EnterExitEffect,
+    transition: TransitionState,
+}
+
+impl EnterExitState {
+    /// Calculate state of a component after a scene update.
+    ///
+    /// `previous` is `None` if component was not present in the previous scene, otherwise
+    /// it contains its previous enter/exit state.
+    pub fn on_scene_update(
+        enter_transition: Option<EnterExitTransition>,
+        previous: Option<Option<&Self>>,
+        last_pts: Duration,
+    ) -> Option<Self> {
+        match previous {
+            // Component was removed, but it is added back before exit transition finished.
+            Some(Some(state)) if state.direction == Direction::Exit => {
+                enter_transition.map(|t| Self::new(Direction::Enter, t, last_pts))
+            }
+            Some(Some(state)) if !state.is_finished(last_pts) => Some(state.clone()),
+            Some(_) => None,
+            None => enter_transition.map(|t| Self::new(Direction::Enter, t, last_pts)),
+        }
+    }
+
+    /// Calculate state of a component that was removed from the scene. Returns `None`
+    /// if component should not be rendered anymore.
+    pub fn on_remove(
+        exit_transition: Option<EnterExitTransition>,
+        current: Option<&Self>,
+        last_pts: Duration,
+    ) -> Option<Self> {
+        match current {
+            Some(state) if state.direction == Direction::Exit => {
+                (!state.is_finished(last_pts)).then(|| state.clone())
+            }
+            _ => exit_transition.map(|t| Self::new(Direction::Exit, t, last_pts)),
+        }
+    }
+
+    fn new(direction: Direction, transition: EnterExitTransition, last_pts: Duration) -> Self {
+        Self {
+            direction,
+            effect: transition.effect,
+            transition: TransitionState {
+                initial_offset: (TransitionProgress(0.0), InterpolationState(0.0)),
+                start_pts: last_pts,
+                duration: transition.transition.duration,
+                curve_duration: transition.transition.duration,
+                interpolation_kind: transition.transition.interpolation_kind,
+            },
+        }
+    }
+
+    fn is_finished(&self, pts: Duration) -> bool {
+        self.transition.start_pts + self.transition.duration <= pts
+    }
+
+    /// Returns true if the component was removed and it is no longer visible.
+    pub fn is_exit_finished(&self, pts: Duration) -> bool {
+        self.direction == Direction::Exit && self.is_finished(pts)
+    }
+
+    /// Apply the effect on a layout of the component. `layout` is expected to
+    /// be a top-level layout of the component (in coordinates of its parent).
+    pub fn apply(&self, mut layout: NestedLayout, pts: Duration) -> NestedLayout {
+        // Value in range [0, 1], where 0 means that component is hidden and 1 that
+        // it is fully visible.
+        let visibility = match (self.direction, self.is_finished(pts)) {
+            (Direction::Enter, true) => return layout,
+            (Direction::Exit, true) => 0.0,
+            (Direction::Enter, false) => self.transition.state(pts).0 as f32,
+            (Direction::Exit, false) => 1.0 - self.transition.state(pts).0 as f32,
+        };
+        if visibility <= 0.0 {
+            layout.opacity = 0.0;
+            return layout;
+        }
+
+        match self.effect {
+            EnterExitEffect::Fade => {
+                layout.opacity *= visibility;
+                layout
+            }
+            EnterExitEffect::Slide { from } => {
+                let hidden = 1.0 - visibility;
+                match from {
+                    SlideEdge::Top => layout.top -= layout.height * hidden,
+                    SlideEdge::Bottom => layout.top += layout.height * hidden,
+                    SlideEdge::Left => layout.left -= layout.width * hidden,
+                    SlideEdge::Right => layout.left += layout.width * hidden,
+                };
+                layout
+            }
+            EnterExitEffect::Scale => {
+                // scale_x/scale_y only affect children, so the layout needs to be wrapped
+                // to scale it around its center.
+                let (top, left, width, height) =
+                    (layout.top, layout.left, layout.width, layout.height);
+                let child_nodes_count = layout.child_nodes_count;
+                layout.top = 0.0;
+                layout.left = 0.0;
+                NestedLayout {
+                    top: top + (height * (1.0 - visibility) / 2.0),
+                    left: left + (width * (1.0 - visibility) / 2.0),
+                    width: width * visibility,
+                    height: height * visibility,
+                    rotation_degrees: 0.0,
+                    scale_x: visibility,
+                    scale_y: visibility,
+                    crop: None,
+                    mask: None,
+                    content: LayoutContent::None,
+                    child_nodes_count,
+                    children: vec![layout],
+                    border_width: 0.0,
+                    border_color: RGBAColor(0, 0, 0, 0),
+                    border_gradient: None,
+                    border_radius: BorderRadius::ZERO,
+                    box_shadow: vec![],
+                    blend_mode: BlendMode::Normal,
+                    opacity: 1.0,
+                    blur_radius: 0.0,
+                    backdrop_blur: 0.0,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        scene::{EnterExitEffect, EnterExitTransition, InterpolationKind, Transition},
+        transformations::layout::NestedLayout,
+    };
+
+    use super::{Direction, EnterExitState};
+
+    fn fade() -> Option<EnterExitTransition> {
+        Some(EnterExitTransition {
+            effect: EnterExitEffect::Fade,
+            transition: Transition {
+                duration: Duration::from_secs(1),
+                interpolation_kind: InterpolationKind::Linear,
+            },
+        })
+    }
+
+    fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+
+    #[test]
+    fn enter_only_when_added() {
+        let added = EnterExitState::on_scene_update(fade(), None, secs(1.0)).unwrap();
+        assert_eq!(added.direction, Direction::Enter);
+        assert_eq!(added.transition.start_pts, secs(1.0));
+
+        // Component was already in the previous scene and its enter transition finished.
+        assert!(EnterExitState::on_scene_update(fade(), Some(None), secs(3.0)).is_none());
+        assert!(EnterExitState::on_scene_update(fade(), Some(Some(&added)), secs(3.0)).is_none());
+
+        // Enter transition is still running, so it is not restarted.
+        let running =
+            EnterExitState::on_scene_update(fade(), Some(Some(&added)), secs(1.5)).unwrap();
+        assert_eq!(running.transition.start_pts, secs(1.0));
+    }
+
+    #[test]
+    fn exit_and_enter_again() {
+        assert!(EnterExitState::on_remove(None, None, secs(1.0)).is_none());
+
+        let exiting = EnterExitState::on_remove(fade(), None, secs(1.0)).unwrap();
+        assert_eq!(exiting.direction, Direction::Exit);
+
+        // Another scene update while the component is still exiting.
+        let still_exiting = EnterExitState::on_remove(fade(), Some(&exiting), secs(1.5)).unwrap();
+        assert_eq!(still_exiting.transition.start_pts, secs(1.0));
+        assert!(EnterExitState::on_remove(fade(), Some(&exiting), secs(2.0)).is_none());
+
+        // Added back before the exit transition finished.
+        let entering =
+            EnterExitState::on_scene_update(fade(), Some(Some(&exiting)), secs(1.5)).unwrap();
+        assert_eq!(entering.direction, Direction::Enter);
+        assert_eq!(entering.transition.start_pts, secs(1.5));
+    }
+
+    #[test]
+    fn fade_changes_opacity() {
+        let layout = || NestedLayout {
+            width: 100.0,
+            height: 100.0,
+            ..NestedLayout::child_nodes_placeholder(0)
+        };
+        let entering = EnterExitState::on_scene_update(fade(), None, secs(0.0)).unwrap();
+        assert_eq!(entering.apply(layout(), secs(0.25)).opacity, 0.25);
+        assert_eq!(entering.apply(layout(), secs(2.0)).opacity, 1.0);
+
+        let exiting = EnterExitState::on_remove(fade(), None, secs(0.0)).unwrap();
+        assert_eq!(exiting.apply(layout(), secs(0.25)).opacity, 0.75);
+        assert_eq!(exiting.apply(layout(), secs(2.0)).opacity, 0.0);
+    }
+}
@@ -0,0 +1,66 @@
+/// Position of a damped spring that starts at 0 and settles at 1.
+///
+/// * `time` - time since the start of a transition in seconds.
+/// * `initial_velocity` - in units of the full distance per second.
+///
+/// Based on the analytical solution of a damped harmonic oscillator.
+pub fn spring_easing(
+    time: f64,
+    stiffness: f64,
+    damping: f64,
+    mass: f64,
+    initial_velocity: f64,
+) -> f64 {
+    let natural_frequency = f64::sqrt(stiffness / mass);
+    let damping_ratio = damping / (2.0 * f64::sqrt(stiffness * mass));
+
+    // Displacement from the target position (starts at 1 and settles at 0).
+    let displacement = if damping_ratio < 1.0 {
+        // under-damped
+        let damped_frequency = natural_frequency * f64::sqrt(1.0 - damping_ratio * damping_ratio);
+        let a = 1.0;
+        let b = (damping_ratio * natural_frequency - initial_velocity) / damped_frequency;
+        f64::exp(-damping_ratio * natural_frequency * time)
+            * (a * f64::cos(damped_frequency * time) + b * f64::sin(damped_frequency * time))
+    } else if damping_ratio == 1.0 {
+        // critically damped
+        let a = 1.0;
+        let b = natural_frequency - initial_velocity;
+        f64::exp(-natural_frequency * time) * (a + b * time)
+    } else {
+        // over-damped
+        let root = f64::sqrt(damping_ratio * damping_ratio - 1.0);
+        let r1 = -natural_frequency * (damping_ratio - root);
+        let r2 = -natural_frequency * (damping_ratio + root);
+        let c1 = (-initial_velocity - r2) / (r1 - r2);
+        let c2 = 1.0 - c1;
+        c1 * f64::exp(r1 * time) + c2 * f64::exp(r2 * time)
+    };
+
+    1.0 - displacement
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spring_easing;
+
+    fn velocity_at_start(stiffness: f64, damping: f64, initial_velocity: f64) -> f64 {
+        let dt = 1e-6;
+        spring_easing(dt, stiffness, damping, 1.0, initial_velocity) / dt
+    }
+
+    #[test]
+    fn starts_at_zero_and_settles_at_one() {
+        for damping in [5.0, 20.0, 40.0] {
+            assert!(spring_easing(0.0, 100.0, damping, 1.0, 0.0).abs() < 1e-9);
+            assert!((spring_easing(10.0, 100.0, damping, 1.0, 0.0) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn respects_initial_velocity() {
+        for damping in [5.0, 20.0, 40.0] {
+            assert!((velocity_at_start(100.0, damping, 3.0) - 3.0).abs() < 1e-2);
+        }
+    }
+}
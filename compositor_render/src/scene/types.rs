@@ -70,11 +70,23 @@ pub enum HorizontalPosition {
     RightOffset(f32),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum InterpolationKind {
     Linear,
     Bounce,
-    CubicBezier { x1: f64, y1: f64, x2: f64, y2: f64 },
+    CubicBezier {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+    },
+    /// `initial_velocity` is expressed in units of the full transition distance per second.
+    Spring {
+        stiffness: f64,
+        damping: f64,
+        mass: f64,
+        initial_velocity: f64,
+    },
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -145,6 +157,42 @@ impl Sub<f32> for BorderRadius {
     }
 }
 
+/// Fill that smoothly changes between the colors of its stops. Positions are
+/// relative to the area that is filled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Sorted by `offset`, at most `Gradient::MAX_STOPS` entries.
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    pub const MAX_STOPS: usize = 8;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKind {
+    /// Colors change along a line that goes through the center in the direction
+    /// of `angle_degrees` (`0` points up, `90` points right).
+    Linear { angle_degrees: f32 },
+    /// Colors change with a distance from the center, the last stop is placed
+    /// at the farthest corner. Center is defined as a fraction of the size.
+    Radial { center_x: f32, center_y: f32 },
+    /// Colors change clockwise around the center, starting at `angle_degrees`.
+    Conic {
+        angle_degrees: f32,
+        center_x: f32,
+        center_y: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Value between 0.0 and 1.0.
+    pub offset: f32,
+    pub color: RGBAColor,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BoxShadow {
     pub offset_x: f32,
@@ -1,10 +1,16 @@
-use super::{HorizontalPosition, VerticalPosition};
+use super::{
+    Gradient, GradientKind, GradientStop, HorizontalPosition, RGBAColor, VerticalPosition,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct InterpolationState(pub f64);
 
 pub trait ContinuousValue {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self;
+
+    /// Appends numeric coordinates of the value, they are used to measure how far apart
+    /// two values are. Only values that are interpolated have to be included.
+    fn push_coordinates(&self, _coordinates: &mut Vec<f64>) {}
 }
 
 fn interpolate_f64(start: f64, end: f64, state: InterpolationState) -> f64 {
@@ -15,24 +21,40 @@ impl ContinuousValue for i32 {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
         interpolate_f64(*start as f64, *end as f64, state) as Self
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.push(*self as f64)
+    }
 }
 
 impl ContinuousValue for f64 {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
         interpolate_f64(*start, *end, state)
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.push(*self)
+    }
 }
 
 impl ContinuousValue for f32 {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
         interpolate_f64(*start as f64, *end as f64, state) as f32
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.push(*self as f64)
+    }
 }
 
 impl ContinuousValue for usize {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
         interpolate_f64(*start as f64, *end as f64, state) as usize
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.push(*self as f64)
+    }
 }
 
 impl<T: ContinuousValue + Clone> ContinuousValue for Option<T> {
@@ -42,6 +64,32 @@ impl<T: ContinuousValue + Clone> ContinuousValue for Option<T> {
             (_, end) => end.clone(),
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        if let Some(value) = self {
+            value.push_coordinates(coordinates)
+        }
+    }
+}
+
+impl ContinuousValue for RGBAColor {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        let channel = |start: u8, end: u8| {
+            interpolate_f64(start as f64, end as f64, state)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Self(
+            channel(start.0, end.0),
+            channel(start.1, end.1),
+            channel(start.2, end.2),
+            channel(start.3, end.3),
+        )
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        coordinates.extend([self.0, self.1, self.2, self.3].map(f64::from))
+    }
 }
 
 impl From<InterpolationState> for f64 {
@@ -62,6 +110,14 @@ impl ContinuousValue for VerticalPosition {
             (_, end) => *end,
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        match self {
+            VerticalPosition::TopOffset(offset) | VerticalPosition::BottomOffset(offset) => {
+                offset.push_coordinates(coordinates)
+            }
+        }
+    }
 }
 
 impl ContinuousValue for HorizontalPosition {
@@ -76,4 +132,326 @@ impl ContinuousValue for HorizontalPosition {
             (_, end) => *end,
         }
     }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        match self {
+            HorizontalPosition::LeftOffset(offset) | HorizontalPosition::RightOffset(offset) => {
+                offset.push_coordinates(coordinates)
+            }
+        }
+    }
+}
+
+impl ContinuousValue for Gradient {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        if std::mem::discriminant(&start.kind) != std::mem::discriminant(&end.kind) {
+            // Gradients of different kinds can't be blended directly. Both of them are
+            // blended into a single color in the middle of the transition, which looks
+            // the same regardless of the kind.
+            let middle_color = ContinuousValue::interpolate(
+                &start.color_at(0.5),
+                &end.color_at(0.5),
+                InterpolationState(0.5),
+            );
+            let state = f64::from(state);
+            return match state < 0.5 {
+                true => Self::interpolate(
+                    start,
+                    &Gradient::solid(start.kind, middle_color),
+                    InterpolationState(state * 2.0),
+                ),
+                false => Self::interpolate(
+                    &Gradient::solid(end.kind, middle_color),
+                    end,
+                    InterpolationState(state * 2.0 - 1.0),
+                ),
+            };
+        }
+
+        let kind = ContinuousValue::interpolate(&start.kind, &end.kind, state);
+        // If both gradients have the same number of stops, they are matched by index,
+        // so offsets of the stops are interpolated too.
+        if start.stops.len() == end.stops.len() {
+            return Self {
+                kind,
+                stops: start
+                    .stops
+                    .iter()
+                    .zip(end.stops.iter())
+                    .map(|(start, end)| ContinuousValue::interpolate(start, end, state))
+                    .collect(),
+            };
+        }
+        Self {
+            kind,
+            stops: common_offsets(start, end)
+                .into_iter()
+                .map(|offset| GradientStop {
+                    offset,
+                    color: ContinuousValue::interpolate(
+                        &start.color_at(offset),
+                        &end.color_at(offset),
+                        state,
+                    ),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Gradient {
+    /// Gradient with a single color, it looks the same regardless of the `kind`.
+    fn solid(kind: GradientKind, color: RGBAColor) -> Self {
+        Self {
+            kind,
+            stops: vec![
+                GradientStop { offset: 0.0, color },
+                GradientStop { offset: 1.0, color },
+            ],
+        }
+    }
+
+    /// Color at the `offset`, colors between stops are interpolated linearly.
+    fn color_at(&self, offset: f32) -> RGBAColor {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return RGBAColor(0, 0, 0, 0);
+        };
+        match self.stops.iter().position(|stop| stop.offset >= offset) {
+            Some(0) => first.color,
+            Some(index) => {
+                let (before, after) = (self.stops[index - 1], self.stops[index]);
+                let state = (offset - before.offset) / (after.offset - before.offset);
+                ContinuousValue::interpolate(
+                    &before.color,
+                    &after.color,
+                    InterpolationState(state as f64),
+                )
+            }
+            None => last.color,
+        }
+    }
+}
+
+/// Offsets of the stops of both gradients. If there are too many of them, gradients
+/// are sampled at evenly spaced offsets instead.
+fn common_offsets(start: &Gradient, end: &Gradient) -> Vec<f32> {
+    let mut offsets: Vec<f32> = start
+        .stops
+        .iter()
+        .chain(end.stops.iter())
+        .map(|stop| stop.offset)
+        .collect();
+    offsets.sort_by(f32::total_cmp);
+    offsets.dedup();
+    if offsets.len() <= Gradient::MAX_STOPS {
+        return offsets;
+    }
+    let last_index = (Gradient::MAX_STOPS - 1) as f32;
+    (0..Gradient::MAX_STOPS)
+        .map(|index| index as f32 / last_index)
+        .collect()
+}
+
+/// Fill defined by a color and a gradient that replaces it. The color is treated as
+/// a gradient with a single color, so a transition between a color and a gradient is
+/// smooth.
+pub(crate) fn interpolate_gradient_fill(
+    (start_color, start_gradient): (RGBAColor, &Option<Gradient>),
+    (end_color, end_gradient): (RGBAColor, &Option<Gradient>),
+    state: InterpolationState,
+) -> Option<Gradient> {
+    if f64::from(state) >= 1.0 {
+        return end_gradient.clone();
+    }
+    match (start_gradient, end_gradient) {
+        (None, None) => None,
+        (Some(start), Some(end)) => Some(ContinuousValue::interpolate(start, end, state)),
+        (None, Some(end)) => Some(ContinuousValue::interpolate(
+            &Gradient::solid(end.kind, start_color),
+            end,
+            state,
+        )),
+        (Some(start), None) => Some(ContinuousValue::interpolate(
+            start,
+            &Gradient::solid(start.kind, end_color),
+            state,
+        )),
+    }
+}
+
+impl ContinuousValue for GradientKind {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        match (start, end) {
+            (
+                GradientKind::Linear { angle_degrees },
+                GradientKind::Linear {
+                    angle_degrees: angle_degrees_end,
+                },
+            ) => GradientKind::Linear {
+                angle_degrees: ContinuousValue::interpolate(
+                    angle_degrees,
+                    angle_degrees_end,
+                    state,
+                ),
+            },
+            (
+                GradientKind::Radial { center_x, center_y },
+                GradientKind::Radial {
+                    center_x: center_x_end,
+                    center_y: center_y_end,
+                },
+            ) => GradientKind::Radial {
+                center_x: ContinuousValue::interpolate(center_x, center_x_end, state),
+                center_y: ContinuousValue::interpolate(center_y, center_y_end, state),
+            },
+            (
+                GradientKind::Conic {
+                    angle_degrees,
+                    center_x,
+                    center_y,
+                },
+                GradientKind::Conic {
+                    angle_degrees: angle_degrees_end,
+                    center_x: center_x_end,
+                    center_y: center_y_end,
+                },
+            ) => GradientKind::Conic {
+                angle_degrees: ContinuousValue::interpolate(
+                    angle_degrees,
+                    angle_degrees_end,
+                    state,
+                ),
+                center_x: ContinuousValue::interpolate(center_x, center_x_end, state),
+                center_y: ContinuousValue::interpolate(center_y, center_y_end, state),
+            },
+            (_, end) => *end,
+        }
+    }
+}
+
+impl ContinuousValue for GradientStop {
+    fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
+        Self {
+            offset: ContinuousValue::interpolate(&start.offset, &end.offset, state),
+            color: ContinuousValue::interpolate(&start.color, &end.color, state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scene::{Gradient, GradientKind, GradientStop, RGBAColor};
+
+    use super::{interpolate_gradient_fill, ContinuousValue, InterpolationState};
+
+    const RED: RGBAColor = RGBAColor(255, 0, 0, 255);
+    const GREEN: RGBAColor = RGBAColor(0, 255, 0, 255);
+    const BLUE: RGBAColor = RGBAColor(0, 0, 255, 255);
+
+    const LINEAR: GradientKind = GradientKind::Linear { angle_degrees: 0.0 };
+    const RADIAL: GradientKind = GradientKind::Radial {
+        center_x: 0.5,
+        center_y: 0.5,
+    };
+
+    fn gradient(kind: GradientKind, stops: &[(f32, RGBAColor)]) -> Gradient {
+        Gradient {
+            kind,
+            stops: stops
+                .iter()
+                .map(|(offset, color)| GradientStop {
+                    offset: *offset,
+                    color: *color,
+                })
+                .collect(),
+        }
+    }
+
+    fn interpolate(start: &Gradient, end: &Gradient, state: f64) -> Gradient {
+        ContinuousValue::interpolate(start, end, InterpolationState(state))
+    }
+
+    #[test]
+    fn different_number_of_stops_is_resampled() {
+        let start = gradient(LINEAR, &[(0.0, RED), (1.0, BLUE)]);
+        let end = gradient(LINEAR, &[(0.0, RED), (0.5, GREEN), (1.0, BLUE)]);
+
+        assert_eq!(
+            interpolate(&start, &end, 0.0),
+            gradient(
+                LINEAR,
+                &[(0.0, RED), (0.5, RGBAColor(128, 0, 128, 255)), (1.0, BLUE)]
+            )
+        );
+        assert_eq!(
+            interpolate(&start, &end, 0.5),
+            gradient(
+                LINEAR,
+                &[(0.0, RED), (0.5, RGBAColor(64, 128, 64, 255)), (1.0, BLUE)]
+            )
+        );
+        assert_eq!(interpolate(&start, &end, 1.0), end);
+    }
+
+    #[test]
+    fn resampled_stops_do_not_exceed_the_limit() {
+        let offsets = |count: usize, shift: f32| -> Vec<(f32, RGBAColor)> {
+            (0..count)
+                .map(|index| (index as f32 / count as f32 + shift, RED))
+                .collect()
+        };
+        let start = gradient(LINEAR, &offsets(Gradient::MAX_STOPS, 0.01));
+        let end = gradient(LINEAR, &offsets(Gradient::MAX_STOPS - 1, 0.0));
+
+        let stops = interpolate(&start, &end, 0.5).stops;
+        assert_eq!(stops.len(), Gradient::MAX_STOPS);
+        assert_eq!(stops.first().unwrap().offset, 0.0);
+        assert_eq!(stops.last().unwrap().offset, 1.0);
+    }
+
+    #[test]
+    fn different_kinds_blend_through_a_single_color() {
+        let start = gradient(LINEAR, &[(0.0, RED), (1.0, RED)]);
+        let end = gradient(RADIAL, &[(0.0, BLUE), (1.0, BLUE)]);
+        let middle = RGBAColor(128, 0, 128, 255);
+
+        let quarter = interpolate(&start, &end, 0.25);
+        assert_eq!(quarter.kind, LINEAR);
+        assert_eq!(quarter.stops[0].color, RGBAColor(192, 0, 64, 255));
+
+        assert_eq!(
+            interpolate(&start, &end, 0.5),
+            gradient(RADIAL, &[(0.0, middle), (1.0, middle)])
+        );
+        assert_eq!(interpolate(&start, &end, 0.75).kind, RADIAL);
+        assert_eq!(interpolate(&start, &end, 1.0), end);
+    }
+
+    #[test]
+    fn color_to_gradient() {
+        let end = Some(gradient(LINEAR, &[(0.0, RED), (1.0, BLUE)]));
+        let fill = |state| {
+            interpolate_gradient_fill((GREEN, &None), (GREEN, &end), InterpolationState(state))
+        };
+
+        assert_eq!(
+            fill(0.0),
+            Some(gradient(LINEAR, &[(0.0, GREEN), (1.0, GREEN)]))
+        );
+        assert_eq!(
+            fill(0.5),
+            Some(gradient(
+                LINEAR,
+                &[
+                    (0.0, RGBAColor(128, 128, 0, 255)),
+                    (1.0, RGBAColor(0, 128, 128, 255))
+                ]
+            ))
+        );
+        assert_eq!(fill(1.0), end);
+        assert_eq!(
+            interpolate_gradient_fill((RED, &end), (BLUE, &None), InterpolationState(1.0)),
+            None
+        );
+    }
 }
@@ -2,10 +2,10 @@ use std::collections::{HashMap, HashSet};
 
 use crate::{OutputId, RendererId};
 
-use super::{Component, ComponentId, OutputScene, SceneError};
+use super::{Component, ComponentId, GridPlacement, MaskSource, OutputScene, SceneError};
 
 impl Component {
-    fn component_id(&self) -> Option<&ComponentId> {
+    pub(super) fn component_id(&self) -> Option<&ComponentId> {
         match self {
             Component::InputStream(input) => input.id.as_ref(),
             Component::Shader(shader) => shader.id.as_ref(),
@@ -15,10 +15,17 @@ impl Component {
             Component::View(view) => view.id.as_ref(),
             Component::Tiles(tiles) => tiles.id.as_ref(),
             Component::Rescaler(rescaler) => rescaler.id.as_ref(),
+            Component::Grid(grid) => grid.id.as_ref(),
+            Component::Mask(mask) => mask.id.as_ref(),
+            Component::ChromaKey(chroma_key) => chroma_key.id.as_ref(),
+            Component::ColorCorrection(color_correction) => color_correction.id.as_ref(),
+            Component::Ticker(ticker) => ticker.id.as_ref(),
+            Component::Subtitles(subtitles) => subtitles.id.as_ref(),
+            Component::AudioMeter(audio_meter) => audio_meter.id.as_ref(),
         }
     }
 
-    fn children(&self) -> Vec<&Component> {
+    pub(super) fn children(&self) -> Vec<&Component> {
         match self {
             Component::InputStream(_input) => vec![],
             Component::Shader(shader) => shader.children.iter().collect(),
@@ -28,6 +35,24 @@ impl Component {
             Component::View(view) => view.children.iter().collect(),
             Component::Tiles(tiles) => tiles.children.iter().collect(),
             Component::Rescaler(rescaler) => vec![rescaler.child.as_ref()],
+            Component::Grid(grid) => grid.children.iter().collect(),
+            Component::Mask(mask) => match &mask.source {
+                MaskSource::Component(source) => vec![mask.child.as_ref(), source.as_ref()],
+                MaskSource::Path(_) => vec![mask.child.as_ref()],
+            },
+            Component::ChromaKey(chroma_key) => vec![chroma_key.child.as_ref()],
+            Component::ColorCorrection(color_correction) => vec![color_correction.child.as_ref()],
+            Component::Ticker(_ticker) => vec![],
+            Component::Subtitles(_subtitles) => vec![],
+            Component::AudioMeter(_audio_meter) => vec![],
+        }
+    }
+
+    fn grid_placement(&self) -> Option<GridPlacement> {
+        match self {
+            Component::View(view) => Some(view.grid_placement),
+            Component::Rescaler(rescaler) => Some(rescaler.grid_placement),
+            _ => None,
         }
     }
 }
@@ -42,6 +67,7 @@ pub(super) fn validate_scene_update(
 
     validate_component_ids_uniqueness(&updated_outputs)?;
     validate_web_renderer_ids_uniqueness(&updated_outputs)?;
+    validate_grid_placement(&updated_outputs)?;
     Ok(())
 }
 
@@ -99,3 +125,27 @@ fn validate_web_renderer_ids_uniqueness(outputs: &[&OutputScene]) -> Result<(),
         .iter()
         .try_for_each(|output| visit(&output.scene_root, &mut web_renderer_ids))
 }
+
+/// `grid_placement` is only used by a parent `Grid`, so it can't be defined anywhere else.
+fn validate_grid_placement(outputs: &[&OutputScene]) -> Result<(), SceneError> {
+    fn visit(component: &Component, is_grid_child: bool) -> Result<(), SceneError> {
+        let has_placement = component
+            .grid_placement()
+            .is_some_and(|placement| placement != GridPlacement::default());
+        if has_placement && !is_grid_child {
+            return Err(SceneError::GridPlacementOutsideOfGrid(
+                component.component_id().cloned(),
+            ));
+        }
+
+        let is_grid = matches!(component, Component::Grid(_));
+        component
+            .children()
+            .into_iter()
+            .try_for_each(|c| visit(c, is_grid))
+    }
+
+    outputs
+        .iter()
+        .try_for_each(|output| visit(&output.scene_root, false))
+}
@@ -3,12 +3,14 @@ use std::time::Duration;
 use crate::{scene::ViewChildrenDirection, transformations::layout::NestedLayout};
 
 use super::{
+    animation::KeyframeAnimationState,
     components::ViewComponent,
     layout::StatefulLayoutComponent,
     scene_state::BuildStateTreeCtx,
-    transition::{TransitionOptions, TransitionState},
+    transition::{velocity_scale, EnterExitState, TransitionOptions, TransitionState},
     types::interpolation::ContinuousValue,
-    BorderRadius, BoxShadow, Component, ComponentId, IntermediateNode, Overflow, Position,
+    AlignItems, BlendMode, BorderRadius, BoxShadow, Component, ComponentId, EnterExitTransition,
+    Gradient, GridPlacement, IntermediateNode, JustifyContent, Overflow, Padding, Position,
     RGBAColor, SceneError, Size, StatefulComponent,
 };
 
@@ -20,6 +22,9 @@ pub(super) struct StatefulViewComponent {
     start: Option<ViewComponentParam>,
     end: ViewComponentParam,
     transition: Option<TransitionState>,
+    enter_exit: Option<EnterExitState>,
+    exit_transition: Option<EnterExitTransition>,
+    animations: Vec<KeyframeAnimationState>,
     children: Vec<StatefulComponent>,
 }
 
@@ -32,20 +37,93 @@ struct ViewComponentParam {
     overflow: Overflow,
 
     background_color: RGBAColor,
+    background_gradient: Option<Gradient>,
     border_radius: BorderRadius,
     border_width: f32,
     border_color: RGBAColor,
+    border_gradient: Option<Gradient>,
 
     box_shadow: Vec<BoxShadow>,
+    opacity: f32,
+    blend_mode: BlendMode,
+    blur_radius: f32,
+    backdrop_blur: f32,
+
+    padding: Padding,
+    gap: f32,
+    justify_content: JustifyContent,
+    align_items: AlignItems,
+    /// Alignments the children are moving away from during a transition. Offsets of
+    /// the children are blended between them and the current alignment.
+    previous_alignments: Vec<PreviousAlignment>,
+
+    flex_grow: f32,
+    flex_shrink: f32,
+
+    grid_placement: GridPlacement,
+    pinned: bool,
+}
+
+/// Defaults of the optional fields in the API, tests only override what they need.
+#[cfg(test)]
+impl Default for ViewComponentParam {
+    fn default() -> Self {
+        Self {
+            id: None,
+            direction: ViewChildrenDirection::Row,
+            position: Position::Static {
+                width: None,
+                height: None,
+            },
+            overflow: Overflow::Hidden,
+            background_color: RGBAColor(0, 0, 0, 0),
+            background_gradient: None,
+            border_radius: BorderRadius::ZERO,
+            border_width: 0.0,
+            border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
+            box_shadow: vec![],
+            opacity: 1.0,
+            blend_mode: BlendMode::Normal,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+            padding: Padding::ZERO,
+            gap: 0.0,
+            justify_content: JustifyContent::Start,
+            align_items: AlignItems::Stretch,
+            previous_alignments: vec![],
+            flex_grow: 0.0,
+            flex_shrink: 0.0,
+            grid_placement: GridPlacement::default(),
+            pinned: false,
+        }
+    }
+}
+
+/// Alignment of children that a view is transitioning away from. `weight` is a share
+/// of the offsets of the children that is calculated with this alignment.
+#[derive(Debug, Clone, Copy)]
+struct PreviousAlignment {
+    justify_content: JustifyContent,
+    align_items: AlignItems,
+    weight: f32,
 }
 
 impl StatefulViewComponent {
     fn view(&self, pts: Duration) -> ViewComponentParam {
-        let (Some(transition), Some(start)) = (&self.transition, &self.start) else {
-            return self.end.clone();
+        let mut view = match (&self.transition, &self.start) {
+            (Some(transition), Some(start)) => {
+                let interpolation_progress = transition.state(pts);
+                ContinuousValue::interpolate(start, &self.end, interpolation_progress)
+            }
+            _ => self.end.clone(),
         };
-        let interpolation_progress = transition.state(pts);
-        ContinuousValue::interpolate(start, &self.end, interpolation_progress)
+        for animation in self.animations.iter() {
+            if let Some(value) = animation.value(pts) {
+                view.apply_animated_value(animation.property(), value);
+            }
+        }
+        view
     }
 
     pub(super) fn children(&self) -> Vec<&StatefulComponent> {
@@ -56,10 +134,26 @@ impl StatefulViewComponent {
         self.children.iter_mut().collect()
     }
 
-    /// External position of a component (includes border)
+    /// External position of a component (includes border and padding)
     pub(super) fn position(&self, pts: Duration) -> Position {
         let view = self.view(pts);
-        view.position.with_border(view.border_width)
+        view.position
+            .with_padding(view.padding)
+            .with_border(view.border_width)
+    }
+
+    /// Flex grow and flex shrink factors used if the component is a static child of a View.
+    pub(super) fn flex_factors(&self, pts: Duration) -> (f32, f32) {
+        let view = self.view(pts);
+        (view.flex_grow, view.flex_shrink)
+    }
+
+    pub(super) fn grid_placement(&self) -> GridPlacement {
+        self.end.grid_placement
+    }
+
+    pub(super) fn pinned(&self) -> bool {
+        self.end.pinned
     }
 
     pub(super) fn component_id(&self) -> Option<&ComponentId> {
@@ -87,7 +181,30 @@ impl StatefulViewComponent {
 
     pub(super) fn layout(&mut self, size: Size, pts: Duration) -> NestedLayout {
         let view = self.view(pts);
-        view.layout(size, &mut self.children, pts)
+        let removed = StatefulLayoutComponent::take_removed_children(&mut self.children, pts);
+        let mut layout = view.layout(size, &mut self.children, pts);
+        StatefulLayoutComponent::restore_removed_children(&mut self.children, removed, &mut layout);
+        match &self.enter_exit {
+            Some(enter_exit) => enter_exit.apply(layout, pts),
+            None => layout,
+        }
+    }
+
+    pub(super) fn is_exit_finished(&self, pts: Duration) -> bool {
+        self.enter_exit
+            .as_ref()
+            .is_some_and(|enter_exit| enter_exit.is_exit_finished(pts))
+    }
+
+    /// Returns copy of this component that should be kept in the scene after it was
+    /// removed, or `None` if it should be removed immediately.
+    pub(super) fn exiting(&self, last_pts: Duration) -> Option<Self> {
+        let enter_exit =
+            EnterExitState::on_remove(self.exit_transition, self.enter_exit.as_ref(), last_pts)?;
+        Some(Self {
+            enter_exit: Some(enter_exit),
+            ..self.clone()
+        })
     }
 }
 
@@ -107,36 +224,76 @@ impl ViewComponent {
                 _ => None,
             });
 
+        let end = ViewComponentParam {
+            id: self.id,
+            direction: self.direction,
+            position: self.position,
+            background_color: self.background_color,
+            background_gradient: self.background_gradient,
+            overflow: self.overflow,
+            border_radius: self.border_radius,
+            border_width: self.border_width,
+            border_color: self.border_color,
+            border_gradient: self.border_gradient,
+            box_shadow: self.box_shadow,
+            opacity: self.opacity,
+            blend_mode: self.blend_mode,
+            blur_radius: self.blur_radius,
+            backdrop_blur: self.backdrop_blur,
+            padding: self.padding,
+            gap: self.gap,
+            justify_content: self.justify_content,
+            align_items: self.align_items,
+            previous_alignments: vec![],
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            grid_placement: self.grid_placement,
+            pinned: self.pinned,
+        };
         // TODO: to handle cases like transition from top to bottom this view needs
         // to be further processed to use the same type of coordinates as end
         let start = previous_state.map(|state| state.view(ctx.last_render_pts));
+        let velocity_scale = previous_state
+            .zip(start.as_ref())
+            .and_then(|(state, start)| {
+                velocity_scale((state.start.as_ref()?, &state.end), (start, &end))
+            });
         let transition = TransitionState::new(
             self.transition.map(|transition| TransitionOptions {
                 duration: transition.duration,
                 interpolation_kind: transition.interpolation_kind,
             }),
             previous_state.and_then(|s| s.transition.clone()),
+            velocity_scale,
+            ctx.last_render_pts,
+        );
+        let enter_exit = EnterExitState::on_scene_update(
+            self.enter_transition,
+            previous_state.map(|s| s.enter_exit.as_ref()),
             ctx.last_render_pts,
         );
+        let mut children = self
+            .children
+            .into_iter()
+            .map(|c| Component::stateful_component(c, ctx))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(previous_state) = previous_state {
+            ctx.retain_exiting_children(&previous_state.children, &mut children);
+        }
         let view = StatefulViewComponent {
             start,
-            end: ViewComponentParam {
-                id: self.id,
-                direction: self.direction,
-                position: self.position,
-                background_color: self.background_color,
-                overflow: self.overflow,
-                border_radius: self.border_radius,
-                border_width: self.border_width,
-                border_color: self.border_color,
-                box_shadow: self.box_shadow,
-            },
+            end,
             transition,
-            children: self
-                .children
-                .into_iter()
-                .map(|c| Component::stateful_component(c, ctx))
-                .collect::<Result<_, _>>()?,
+            enter_exit,
+            exit_transition: self.exit_transition,
+            animations: KeyframeAnimationState::new_list(
+                self.animations,
+                previous_state
+                    .map(|state| state.animations.as_slice())
+                    .unwrap_or_default(),
+                ctx.last_render_pts,
+            ),
+            children,
         };
         Ok(StatefulComponent::Layout(StatefulLayoutComponent::View(
             view,
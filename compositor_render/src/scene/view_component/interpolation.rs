@@ -1,6 +1,10 @@
-use crate::scene::types::interpolation::{ContinuousValue, InterpolationState};
+use crate::scene::{
+    animation::animate_position,
+    types::interpolation::{interpolate_gradient_fill, ContinuousValue, InterpolationState},
+    AnimatedProperty, BorderRadius, KeyframeValue,
+};
 
-use super::ViewComponentParam;
+use super::{PreviousAlignment, ViewComponentParam};
 
 impl ContinuousValue for ViewComponentParam {
     fn interpolate(start: &Self, end: &Self, state: InterpolationState) -> Self {
@@ -9,6 +13,11 @@ impl ContinuousValue for ViewComponentParam {
             direction: end.direction.clone(),
             position: ContinuousValue::interpolate(&start.position, &end.position, state),
             background_color: end.background_color,
+            background_gradient: interpolate_gradient_fill(
+                (start.background_color, &start.background_gradient),
+                (end.background_color, &end.background_gradient),
+                state,
+            ),
             overflow: end.overflow,
             border_radius: ContinuousValue::interpolate(
                 &start.border_radius,
@@ -21,7 +30,123 @@ impl ContinuousValue for ViewComponentParam {
                 state,
             ),
             border_color: end.border_color,
+            border_gradient: interpolate_gradient_fill(
+                (start.border_color, &start.border_gradient),
+                (end.border_color, &end.border_gradient),
+                state,
+            ),
             box_shadow: ContinuousValue::interpolate(&start.box_shadow, &end.box_shadow, state),
+            opacity: ContinuousValue::interpolate(&start.opacity, &end.opacity, state),
+            blend_mode: end.blend_mode,
+            blur_radius: ContinuousValue::interpolate(&start.blur_radius, &end.blur_radius, state),
+            backdrop_blur: ContinuousValue::interpolate(
+                &start.backdrop_blur,
+                &end.backdrop_blur,
+                state,
+            ),
+            padding: ContinuousValue::interpolate(&start.padding, &end.padding, state),
+            gap: ContinuousValue::interpolate(&start.gap, &end.gap, state),
+            justify_content: end.justify_content,
+            align_items: end.align_items,
+            previous_alignments: interpolate_alignments(start, end, state),
+            flex_grow: ContinuousValue::interpolate(&start.flex_grow, &end.flex_grow, state),
+            flex_shrink: ContinuousValue::interpolate(&start.flex_shrink, &end.flex_shrink, state),
+            grid_placement: end.grid_placement,
+            pinned: end.pinned,
+        }
+    }
+
+    fn push_coordinates(&self, coordinates: &mut Vec<f64>) {
+        self.position.push_coordinates(coordinates);
+        self.border_radius.push_coordinates(coordinates);
+        self.border_width.push_coordinates(coordinates);
+        self.box_shadow.push_coordinates(coordinates);
+        self.opacity.push_coordinates(coordinates);
+        self.blur_radius.push_coordinates(coordinates);
+        self.backdrop_blur.push_coordinates(coordinates);
+        self.padding.push_coordinates(coordinates);
+        self.gap.push_coordinates(coordinates);
+        self.flex_grow.push_coordinates(coordinates);
+        self.flex_shrink.push_coordinates(coordinates);
+    }
+}
+
+/// Alignments of the `start` view fade out during a transition, so the children move
+/// smoothly when `justify_content` or `align_items` changes.
+fn interpolate_alignments(
+    start: &ViewComponentParam,
+    end: &ViewComponentParam,
+    state: InterpolationState,
+) -> Vec<PreviousAlignment> {
+    // Spring and bounce interpolations can overshoot, so the weight might be negative.
+    let start_weight = 1.0 - state.0 as f32;
+    if start_weight == 0.0 {
+        return vec![];
+    }
+    let start_alignment = PreviousAlignment {
+        justify_content: start.justify_content,
+        align_items: start.align_items,
+        weight: 1.0
+            - start
+                .previous_alignments
+                .iter()
+                .map(|alignment| alignment.weight)
+                .sum::<f32>(),
+    };
+    let mut alignments: Vec<PreviousAlignment> = vec![];
+    for alignment in start.previous_alignments.iter().chain([&start_alignment]) {
+        let key = (alignment.justify_content, alignment.align_items);
+        // The rest of the weight belongs to the end alignment.
+        if key == (end.justify_content, end.align_items) {
+            continue;
+        }
+        let weight = alignment.weight * start_weight;
+        match alignments
+            .iter_mut()
+            .find(|existing| (existing.justify_content, existing.align_items) == key)
+        {
+            Some(existing) => existing.weight += weight,
+            None => alignments.push(PreviousAlignment {
+                weight,
+                ..*alignment
+            }),
+        }
+    }
+    alignments
+}
+
+impl ViewComponentParam {
+    pub(super) fn apply_animated_value(
+        &mut self,
+        property: AnimatedProperty,
+        value: KeyframeValue,
+    ) {
+        match (property, value) {
+            (AnimatedProperty::Opacity, KeyframeValue::Number(opacity)) => {
+                self.opacity = opacity.clamp(0.0, 1.0)
+            }
+            (AnimatedProperty::BorderWidth, KeyframeValue::Number(width)) => {
+                self.border_width = f32::max(width, 0.0)
+            }
+            (AnimatedProperty::BorderRadius, KeyframeValue::Number(radius)) => {
+                self.border_radius = BorderRadius::new_with_radius(radius)
+            }
+            (AnimatedProperty::BorderColor, KeyframeValue::Color(color)) => {
+                self.border_color = color
+            }
+            (AnimatedProperty::BlurRadius, KeyframeValue::Number(radius)) => {
+                self.blur_radius = f32::max(radius, 0.0)
+            }
+            (AnimatedProperty::BackdropBlur, KeyframeValue::Number(radius)) => {
+                self.backdrop_blur = f32::max(radius, 0.0)
+            }
+            (AnimatedProperty::BackgroundColor, KeyframeValue::Color(color)) => {
+                self.background_color = color
+            }
+            (property, KeyframeValue::Number(value)) => {
+                animate_position(&mut self.position, property, value)
+            }
+            (_, KeyframeValue::Color(_)) => (),
         }
     }
 }
@@ -2,8 +2,8 @@ use std::time::Duration;
 
 use crate::{
     scene::{
-        layout::StatefulLayoutComponent, BorderRadius, Overflow, Position, RGBAColor, Size,
-        StatefulComponent, ViewChildrenDirection,
+        layout::StatefulLayoutComponent, AlignItems, BlendMode, BorderRadius, JustifyContent,
+        Overflow, Position, RGBAColor, Size, StatefulComponent, ViewChildrenDirection,
     },
     transformations::layout::{LayoutContent, Mask, NestedLayout},
 };
@@ -14,17 +14,18 @@ use super::ViewComponentParam;
 struct StaticChildLayoutOpts {
     width: Option<f32>,
     height: Option<f32>,
-    /// Offset inside parent component (position where next static child should start).
-    static_offset: f32,
-    /// Define size(width or height) of a static component if it's not
-    /// already defined explicitly.
+    /// Offset along the main axis inside parent component (position where this child starts).
+    /// For direction=row it is a left offset, for direction=column a top offset.
+    main_offset: f32,
+    /// Size of a component along the main axis (it already includes flex grow/shrink).
     /// For direction=row defines width of a static component
     /// For direction=column defines height of a static component
-    static_child_size: f32,
+    main_size: f32,
     parent_size: Size,
-    /// border width before rescaling, it is used to calculate top/left offset correctly
-    /// when `overflow: fit` is set
-    parent_border_width: f32,
+    /// Offset of the content area (border and padding) along the cross axis before
+    /// rescaling, it is used to calculate top/left offset correctly when `overflow: fit` is set
+    parent_cross_offset: f32,
+    scale: f32,
 }
 
 impl ViewComponentParam {
@@ -38,7 +39,17 @@ impl ViewComponentParam {
             width: f32::max(size.width - 2.0 * self.border_width, 0.0),
             height: f32::max(size.height - 2.0 * self.border_width, 0.0),
         };
-        let static_child_size = self.static_child_size(content_size, children, pts);
+        let children_area_size = Size {
+            width: f32::max(
+                content_size.width - self.padding.left - self.padding.right,
+                0.0,
+            ),
+            height: f32::max(
+                content_size.height - self.padding.top - self.padding.bottom,
+                0.0,
+            ),
+        };
+        let main_sizes = self.static_children_main_sizes(children_area_size, children, pts);
         let (scale, crop, mask) = match self.overflow {
             Overflow::Visible => (1.0, None, None),
             Overflow::Hidden => (
@@ -53,7 +64,7 @@ impl ViewComponentParam {
                 }),
             ),
             Overflow::Fit => (
-                self.scale_factor_for_overflow_fit(content_size, children, pts),
+                self.scale_factor_for_overflow_fit(children_area_size, &main_sizes, children, pts),
                 None,
                 Some(Mask {
                     radius: self.border_radius - self.border_width,
@@ -64,11 +75,14 @@ impl ViewComponentParam {
                 }),
             ),
         };
+        let main_offsets =
+            self.static_children_main_offsets(children_area_size, &main_sizes, scale);
+        let parent_cross_offset = match self.direction {
+            ViewChildrenDirection::Row => (self.border_width + self.padding.top) / scale,
+            ViewChildrenDirection::Column => (self.border_width + self.padding.left) / scale,
+        };
 
-        // offset along x or y direction (depends on self.direction) where next
-        // child component should be placed
-        let mut static_offset = self.border_width / scale;
-
+        let mut static_children = main_sizes.into_iter().zip(main_offsets);
         let children: Vec<_> = children
             .iter_mut()
             .map(|child| {
@@ -81,21 +95,20 @@ impl ViewComponentParam {
                 };
                 match position {
                     Position::Static { width, height } => {
-                        let (layout, updated_static_offset) = self.layout_static_child(
+                        let (main_size, main_offset) = static_children.next().unwrap_or_default();
+                        self.layout_static_child(
                             child,
                             StaticChildLayoutOpts {
                                 width,
                                 height,
-                                static_offset,
-                                static_child_size,
-                                parent_size: content_size,
-                                parent_border_width: self.border_width / scale,
+                                main_offset,
+                                main_size,
+                                parent_size: children_area_size,
+                                parent_cross_offset,
+                                scale,
                             },
                             pts,
-                        );
-
-                        static_offset = updated_static_offset;
-                        layout
+                        )
                     }
                     Position::Absolute(position) => {
                         StatefulLayoutComponent::layout_absolute_position_child(
@@ -115,13 +128,21 @@ impl ViewComponentParam {
             scale_y: scale,
             crop,
             mask,
-            content: LayoutContent::Color(self.background_color),
+            content: match &self.background_gradient {
+                Some(gradient) => LayoutContent::Gradient(gradient.clone()),
+                None => LayoutContent::Color(self.background_color),
+            },
             child_nodes_count: children.iter().map(|l| l.child_nodes_count).sum(),
             children,
             border_width: self.border_width,
             border_color: self.border_color,
+            border_gradient: self.border_gradient.clone(),
             border_radius: self.border_radius,
             box_shadow: self.box_shadow.clone(),
+            blend_mode: self.blend_mode,
+            opacity: self.opacity,
+            blur_radius: self.blur_radius,
+            backdrop_blur: self.backdrop_blur,
         }
     }
 
@@ -130,27 +151,24 @@ impl ViewComponentParam {
         child: &mut StatefulComponent,
         opts: StaticChildLayoutOpts,
         pts: Duration,
-    ) -> (NestedLayout, f32) {
-        let mut static_offset = opts.static_offset;
+    ) -> NestedLayout {
         let (top, left, width, height) = match self.direction {
             ViewChildrenDirection::Row => {
-                let width = opts.width.unwrap_or(opts.static_child_size);
+                let width = opts.main_size;
                 let height = opts.height.unwrap_or(opts.parent_size.height);
-                let top = opts.parent_border_width;
-                let left = static_offset;
-                static_offset += width;
+                let top = self.cross_offset(opts.height, opts.parent_size.height, &opts);
+                let left = opts.main_offset;
                 (top, left, width, height)
             }
             ViewChildrenDirection::Column => {
-                let height = opts.height.unwrap_or(opts.static_child_size);
+                let height = opts.main_size;
                 let width = opts.width.unwrap_or(opts.parent_size.width);
-                let top = static_offset;
-                let left = opts.parent_border_width;
-                static_offset += height;
+                let top = opts.main_offset;
+                let left = self.cross_offset(opts.width, opts.parent_size.width, &opts);
                 (top, left, width, height)
             }
         };
-        let layout = match child {
+        match child {
             StatefulComponent::Layout(layout_component) => {
                 let children_layouts = layout_component.layout(Size { width, height }, pts);
                 NestedLayout {
@@ -168,8 +186,13 @@ impl ViewComponentParam {
                     children: vec![children_layouts],
                     border_width: 0.0,
                     border_color: RGBAColor(0, 0, 0, 0),
+                    border_gradient: None,
                     border_radius: BorderRadius::ZERO,
                     box_shadow: vec![],
+                    blend_mode: BlendMode::Normal,
+                    opacity: 1.0,
+                    blur_radius: 0.0,
+                    backdrop_blur: 0.0,
                 }
             }
             _ => NestedLayout {
@@ -187,50 +210,198 @@ impl ViewComponentParam {
                 children: vec![],
                 border_width: 0.0,
                 border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
                 border_radius: BorderRadius::ZERO,
                 box_shadow: vec![],
+                blend_mode: BlendMode::Normal,
+                opacity: 1.0,
+                blur_radius: 0.0,
+                backdrop_blur: 0.0,
             },
+        }
+    }
+
+    /// Offset of a static child along the cross axis. Children without an explicit
+    /// size on that axis fill the entire parent, so they are not aligned.
+    fn cross_offset(
+        &self,
+        cross_size: Option<f32>,
+        parent_cross_size: f32,
+        opts: &StaticChildLayoutOpts,
+    ) -> f32 {
+        let free_space = parent_cross_size / opts.scale - cross_size.unwrap_or(0.0);
+        let aligned_offset = |align_items| match (align_items, cross_size) {
+            (_, None) | (AlignItems::Start | AlignItems::Stretch, _) => 0.0,
+            (AlignItems::End, _) => free_space,
+            (AlignItems::Center, _) => free_space / 2.0,
         };
-        (layout, static_offset)
+        let offset = aligned_offset(self.align_items);
+        let offset = self
+            .previous_alignments
+            .iter()
+            .fold(offset, |blended, previous| {
+                blended + (aligned_offset(previous.align_items) - offset) * previous.weight
+            });
+        opts.parent_cross_offset + offset
     }
 
-    /// Calculate a size of a static child component that does not have it explicitly defined.
-    /// Returned value represents width if the direction is `ViewChildrenDirection::Row` or
-    /// height if the direction is `ViewChildrenDirection::Column`.
+    /// Calculate sizes of static children along the main axis. Returned values represent
+    /// widths if the direction is `ViewChildrenDirection::Row` or heights if the direction
+    /// is `ViewChildrenDirection::Column`.
     ///
-    /// size represents dimensions of content (without a border).
-    fn static_child_size(&self, size: Size, children: &[StatefulComponent], pts: Duration) -> f32 {
+    /// Children without explicitly defined size share the remaining space equally. If
+    /// there is still some free space left (or children overflow), it is distributed
+    /// according to `flex_grow` (or `flex_shrink`) of the children.
+    ///
+    /// size represents dimensions of content (without a border and padding).
+    fn static_children_main_sizes(
+        &self,
+        size: Size,
+        children: &[StatefulComponent],
+        pts: Duration,
+    ) -> Vec<f32> {
         let max_size = match self.direction {
-            super::ViewChildrenDirection::Row => size.width,
-            super::ViewChildrenDirection::Column => size.height,
+            ViewChildrenDirection::Row => size.width,
+            ViewChildrenDirection::Column => size.height,
         };
-
-        let children_with_unknown_size_count = Self::static_children_iter(children, pts)
-            .filter(|child| match self.direction {
-                ViewChildrenDirection::Row => child.width(pts).is_none(),
-                ViewChildrenDirection::Column => child.height(pts).is_none(),
+        let static_children: Vec<_> = Self::static_children_iter(children, pts)
+            .map(|child| {
+                let size = match self.direction {
+                    ViewChildrenDirection::Row => child.width(pts),
+                    ViewChildrenDirection::Column => child.height(pts),
+                };
+                let (flex_grow, flex_shrink) = match child {
+                    StatefulComponent::Layout(layout) => layout.flex_factors(pts),
+                    _ => (0.0, 0.0),
+                };
+                (size, flex_grow, flex_shrink)
             })
+            .collect();
+
+        let available_size = max_size - self.gaps_size(static_children.len());
+        let children_with_unknown_size_count = static_children
+            .iter()
+            .filter(|(size, _, _)| size.is_none())
             .count();
-        let static_children_sum = self.sum_static_children_sizes(children, pts);
+        let known_sizes_sum: f32 = static_children
+            .iter()
+            .filter_map(|(size, _, _)| *size)
+            .sum();
+        let unknown_size = match children_with_unknown_size_count {
+            0 => 0.0,
+            count => f32::max(0.0, (available_size - known_sizes_sum) / count as f32),
+        };
 
-        if children_with_unknown_size_count == 0 {
-            return 0.0; // if there is no dynamically sized children then this value does not matter
+        let mut sizes: Vec<f32> = static_children
+            .iter()
+            .map(|(size, _, _)| size.unwrap_or(unknown_size))
+            .collect();
+        let free_space = available_size - sizes.iter().sum::<f32>();
+        if free_space > 0.0 {
+            // Similar to CSS, if sum of factors is smaller than 1, only part
+            // of the free space is distributed.
+            let grow_sum = static_children.iter().map(|(_, grow, _)| grow).sum::<f32>();
+            if grow_sum > 0.0 {
+                let grow_sum = f32::max(grow_sum, 1.0);
+                for (size, (_, grow, _)) in sizes.iter_mut().zip(static_children.iter()) {
+                    *size += free_space * grow / grow_sum;
+                }
+            }
+        } else if free_space < 0.0 {
+            // Shrink factors are weighted by the size, so larger children shrink more.
+            let shrink_sum = sizes
+                .iter()
+                .zip(static_children.iter())
+                .map(|(size, (_, _, shrink))| size * shrink)
+                .sum::<f32>();
+            if shrink_sum > 0.0 {
+                for (size, (_, _, shrink)) in sizes.iter_mut().zip(static_children.iter()) {
+                    *size = f32::max(0.0, *size + free_space * *size * shrink / shrink_sum);
+                }
+            }
         }
-        f32::max(
-            0.0,
-            (max_size - static_children_sum) / children_with_unknown_size_count as f32,
-        )
+        sizes
+    }
+
+    /// Calculate offsets of static children along the main axis (in the coordinates of
+    /// children, so before rescaling caused by `overflow: fit`).
+    fn static_children_main_offsets(&self, size: Size, main_sizes: &[f32], scale: f32) -> Vec<f32> {
+        let offsets = self.justified_main_offsets(self.justify_content, size, main_sizes, scale);
+        self.previous_alignments
+            .iter()
+            .fold(offsets.clone(), |mut blended, previous| {
+                let previous_offsets =
+                    self.justified_main_offsets(previous.justify_content, size, main_sizes, scale);
+                for ((blended, offset), previous_offset) in
+                    blended.iter_mut().zip(&offsets).zip(previous_offsets)
+                {
+                    *blended += (previous_offset - offset) * previous.weight;
+                }
+                blended
+            })
+    }
+
+    /// Offsets of static children along the main axis for the `justify_content`.
+    fn justified_main_offsets(
+        &self,
+        justify_content: JustifyContent,
+        size: Size,
+        main_sizes: &[f32],
+        scale: f32,
+    ) -> Vec<f32> {
+        let (padding, max_size) = match self.direction {
+            ViewChildrenDirection::Row => (self.padding.left, size.width),
+            ViewChildrenDirection::Column => (self.padding.top, size.height),
+        };
+        let count = main_sizes.len() as f32;
+        let free_space =
+            max_size / scale - main_sizes.iter().sum::<f32>() - self.gaps_size(main_sizes.len());
+
+        // offset of the first child and space between children
+        let (start, spacing) = match justify_content {
+            JustifyContent::Start => (0.0, self.gap),
+            JustifyContent::End => (free_space, self.gap),
+            JustifyContent::Center => (free_space / 2.0, self.gap),
+            JustifyContent::SpaceBetween if free_space > 0.0 && count > 1.0 => {
+                (0.0, self.gap + free_space / (count - 1.0))
+            }
+            JustifyContent::SpaceBetween => (0.0, self.gap),
+            JustifyContent::SpaceAround if free_space > 0.0 => {
+                (free_space / count / 2.0, self.gap + free_space / count)
+            }
+            JustifyContent::SpaceEvenly if free_space > 0.0 => (
+                free_space / (count + 1.0),
+                self.gap + free_space / (count + 1.0),
+            ),
+            JustifyContent::SpaceAround | JustifyContent::SpaceEvenly => {
+                (free_space / 2.0, self.gap)
+            }
+        };
+
+        let mut offset = (self.border_width + padding) / scale + start;
+        main_sizes
+            .iter()
+            .map(|size| {
+                let child_offset = offset;
+                offset += size + spacing;
+                child_offset
+            })
+            .collect()
+    }
+
+    fn gaps_size(&self, static_children_count: usize) -> f32 {
+        self.gap * static_children_count.saturating_sub(1) as f32
     }
 
     fn scale_factor_for_overflow_fit(
         &self,
         content_size: Size,
+        main_sizes: &[f32],
         children: &[StatefulComponent],
         pts: Duration,
     ) -> f32 {
-        let sum_size = self
-            .sum_static_children_sizes(children, pts)
-            .max(0.000000001); // avoid division by 0
+        let sum_size =
+            (main_sizes.iter().sum::<f32>() + self.gaps_size(main_sizes.len())).max(0.000000001); // avoid division by 0
         let (max_size, max_alternative_size) = match self.direction {
             super::ViewChildrenDirection::Row => (content_size.width, content_size.height),
             super::ViewChildrenDirection::Column => (content_size.height, content_size.width),
@@ -253,17 +424,6 @@ impl ViewComponentParam {
         )
     }
 
-    fn sum_static_children_sizes(&self, children: &[StatefulComponent], pts: Duration) -> f32 {
-        let size_accessor = match self.direction {
-            super::ViewChildrenDirection::Row => StatefulComponent::width,
-            super::ViewChildrenDirection::Column => StatefulComponent::height,
-        };
-
-        Self::static_children_iter(children, pts)
-            .map(|component| size_accessor(component, pts).unwrap_or(0.0))
-            .sum()
-    }
-
     fn static_children_iter(
         children: &[StatefulComponent],
         pts: Duration,
@@ -277,3 +437,267 @@ impl ViewComponentParam {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::{
+        scene::{
+            input_stream_component::StatefulInputStreamComponent,
+            layout::StatefulLayoutComponent,
+            transition::EnterExitState,
+            types::interpolation::{ContinuousValue, InterpolationState},
+            AlignItems, EnterExitEffect, EnterExitTransition, InputStreamComponent,
+            InterpolationKind, JustifyContent, Padding, Position, Size, StatefulComponent,
+            Transition, ViewChildrenDirection,
+        },
+        InputId,
+    };
+
+    use super::{super::StatefulViewComponent, StaticChildLayoutOpts, ViewComponentParam};
+
+    const SIZE: Size = Size {
+        width: 100.0,
+        height: 50.0,
+    };
+
+    fn view(direction: ViewChildrenDirection) -> ViewComponentParam {
+        ViewComponentParam {
+            direction,
+            ..Default::default()
+        }
+    }
+
+    /// Child with a known size, e.g. an input stream.
+    fn input(width: f32, height: f32) -> StatefulComponent {
+        StatefulComponent::InputStream(StatefulInputStreamComponent {
+            component: InputStreamComponent {
+                id: None,
+                input_id: InputId(Arc::from("input")),
+            },
+            size: Size { width, height },
+        })
+    }
+
+    /// View child in a row with an optional width.
+    fn child_view(width: Option<f32>, flex_grow: f32, flex_shrink: f32) -> StatefulComponent {
+        StatefulComponent::Layout(StatefulLayoutComponent::View(StatefulViewComponent {
+            start: None,
+            end: ViewComponentParam {
+                position: Position::Static {
+                    width,
+                    height: None,
+                },
+                flex_grow,
+                flex_shrink,
+                ..view(ViewChildrenDirection::Row)
+            },
+            transition: None,
+            enter_exit: None,
+            exit_transition: None,
+            animations: vec![],
+            children: vec![],
+        }))
+    }
+
+    fn main_sizes(view: &ViewComponentParam, children: &[StatefulComponent]) -> Vec<f32> {
+        view.static_children_main_sizes(SIZE, children, Duration::ZERO)
+    }
+
+    #[test]
+    fn unknown_sizes_share_remaining_space() {
+        let row = view(ViewChildrenDirection::Row);
+        let children = [
+            input(20.0, 10.0),
+            child_view(None, 0.0, 0.0),
+            child_view(None, 0.0, 0.0),
+        ];
+        assert_eq!(main_sizes(&row, &children), vec![20.0, 40.0, 40.0]);
+
+        let column = view(ViewChildrenDirection::Column);
+        assert_eq!(
+            main_sizes(&column, &[input(20.0, 10.0), input(20.0, 10.0)]),
+            vec![10.0, 10.0]
+        );
+    }
+
+    #[test]
+    fn gap_is_subtracted_before_unknown_sizes() {
+        let row = ViewComponentParam {
+            gap: 10.0,
+            ..view(ViewChildrenDirection::Row)
+        };
+        let children = [
+            child_view(None, 0.0, 0.0),
+            child_view(None, 0.0, 0.0),
+            child_view(None, 0.0, 0.0),
+        ];
+        assert_eq!(main_sizes(&row, &children), vec![80.0 / 3.0; 3]);
+    }
+
+    #[test]
+    fn flex_grow_and_shrink() {
+        let row = view(ViewChildrenDirection::Row);
+        let grow = [
+            child_view(Some(20.0), 1.0, 0.0),
+            child_view(Some(20.0), 3.0, 0.0),
+        ];
+        assert_eq!(main_sizes(&row, &grow), vec![35.0, 65.0]);
+
+        // Sum of factors lower than 1 distributes only part of the free space.
+        let partial_grow = [child_view(Some(20.0), 0.5, 0.0)];
+        assert_eq!(main_sizes(&row, &partial_grow), vec![60.0]);
+
+        // Shrink is weighted by size.
+        let shrink = [
+            child_view(Some(150.0), 0.0, 1.0),
+            child_view(Some(50.0), 0.0, 1.0),
+        ];
+        assert_eq!(main_sizes(&row, &shrink), vec![75.0, 25.0]);
+    }
+
+    #[test]
+    fn justify_content_offsets() {
+        fn offsets(justify_content: JustifyContent, gap: f32) -> Vec<f32> {
+            let row = ViewComponentParam {
+                justify_content,
+                gap,
+                padding: Padding {
+                    top: 0.0,
+                    right: 0.0,
+                    bottom: 0.0,
+                    left: 5.0,
+                },
+                ..view(ViewChildrenDirection::Row)
+            };
+            // Children area without the padding.
+            let size = Size {
+                width: 95.0,
+                height: 50.0,
+            };
+            row.static_children_main_offsets(size, &[20.0, 15.0], 1.0)
+        }
+
+        assert_eq!(offsets(JustifyContent::Start, 0.0), vec![5.0, 25.0]);
+        assert_eq!(offsets(JustifyContent::Start, 10.0), vec![5.0, 35.0]);
+        assert_eq!(offsets(JustifyContent::End, 0.0), vec![65.0, 85.0]);
+        assert_eq!(offsets(JustifyContent::Center, 10.0), vec![30.0, 60.0]);
+        assert_eq!(offsets(JustifyContent::SpaceBetween, 0.0), vec![5.0, 85.0]);
+        assert_eq!(offsets(JustifyContent::SpaceAround, 0.0), vec![20.0, 70.0]);
+        assert_eq!(offsets(JustifyContent::SpaceEvenly, 0.0), vec![25.0, 65.0]);
+    }
+
+    #[test]
+    fn overflowing_children_are_not_spaced() {
+        let row = ViewComponentParam {
+            justify_content: JustifyContent::SpaceBetween,
+            ..view(ViewChildrenDirection::Row)
+        };
+        assert_eq!(
+            row.static_children_main_offsets(SIZE, &[80.0, 80.0], 1.0),
+            vec![0.0, 80.0]
+        );
+    }
+
+    #[test]
+    fn align_items_cross_offset() {
+        fn cross_offset(align_items: AlignItems, cross_size: Option<f32>) -> f32 {
+            let row = ViewComponentParam {
+                align_items,
+                ..view(ViewChildrenDirection::Row)
+            };
+            let opts = StaticChildLayoutOpts {
+                width: None,
+                height: cross_size,
+                main_offset: 0.0,
+                main_size: 0.0,
+                parent_size: SIZE,
+                parent_cross_offset: 5.0,
+                scale: 1.0,
+            };
+            row.cross_offset(cross_size, SIZE.height, &opts)
+        }
+
+        assert_eq!(cross_offset(AlignItems::Start, Some(20.0)), 5.0);
+        assert_eq!(cross_offset(AlignItems::End, Some(20.0)), 35.0);
+        assert_eq!(cross_offset(AlignItems::Center, Some(20.0)), 20.0);
+        assert_eq!(cross_offset(AlignItems::Stretch, Some(20.0)), 5.0);
+        // Children without a size fill the parent regardless of alignment.
+        assert_eq!(cross_offset(AlignItems::End, None), 5.0);
+    }
+
+    #[test]
+    fn alignment_change_is_interpolated() {
+        let row = |justify_content, align_items| ViewComponentParam {
+            justify_content,
+            align_items,
+            ..view(ViewChildrenDirection::Row)
+        };
+        let offsets = |view: &ViewComponentParam| {
+            let opts = StaticChildLayoutOpts {
+                width: None,
+                height: Some(20.0),
+                main_offset: 0.0,
+                main_size: 0.0,
+                parent_size: SIZE,
+                parent_cross_offset: 0.0,
+                scale: 1.0,
+            };
+            (
+                view.static_children_main_offsets(SIZE, &[20.0, 20.0], 1.0),
+                view.cross_offset(Some(20.0), SIZE.height, &opts),
+            )
+        };
+        let start = row(JustifyContent::Start, AlignItems::Start);
+        let end = row(JustifyContent::End, AlignItems::End);
+
+        let middle = ContinuousValue::interpolate(&start, &end, InterpolationState(0.5));
+        assert_eq!(offsets(&middle), (vec![30.0, 50.0], 15.0));
+        let finished = ContinuousValue::interpolate(&start, &end, InterpolationState(1.0));
+        assert_eq!(offsets(&finished), offsets(&end));
+
+        // Transition interrupted in the middle starts where the previous one stopped.
+        let center = row(JustifyContent::Center, AlignItems::Center);
+        let interrupted = ContinuousValue::interpolate(&middle, &center, InterpolationState(0.0));
+        assert_eq!(offsets(&interrupted), offsets(&middle));
+        let interrupted = ContinuousValue::interpolate(&middle, &center, InterpolationState(0.5));
+        assert_eq!(offsets(&interrupted), (vec![30.0, 50.0], 15.0));
+    }
+
+    #[test]
+    fn sibling_takes_space_of_child_after_its_exit_finishes() {
+        let exit_transition = EnterExitTransition {
+            effect: EnterExitEffect::Fade,
+            transition: Transition {
+                duration: Duration::from_secs(1),
+                interpolation_kind: InterpolationKind::Linear,
+            },
+        };
+        let mut exiting = child_view(Some(30.0), 0.0, 0.0);
+        if let StatefulComponent::Layout(StatefulLayoutComponent::View(view)) = &mut exiting {
+            view.enter_exit =
+                EnterExitState::on_remove(Some(exit_transition), None, Duration::ZERO);
+        }
+        let mut root = StatefulViewComponent {
+            start: None,
+            end: view(ViewChildrenDirection::Row),
+            transition: None,
+            enter_exit: None,
+            exit_transition: None,
+            animations: vec![],
+            children: vec![exiting, child_view(Some(20.0), 0.0, 0.0)],
+        };
+
+        let layout = root.layout(SIZE, Duration::from_millis(500));
+        assert_eq!(layout.children[1].left, 30.0);
+
+        // No scene update happened, but the exit transition is finished.
+        let layout = root.layout(SIZE, Duration::from_secs(2));
+        assert_eq!(layout.children.len(), 2);
+        assert_eq!(layout.children[0].width, 0.0);
+        assert_eq!(layout.children[1].left, 0.0);
+        assert_eq!(layout.children[1].width, 20.0);
+        assert_eq!(root.children.len(), 2);
+    }
+}
@@ -1,13 +1,19 @@
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use glyphon::fontdb;
+use glyphon::fontdb::Source;
 
-use crate::error::{RegisterRendererError, UnregisterRendererError};
+use crate::asset::AssetSource;
+use crate::error::{
+    AddSubtitleCueError, RegisterFontError, RegisterRendererError, UnregisterFontError,
+    UnregisterRendererError,
+};
 
 use crate::scene::{Component, OutputScene};
+use crate::transformations::color_correction_renderer::lut::Lut;
 use crate::transformations::image_renderer::Image;
 use crate::transformations::shader::Shader;
+use crate::transformations::text_renderer::subtitles::{SubtitleCue, Subtitles};
 use crate::transformations::web_renderer::{self, WebRenderer};
 use crate::{
     error::{InitRendererEngineError, RenderSceneError, UpdateSceneError},
@@ -17,12 +23,12 @@ use crate::{
     types::Framerate,
     EventLoop, FrameSet, InputId, OutputId,
 };
-use crate::{image, OutputFrameFormat, Resolution};
+use crate::{image, lut, AudioLevels, OutputFrameFormat, Resolution};
 use crate::{
     scene::SceneState,
     wgpu::{WgpuCtx, WgpuErrorScope},
 };
-use crate::{shader, RegistryType, RendererId};
+use crate::{shader, subtitles, RegistryType, RendererId};
 
 use self::{
     render_graph::RenderGraph,
@@ -74,13 +80,15 @@ pub(crate) struct RegisterCtx {
 }
 
 /// RendererSpec provides configuration necessary to construct Renderer. Renderers
-/// are entities like shader, image or chromium_instance and can be used by nodes
+/// are entities like shader, image, LUT, subtitles or chromium_instance and can be used by nodes
 /// to transform or generate frames.
 #[derive(Debug, Clone)]
 pub enum RendererSpec {
     Shader(shader::ShaderSpec),
     WebRenderer(web_renderer::WebRendererSpec),
     Image(image::ImageSpec),
+    Lut(lut::LutSpec),
+    Subtitles(subtitles::SubtitlesSpec),
 }
 
 impl Renderer {
@@ -98,11 +106,14 @@ impl Renderer {
     }
 
     pub fn unregister_input(&self, input_id: &InputId) {
-        self.0
-            .lock()
-            .unwrap()
-            .render_graph
-            .unregister_input(input_id);
+        let mut guard = self.0.lock().unwrap();
+        guard.render_graph.unregister_input(input_id);
+        guard.renderers.audio_levels.remove_input(input_id);
+    }
+
+    /// Levels of the input audio used by the components, updated by the audio mixer.
+    pub fn audio_levels(&self) -> AudioLevels {
+        self.0.lock().unwrap().renderers.audio_levels.clone()
     }
 
     pub fn unregister_output(&self, output_id: &OutputId) {
@@ -142,6 +153,23 @@ impl Renderer {
                 let mut guard = self.0.lock().unwrap();
                 Ok(guard.renderers.images.register(id, asset)?)
             }
+            RendererSpec::Lut(spec) => {
+                let lut = Lut::new(&ctx, spec)
+                    .map_err(|err| RegisterRendererError::Lut(err, id.clone()))?;
+
+                let mut guard = self.0.lock().unwrap();
+                Ok(guard.renderers.luts.register(id, Arc::new(lut))?)
+            }
+            RendererSpec::Subtitles(spec) => {
+                let subtitles = Subtitles::new(spec)
+                    .map_err(|err| RegisterRendererError::Subtitles(err, id.clone()))?;
+
+                let mut guard = self.0.lock().unwrap();
+                Ok(guard
+                    .renderers
+                    .subtitles
+                    .register(id, Arc::new(subtitles))?)
+            }
         }
     }
 
@@ -155,13 +183,57 @@ impl Renderer {
             RegistryType::Shader => guard.renderers.shaders.unregister(renderer_id)?,
             RegistryType::WebRenderer => guard.renderers.web_renderers.unregister(renderer_id)?,
             RegistryType::Image => guard.renderers.images.unregister(renderer_id)?,
+            RegistryType::Lut => guard.renderers.luts.unregister(renderer_id)?,
+            RegistryType::Subtitles => guard.renderers.subtitles.unregister(renderer_id)?,
         }
         Ok(())
     }
 
-    pub fn register_font(&self, font_source: fontdb::Source) {
+    /// Adds a cue to the registered subtitles. If `start` is not provided, the cue
+    /// is displayed starting from the last rendered frame.
+    pub fn add_subtitle_cue(
+        &self,
+        subtitles_id: &RendererId,
+        start: Option<Duration>,
+        duration: Duration,
+        text: String,
+    ) -> Result<(), AddSubtitleCueError> {
+        let guard = self.0.lock().unwrap();
+        let subtitles = guard
+            .renderers
+            .subtitles
+            .get(subtitles_id)
+            .ok_or_else(|| AddSubtitleCueError::NotFound(subtitles_id.clone()))?;
+        let start = start.unwrap_or_else(|| guard.scene.last_pts());
+        subtitles.add_cue(SubtitleCue {
+            start,
+            // cue that would end after `Duration::MAX` is displayed until the end
+            end: start.saturating_add(duration),
+            text: text.into(),
+        });
+        Ok(())
+    }
+
+    /// Returns font families defined in the registered file.
+    pub fn register_font(
+        &self,
+        font_source: AssetSource,
+    ) -> Result<Vec<String>, RegisterFontError> {
+        let file = font_source.load()?;
+        let ctx = self.0.lock().unwrap().text_renderer_ctx.clone();
+        ctx.register_font(Source::Binary(Arc::new(file)))
+    }
+
+    /// Removes all fonts of the `font_family` that were added with `register_font`.
+    pub fn unregister_font(&self, font_family: &str) -> Result<(), UnregisterFontError> {
+        let ctx = self.0.lock().unwrap().text_renderer_ctx.clone();
+        ctx.unregister_font(font_family)
+    }
+
+    /// Font families that can be used in text components, sorted alphabetically.
+    pub fn font_families(&self) -> Vec<String> {
         let ctx = self.0.lock().unwrap().text_renderer_ctx.clone();
-        ctx.add_font(font_source);
+        ctx.font_families()
     }
 
     pub fn render(&self, input: FrameSet<InputId>) -> Result<FrameSet<OutputId>, RenderSceneError> {
@@ -181,6 +253,19 @@ impl Renderer {
             .update_scene(output_id, resolution, scene_root, output_format)
     }
 
+    /// Rebuild the current scene of an output for a new resolution. Output texture
+    /// is re-allocated, so frames produced after this call have the new size.
+    pub fn update_output_resolution(
+        &mut self,
+        output_id: OutputId,
+        resolution: Resolution,
+    ) -> Result<(), UpdateSceneError> {
+        self.0
+            .lock()
+            .unwrap()
+            .update_output_resolution(output_id, resolution)
+    }
+
     pub fn wgpu_ctx(&self) -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
         let guard = self.0.lock().unwrap();
         (guard.wgpu_ctx.device.clone(), guard.wgpu_ctx.queue.clone())
@@ -270,4 +355,20 @@ impl InnerRenderer {
         )?;
         Ok(())
     }
+
+    pub fn update_output_resolution(
+        &mut self,
+        output_id: OutputId,
+        resolution: Resolution,
+    ) -> Result<(), UpdateSceneError> {
+        let (Some(scene_root), Some(output)) = (
+            self.scene.scene_root(&output_id),
+            self.render_graph.outputs.get(&output_id),
+        ) else {
+            return Err(UpdateSceneError::OutputNotRegistered(output_id));
+        };
+        let scene_root = scene_root.clone();
+        let output_format = output.output_format;
+        self.update_scene(output_id, resolution, scene_root, output_format)
+    }
 }
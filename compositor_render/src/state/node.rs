@@ -3,9 +3,15 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::vec;
 
-use crate::scene::{self, ComponentId, ShaderComponentParams};
+use crate::scene::{
+    self, ChromaKeyComponentParams, ColorCorrectionComponentParams, ComponentId,
+    MaskComponentParams, ShaderComponentParams,
+};
+use crate::transformations::chroma_key_renderer::ChromaKeyNode;
+use crate::transformations::color_correction_renderer::ColorCorrectionNode;
 use crate::transformations::image_renderer::Image;
 use crate::transformations::layout::LayoutNode;
+use crate::transformations::mask_renderer::MaskNode;
 use crate::transformations::shader::node::ShaderNode;
 use crate::transformations::shader::Shader;
 use crate::InputId;
@@ -24,6 +30,9 @@ pub(super) enum InnerRenderNode {
     Web(WebRendererNode),
     Text(TextRendererNode),
     Image(ImageNode),
+    Mask(MaskNode),
+    ChromaKey(ChromaKeyNode),
+    ColorCorrection(ColorCorrectionNode),
     Layout(LayoutNode),
     InputStreamRef(InputId),
 }
@@ -38,13 +47,16 @@ impl InnerRenderNode {
     ) {
         match self {
             InnerRenderNode::Shader(ref shader) => {
-                shader.render(ctx.wgpu_ctx, sources, target, pts);
+                shader.render(ctx, sources, target, pts);
             }
             InnerRenderNode::Web(renderer) => renderer.render(ctx, sources, target),
             InnerRenderNode::Text(renderer) => {
-                renderer.render(ctx, target);
+                renderer.render(ctx, target, pts);
             }
             InnerRenderNode::Image(ref node) => node.render(ctx, target, pts),
+            InnerRenderNode::Mask(ref node) => node.render(ctx, sources, target),
+            InnerRenderNode::ChromaKey(ref node) => node.render(ctx, sources, target, pts),
+            InnerRenderNode::ColorCorrection(ref node) => node.render(ctx, sources, target, pts),
             InnerRenderNode::InputStreamRef(_) => {
                 // Nothing to do, textures on input nodes should be populated
                 // at the start of render loop
@@ -80,6 +92,13 @@ impl RenderNode {
             }
             scene::NodeParams::Image(image) => Self::new_image_node(image),
             scene::NodeParams::Text(text_params) => Self::new_text_node(text_params),
+            scene::NodeParams::Mask(mask_params) => Self::new_mask_node(ctx, children, mask_params),
+            scene::NodeParams::ChromaKey(chroma_key_params) => {
+                Self::new_chroma_key_node(ctx, children, chroma_key_params)
+            }
+            scene::NodeParams::ColorCorrection(color_correction_params) => {
+                Self::new_color_correction_node(ctx, children, color_correction_params)
+            }
             scene::NodeParams::Layout(layout_provider) => {
                 Self::new_layout_node(ctx, children, layout_provider)
             }
@@ -112,6 +131,7 @@ impl RenderNode {
             ctx,
             shader,
             &shader_params.shader_param,
+            &shader_params.audio_inputs,
             &shader_params.size.into(),
         ));
         let mut output = NodeTexture::new();
@@ -164,6 +184,62 @@ impl RenderNode {
         }
     }
 
+    pub(super) fn new_mask_node(
+        ctx: &RenderCtx,
+        children: Vec<RenderNode>,
+        mask_params: MaskComponentParams,
+    ) -> Self {
+        let node = InnerRenderNode::Mask(MaskNode::new(
+            ctx,
+            mask_params.mode,
+            mask_params.path,
+            mask_params.size.into(),
+        ));
+        let mut output = NodeTexture::new();
+        output.ensure_size(ctx.wgpu_ctx, mask_params.size.into());
+
+        Self {
+            renderer: node,
+            output,
+            children,
+        }
+    }
+
+    pub(super) fn new_chroma_key_node(
+        ctx: &RenderCtx,
+        children: Vec<RenderNode>,
+        chroma_key_params: ChromaKeyComponentParams,
+    ) -> Self {
+        let mut output = NodeTexture::new();
+        output.ensure_size(ctx.wgpu_ctx, chroma_key_params.size.into());
+        let node = InnerRenderNode::ChromaKey(ChromaKeyNode::new(ctx, chroma_key_params));
+
+        Self {
+            renderer: node,
+            output,
+            children,
+        }
+    }
+
+    pub(super) fn new_color_correction_node(
+        ctx: &RenderCtx,
+        children: Vec<RenderNode>,
+        color_correction_params: ColorCorrectionComponentParams,
+    ) -> Self {
+        let mut output = NodeTexture::new();
+        output.ensure_size(ctx.wgpu_ctx, color_correction_params.size.into());
+        let node = InnerRenderNode::ColorCorrection(ColorCorrectionNode::new(
+            ctx,
+            color_correction_params,
+        ));
+
+        Self {
+            renderer: node,
+            output,
+            children,
+        }
+    }
+
     pub(super) fn new_layout_node(
         ctx: &RenderCtx,
         children: Vec<RenderNode>,
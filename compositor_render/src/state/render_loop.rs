@@ -84,6 +84,7 @@ pub(super) fn read_outputs(
                             height: size.height as usize,
                         },
                         pts,
+                        captions: None,
                     };
                     partial_textures.push(PartialOutputFrame::CompleteFrame {
                         output_id: output_id.clone(),
@@ -125,6 +126,7 @@ pub(super) fn read_outputs(
                         data: FrameData::Rgba8UnormWgpuTexture(Arc::new(wgpu_texture)),
                         resolution,
                         pts,
+                        captions: None,
                     };
                     partial_textures.push(PartialOutputFrame::CompleteFrame {
                         output_id: output_id.clone(),
@@ -156,6 +158,7 @@ pub(super) fn read_outputs(
                     data,
                     resolution,
                     pts,
+                    captions: None,
                 };
                 result.insert(output_id.clone(), frame);
             }
@@ -1,10 +1,18 @@
 use std::sync::Arc;
 
 use crate::{
+    audio_levels::AudioLevels,
     error::InitRendererEngineError,
     registry::{RegistryType, RendererRegistry},
     transformations::{
-        image_renderer::Image, layout::LayoutRenderer, shader::Shader, web_renderer::WebRenderer,
+        chroma_key_renderer::ChromaKeyRenderer,
+        color_correction_renderer::{lut::Lut, ColorCorrectionRenderer},
+        image_renderer::Image,
+        layout::LayoutRenderer,
+        mask_renderer::MaskRenderer,
+        shader::Shader,
+        text_renderer::{effects::TextEffectsRenderer, subtitles::Subtitles},
+        web_renderer::WebRenderer,
     },
 };
 
@@ -14,7 +22,14 @@ pub(crate) struct Renderers {
     pub(crate) shaders: RendererRegistry<Arc<Shader>>,
     pub(crate) web_renderers: RendererRegistry<Arc<WebRenderer>>,
     pub(crate) images: RendererRegistry<Image>,
+    pub(crate) luts: RendererRegistry<Arc<Lut>>,
+    pub(crate) subtitles: RendererRegistry<Arc<Subtitles>>,
     pub(crate) layout: LayoutRenderer,
+    pub(crate) mask: Arc<MaskRenderer>,
+    pub(crate) chroma_key: Arc<ChromaKeyRenderer>,
+    pub(crate) color_correction: Arc<ColorCorrectionRenderer>,
+    pub(crate) text_effects: Arc<TextEffectsRenderer>,
+    pub(crate) audio_levels: AudioLevels,
 }
 
 impl Renderers {
@@ -23,8 +38,27 @@ impl Renderers {
             shaders: RendererRegistry::new(RegistryType::Shader),
             web_renderers: RendererRegistry::new(RegistryType::WebRenderer),
             images: RendererRegistry::new(RegistryType::Image),
+            luts: RendererRegistry::new(RegistryType::Lut),
+            subtitles: RendererRegistry::new(RegistryType::Subtitles),
             layout: LayoutRenderer::new(&wgpu_ctx)
                 .map_err(InitRendererEngineError::LayoutTransformationsInitError)?,
+            mask: Arc::new(
+                MaskRenderer::new(&wgpu_ctx)
+                    .map_err(InitRendererEngineError::MaskTransformationInitError)?,
+            ),
+            chroma_key: Arc::new(
+                ChromaKeyRenderer::new(&wgpu_ctx)
+                    .map_err(InitRendererEngineError::ChromaKeyTransformationInitError)?,
+            ),
+            color_correction: Arc::new(
+                ColorCorrectionRenderer::new(&wgpu_ctx)
+                    .map_err(InitRendererEngineError::ColorCorrectionTransformationInitError)?,
+            ),
+            text_effects: Arc::new(
+                TextEffectsRenderer::new(&wgpu_ctx)
+                    .map_err(InitRendererEngineError::TextEffectsTransformationInitError)?,
+            ),
+            audio_levels: AudioLevels::default(),
         })
     }
 }
@@ -1,5 +1,8 @@
+pub mod chroma_key_renderer;
+pub mod color_correction_renderer;
 pub mod image_renderer;
 pub mod layout;
+pub mod mask_renderer;
 pub mod shader;
 pub mod text_renderer;
 pub mod web_renderer;
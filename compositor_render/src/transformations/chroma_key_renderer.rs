@@ -0,0 +1,154 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    scene::{ChromaKeyComponentParams, ChromaKeyParams},
+    state::RenderCtx,
+    utils::srgb_to_linear,
+    wgpu::{
+        common_pipeline::{self, CreateShaderError, Sampler},
+        texture::{NodeTexture, NodeTextureState},
+        WgpuCtx, WgpuErrorScope,
+    },
+    Resolution,
+};
+
+/// Built-in pipeline that removes pixels similar to the key color and
+/// suppresses the key color spill on the remaining ones.
+#[derive(Debug)]
+pub struct ChromaKeyRenderer {
+    pipeline: wgpu::RenderPipeline,
+    sampler: Sampler,
+    texture_bgl: wgpu::BindGroupLayout,
+}
+
+impl ChromaKeyRenderer {
+    pub fn new(wgpu_ctx: &Arc<WgpuCtx>) -> Result<Self, CreateShaderError> {
+        let scope = WgpuErrorScope::push(&wgpu_ctx.device);
+
+        let shader_module = wgpu_ctx
+            .device
+            .create_shader_module(wgpu::include_wgsl!("./chroma_key_renderer/chroma_key.wgsl"));
+        let sampler = Sampler::new(&wgpu_ctx.device);
+        let texture_bgl = common_pipeline::create_single_texture_bgl(&wgpu_ctx.device);
+
+        let pipeline_layout =
+            wgpu_ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("chroma key pipeline layout"),
+                    bind_group_layouts: &[&texture_bgl, &sampler.bind_group_layout],
+                    push_constant_ranges: &[wgpu::PushConstantRange {
+                        stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        range: 0..32,
+                    }],
+                });
+
+        let pipeline = common_pipeline::create_render_pipeline(
+            &wgpu_ctx.device,
+            &pipeline_layout,
+            &shader_module,
+        );
+
+        scope.pop(&wgpu_ctx.device)?;
+
+        Ok(Self {
+            pipeline,
+            sampler,
+            texture_bgl,
+        })
+    }
+
+    fn render(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        source: &wgpu::TextureView,
+        params: ChromaKeyParams,
+        target: &NodeTextureState,
+    ) {
+        let texture_bg = wgpu_ctx
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("chroma key texture bind group"),
+                layout: &self.texture_bgl,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(source),
+                }],
+            });
+
+        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("chroma key"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                    view: &target.rgba_texture().texture().view,
+                    resolve_target: None,
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_push_constants(
+                wgpu::ShaderStages::VERTEX_FRAGMENT,
+                0,
+                &params_to_bytes(params),
+            );
+            render_pass.set_bind_group(0, &texture_bg, &[]);
+            render_pass.set_bind_group(1, &self.sampler.bind_group, &[]);
+
+            wgpu_ctx.plane.draw(&mut render_pass);
+        }
+        wgpu_ctx.queue.submit(Some(encoder.finish()));
+    }
+}
+
+fn params_to_bytes(params: ChromaKeyParams) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    result[0..4].copy_from_slice(&srgb_to_linear(params.key_color.0).to_le_bytes());
+    result[4..8].copy_from_slice(&srgb_to_linear(params.key_color.1).to_le_bytes());
+    result[8..12].copy_from_slice(&srgb_to_linear(params.key_color.2).to_le_bytes());
+    result[12..16].copy_from_slice(&params.similarity.to_le_bytes());
+    result[16..20].copy_from_slice(&params.smoothness.to_le_bytes());
+    result[20..24].copy_from_slice(&params.spill.to_le_bytes());
+    result
+}
+
+pub struct ChromaKeyNode {
+    renderer: Arc<ChromaKeyRenderer>,
+    params: ChromaKeyComponentParams,
+    resolution: Resolution,
+}
+
+impl ChromaKeyNode {
+    pub fn new(ctx: &RenderCtx, params: ChromaKeyComponentParams) -> Self {
+        Self {
+            renderer: ctx.renderers.chroma_key.clone(),
+            resolution: params.size.into(),
+            params,
+        }
+    }
+
+    pub fn render(
+        &self,
+        ctx: &RenderCtx,
+        sources: &[&NodeTexture],
+        target: &mut NodeTexture,
+        pts: Duration,
+    ) {
+        let source = sources
+            .first()
+            .and_then(|texture| texture.state())
+            .map(|state| &state.rgba_texture().texture().view)
+            .unwrap_or(&ctx.wgpu_ctx.empty_texture.view);
+
+        let target = target.ensure_size(ctx.wgpu_ctx, self.resolution);
+        self.renderer
+            .render(ctx.wgpu_ctx, source, self.params.params(pts), target);
+    }
+}
@@ -0,0 +1,68 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+struct ChromaKeyInfo {
+    // Key color in linear RGB.
+    key_color: vec3<f32>,
+    similarity: f32,
+    smoothness: f32,
+    spill: f32,
+}
+
+var<push_constant> chroma_key_info: ChromaKeyInfo;
+
+@group(0) @binding(0) var texture: texture_2d<f32>;
+@group(1) @binding(0) var sampler_: sampler;
+
+const LUMA: vec3<f32> = vec3<f32>(0.2126, 0.7152, 0.0722);
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    output.position = vec4(input.position, 1.0);
+    output.tex_coords = input.tex_coords;
+
+    return output;
+}
+
+// Color without its luminance. Comparing chroma instead of full colors makes
+// keying less sensitive to shadows and uneven lighting of the background.
+fn chroma(color: vec3<f32>) -> vec3<f32> {
+    return color - vec3<f32>(dot(color, LUMA));
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // Chroma distance is calculated on linear values.
+    let color = textureSample(texture, sampler_, input.tex_coords);
+
+    let key_chroma = chroma(chroma_key_info.key_color);
+    let color_chroma = chroma(color.rgb);
+
+    let distance = distance(color_chroma, key_chroma);
+    let alpha = smoothstep(
+        chroma_key_info.similarity,
+        chroma_key_info.similarity + chroma_key_info.smoothness,
+        distance
+    );
+
+    // Spill suppression removes the part of the chroma that points in the direction
+    // of the key color, e.g. green light reflected from the background.
+    var rgb = color.rgb;
+    let key_chroma_length = length(key_chroma);
+    if (key_chroma_length > 0.0001) {
+        let key_direction = key_chroma / key_chroma_length;
+        let spill = max(dot(color_chroma, key_direction), 0.0);
+        rgb = clamp(rgb - key_direction * spill * chroma_key_info.spill, vec3<f32>(0.0), vec3<f32>(1.0));
+    }
+
+    return vec4<f32>(rgb, color.a * alpha);
+}
@@ -0,0 +1,226 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    scene::{ColorCorrectionComponentParams, ColorCorrectionParams},
+    state::RenderCtx,
+    wgpu::{
+        common_pipeline::{self, CreateShaderError, Sampler},
+        texture::{NodeTexture, NodeTextureState},
+        WgpuCtx, WgpuErrorScope,
+    },
+    Resolution,
+};
+
+use self::lut::Lut;
+
+pub mod lut;
+
+/// Built-in pipeline that adjusts colors of the texture and optionally
+/// maps them through a 3D LUT.
+#[derive(Debug)]
+pub struct ColorCorrectionRenderer {
+    pipeline: wgpu::RenderPipeline,
+    sampler: Sampler,
+    textures_bgl: wgpu::BindGroupLayout,
+    /// Bound instead of a LUT if component does not use one.
+    empty_lut: Lut,
+}
+
+impl ColorCorrectionRenderer {
+    pub fn new(wgpu_ctx: &Arc<WgpuCtx>) -> Result<Self, CreateShaderError> {
+        let scope = WgpuErrorScope::push(&wgpu_ctx.device);
+
+        let shader_module = wgpu_ctx.device.create_shader_module(wgpu::include_wgsl!(
+            "./color_correction_renderer/color_correction.wgsl"
+        ));
+        let sampler = Sampler::new(&wgpu_ctx.device);
+        let texture_entry = |binding, view_dimension| wgpu::BindGroupLayoutEntry {
+            binding,
+            count: None,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                view_dimension,
+            },
+        };
+        let textures_bgl =
+            wgpu_ctx
+                .device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("color correction textures bgl"),
+                    entries: &[
+                        texture_entry(0, wgpu::TextureViewDimension::D2),
+                        texture_entry(1, wgpu::TextureViewDimension::D3),
+                    ],
+                });
+
+        let pipeline_layout =
+            wgpu_ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("color correction pipeline layout"),
+                    bind_group_layouts: &[&textures_bgl, &sampler.bind_group_layout],
+                    push_constant_ranges: &[wgpu::PushConstantRange {
+                        stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        range: 0..112,
+                    }],
+                });
+
+        let pipeline = common_pipeline::create_render_pipeline(
+            &wgpu_ctx.device,
+            &pipeline_layout,
+            &shader_module,
+        );
+        let empty_lut = Lut::empty(wgpu_ctx);
+
+        scope.pop(&wgpu_ctx.device)?;
+
+        Ok(Self {
+            pipeline,
+            sampler,
+            textures_bgl,
+            empty_lut,
+        })
+    }
+
+    fn render(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        source: &wgpu::TextureView,
+        lut: Option<&Lut>,
+        params: ColorCorrectionParams,
+        target: &NodeTextureState,
+    ) {
+        let (lut, lut_strength) = match lut {
+            Some(lut) => (lut, params.lut_strength),
+            None => (&self.empty_lut, 0.0),
+        };
+        let textures_bg = wgpu_ctx
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("color correction textures bind group"),
+                layout: &self.textures_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(source),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&lut.view),
+                    },
+                ],
+            });
+
+        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("color correction"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                    view: &target.rgba_texture().texture().view,
+                    resolve_target: None,
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_push_constants(
+                wgpu::ShaderStages::VERTEX_FRAGMENT,
+                0,
+                &params_to_bytes(params, lut, lut_strength),
+            );
+            render_pass.set_bind_group(0, &textures_bg, &[]);
+            render_pass.set_bind_group(1, &self.sampler.bind_group, &[]);
+
+            wgpu_ctx.plane.draw(&mut render_pass);
+        }
+        wgpu_ctx.queue.submit(Some(encoder.finish()));
+    }
+}
+
+/// Layout has to match `ColorCorrectionInfo` in the shader.
+fn params_to_bytes(params: ColorCorrectionParams, lut: &Lut, lut_strength: f32) -> [u8; 112] {
+    let lift_gamma_gain = params.lift_gamma_gain;
+    let values: [f32; 28] = [
+        lift_gamma_gain.lift.red,
+        lift_gamma_gain.lift.green,
+        lift_gamma_gain.lift.blue,
+        0.0,
+        lift_gamma_gain.gamma.red,
+        lift_gamma_gain.gamma.green,
+        lift_gamma_gain.gamma.blue,
+        0.0,
+        lift_gamma_gain.gain.red,
+        lift_gamma_gain.gain.green,
+        lift_gamma_gain.gain.blue,
+        0.0,
+        lut.domain_min[0],
+        lut.domain_min[1],
+        lut.domain_min[2],
+        lut.size as f32,
+        lut.domain_max[0],
+        lut.domain_max[1],
+        lut.domain_max[2],
+        lut_strength,
+        params.brightness,
+        params.contrast,
+        params.saturation,
+        params.gamma,
+        params.temperature,
+        params.tint,
+        0.0,
+        0.0,
+    ];
+
+    let mut result = [0u8; 112];
+    for (chunk, value) in result.chunks_exact_mut(4).zip(values) {
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+    result
+}
+
+pub struct ColorCorrectionNode {
+    renderer: Arc<ColorCorrectionRenderer>,
+    params: ColorCorrectionComponentParams,
+    resolution: Resolution,
+}
+
+impl ColorCorrectionNode {
+    pub fn new(ctx: &RenderCtx, params: ColorCorrectionComponentParams) -> Self {
+        Self {
+            renderer: ctx.renderers.color_correction.clone(),
+            resolution: params.size.into(),
+            params,
+        }
+    }
+
+    pub fn render(
+        &self,
+        ctx: &RenderCtx,
+        sources: &[&NodeTexture],
+        target: &mut NodeTexture,
+        pts: Duration,
+    ) {
+        let source = sources
+            .first()
+            .and_then(|texture| texture.state())
+            .map(|state| &state.rgba_texture().texture().view)
+            .unwrap_or(&ctx.wgpu_ctx.empty_texture.view);
+
+        let target = target.ensure_size(ctx.wgpu_ctx, self.resolution);
+        self.renderer.render(
+            ctx.wgpu_ctx,
+            source,
+            self.params.lut.as_deref(),
+            self.params.params(pts),
+            target,
+        );
+    }
+}
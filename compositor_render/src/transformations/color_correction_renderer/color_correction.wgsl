@@ -0,0 +1,106 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+struct ColorCorrectionInfo {
+    lift: vec4<f32>,
+    gamma_rgb: vec4<f32>,
+    gain: vec4<f32>,
+    // w - size of the LUT
+    lut_domain_min: vec4<f32>,
+    // w - LUT strength, 0.0 if LUT is not used
+    lut_domain_max: vec4<f32>,
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+    gamma: f32,
+    temperature: f32,
+    tint: f32,
+}
+
+var<push_constant> color_correction_info: ColorCorrectionInfo;
+
+@group(0) @binding(0) var texture: texture_2d<f32>;
+@group(0) @binding(1) var lut_texture: texture_3d<f32>;
+@group(1) @binding(0) var sampler_: sampler;
+
+const LUMA: vec3<f32> = vec3<f32>(0.2126, 0.7152, 0.0722);
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    output.position = vec4(input.position, 1.0);
+    output.tex_coords = input.tex_coords;
+
+    return output;
+}
+
+fn linear_to_srgb(color: vec3<f32>) -> vec3<f32> {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, color <= vec3<f32>(0.0031308));
+}
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, color <= vec3<f32>(0.04045));
+}
+
+fn apply_lut(color: vec3<f32>) -> vec3<f32> {
+    let info = color_correction_info;
+    let lut_size = info.lut_domain_min.w;
+    let normalized = clamp(
+        (color - info.lut_domain_min.xyz) / (info.lut_domain_max.xyz - info.lut_domain_min.xyz),
+        vec3<f32>(0.0),
+        vec3<f32>(1.0)
+    );
+    // Sample between centers of the first and last texel, so the edge entries
+    // of the table map exactly to the edges of the domain.
+    let coords = normalized * ((lut_size - 1.0) / lut_size) + 0.5 / lut_size;
+    return textureSampleLevel(lut_texture, sampler_, coords, 0.0).rgb;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let info = color_correction_info;
+    let source_color = textureSample(texture, sampler_, input.tex_coords);
+
+    // White balance is applied in linear space, where it behaves like a change of
+    // the light color.
+    let white_balance = vec3<f32>(
+        1.0 + 0.2 * info.temperature,
+        1.0 - 0.2 * info.tint,
+        1.0 - 0.2 * info.temperature,
+    );
+    let balanced = source_color.rgb * white_balance;
+
+    // Remaining adjustments operate on gamma encoded values, so they match
+    // the behavior of the similar controls in video editors.
+    var color = linear_to_srgb(clamp(balanced, vec3<f32>(0.0), vec3<f32>(1.0)));
+
+    // Lift raises shadows without affecting white, gain scales highlights without
+    // affecting black, gamma bends midtones.
+    color = info.gain.rgb * (color + info.lift.rgb * (vec3<f32>(1.0) - color));
+    color = pow(max(color, vec3<f32>(0.0)), 1.0 / max(info.gamma_rgb.rgb, vec3<f32>(0.01)));
+
+    color = color + info.brightness;
+    color = (color - 0.5) * info.contrast + 0.5;
+    color = mix(vec3<f32>(dot(color, LUMA)), color, info.saturation);
+    color = pow(max(color, vec3<f32>(0.0)), vec3<f32>(1.0 / max(info.gamma, 0.01)));
+    color = clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+
+    let lut_strength = info.lut_domain_max.w;
+    if (lut_strength > 0.0) {
+        color = mix(color, apply_lut(color), lut_strength);
+    }
+
+    return vec4<f32>(srgb_to_linear(color), source_color.a);
+}
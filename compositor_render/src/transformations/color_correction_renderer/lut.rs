@@ -0,0 +1,301 @@
+use std::str::Utf8Error;
+
+use crate::{
+    asset::{AssetLoadError, AssetSource},
+    state::RegisterCtx,
+    wgpu::WgpuCtx,
+};
+
+#[derive(Debug, Clone)]
+pub struct LutSpec {
+    pub src: AssetSource,
+}
+
+/// 3D lookup table loaded from a `.cube` file and uploaded to the GPU.
+#[derive(Debug)]
+pub struct Lut {
+    _texture: wgpu::Texture,
+    pub(crate) view: wgpu::TextureView,
+    pub(crate) size: u32,
+    pub(crate) domain_min: [f32; 3],
+    pub(crate) domain_max: [f32; 3],
+}
+
+impl Lut {
+    pub fn new(ctx: &RegisterCtx, spec: LutSpec) -> Result<Self, LutError> {
+        let file = spec.src.load()?;
+        let cube = CubeFile::parse(std::str::from_utf8(&file)?)?;
+        Ok(Self::upload(&ctx.wgpu_ctx, cube))
+    }
+
+    /// Placeholder 1x1x1 table bound when a component does not use a LUT.
+    pub(crate) fn empty(wgpu_ctx: &WgpuCtx) -> Self {
+        Self::upload(
+            wgpu_ctx,
+            CubeFile {
+                size: 1,
+                domain_min: [0.0; 3],
+                domain_max: [1.0; 3],
+                table: vec![[0.0; 3]],
+            },
+        )
+    }
+
+    fn upload(wgpu_ctx: &WgpuCtx, cube: CubeFile) -> Self {
+        let size = wgpu::Extent3d {
+            width: cube.size,
+            height: cube.size,
+            depth_or_array_layers: cube.size,
+        };
+        let format = wgpu::TextureFormat::Rgba16Float;
+        let texture = wgpu_ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("3D LUT texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D3,
+            format,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[format],
+        });
+
+        // Entries in a `.cube` file are ordered with red changing the fastest, so they
+        // map directly to (x, y, z) = (red, green, blue) texels.
+        let data: Vec<u8> = cube
+            .table
+            .iter()
+            .flat_map(|[r, g, b]| [*r, *g, *b, 1.0])
+            .flat_map(|value| half::f16::from_f32(value).to_le_bytes())
+            .collect();
+
+        wgpu_ctx.queue.write_texture(
+            wgpu::ImageCopyTexture {
+                aspect: wgpu::TextureAspect::All,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                texture: &texture,
+            },
+            &data,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(cube.size * 8),
+                rows_per_image: Some(cube.size),
+            },
+            size,
+        );
+        wgpu_ctx.queue.submit([]);
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            _texture: texture,
+            view,
+            size: cube.size,
+            domain_min: cube.domain_min,
+            domain_max: cube.domain_max,
+        }
+    }
+}
+
+/// Maximal supported `LUT_3D_SIZE`. 256^3 entries already take 64MB of GPU memory.
+const MAX_LUT_SIZE: u32 = 256;
+
+#[derive(Debug, PartialEq)]
+struct CubeFile {
+    size: u32,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    table: Vec<[f32; 3]>,
+}
+
+impl CubeFile {
+    fn parse(content: &str) -> Result<Self, CubeParseError> {
+        let mut size = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table: Vec<[f32; 3]> = Vec::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let keyword = tokens.next().unwrap_or_default();
+            match keyword {
+                "TITLE" => (),
+                "LUT_1D_SIZE" => return Err(CubeParseError::Unsupported1DLut),
+                "LUT_3D_SIZE" => {
+                    let value = tokens
+                        .next()
+                        .and_then(|value| value.parse::<u32>().ok())
+                        .ok_or(CubeParseError::InvalidLine(line_number))?;
+                    if !(2..=MAX_LUT_SIZE).contains(&value) {
+                        return Err(CubeParseError::InvalidSize(value));
+                    }
+                    size = Some(value);
+                }
+                "DOMAIN_MIN" => domain_min = parse_values(tokens, line_number)?,
+                "DOMAIN_MAX" => domain_max = parse_values(tokens, line_number)?,
+                "LUT_3D_INPUT_RANGE" => {
+                    let [min, max] = parse_values(tokens, line_number)?;
+                    domain_min = [min; 3];
+                    domain_max = [max; 3];
+                }
+                _ => {
+                    let entry = parse_values(line.split_whitespace(), line_number)?;
+                    table.push(entry);
+                }
+            }
+        }
+
+        let size = size.ok_or(CubeParseError::MissingSize)?;
+        let expected_entries = (size * size * size) as usize;
+        if table.len() != expected_entries {
+            return Err(CubeParseError::WrongEntriesCount {
+                expected: expected_entries,
+                actual: table.len(),
+            });
+        }
+        if (0..3).any(|channel| domain_max[channel] <= domain_min[channel]) {
+            return Err(CubeParseError::InvalidDomain);
+        }
+
+        Ok(Self {
+            size,
+            domain_min,
+            domain_max,
+            table,
+        })
+    }
+}
+
+/// Parses exactly `N` numbers, any missing or additional token is an error.
+fn parse_values<'a, const N: usize>(
+    mut tokens: impl Iterator<Item = &'a str>,
+    line: usize,
+) -> Result<[f32; N], CubeParseError> {
+    let mut values = [0.0; N];
+    for value in values.iter_mut() {
+        *value = tokens
+            .next()
+            .and_then(|value| value.parse::<f32>().ok())
+            .ok_or(CubeParseError::InvalidLine(line))?;
+    }
+    match tokens.next() {
+        Some(_) => Err(CubeParseError::InvalidLine(line)),
+        None => Ok(values),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LutError {
+    #[error("Failed to load LUT file: {0}")]
+    LutLoad(#[from] AssetLoadError),
+
+    #[error("Invalid utf-8 content inside LUT file: {0}")]
+    InvalidUtf8Content(#[from] Utf8Error),
+
+    #[error(transparent)]
+    ParsingCubeFailed(#[from] CubeParseError),
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum CubeParseError {
+    #[error("Failed to parse line {0} of the .cube file.")]
+    InvalidLine(usize),
+
+    #[error("Missing LUT_3D_SIZE in the .cube file.")]
+    MissingSize,
+
+    #[error("LUT_3D_SIZE has to be between 2 and {MAX_LUT_SIZE}, found {0}.")]
+    InvalidSize(u32),
+
+    #[error("Only 3D LUTs are supported, found LUT_1D_SIZE in the .cube file.")]
+    Unsupported1DLut,
+
+    #[error("Expected {expected} table entries in the .cube file, found {actual}.")]
+    WrongEntriesCount { expected: usize, actual: usize },
+
+    #[error("DOMAIN_MAX has to be greater than DOMAIN_MIN for every channel.")]
+    InvalidDomain,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CubeFile, CubeParseError};
+
+    const IDENTITY: &str = r#"
+# Created by hand
+TITLE "identity"
+LUT_3D_SIZE 2
+DOMAIN_MIN 0.0 0.0 0.0
+DOMAIN_MAX 1.0 1.0 1.0
+
+0.0 0.0 0.0
+1.0 0.0 0.0
+0.0 1.0 0.0
+1.0 1.0 0.0
+0.0 0.0 1.0
+1.0 0.0 1.0
+0.0 1.0 1.0
+1.0 1.0 1.0
+"#;
+
+    #[test]
+    fn parse_identity() {
+        let cube = CubeFile::parse(IDENTITY).unwrap();
+        assert_eq!(cube.size, 2);
+        assert_eq!(cube.domain_max, [1.0; 3]);
+        assert_eq!(cube.table.len(), 8);
+        assert_eq!(cube.table[1], [1.0, 0.0, 0.0]);
+        assert_eq!(cube.table[6], [0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn missing_entries() {
+        let (content, _last_entry) = IDENTITY.trim_end().rsplit_once('\n').unwrap();
+        assert_eq!(
+            CubeFile::parse(content),
+            Err(CubeParseError::WrongEntriesCount {
+                expected: 8,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_entry() {
+        let content = IDENTITY.replace("1.0 0.0 0.0\n", "1.0 0.0\n");
+        assert_eq!(
+            CubeFile::parse(&content),
+            Err(CubeParseError::InvalidLine(9))
+        );
+    }
+
+    #[test]
+    fn input_range_sets_domain_of_all_channels() {
+        let content = IDENTITY
+            .replace("DOMAIN_MIN 0.0 0.0 0.0\n", "LUT_3D_INPUT_RANGE -0.5 2.0\n")
+            .replace("DOMAIN_MAX 1.0 1.0 1.0\n", "");
+        let cube = CubeFile::parse(&content).unwrap();
+        assert_eq!(cube.domain_min, [-0.5; 3]);
+        assert_eq!(cube.domain_max, [2.0; 3]);
+
+        let content = IDENTITY.replace("DOMAIN_MIN 0.0 0.0 0.0\n", "LUT_3D_INPUT_RANGE 0.0\n");
+        assert_eq!(
+            CubeFile::parse(&content),
+            Err(CubeParseError::InvalidLine(5))
+        );
+    }
+
+    #[test]
+    fn lut_1d_not_supported() {
+        assert_eq!(
+            CubeFile::parse("LUT_1D_SIZE 2\n0.0 0.0 0.0\n1.0 1.0 1.0\n"),
+            Err(CubeParseError::Unsupported1DLut)
+        );
+    }
+}
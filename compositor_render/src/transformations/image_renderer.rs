@@ -1,5 +1,4 @@
 use std::{
-    fs, io,
     str::{from_utf8, Utf8Error},
     sync::{Arc, Mutex},
     time::Duration,
@@ -14,6 +13,7 @@ use resvg::{
 };
 
 use crate::{
+    asset::{AssetLoadError, AssetSource},
     state::{RegisterCtx, RenderCtx},
     wgpu::{
         texture::{NodeTexture, RGBATexture},
@@ -24,17 +24,10 @@ use crate::{
 
 #[derive(Debug, Clone)]
 pub struct ImageSpec {
-    pub src: ImageSource,
+    pub src: AssetSource,
     pub image_type: ImageType,
 }
 
-#[derive(Debug, Clone)]
-pub enum ImageSource {
-    Url { url: String },
-    LocalPath { path: String },
-    Bytes { bytes: Bytes },
-}
-
 #[derive(Debug, Clone)]
 pub enum ImageType {
     Png,
@@ -52,7 +45,7 @@ pub enum Image {
 
 impl Image {
     pub fn new(ctx: &RegisterCtx, spec: ImageSpec) -> Result<Self, ImageError> {
-        let file = Self::download_file(&spec.src)?;
+        let file = spec.src.load()?;
         let renderer = match spec.image_type {
             ImageType::Png => {
                 let asset = BitmapAsset::new(&ctx.wgpu_ctx, file, ImageFormat::Png)?;
@@ -88,27 +81,6 @@ impl Image {
             Image::Svg(asset) => asset.resolution(),
         }
     }
-
-    fn download_file(src: &ImageSource) -> Result<bytes::Bytes, ImageError> {
-        match src {
-            ImageSource::Url { url } => {
-                #[cfg(target_arch = "wasm32")]
-                return Err(ImageError::ImageSourceUrlNotSupported);
-
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    let response = reqwest::blocking::get(url)?;
-                    let response = response.error_for_status()?;
-                    Ok(response.bytes()?)
-                }
-            }
-            ImageSource::LocalPath { path } => {
-                let file = fs::read(path)?;
-                Ok(Bytes::from(file))
-            }
-            ImageSource::Bytes { bytes } => Ok(bytes.clone()),
-        }
-    }
 }
 
 pub enum ImageNode {
@@ -432,11 +404,8 @@ fn copy_texture_to_node_texture(ctx: &WgpuCtx, source: &RGBATexture, target: &mu
 
 #[derive(Debug, thiserror::Error)]
 pub enum ImageError {
-    #[error("Failed to download asset: {0}")]
-    AssetDownload(#[from] reqwest::Error),
-
-    #[error("Failed to read image from disk: {0}")]
-    AssetDiskReadError(#[from] io::Error),
+    #[error("Failed to load image file: {0}")]
+    ImageLoad(#[from] AssetLoadError),
 
     #[error("Failed to parse an image: {0}")]
     FailedToReadAsBitmap(#[from] image::ImageError),
@@ -446,9 +415,6 @@ pub enum ImageError {
 
     #[error(transparent)]
     ParsingAnimatedFailed(#[from] AnimatedError),
-
-    #[error("Providing URL as image source is not supported on wasm platform")]
-    ImageSourceUrlNotSupported,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -1,18 +1,19 @@
 use std::{sync::Arc, time::Duration};
 
 use crate::{
-    scene::{BorderRadius, BoxShadow, RGBAColor, Size},
+    scene::{BlendMode, BorderRadius, BoxShadow, Gradient, RGBAColor, Size},
     state::RenderCtx,
     wgpu::texture::NodeTexture,
     Resolution,
 };
 
+mod blur;
 mod flatten;
 mod layout_renderer;
 mod params;
 mod shader;
 
-use self::shader::LayoutShader;
+use self::{blur::BlurTextures, shader::LayoutShader};
 
 pub(crate) use layout_renderer::LayoutRenderer;
 
@@ -26,6 +27,22 @@ pub(crate) trait LayoutProvider: Send {
 pub(crate) struct LayoutNode {
     layout_provider: Box<dyn LayoutProvider>,
     shader: Arc<LayoutShader>,
+    /// Used by layouts with a blend mode or a backdrop blur to read what was
+    /// rendered before them.
+    backdrop: NodeTexture,
+    /// Textures used to blur the backdrop.
+    blur_textures: BlurTextures,
+    /// Offscreen textures of layouts with `blur_radius`, of blurred box shadows
+    /// and of transparent or blended groups.
+    layers: Vec<LayerTextures>,
+}
+
+/// Each layer has its own set of textures, so they are not reallocated
+/// if layers have different sizes.
+struct LayerTextures {
+    texture: NodeTexture,
+    backdrop: NodeTexture,
+    blur_textures: BlurTextures,
 }
 
 /// When rendering we cut this fragment from texture and stretch it on
@@ -68,6 +85,11 @@ struct RenderLayout {
     // border radius needs to applied before cropping, so we can't just make it a part of a parent
     // mask
     border_radius: BorderRadius,
+    // Final opacity of this layout, includes opacity of all the parents.
+    opacity: f32,
+    // Blend mode of this layout. Blend modes of parents are applied to the layers
+    // that contain this layout.
+    blend_mode: BlendMode,
     masks: Vec<Mask>,
     content: RenderLayoutContent,
 }
@@ -76,26 +98,63 @@ struct RenderLayout {
 enum RenderLayoutContent {
     Color {
         color: RGBAColor,
+        /// Replaces `color` if defined.
+        gradient: Option<Gradient>,
         border_color: RGBAColor,
+        /// Replaces `border_color` if defined.
+        border_gradient: Option<Gradient>,
         border_width: f32,
     },
     ChildNode {
-        index: usize,
+        source: TextureSource,
         border_color: RGBAColor,
         border_width: f32,
         crop: Crop,
+        gradient_fill: Option<GradientFill>,
     },
-    #[allow(dead_code)]
-    BoxShadow { color: RGBAColor, blur_radius: f32 },
+    /// Blurred copy of everything rendered before this layout.
+    BackdropBlur { blur_radius: f32 },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TextureSource {
+    /// Index of the input node.
+    ChildNode(usize),
+    /// Index of the layer.
+    Layer(usize),
+}
+
+/// Layout subtree rendered to an offscreen texture, so it can be blurred,
+/// made transparent or blended as a whole. Layers are ordered in a way that
+/// layers used by other layers come first.
+#[derive(Debug)]
+struct Layer {
+    layouts: Vec<RenderLayout>,
+    resolution: Resolution,
+    blur_radius: f32,
 }
 
 #[derive(Debug, Clone)]
 pub enum LayoutContent {
     Color(RGBAColor),
-    ChildNode { index: usize, size: Size },
+    Gradient(Gradient),
+    ChildNode {
+        index: usize,
+        size: Size,
+        gradient_fill: Option<GradientFill>,
+    },
     None,
 }
 
+/// Replaces colors of a child node with a gradient. Alpha of the texture defines
+/// where the gradient is visible, e.g. white glyphs of a text.
+#[derive(Debug, Clone)]
+pub struct GradientFill {
+    pub gradient: Gradient,
+    /// Rendered where the texture is transparent.
+    pub background_color: RGBAColor,
+}
+
 #[derive(Debug, Clone)]
 pub struct NestedLayout {
     // top-left corner, includes border of current element
@@ -133,8 +192,24 @@ pub struct NestedLayout {
 
     pub border_width: f32,
     pub border_color: RGBAColor,
+    /// Replaces `border_color` if defined.
+    pub border_gradient: Option<Gradient>,
     pub border_radius: BorderRadius,
     pub box_shadow: Vec<BoxShadow>,
+    /// Defines how this layout, its box shadows and all of its children are composed
+    /// with everything that was rendered before them. Layout with children is composed
+    /// with them first and the result is blended as a whole.
+    pub blend_mode: BlendMode,
+    /// Value between 0.0 and 1.0. Affects this layout, its box shadows and all
+    /// of its children. Layout with children is composed with them first and
+    /// the result is made transparent as a whole.
+    pub opacity: f32,
+    /// Blur applied to this layout and all of its children. Box shadows are not
+    /// affected.
+    pub blur_radius: f32,
+    /// Blur applied to everything rendered before this layout, visible in the area
+    /// of this layout.
+    pub backdrop_blur: f32,
 
     pub(crate) children: Vec<NestedLayout>,
     /// Describes how many children of this component are nodes. This value also
@@ -154,6 +229,9 @@ impl LayoutNode {
         Self {
             layout_provider,
             shader,
+            backdrop: NodeTexture::new(),
+            blur_textures: BlurTextures::default(),
+            layers: vec![],
         }
     }
 
@@ -170,29 +248,77 @@ impl LayoutNode {
             .collect();
         let output_resolution = self.layout_provider.resolution(pts);
         let layouts = self.layout_provider.layouts(pts, &input_resolutions);
-        let layouts = layouts.flatten(&input_resolutions, output_resolution);
+        let (layouts, layers) = layouts.flatten(&input_resolutions, output_resolution);
 
-        let textures: Vec<Option<&NodeTexture>> = layouts
-            .iter()
-            .map(|layout| match layout.content {
-                RenderLayoutContent::BoxShadow { .. } => None,
-                RenderLayoutContent::Color { .. } => None,
-                RenderLayoutContent::ChildNode { index, .. } => match sources.get(index) {
-                    Some(node_texture) => Some(*node_texture),
-                    None => {
-                        error!("Invalid source index in layout");
-                        None
-                    }
-                },
-            })
-            .collect();
+        self.layers.resize_with(layers.len(), || LayerTextures {
+            texture: NodeTexture::new(),
+            backdrop: NodeTexture::new(),
+            blur_textures: BlurTextures::default(),
+        });
+        for (index, layer) in layers.into_iter().enumerate() {
+            let (rendered_layers, remaining_layers) = self.layers.split_at_mut(index);
+            let layer_textures = &mut remaining_layers[0];
+            let textures = layout_textures(&layer.layouts, sources, rendered_layers);
+            let layer_target = layer_textures
+                .texture
+                .ensure_size(ctx.wgpu_ctx, layer.resolution);
+            self.shader.render(
+                ctx.wgpu_ctx,
+                layer.resolution,
+                layer.layouts,
+                &textures,
+                layer_target,
+                &mut layer_textures.backdrop,
+                &mut layer_textures.blur_textures,
+            );
+            if layer.blur_radius > 0.0 {
+                self.shader.blur(
+                    ctx.wgpu_ctx,
+                    layer_target,
+                    &mut layer_textures.blur_textures,
+                    layer.blur_radius,
+                );
+            }
+        }
 
+        let textures = layout_textures(&layouts, sources, &self.layers);
         let target = target.ensure_size(ctx.wgpu_ctx, output_resolution);
-        self.shader
-            .render(ctx.wgpu_ctx, output_resolution, layouts, &textures, target);
+        self.shader.render(
+            ctx.wgpu_ctx,
+            output_resolution,
+            layouts,
+            &textures,
+            target,
+            &mut self.backdrop,
+            &mut self.blur_textures,
+        );
     }
 }
 
+fn layout_textures<'a>(
+    layouts: &[RenderLayout],
+    sources: &[&'a NodeTexture],
+    layers: &'a [LayerTextures],
+) -> Vec<Option<&'a NodeTexture>> {
+    layouts
+        .iter()
+        .map(|layout| match layout.content {
+            RenderLayoutContent::Color { .. } => None,
+            RenderLayoutContent::BackdropBlur { .. } => None,
+            RenderLayoutContent::ChildNode { source, .. } => {
+                let texture = match source {
+                    TextureSource::ChildNode(index) => sources.get(index).copied(),
+                    TextureSource::Layer(index) => layers.get(index).map(|layer| &layer.texture),
+                };
+                if texture.is_none() {
+                    error!("Invalid source index in layout");
+                }
+                texture
+            }
+        })
+        .collect()
+}
+
 impl NestedLayout {
     /// NestedLayout that won't ever be rendered. It's intended to be optimized out
     /// in the flattening process. Its only purpose is to keep track of child nodes that are not
@@ -213,8 +339,13 @@ impl NestedLayout {
             child_nodes_count,
             border_width: 0.0,
             border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
             border_radius: BorderRadius::ZERO,
             box_shadow: vec![],
+            blend_mode: BlendMode::Normal,
+            opacity: 1.0,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
         }
     }
 }
@@ -14,17 +14,6 @@ struct VertexOutput {
     @location(2) center_position: vec2<f32>,
 }
 
-struct BoxShadowParams {
-    border_radius: vec4<f32>,
-    color: vec4<f32>,
-    top: f32,
-    left: f32,
-    width: f32,
-    height: f32,
-    rotation_degrees: f32,
-    blur_radius: f32,
-}
-
 struct TextureParams {
     border_radius: vec4<f32>,
     border_color: vec4<f32>,
@@ -42,6 +31,10 @@ struct TextureParams {
     rotation_degrees: f32,
     // border size in pixels
     border_width: f32,
+    // index in gradient_params or -1, if defined texture alpha is filled with the gradient
+    gradient: i32,
+    // rendered below the gradient fill
+    background_color: vec4<f32>,
 }
 
 struct ColorParams {
@@ -56,6 +49,23 @@ struct ColorParams {
 
     rotation_degrees: f32,
     border_width: f32,
+
+    // indexes in gradient_params or -1, if defined they replace color and border_color
+    gradient: i32,
+    border_gradient: i32,
+}
+
+struct GradientParams {
+    // 0 -> Linear, 1 -> Radial, 2 -> Conic
+    kind: u32,
+    stops_len: u32,
+    angle_degrees: f32,
+    // center as a fraction of the size
+    center_x: f32,
+    center_y: f32,
+    // offsets of the color stops, 4 per element
+    offsets: array<vec4<f32>, 2>,
+    colors: array<vec4<f32>, 8>,
 }
 
 struct ParentMask {
@@ -67,19 +77,25 @@ struct ParentMask {
 }
 
 struct LayoutInfo {
-    // 0 -> Texture, 1 -> Color, 2 -> BoxShadow
+    // 0 -> Texture, 1 -> Color, 2 -> BackdropBlur
     layout_type: u32,
     index: u32,
-    masks_len: u32
+    masks_len: u32,
+    opacity: f32,
+    // 0 -> Normal, 1 -> Multiply, 2 -> Screen, 3 -> Overlay, 4 -> Add, 5 -> Difference
+    blend_mode: u32,
 }
 
 
 @group(0) @binding(0) var texture: texture_2d<f32>;
+// Copy of everything rendered before the current layout. Bound only
+// if blend_mode is not Normal or for BackdropBlur layouts (already blurred).
+@group(0) @binding(1) var backdrop_texture: texture_2d<f32>;
 
 @group(1) @binding(0) var<uniform> output_resolution: vec4<f32>;
 @group(1) @binding(1) var<uniform> texture_params: array<TextureParams, 100>;
 @group(1) @binding(2) var<uniform> color_params: array<ColorParams, 100>;
-@group(1) @binding(3) var<uniform> box_shadow_params: array<BoxShadowParams, 100>;
+@group(1) @binding(3) var<uniform> gradient_params: array<GradientParams, 200>;
 
 @group(2) @binding(0) var<uniform> masks: array<ParentMask, 20>;
 
@@ -192,8 +208,8 @@ fn vs_main(input: VertexInput) -> VertexOutput {
             let rect_size = vec2<f32>(texture_params[layout_info.index].width, texture_params[layout_info.index].height);
             output.center_position = input.position.xy / 2.0 * rect_size;
         }
-        // color
-        case 1u: {
+        // color, backdrop blur uses the same params
+        case 1u, 2u: {
             let vertices_transformation = vertices_transformation_matrix(
                 color_params[layout_info.index].left,
                 color_params[layout_info.index].top,
@@ -206,23 +222,6 @@ fn vs_main(input: VertexInput) -> VertexOutput {
             let rect_size = vec2<f32>(color_params[layout_info.index].width, color_params[layout_info.index].height);
             output.center_position = input.position.xy / 2.0 * rect_size;
         }
-        // box shadow
-        case 2u:  {
-            let width = box_shadow_params[layout_info.index].width + 2.0 * box_shadow_params[layout_info.index].blur_radius;
-            let height = box_shadow_params[layout_info.index].height + 2.0 * box_shadow_params[layout_info.index].blur_radius;
-
-            let vertices_transformation = vertices_transformation_matrix(
-                box_shadow_params[layout_info.index].left - box_shadow_params[layout_info.index].blur_radius,
-                box_shadow_params[layout_info.index].top - box_shadow_params[layout_info.index].blur_radius,
-                width,
-                height,
-                box_shadow_params[layout_info.index].rotation_degrees
-            );
-            output.position = vertices_transformation * vec4(input.position, 1.0);
-            output.tex_coords = input.tex_coords;
-            let rect_size = vec2<f32>(width, height);
-            output.center_position = input.position.xy / 2.0 * rect_size;
-        }
         default {}
     }
 
@@ -250,11 +249,143 @@ fn roundedRectSDF(dist: vec2<f32>, size: vec2<f32>, radius: vec4<f32>, rotation:
     return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0, 0.0))) - r.x;
 }
 
+// Position relative to the top-left corner of the rectangle
+fn rect_position(center_position: vec2<f32>, size: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(center_position.x, -center_position.y) + size / 2.0;
+}
+
+// Offset of the color stop of the gradient at `index`
+fn gradient_offset(index: i32, stop: i32) -> f32 {
+    return gradient_params[index].offsets[stop / 4][stop % 4];
+}
+
+// Color of the gradient at `position` in a rectangle of `size`
+fn gradient_color(index: i32, position: vec2<f32>, size: vec2<f32>) -> vec4<f32> {
+    let center = vec2<f32>(gradient_params[index].center_x, gradient_params[index].center_y) * size;
+    let from_center = position - center;
+
+    var offset = 0.0;
+    switch gradient_params[index].kind {
+        // radial, ends in the farthest corner
+        case 1u: {
+            let farthest_corner = max(center, size - center);
+            offset = length(from_center) / max(length(farthest_corner), 0.0001);
+        }
+        // conic, angle is measured clockwise from the top
+        case 2u: {
+            let angle = degrees(atan2(from_center.x, -from_center.y));
+            offset = fract((angle - gradient_params[index].angle_degrees) / 360.0);
+        }
+        // linear, gradient line is long enough to reach the corners
+        default {
+            let angle = radians(gradient_params[index].angle_degrees);
+            let direction = vec2<f32>(sin(angle), -cos(angle));
+            let line_length = abs(size.x * direction.x) + abs(size.y * direction.y);
+            offset = dot(from_center, direction) / max(line_length, 0.0001) + 0.5;
+        }
+    }
+
+    let stops_len = i32(gradient_params[index].stops_len);
+    if (offset <= gradient_offset(index, 0)) {
+        return gradient_params[index].colors[0];
+    }
+    for (var i = 1; i < stops_len; i++) {
+        let stop_offset = gradient_offset(index, i);
+        if (offset < stop_offset) {
+            let previous_offset = gradient_offset(index, i - 1);
+            let progress = (offset - previous_offset) / max(stop_offset - previous_offset, 0.0001);
+            return mix_colors(
+                gradient_params[index].colors[i - 1],
+                gradient_params[index].colors[i],
+                progress
+            );
+        }
+    }
+    return gradient_params[index].colors[stops_len - 1];
+}
+
+// Colors are premultiplied before mixing, so transparent stops do not darken the gradient.
+fn mix_colors(start: vec4<f32>, end: vec4<f32>, progress: f32) -> vec4<f32> {
+    let color = mix(vec4<f32>(start.rgb * start.a, start.a), vec4<f32>(end.rgb * end.a, end.a), progress);
+    if (color.a < 0.0001) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
+
+// Composes `top` color over `bottom` color
+fn compose(top: vec4<f32>, bottom: vec4<f32>) -> vec4<f32> {
+    let alpha = top.a + bottom.a * (1.0 - top.a);
+    if (alpha < 0.0001) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>((top.rgb * top.a + bottom.rgb * bottom.a * (1.0 - top.a)) / alpha, alpha);
+}
+
+fn linear_to_srgb(color: vec3<f32>) -> vec3<f32> {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, color <= vec3<f32>(0.0031308));
+}
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, color <= vec3<f32>(0.04045));
+}
+
+// Arguments and the result are sRGB encoded, blend modes are defined for
+// gamma-encoded colors (like in CSS), so they can't be applied to linear values.
+fn blend(backdrop: vec3<f32>, source: vec3<f32>) -> vec3<f32> {
+    switch layout_info.blend_mode {
+        case 1u: {
+            return backdrop * source;
+        }
+        case 2u: {
+            return backdrop + source - (backdrop * source);
+        }
+        case 3u: {
+            return select(
+                1.0 - 2.0 * (1.0 - backdrop) * (1.0 - source),
+                2.0 * backdrop * source,
+                backdrop <= vec3<f32>(0.5)
+            );
+        }
+        case 4u: {
+            return min(backdrop + source, vec3<f32>(1.0));
+        }
+        case 5u: {
+            return abs(backdrop - source);
+        }
+        default {
+            return source;
+        }
+    }
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let color = layout_color(input);
+    if (layout_info.blend_mode == 0u) {
+        return color;
+    }
+
+    // Blended color replaces the source color only where the backdrop is visible,
+    // alpha is composed by the pipeline blend state as for normal layouts.
+    let backdrop = textureLoad(backdrop_texture, vec2<i32>(input.position.xy), 0);
+    let blended = srgb_to_linear(blend(
+        linear_to_srgb(clamp(backdrop.rgb, vec3<f32>(0.0), vec3<f32>(1.0))),
+        linear_to_srgb(clamp(color.rgb, vec3<f32>(0.0), vec3<f32>(1.0))),
+    ));
+    return vec4<f32>(mix(color.rgb, blended, backdrop.a), color.a);
+}
+
+fn layout_color(input: VertexOutput) -> vec4<f32> {
     let transparent = vec4<f32>(1.0, 1.0, 1.0, 0.0);
 
-    var mask_alpha = 1.0;
+    // Opacity is applied to every layout separately, so it is multiplied
+    // together with the masks.
+    var mask_alpha = layout_info.opacity;
 
     for (var i = 0; i < i32(layout_info.masks_len); i++) {
         let radius = masks[i].radius;
@@ -275,7 +406,7 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
 
     switch layout_info.layout_type {
         case 0u: {
-            let sample = textureSample(texture, sampler_, input.tex_coords);
+            var sample = textureSample(texture, sampler_, input.tex_coords);
 
             let width = texture_params[layout_info.index].width;
             let height = texture_params[layout_info.index].height;
@@ -283,8 +414,16 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
             let rotation_degrees = texture_params[layout_info.index].rotation_degrees;
             let border_width = texture_params[layout_info.index].border_width;
             let border_color = texture_params[layout_info.index].border_color;
+            let gradient = texture_params[layout_info.index].gradient;
 
             let size = vec2<f32>(width, height);
+            if (gradient >= 0) {
+                let fill = gradient_color(gradient, rect_position(input.center_position, size), size);
+                sample = compose(
+                    vec4<f32>(fill.rgb, fill.a * sample.a),
+                    texture_params[layout_info.index].background_color
+                );
+            }
             let edge_distance = -roundedRectSDF(
                 input.center_position,
                 size, 
@@ -311,16 +450,25 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
             }
         }
         case 1u: {
-            let color = color_params[layout_info.index].color;
+            var color = color_params[layout_info.index].color;
 
             let width = color_params[layout_info.index].width;
             let height = color_params[layout_info.index].height;
             let border_radius = color_params[layout_info.index].border_radius;
             let rotation_degrees = color_params[layout_info.index].rotation_degrees;
             let border_width = color_params[layout_info.index].border_width;
-            let border_color = color_params[layout_info.index].border_color;
+            var border_color = color_params[layout_info.index].border_color;
+            let gradient = color_params[layout_info.index].gradient;
+            let border_gradient = color_params[layout_info.index].border_gradient;
 
             let size = vec2<f32>(width, height);
+            let position = rect_position(input.center_position, size);
+            if (gradient >= 0) {
+                color = gradient_color(gradient, position, size);
+            }
+            if (border_gradient >= 0) {
+                border_color = gradient_color(border_gradient, position, size);
+            }
             let edge_distance = -roundedRectSDF(
                 input.center_position,
                 size, 
@@ -345,25 +493,23 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
             }
         }
         case 2u: {
-            let color = box_shadow_params[layout_info.index].color;
+            let backdrop = textureLoad(backdrop_texture, vec2<i32>(input.position.xy), 0);
 
-            let width = box_shadow_params[layout_info.index].width;
-            let height = box_shadow_params[layout_info.index].height;
-            let border_radius = box_shadow_params[layout_info.index].border_radius;
-            let rotation_degrees = box_shadow_params[layout_info.index].rotation_degrees;
-            let blur_radius = box_shadow_params[layout_info.index].blur_radius;
+            let width = color_params[layout_info.index].width;
+            let height = color_params[layout_info.index].height;
+            let border_radius = color_params[layout_info.index].border_radius;
+            let rotation_degrees = color_params[layout_info.index].rotation_degrees;
 
             let size = vec2<f32>(width, height);
             let edge_distance = -roundedRectSDF(
                 input.center_position,
-                size, 
-                border_radius, 
+                size,
+                border_radius,
                 rotation_degrees
             );
-            
-            let blur_alpha = smoothstep(-blur_radius / 2.0, blur_radius / 2.0, edge_distance) * mask_alpha;
 
-            return vec4<f32>(color.rgb, color.a * blur_alpha);
+            let content_alpha = smoothstep(-0.5, 0.5, edge_distance);
+            return vec4<f32>(backdrop.rgb, backdrop.a * content_alpha * mask_alpha);
         }
         default {
             return vec4(0.0, 0.0, 0.0, 0.0);
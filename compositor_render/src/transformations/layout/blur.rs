@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use crate::{
+    wgpu::{
+        common_pipeline,
+        texture::{NodeTexture, NodeTextureState},
+        WgpuCtx,
+    },
+    Resolution,
+};
+
+/// Maximal number of samples on each side of a pixel in a single blur pass,
+/// needs to match `MAX_SAMPLES` in `blur.wgsl`.
+const MAX_SAMPLES: f32 = 24.0;
+
+/// Distance from the edge of the content at which the blur is no longer visible.
+/// Kernel is cut off at 3 standard deviations, standard deviation is `radius / 2`.
+pub(super) fn blur_extent(radius: f32) -> f32 {
+    1.5 * radius
+}
+
+/// Separable Gaussian blur. Used to blur layouts with `blur_radius`, box shadows and
+/// the content behind layouts with `backdrop_blur`.
+///
+/// Textures are first downsampled, so a kernel of any radius fits in `MAX_SAMPLES`
+/// texels and each of them is sampled. Result is upsampled back to the original size.
+#[derive(Debug)]
+pub(super) struct BlurPipeline {
+    pipeline: wgpu::RenderPipeline,
+    texture_bgl: wgpu::BindGroupLayout,
+}
+
+/// Textures used by `BlurPipeline`, kept between frames so they are not reallocated.
+#[derive(Default)]
+pub(super) struct BlurTextures {
+    /// Stores the result of the horizontal pass.
+    intermediate: NodeTexture,
+    /// Downsampled copies of the blurred texture, each of them half the size of
+    /// the previous one.
+    levels: Vec<NodeTexture>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BlurPass {
+    Horizontal,
+    Vertical,
+    Downsample,
+    Upsample,
+}
+
+impl BlurPipeline {
+    pub fn new(wgpu_ctx: &Arc<WgpuCtx>) -> Self {
+        let shader_module = wgpu_ctx
+            .device
+            .create_shader_module(wgpu::include_wgsl!("./blur.wgsl"));
+        let texture_bgl = common_pipeline::create_single_texture_bgl(&wgpu_ctx.device);
+
+        let pipeline_layout =
+            wgpu_ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("blur pipeline layout"),
+                    bind_group_layouts: &[&texture_bgl],
+                    push_constant_ranges: &[wgpu::PushConstantRange {
+                        stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        range: 0..16,
+                    }],
+                });
+
+        // Every pass overwrites the entire target, so the result is not blended
+        // with the previous content.
+        let pipeline = common_pipeline::create_render_pipeline_with_blend(
+            &wgpu_ctx.device,
+            &pipeline_layout,
+            &shader_module,
+            None,
+        );
+
+        Self {
+            pipeline,
+            texture_bgl,
+        }
+    }
+
+    /// Blurs `texture` in place.
+    pub fn encode(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &NodeTextureState,
+        textures: &mut BlurTextures,
+        radius: f32,
+    ) {
+        let levels_count = downsample_levels(radius, texture.resolution());
+        let mut resolution = texture.resolution();
+        textures
+            .levels
+            .resize_with(levels_count, NodeTexture::default);
+        for level in textures.levels.iter_mut() {
+            resolution = Resolution {
+                width: resolution.width.div_ceil(2),
+                height: resolution.height.div_ceil(2),
+            };
+            level.ensure_size(wgpu_ctx, resolution);
+        }
+        let intermediate = textures.intermediate.ensure_size(wgpu_ctx, resolution);
+
+        let levels: Vec<&NodeTextureState> = std::iter::once(texture)
+            .chain(textures.levels.iter().filter_map(NodeTexture::state))
+            .collect();
+        for pair in levels.windows(2) {
+            self.encode_pass(
+                wgpu_ctx,
+                encoder,
+                pair[0],
+                pair[1],
+                BlurPass::Downsample,
+                0.0,
+            );
+        }
+
+        let smallest = levels[levels.len() - 1];
+        let radius = radius / (1 << levels_count) as f32;
+        self.encode_pass(
+            wgpu_ctx,
+            encoder,
+            smallest,
+            intermediate,
+            BlurPass::Horizontal,
+            radius,
+        );
+        self.encode_pass(
+            wgpu_ctx,
+            encoder,
+            intermediate,
+            smallest,
+            BlurPass::Vertical,
+            radius,
+        );
+
+        for pair in levels.windows(2).rev() {
+            self.encode_pass(wgpu_ctx, encoder, pair[1], pair[0], BlurPass::Upsample, 0.0);
+        }
+    }
+
+    fn encode_pass(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        encoder: &mut wgpu::CommandEncoder,
+        source: &NodeTextureState,
+        target: &NodeTextureState,
+        pass: BlurPass,
+        radius: f32,
+    ) {
+        let texture_bg = wgpu_ctx
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("blur texture bind group"),
+                layout: &self.texture_bgl,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(
+                        &source.rgba_texture().texture().view,
+                    ),
+                }],
+            });
+
+        let (direction, pass_type) = match pass {
+            BlurPass::Horizontal => ([1.0f32, 0.0], 0u32),
+            BlurPass::Vertical => ([0.0, 1.0], 0),
+            BlurPass::Downsample => ([0.0, 0.0], 1),
+            BlurPass::Upsample => ([0.0, 0.0], 2),
+        };
+        let mut push_constants = [0u8; 16];
+        push_constants[0..4].copy_from_slice(&direction[0].to_le_bytes());
+        push_constants[4..8].copy_from_slice(&direction[1].to_le_bytes());
+        push_constants[8..12].copy_from_slice(&radius.to_le_bytes());
+        push_constants[12..16].copy_from_slice(&pass_type.to_le_bytes());
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("blur"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+                view: &target.rgba_texture().texture().view,
+                resolve_target: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_push_constants(wgpu::ShaderStages::VERTEX_FRAGMENT, 0, &push_constants);
+        render_pass.set_bind_group(0, &texture_bg, &[]);
+
+        wgpu_ctx.plane.draw(&mut render_pass);
+    }
+}
+
+/// Number of times the texture is downsampled before it is blurred, so the
+/// kernel does not extend further than `MAX_SAMPLES` texels. Textures are not
+/// downsampled below 1 pixel, kernel is cut off in that case.
+fn downsample_levels(radius: f32, resolution: Resolution) -> usize {
+    let max_levels = usize::min(resolution.width, resolution.height)
+        .max(1)
+        .ilog2() as usize;
+    let levels = (blur_extent(radius) / MAX_SAMPLES).log2().ceil();
+    match levels > 0.0 {
+        true => usize::min(levels as usize, max_levels),
+        false => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Resolution;
+
+    use super::downsample_levels;
+
+    const FULL_HD: Resolution = Resolution {
+        width: 1920,
+        height: 1080,
+    };
+
+    #[test]
+    fn small_radius_is_not_downsampled() {
+        assert_eq!(downsample_levels(0.0, FULL_HD), 0);
+        assert_eq!(downsample_levels(16.0, FULL_HD), 0);
+    }
+
+    #[test]
+    fn kernel_fits_in_max_samples_after_downsampling() {
+        assert_eq!(downsample_levels(17.0, FULL_HD), 1);
+        assert_eq!(downsample_levels(32.0, FULL_HD), 1);
+        assert_eq!(downsample_levels(100.0, FULL_HD), 3);
+    }
+
+    #[test]
+    fn texture_is_not_downsampled_below_one_pixel() {
+        let resolution = Resolution {
+            width: 1000,
+            height: 5,
+        };
+        assert_eq!(downsample_levels(1000.0, resolution), 2);
+    }
+}
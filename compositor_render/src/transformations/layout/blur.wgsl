@@ -0,0 +1,108 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+struct BlurInfo {
+    // (1, 0) for a horizontal pass, (0, 1) for a vertical pass
+    direction: vec2<f32>,
+    // in texels of the source texture, standard deviation is equal to radius / 2
+    radius: f32,
+    // 0 -> Gaussian blur pass, 1 -> downsample to half size, 2 -> upsample to target size
+    pass_type: u32,
+}
+
+var<push_constant> blur_info: BlurInfo;
+
+@group(0) @binding(0) var texture: texture_2d<f32>;
+
+// Maximal number of samples on each side of the current pixel. Textures are downsampled
+// before the blur, so the kernel of larger radiuses still covers every texel.
+const MAX_SAMPLES: i32 = 24;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    output.position = vec4(input.position, 1.0);
+    output.tex_coords = input.tex_coords;
+
+    return output;
+}
+
+// Colors are premultiplied before they are summed, otherwise fully transparent
+// pixels would darken the edges of the content.
+fn premultiplied(color: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(color.rgb * color.a, color.a);
+}
+
+fn unpremultiplied(color: vec4<f32>) -> vec4<f32> {
+    if (color.a < 0.0001) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
+
+fn load(coords: vec2<i32>) -> vec4<f32> {
+    let max_coords = vec2<i32>(textureDimensions(texture)) - 1;
+    return premultiplied(textureLoad(texture, clamp(coords, vec2<i32>(0), max_coords), 0));
+}
+
+fn blur(position: vec2<f32>) -> vec4<f32> {
+    let coords = vec2<i32>(floor(position));
+    let direction = vec2<i32>(blur_info.direction);
+    let sigma = max(blur_info.radius / 2.0, 0.5);
+    // Kernel is cut off at 3 sigma, weights beyond that are negligible.
+    let samples = min(i32(ceil(3.0 * sigma)), MAX_SAMPLES);
+
+    var sum = vec4<f32>(0.0);
+    var weights_sum = 0.0;
+    for (var i = -samples; i <= samples; i++) {
+        let offset = f32(i);
+        let weight = exp(-(offset * offset) / (2.0 * sigma * sigma));
+        sum += load(coords + direction * i) * weight;
+        weights_sum += weight;
+    }
+    return sum / weights_sum;
+}
+
+// Averages 2x2 block of texels. Odd texture sizes repeat the last row or column.
+fn downsample(position: vec2<f32>) -> vec4<f32> {
+    let coords = vec2<i32>(floor(position)) * 2;
+    let sum = load(coords)
+        + load(coords + vec2<i32>(1, 0))
+        + load(coords + vec2<i32>(0, 1))
+        + load(coords + vec2<i32>(1, 1));
+    return sum / 4.0;
+}
+
+// Bilinear interpolation of premultiplied colors. Sampler would interpolate
+// colors of transparent texels with the same weight as the opaque ones.
+fn upsample(tex_coords: vec2<f32>) -> vec4<f32> {
+    let position = tex_coords * vec2<f32>(textureDimensions(texture)) - 0.5;
+    let coords = vec2<i32>(floor(position));
+    let t = fract(position);
+    let top = mix(load(coords), load(coords + vec2<i32>(1, 0)), t.x);
+    let bottom = mix(load(coords + vec2<i32>(0, 1)), load(coords + vec2<i32>(1, 1)), t.x);
+    return mix(top, bottom, t.y);
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    switch (blur_info.pass_type) {
+        case 1u: {
+            return unpremultiplied(downsample(input.position.xy));
+        }
+        case 2u: {
+            return unpremultiplied(upsample(input.tex_coords));
+        }
+        default: {
+            return unpremultiplied(blur(input.position.xy));
+        }
+    }
+}
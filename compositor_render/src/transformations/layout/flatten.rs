@@ -1,21 +1,47 @@
 use std::{iter, mem};
 
-use crate::{scene::RGBAColor, Resolution};
+use crate::{
+    scene::{BlendMode, BorderRadius, RGBAColor},
+    Resolution,
+};
 
 use super::{
-    BoxShadow, Crop, LayoutContent, Mask, NestedLayout, RenderLayout, RenderLayoutContent,
+    blur::blur_extent, BoxShadow, Crop, Layer, LayoutContent, Mask, NestedLayout, RenderLayout,
+    RenderLayoutContent, TextureSource,
 };
 
 impl NestedLayout {
+    /// Returns layouts that should be rendered on the output and layers that need
+    /// to be rendered before them.
     pub(super) fn flatten(
         self,
         input_resolutions: &[Option<Resolution>],
         resolution: Resolution,
+    ) -> (Vec<RenderLayout>, Vec<Layer>) {
+        let mut layers = vec![];
+        let (shadow, layouts) = self.inner_flatten(0, vec![], &mut layers);
+        let layouts = Self::finalize_layouts(
+            shadow.into_iter().chain(layouts),
+            input_resolutions,
+            resolution,
+        );
+        let layers = layers
+            .into_iter()
+            .map(|layer| Layer {
+                layouts: Self::finalize_layouts(layer.layouts, input_resolutions, layer.resolution),
+                ..layer
+            })
+            .collect();
+        (layouts, layers)
+    }
+
+    fn finalize_layouts(
+        layouts: impl IntoIterator<Item = RenderLayout>,
+        input_resolutions: &[Option<Resolution>],
+        resolution: Resolution,
     ) -> Vec<RenderLayout> {
-        let (shadow, layouts) = self.inner_flatten(0, vec![]);
-        shadow
+        layouts
             .into_iter()
-            .chain(layouts)
             .filter(|layout| Self::should_render(layout, input_resolutions, resolution))
             .map(NestedLayout::fix_final_render_layout)
             .collect()
@@ -25,12 +51,22 @@ impl NestedLayout {
         mut self,
         child_index_offset: usize,
         parent_masks: Vec<Mask>,
+        layers: &mut Vec<Layer>,
     ) -> (Vec<RenderLayout>, Vec<RenderLayout>) {
+        if self.blur_radius > 0.0 || self.is_group() {
+            return self.layer_flatten(child_index_offset, parent_masks, layers);
+        }
         let mut child_index_offset = child_index_offset;
-        if let LayoutContent::ChildNode { index, size } = self.content {
+        if let LayoutContent::ChildNode {
+            index,
+            size,
+            gradient_fill,
+        } = self.content
+        {
             self.content = LayoutContent::ChildNode {
                 index: index + child_index_offset,
                 size,
+                gradient_fill,
             };
             child_index_offset += 1
         }
@@ -40,7 +76,7 @@ impl NestedLayout {
         let box_shadow_layouts = self
             .box_shadow
             .iter()
-            .map(|shadow| self.box_shadow_layout(shadow, &parent_masks))
+            .map(|shadow| self.box_shadow_layout(shadow, &parent_masks, layers))
             .collect();
 
         let parent_masks = match &self.mask {
@@ -59,7 +95,7 @@ impl NestedLayout {
                 .map(|child| {
                     let child_nodes_count = child.child_nodes_count;
                     let (shadows, layouts) =
-                        child.inner_flatten(child_index_offset, parent_masks.clone());
+                        child.inner_flatten(child_index_offset, parent_masks.clone(), layers);
                     child_index_offset += child_nodes_count;
                     (shadows, layouts)
                 })
@@ -75,12 +111,194 @@ impl NestedLayout {
             .map(|l| self.flatten_child(l))
             .collect();
 
+        let backdrop_blur_layout = match self.backdrop_blur > 0.0 {
+            true => vec![self.backdrop_blur_layout(&layout)],
+            false => vec![],
+        };
+
+        (
+            box_shadow_layouts,
+            [
+                backdrop_blur_layout,
+                vec![layout],
+                children_shadow,
+                children_layouts,
+            ]
+            .concat(),
+        )
+    }
+
+    /// Transparent layouts or layouts with a blend mode are composed with their
+    /// children first, so opacity and blend mode are applied to the result once.
+    /// Otherwise, overlapping children would be visible through each other.
+    ///
+    /// If nothing can overlap (e.g. a single child without a background), opacity
+    /// is applied to the child directly and an offscreen layer is not needed.
+    fn is_group(&self) -> bool {
+        if self.children.is_empty() {
+            return false;
+        }
+        self.blend_mode != BlendMode::Normal
+            || (self.opacity < 1.0 && self.has_overlapping_content())
+    }
+
+    /// Returns true if flattening this layout would produce more than one visible
+    /// layout in its area (ignoring its own box shadows and backdrop blur).
+    fn has_overlapping_content(&self) -> bool {
+        match self.children.as_slice() {
+            [] => false,
+            [child] => {
+                self.has_visible_content()
+                    || !child.box_shadow.is_empty()
+                    || child.backdrop_blur > 0.0
+                    || (child.blur_radius <= 0.0
+                        && !child.is_group()
+                        && child.has_overlapping_content())
+            }
+            _ => true,
+        }
+    }
+
+    fn has_visible_content(&self) -> bool {
+        let has_border =
+            self.border_width > 0.0 && (self.border_color.3 != 0 || self.border_gradient.is_some());
+        match self.content {
+            LayoutContent::Color(RGBAColor(_, _, _, 0)) | LayoutContent::None => has_border,
+            _ => true,
+        }
+    }
+
+    /// Flattens layout with `blur_radius` or a group layout into a separate layer.
+    /// Layer is rendered in coordinates of this layout (and blurred if needed).
+    /// Returned layouts only sample the layer texture.
+    ///
+    /// Blurred layouts are cut to their own bounds. Layer of a group is extended to
+    /// also include children and shadows that are outside of the layout.
+    fn layer_flatten(
+        mut self,
+        child_index_offset: usize,
+        parent_masks: Vec<Mask>,
+        layers: &mut Vec<Layer>,
+    ) -> (Vec<RenderLayout>, Vec<RenderLayout>) {
+        let box_shadow_layouts = self
+            .box_shadow
+            .iter()
+            .map(|shadow| self.box_shadow_layout(shadow, &parent_masks, layers))
+            .collect();
+
+        let is_blurred = self.blur_radius > 0.0;
+        let layer_root = NestedLayout {
+            top: 0.0,
+            left: 0.0,
+            rotation_degrees: 0.0,
+            box_shadow: vec![],
+            blend_mode: BlendMode::Normal,
+            opacity: 1.0,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+            children: mem::take(&mut self.children),
+            ..self.clone()
+        };
+        let (layer_shadows, layer_layouts) =
+            layer_root.inner_flatten(child_index_offset, vec![], layers);
+        let mut layer_layouts = [layer_shadows, layer_layouts].concat();
+
+        // If the layout has a mask, everything outside is cut off anyway.
+        let (margin_x, margin_y) = match is_blurred || self.mask.is_some() {
+            true => (0.0, 0.0),
+            false => self.layer_margin(&layer_layouts),
+        };
+        for layout in layer_layouts.iter_mut() {
+            layout.top += margin_y;
+            layout.left += margin_x;
+            for mask in layout.masks.iter_mut() {
+                mask.top += margin_y;
+                mask.left += margin_x;
+            }
+        }
+
+        let width = self.width + 2.0 * margin_x;
+        let height = self.height + 2.0 * margin_y;
+        layers.push(Layer {
+            layouts: layer_layouts,
+            resolution: Resolution {
+                width: f32::max(width.ceil(), 1.0) as usize,
+                height: f32::max(height.ceil(), 1.0) as usize,
+            },
+            blur_radius: self.blur_radius,
+        });
+
+        let layout = RenderLayout {
+            top: self.top - margin_y,
+            left: self.left - margin_x,
+            width,
+            height,
+            rotation_degrees: self.rotation_degrees,
+            content: RenderLayoutContent::ChildNode {
+                source: TextureSource::Layer(layers.len() - 1),
+                crop: Crop {
+                    top: 0.0,
+                    left: 0.0,
+                    width,
+                    height,
+                },
+                border_color: RGBAColor(0, 0, 0, 0),
+                border_width: 0.0,
+                gradient_fill: None,
+            },
+            // Blur spreads content outside of the rounded corners, so they are
+            // cut off again. Rounded corners of a group are already in the layer.
+            border_radius: match is_blurred {
+                true => self.border_radius,
+                false => BorderRadius::ZERO,
+            },
+            masks: parent_masks,
+            opacity: self.opacity,
+            blend_mode: self.blend_mode,
+        };
+        // Backdrop is not a part of the layer, it is blurred separately in the
+        // area of the layout itself.
+        let backdrop_blur_layout = match self.backdrop_blur > 0.0 {
+            true => vec![self.backdrop_blur_layout(&RenderLayout {
+                top: self.top,
+                left: self.left,
+                width: self.width,
+                height: self.height,
+                border_radius: self.border_radius,
+                ..layout.clone()
+            })],
+            false => vec![],
+        };
         (
             box_shadow_layouts,
-            [vec![layout], children_shadow, children_layouts].concat(),
+            [backdrop_blur_layout, vec![layout]].concat(),
         )
     }
 
+    /// Calculates how far `layouts` (in coordinates of this layout) extend beyond this
+    /// layout's bounds. Margins are the same on both sides, so the extended layer has
+    /// the same center and can be rotated like the layout itself.
+    fn layer_margin(&self, layouts: &[RenderLayout]) -> (f32, f32) {
+        layouts
+            .iter()
+            .fold((0.0, 0.0), |(margin_x, margin_y), layout| {
+                let (sin, cos) = layout.rotation_degrees.to_radians().sin_cos();
+                let half_width = (layout.width * cos.abs() + layout.height * sin.abs()) / 2.0;
+                let half_height = (layout.width * sin.abs() + layout.height * cos.abs()) / 2.0;
+                let center_x = layout.left + layout.width / 2.0;
+                let center_y = layout.top + layout.height / 2.0;
+
+                let overflow_x =
+                    f32::max(half_width - center_x, center_x + half_width - self.width);
+                let overflow_y =
+                    f32::max(half_height - center_y, center_y + half_height - self.height);
+                (
+                    f32::max(margin_x, overflow_x),
+                    f32::max(margin_y, overflow_y),
+                )
+            })
+    }
+
     // Final pass on each render layout, it applies following modifications:
     // - If border_width is between 0 and 1 set it to 1.
     // - Remove masks that don't do anything
@@ -125,6 +343,7 @@ impl NestedLayout {
     ) -> bool {
         if layout.width <= 0.0
             || layout.height <= 0.0
+            || layout.opacity <= 0.0
             || layout.top > resolution.height as f32
             || layout.left > resolution.width as f32
         {
@@ -133,18 +352,26 @@ impl NestedLayout {
         match &layout.content {
             RenderLayoutContent::Color {
                 color: RGBAColor(_, _, _, 0),
+                gradient: None,
                 border_color: RGBAColor(_, _, _, border_alpha),
                 border_width,
+                ..
             } => *border_alpha != 0 || *border_width > 0.0,
             RenderLayoutContent::Color { .. } => true,
             RenderLayoutContent::ChildNode {
                 crop,
-                index,
+                source,
                 border_color: RGBAColor(_, _, _, _),
                 border_width: _,
+                gradient_fill: _,
             } => {
                 // TODO: handle a case when only border is visible (currently impossible)
-                let size = input_resolutions.get(*index).copied().flatten();
+                let size = match source {
+                    TextureSource::ChildNode(index) => {
+                        input_resolutions.get(*index).copied().flatten()
+                    }
+                    TextureSource::Layer(_) => None,
+                };
                 if let Some(size) = size {
                     if crop.left > size.width as f32 || crop.top > size.height as f32 {
                         return false;
@@ -155,11 +382,7 @@ impl NestedLayout {
                 }
                 true
             }
-            RenderLayoutContent::BoxShadow {
-                color: RGBAColor(_, _, _, 0),
-                ..
-            } => false,
-            RenderLayoutContent::BoxShadow { .. } => true,
+            RenderLayoutContent::BackdropBlur { blur_radius } => *blur_radius > 0.0,
         }
     }
 
@@ -179,27 +402,32 @@ impl NestedLayout {
                 content: match child.content {
                     RenderLayoutContent::Color {
                         color,
+                        gradient,
                         border_color,
+                        border_gradient,
                         border_width,
                     } => RenderLayoutContent::Color {
                         color,
+                        gradient,
                         border_color,
+                        border_gradient,
                         border_width: border_width * unified_scale,
                     },
                     RenderLayoutContent::ChildNode {
-                        index,
+                        source,
                         border_color,
                         border_width,
                         crop,
+                        gradient_fill,
                     } => RenderLayoutContent::ChildNode {
-                        index,
+                        source,
                         border_color,
                         border_width: border_width * unified_scale,
                         crop,
+                        gradient_fill,
                     },
-                    RenderLayoutContent::BoxShadow { color, blur_radius } => {
-                        RenderLayoutContent::BoxShadow {
-                            color,
+                    RenderLayoutContent::BackdropBlur { blur_radius } => {
+                        RenderLayoutContent::BackdropBlur {
                             blur_radius: blur_radius * unified_scale,
                         }
                     }
@@ -208,6 +436,8 @@ impl NestedLayout {
                 // scaled
                 border_radius: child.border_radius * unified_scale,
                 masks: self.parent_parent_masks(&child.masks),
+                opacity: child.opacity * self.opacity,
+                blend_mode: child.blend_mode,
             },
             Some(crop) => {
                 // Below values are only correct if `crop` is in the same coordinate
@@ -226,7 +456,9 @@ impl NestedLayout {
                 match child.content.clone() {
                     RenderLayoutContent::Color {
                         color,
+                        gradient,
                         border_color,
+                        border_gradient,
                         border_width,
                     } => {
                         RenderLayout {
@@ -237,18 +469,23 @@ impl NestedLayout {
                             rotation_degrees: child.rotation_degrees + self.rotation_degrees, // TODO: not exactly correct
                             content: RenderLayoutContent::Color {
                                 color,
+                                gradient,
                                 border_color,
+                                border_gradient,
                                 border_width: border_width * unified_scale,
                             },
                             border_radius: child.border_radius * unified_scale,
                             masks: self.parent_parent_masks(&child.masks),
+                            opacity: child.opacity * self.opacity,
+                            blend_mode: child.blend_mode,
                         }
                     }
                     RenderLayoutContent::ChildNode {
-                        index,
+                        source,
                         crop: child_crop,
                         border_color,
                         border_width,
+                        gradient_fill,
                     } => {
                         // Calculate how much top/left coordinates changed when cropping. It represents
                         // how much was removed in layout coordinates. Ignore the change of a position that
@@ -275,30 +512,32 @@ impl NestedLayout {
                             height: cropped_height * self.scale_y,
                             rotation_degrees: child.rotation_degrees + self.rotation_degrees, // TODO: not exactly correct
                             content: RenderLayoutContent::ChildNode {
-                                index,
+                                source,
                                 crop,
                                 border_color,
                                 border_width,
+                                gradient_fill,
                             },
                             border_radius: child.border_radius * unified_scale,
                             masks: self.parent_parent_masks(&child.masks),
+                            opacity: child.opacity * self.opacity,
+                            blend_mode: child.blend_mode,
                         }
                     }
-                    RenderLayoutContent::BoxShadow { color, blur_radius } => {
-                        RenderLayout {
-                            top: self.top + (cropped_top * self.scale_y),
-                            left: self.left + (cropped_left * self.scale_x),
-                            width: cropped_width * self.scale_x,
-                            height: cropped_height * self.scale_y,
-                            rotation_degrees: child.rotation_degrees + self.rotation_degrees, // TODO: not exactly correct
-                            content: RenderLayoutContent::BoxShadow {
-                                color,
-                                blur_radius: blur_radius * unified_scale,
-                            },
-                            border_radius: child.border_radius * unified_scale,
-                            masks: self.parent_parent_masks(&child.masks),
-                        }
-                    }
+                    RenderLayoutContent::BackdropBlur { blur_radius } => RenderLayout {
+                        top: self.top + (cropped_top * self.scale_y),
+                        left: self.left + (cropped_left * self.scale_x),
+                        width: cropped_width * self.scale_x,
+                        height: cropped_height * self.scale_y,
+                        rotation_degrees: child.rotation_degrees + self.rotation_degrees, // TODO: not exactly correct
+                        content: RenderLayoutContent::BackdropBlur {
+                            blur_radius: blur_radius * unified_scale,
+                        },
+                        border_radius: child.border_radius * unified_scale,
+                        masks: self.parent_parent_masks(&child.masks),
+                        opacity: child.opacity * self.opacity,
+                        blend_mode: child.blend_mode,
+                    },
                 }
             }
         }
@@ -315,14 +554,27 @@ impl NestedLayout {
             width: self.width,
             height: self.height,
             rotation_degrees: self.rotation_degrees,
-            content: match self.content {
+            content: match &self.content {
                 LayoutContent::Color(color) => RenderLayoutContent::Color {
-                    color,
+                    color: *color,
+                    gradient: None,
+                    border_color: self.border_color,
+                    border_gradient: self.border_gradient.clone(),
+                    border_width: self.border_width,
+                },
+                LayoutContent::Gradient(gradient) => RenderLayoutContent::Color {
+                    color: RGBAColor(0, 0, 0, 0),
+                    gradient: Some(gradient.clone()),
                     border_color: self.border_color,
+                    border_gradient: self.border_gradient.clone(),
                     border_width: self.border_width,
                 },
-                LayoutContent::ChildNode { index, size } => RenderLayoutContent::ChildNode {
+                LayoutContent::ChildNode {
                     index,
+                    size,
+                    gradient_fill,
+                } => RenderLayoutContent::ChildNode {
+                    source: TextureSource::ChildNode(*index),
                     crop: Crop {
                         top: 0.0,
                         left: 0.0,
@@ -331,32 +583,106 @@ impl NestedLayout {
                     },
                     border_color: self.border_color,
                     border_width: self.border_width,
+                    gradient_fill: gradient_fill.clone(),
                 },
                 LayoutContent::None => RenderLayoutContent::Color {
                     color: RGBAColor(0, 0, 0, 0),
+                    gradient: None,
                     border_color: self.border_color,
+                    border_gradient: self.border_gradient.clone(),
                     border_width: self.border_width,
                 },
             },
             border_radius: self.border_radius,
             masks: parent_masks.to_vec(),
+            opacity: self.opacity,
+            blend_mode: self.blend_mode,
         }
     }
 
-    /// calculate RenderLayout for one of self box shadows
-    fn box_shadow_layout(&self, box_shadow: &BoxShadow, parent_masks: &[Mask]) -> RenderLayout {
+    /// Calculate RenderLayout that draws blurred backdrop in the area of `layout`.
+    fn backdrop_blur_layout(&self, layout: &RenderLayout) -> RenderLayout {
         RenderLayout {
+            content: RenderLayoutContent::BackdropBlur {
+                blur_radius: self.backdrop_blur,
+            },
+            // Blurred backdrop replaces content below, it is never blended with it.
+            blend_mode: BlendMode::Normal,
+            ..layout.clone()
+        }
+    }
+
+    /// calculate RenderLayout for one of self box shadows
+    ///
+    /// Shape of a blurred shadow is rendered to a separate layer, so it is blurred
+    /// the same way as layouts with `blur_radius`.
+    fn box_shadow_layout(
+        &self,
+        box_shadow: &BoxShadow,
+        parent_masks: &[Mask],
+        layers: &mut Vec<Layer>,
+    ) -> RenderLayout {
+        let shape = RenderLayout {
             top: self.top + box_shadow.offset_y,
             left: self.left + box_shadow.offset_x,
             width: self.width,
             height: self.height,
             rotation_degrees: self.rotation_degrees, // TODO: this is incorrect
-            border_radius: self.border_radius + (box_shadow.blur_radius / 2.0),
-            content: RenderLayoutContent::BoxShadow {
+            border_radius: self.border_radius,
+            content: RenderLayoutContent::Color {
                 color: box_shadow.color,
-                blur_radius: box_shadow.blur_radius,
+                gradient: None,
+                border_color: RGBAColor(0, 0, 0, 0),
+                border_gradient: None,
+                border_width: 0.0,
             },
             masks: parent_masks.to_vec(),
+            opacity: self.opacity,
+            blend_mode: self.blend_mode,
+        };
+        if box_shadow.blur_radius <= 0.0 || box_shadow.color.3 == 0 {
+            return shape;
+        }
+
+        let margin = blur_extent(box_shadow.blur_radius);
+        let width = self.width + 2.0 * margin;
+        let height = self.height + 2.0 * margin;
+        layers.push(Layer {
+            layouts: vec![RenderLayout {
+                top: margin,
+                left: margin,
+                rotation_degrees: 0.0,
+                masks: vec![],
+                opacity: 1.0,
+                blend_mode: BlendMode::Normal,
+                ..shape.clone()
+            }],
+            resolution: Resolution {
+                width: f32::max(width.ceil(), 1.0) as usize,
+                height: f32::max(height.ceil(), 1.0) as usize,
+            },
+            blur_radius: box_shadow.blur_radius,
+        });
+
+        RenderLayout {
+            top: shape.top - margin,
+            left: shape.left - margin,
+            width,
+            height,
+            border_radius: BorderRadius::ZERO,
+            content: RenderLayoutContent::ChildNode {
+                source: TextureSource::Layer(layers.len() - 1),
+                crop: Crop {
+                    top: 0.0,
+                    left: 0.0,
+                    width,
+                    height,
+                },
+                border_color: RGBAColor(0, 0, 0, 0),
+                border_width: 0.0,
+                gradient_fill: None,
+            },
+            ..shape
         }
     }
 
@@ -388,3 +714,280 @@ impl NestedLayout {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scene::{BlendMode, BorderRadius, RGBAColor},
+        Resolution,
+    };
+
+    use super::super::{
+        BoxShadow, LayoutContent, NestedLayout, RenderLayoutContent, TextureSource,
+    };
+
+    const RESOLUTION: Resolution = Resolution {
+        width: 100,
+        height: 100,
+    };
+
+    fn layout(left: f32, children: Vec<NestedLayout>) -> NestedLayout {
+        NestedLayout {
+            top: 0.0,
+            left,
+            width: 50.0,
+            height: 50.0,
+            rotation_degrees: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            crop: None,
+            mask: None,
+            content: LayoutContent::Color(RGBAColor(255, 0, 0, 255)),
+            children,
+            child_nodes_count: 0,
+            border_width: 0.0,
+            border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
+            border_radius: BorderRadius::ZERO,
+            box_shadow: vec![],
+            blend_mode: BlendMode::Normal,
+            opacity: 1.0,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+        }
+    }
+
+    fn overlapping_children() -> Vec<NestedLayout> {
+        vec![layout(0.0, vec![]), layout(25.0, vec![])]
+    }
+
+    #[test]
+    fn opaque_children_are_flattened() {
+        let (layouts, layers) = layout(0.0, overlapping_children()).flatten(&[], RESOLUTION);
+        assert_eq!(layouts.len(), 3);
+        assert!(layers.is_empty());
+    }
+
+    #[test]
+    fn transparent_layout_without_children_is_not_a_layer() {
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, vec![])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+        assert_eq!(layouts.len(), 1);
+        assert_eq!(layouts[0].opacity, 0.5);
+        assert!(layers.is_empty());
+    }
+
+    #[test]
+    fn transparent_layout_with_children_is_a_group() {
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, overlapping_children())
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert_eq!(layouts.len(), 1);
+        assert_eq!(layouts[0].opacity, 0.5);
+        assert!(matches!(
+            layouts[0].content,
+            RenderLayoutContent::ChildNode {
+                source: TextureSource::Layer(0),
+                ..
+            }
+        ));
+
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].blur_radius, 0.0);
+        assert_eq!(layers[0].layouts.len(), 3);
+        assert!(layers[0].layouts.iter().all(|layout| layout.opacity == 1.0));
+    }
+
+    #[test]
+    fn group_layer_includes_children_outside_of_the_layout() {
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, overlapping_children())
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        // Second child ends 25px to the right of the root, layer is extended on
+        // both sides to keep the same center.
+        assert_eq!((layouts[0].left, layouts[0].width), (-25.0, 100.0));
+        assert_eq!((layouts[0].top, layouts[0].height), (0.0, 50.0));
+        assert_eq!(
+            layers[0].resolution,
+            Resolution {
+                width: 100,
+                height: 50
+            }
+        );
+        let lefts: Vec<_> = layers[0].layouts.iter().map(|l| l.left).collect();
+        assert_eq!(lefts, vec![25.0, 25.0, 50.0]);
+    }
+
+    #[test]
+    fn group_layer_includes_box_shadows_of_children() {
+        let child = NestedLayout {
+            box_shadow: vec![BoxShadow {
+                offset_x: 0.0,
+                offset_y: 10.0,
+                blur_radius: 5.0,
+                color: RGBAColor(0, 0, 0, 255),
+            }],
+            ..layout(0.0, vec![])
+        };
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, vec![child])
+        };
+        let (layouts, _) = root.flatten(&[], RESOLUTION);
+
+        // Blur extends the shadow by 7.5px on each side.
+        assert_eq!((layouts[0].top, layouts[0].height), (-17.5, 85.0));
+        assert_eq!((layouts[0].left, layouts[0].width), (-7.5, 65.0));
+    }
+
+    #[test]
+    fn blurred_box_shadow_is_a_blurred_layer() {
+        let root = NestedLayout {
+            box_shadow: vec![BoxShadow {
+                offset_x: 10.0,
+                offset_y: 0.0,
+                blur_radius: 10.0,
+                color: RGBAColor(0, 0, 0, 128),
+            }],
+            ..layout(0.0, vec![])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert_eq!(layouts.len(), 2);
+        assert!(matches!(
+            layouts[0].content,
+            RenderLayoutContent::ChildNode {
+                source: TextureSource::Layer(0),
+                ..
+            }
+        ));
+        assert_eq!((layouts[0].left, layouts[0].width), (-5.0, 80.0));
+
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].blur_radius, 10.0);
+        assert_eq!(
+            layers[0].resolution,
+            Resolution {
+                width: 80,
+                height: 80
+            }
+        );
+        assert_eq!(layers[0].layouts.len(), 1);
+        assert_eq!(
+            (layers[0].layouts[0].left, layers[0].layouts[0].top),
+            (15.0, 15.0)
+        );
+    }
+
+    #[test]
+    fn sharp_box_shadow_is_not_a_layer() {
+        let root = NestedLayout {
+            box_shadow: vec![BoxShadow {
+                offset_x: 10.0,
+                offset_y: 10.0,
+                blur_radius: 0.0,
+                color: RGBAColor(0, 0, 0, 255),
+            }],
+            ..layout(0.0, vec![])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert!(layers.is_empty());
+        assert!(matches!(
+            layouts[0].content,
+            RenderLayoutContent::Color {
+                color: RGBAColor(0, 0, 0, 255),
+                ..
+            }
+        ));
+        assert_eq!((layouts[0].left, layouts[0].top), (10.0, 10.0));
+    }
+
+    #[test]
+    fn transparent_layout_with_single_child_is_not_a_group() {
+        let root = NestedLayout {
+            opacity: 0.5,
+            content: LayoutContent::None,
+            ..layout(0.0, vec![layout(25.0, vec![])])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert!(layers.is_empty());
+        // Root itself is transparent and not rendered.
+        assert_eq!(layouts.len(), 1);
+        assert_eq!(layouts[0].left, 25.0);
+        assert_eq!(layouts[0].opacity, 0.5);
+    }
+
+    #[test]
+    fn single_child_overlapping_background_is_a_group() {
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, vec![layout(25.0, vec![])])
+        };
+        let (_, layers) = root.flatten(&[], RESOLUTION);
+        assert_eq!(layers.len(), 1);
+    }
+
+    #[test]
+    fn blend_mode_is_applied_to_the_group() {
+        let screen_child = NestedLayout {
+            blend_mode: BlendMode::Screen,
+            ..layout(25.0, vec![])
+        };
+        let root = NestedLayout {
+            blend_mode: BlendMode::Multiply,
+            ..layout(0.0, vec![layout(0.0, vec![]), screen_child])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert_eq!(layouts.len(), 1);
+        assert_eq!(layouts[0].blend_mode, BlendMode::Multiply);
+
+        let blend_modes: Vec<_> = layers[0]
+            .layouts
+            .iter()
+            .map(|layout| layout.blend_mode)
+            .collect();
+        assert_eq!(
+            blend_modes,
+            vec![BlendMode::Normal, BlendMode::Normal, BlendMode::Screen]
+        );
+    }
+
+    #[test]
+    fn nested_transparent_groups() {
+        let child = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, overlapping_children())
+        };
+        let root = NestedLayout {
+            opacity: 0.5,
+            ..layout(0.0, vec![child])
+        };
+        let (layouts, layers) = root.flatten(&[], RESOLUTION);
+
+        assert_eq!(layouts.len(), 1);
+        // Inner group is rendered first, because the outer one samples it.
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].layouts.len(), 3);
+        assert_eq!(layers[1].layouts.len(), 2);
+        assert_eq!(layers[1].layouts[1].opacity, 0.5);
+        assert!(matches!(
+            layers[1].layouts[1].content,
+            RenderLayoutContent::ChildNode {
+                source: TextureSource::Layer(0),
+                ..
+            }
+        ));
+    }
+}
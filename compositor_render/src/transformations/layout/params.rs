@@ -4,29 +4,40 @@ use wgpu::{
     BindGroupLayoutDescriptor, BufferUsages,
 };
 
-use crate::{scene::RGBAColor, wgpu::WgpuCtx, Resolution};
+use crate::{
+    scene::{BlendMode, Gradient, GradientKind, RGBAColor},
+    utils::srgb_to_linear,
+    wgpu::WgpuCtx,
+    Resolution,
+};
 
 use super::{BorderRadius, RenderLayout};
 
 const MAX_MASKS: usize = 20;
 const MAX_LAYOUTS_COUNT: usize = 100;
-const TEXTURE_PARAMS_BUFFER_SIZE: usize = MAX_LAYOUTS_COUNT * 80;
+// Each layout can have a background and a border gradient.
+const MAX_GRADIENTS_COUNT: usize = MAX_LAYOUTS_COUNT * 2;
+const TEXTURE_PARAMS_BUFFER_SIZE: usize = MAX_LAYOUTS_COUNT * 96;
 const COLOR_PARAMS_SIZE: usize = MAX_LAYOUTS_COUNT * 80;
-const BOX_SHADOW_PARAMS_SIZE: usize = MAX_LAYOUTS_COUNT * 80;
+const GRADIENT_PARAMS_SIZE: usize = MAX_GRADIENTS_COUNT * 192;
 
 #[derive(Debug)]
 pub struct LayoutInfo {
     pub layout_type: u32,
     pub index: u32,
     pub masks_len: u32,
+    pub opacity: f32,
+    pub blend_mode: u32,
 }
 
 impl LayoutInfo {
-    pub fn to_bytes(&self) -> [u8; 16] {
-        let mut result = [0u8; 16];
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let mut result = [0u8; 20];
         result[0..4].copy_from_slice(&self.layout_type.to_le_bytes());
         result[4..8].copy_from_slice(&self.index.to_le_bytes());
         result[8..12].copy_from_slice(&self.masks_len.to_le_bytes());
+        result[12..16].copy_from_slice(&self.opacity.to_le_bytes());
+        result[16..20].copy_from_slice(&self.blend_mode.to_le_bytes());
         result
     }
 }
@@ -38,7 +49,7 @@ pub struct ParamsBindGroups {
     output_resolution_buffer: wgpu::Buffer,
     texture_params_buffer: wgpu::Buffer,
     color_params_buffer: wgpu::Buffer,
-    box_shadow_params_buffer: wgpu::Buffer,
+    gradient_params_buffer: wgpu::Buffer,
     pub bind_groups_2: Vec<(wgpu::BindGroup, wgpu::Buffer)>,
     pub bind_group_2_layout: wgpu::BindGroupLayout,
 }
@@ -48,7 +59,7 @@ impl ParamsBindGroups {
         let output_resolution_buffer = create_buffer(ctx, 16);
         let texture_params_buffer = create_buffer(ctx, TEXTURE_PARAMS_BUFFER_SIZE);
         let color_params_buffer = create_buffer(ctx, COLOR_PARAMS_SIZE);
-        let box_shadow_params_buffer = create_buffer(ctx, BOX_SHADOW_PARAMS_SIZE);
+        let gradient_params_buffer = create_buffer(ctx, GRADIENT_PARAMS_SIZE);
 
         let bind_group_1_layout = ctx
             .device
@@ -116,7 +127,7 @@ impl ParamsBindGroups {
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
-                    resource: box_shadow_params_buffer.as_entire_binding(),
+                    resource: gradient_params_buffer.as_entire_binding(),
                 },
             ],
         });
@@ -157,7 +168,7 @@ impl ParamsBindGroups {
             output_resolution_buffer,
             texture_params_buffer,
             color_params_buffer,
-            box_shadow_params_buffer,
+            gradient_params_buffer,
             bind_groups_2,
             bind_group_1_layout,
             bind_group_2_layout,
@@ -191,7 +202,7 @@ impl ParamsBindGroups {
 
         let mut texture_params = Vec::new();
         let mut color_params = Vec::new();
-        let mut box_shadow_params = Vec::new();
+        let mut gradient_params = Vec::new();
 
         for (index, layout) in layouts.iter().enumerate().take(MAX_LAYOUTS_COUNT) {
             let RenderLayout {
@@ -201,21 +212,33 @@ impl ParamsBindGroups {
                 height,
                 rotation_degrees,
                 border_radius,
+                opacity,
+                blend_mode,
                 masks,
                 content,
             } = layout;
             let border_radius_bytes = borders_radius_to_bytes(*border_radius);
+            // There is nothing to blend with below the first layout. Blurred backdrop
+            // replaces the content below, so it is never blended with it.
+            let blend_mode = match (index, content) {
+                (0, _) | (_, super::RenderLayoutContent::BackdropBlur { .. }) => 0,
+                _ => blend_mode_to_u32(*blend_mode),
+            };
 
             match content {
                 super::RenderLayoutContent::Color {
                     color,
+                    gradient,
                     border_color,
+                    border_gradient,
                     border_width,
                 } => {
                     let layout_info = LayoutInfo {
                         layout_type: 1,
                         index: color_params.len() as u32,
                         masks_len: masks.len() as u32,
+                        opacity: *opacity,
+                        blend_mode,
                     };
                     let mut color_params_bytes = [0u8; 80];
                     color_params_bytes[0..16].copy_from_slice(&border_radius_bytes);
@@ -227,21 +250,31 @@ impl ParamsBindGroups {
                     color_params_bytes[60..64].copy_from_slice(&height.to_le_bytes());
                     color_params_bytes[64..68].copy_from_slice(&rotation_degrees.to_le_bytes());
                     color_params_bytes[68..72].copy_from_slice(&border_width.to_le_bytes());
+                    color_params_bytes[72..76].copy_from_slice(
+                        &push_gradient(&mut gradient_params, gradient.as_ref()).to_le_bytes(),
+                    );
+                    color_params_bytes[76..80].copy_from_slice(
+                        &push_gradient(&mut gradient_params, border_gradient.as_ref())
+                            .to_le_bytes(),
+                    );
                     color_params.push(color_params_bytes);
                     layout_infos.push(layout_info);
                 }
                 super::RenderLayoutContent::ChildNode {
-                    index: _,
+                    source: _,
                     crop,
                     border_color,
                     border_width,
+                    gradient_fill,
                 } => {
                     let layout_info = LayoutInfo {
                         layout_type: 0,
                         index: texture_params.len() as u32,
                         masks_len: masks.len() as u32,
+                        opacity: *opacity,
+                        blend_mode,
                     };
-                    let mut texture_params_bytes = [0u8; 80];
+                    let mut texture_params_bytes = [0u8; 96];
                     texture_params_bytes[0..16].copy_from_slice(&border_radius_bytes);
                     texture_params_bytes[16..32].copy_from_slice(&color_to_bytes(*border_color));
                     texture_params_bytes[32..36].copy_from_slice(&top.to_le_bytes());
@@ -254,26 +287,36 @@ impl ParamsBindGroups {
                     texture_params_bytes[60..64].copy_from_slice(&crop.height.to_le_bytes());
                     texture_params_bytes[64..68].copy_from_slice(&rotation_degrees.to_le_bytes());
                     texture_params_bytes[68..72].copy_from_slice(&border_width.to_le_bytes());
+                    let gradient_index = push_gradient(
+                        &mut gradient_params,
+                        gradient_fill.as_ref().map(|fill| &fill.gradient),
+                    );
+                    texture_params_bytes[72..76].copy_from_slice(&gradient_index.to_le_bytes());
+                    if let Some(fill) = gradient_fill {
+                        texture_params_bytes[80..96]
+                            .copy_from_slice(&color_to_bytes(fill.background_color));
+                    }
                     texture_params.push(texture_params_bytes);
                     layout_infos.push(layout_info);
                 }
-                super::RenderLayoutContent::BoxShadow { color, blur_radius } => {
+                super::RenderLayoutContent::BackdropBlur { blur_radius: _ } => {
+                    // Shares params with color layouts, blur itself is applied to the
+                    // backdrop texture before the layout is rendered.
                     let layout_info = LayoutInfo {
                         layout_type: 2,
-                        index: box_shadow_params.len() as u32,
+                        index: color_params.len() as u32,
                         masks_len: masks.len() as u32,
+                        opacity: *opacity,
+                        blend_mode,
                     };
-                    let mut box_shadow_params_bytes = [0u8; 64];
-                    box_shadow_params_bytes[0..16].copy_from_slice(&border_radius_bytes);
-                    box_shadow_params_bytes[16..32].copy_from_slice(&color_to_bytes(*color));
-                    box_shadow_params_bytes[32..36].copy_from_slice(&top.to_le_bytes());
-                    box_shadow_params_bytes[36..40].copy_from_slice(&left.to_le_bytes());
-                    box_shadow_params_bytes[40..44].copy_from_slice(&width.to_le_bytes());
-                    box_shadow_params_bytes[44..48].copy_from_slice(&height.to_le_bytes());
-                    box_shadow_params_bytes[48..52]
-                        .copy_from_slice(&rotation_degrees.to_le_bytes());
-                    box_shadow_params_bytes[52..56].copy_from_slice(&blur_radius.to_le_bytes());
-                    box_shadow_params.push(box_shadow_params_bytes);
+                    let mut color_params_bytes = [0u8; 80];
+                    color_params_bytes[0..16].copy_from_slice(&border_radius_bytes);
+                    color_params_bytes[48..52].copy_from_slice(&top.to_le_bytes());
+                    color_params_bytes[52..56].copy_from_slice(&left.to_le_bytes());
+                    color_params_bytes[56..60].copy_from_slice(&width.to_le_bytes());
+                    color_params_bytes[60..64].copy_from_slice(&height.to_le_bytes());
+                    color_params_bytes[64..68].copy_from_slice(&rotation_degrees.to_le_bytes());
+                    color_params.push(color_params_bytes);
                     layout_infos.push(layout_info);
                 }
             }
@@ -311,24 +354,75 @@ impl ParamsBindGroups {
             ctx.queue
                 .write_buffer(&self.bind_groups_2[index].1, 0, &masks_bytes.concat());
         }
-        texture_params.resize_with(100, || [0u8; 80]);
+        texture_params.resize_with(100, || [0u8; 96]);
         color_params.resize_with(100, || [0u8; 80]);
-        box_shadow_params.resize_with(100, || [0u8; 64]);
+        gradient_params.resize_with(MAX_GRADIENTS_COUNT, || [0u8; 192]);
 
         ctx.queue
             .write_buffer(&self.texture_params_buffer, 0, &texture_params.concat());
         ctx.queue
             .write_buffer(&self.color_params_buffer, 0, &color_params.concat());
-        ctx.queue.write_buffer(
-            &self.box_shadow_params_buffer,
-            0,
-            &box_shadow_params.concat(),
-        );
+        ctx.queue
+            .write_buffer(&self.gradient_params_buffer, 0, &gradient_params.concat());
 
         layout_infos
     }
 }
 
+fn blend_mode_to_u32(blend_mode: BlendMode) -> u32 {
+    match blend_mode {
+        BlendMode::Normal => 0,
+        BlendMode::Multiply => 1,
+        BlendMode::Screen => 2,
+        BlendMode::Overlay => 3,
+        BlendMode::Add => 4,
+        BlendMode::Difference => 5,
+    }
+}
+
+/// Adds gradient params and returns their index, or `-1` if there is no gradient.
+fn push_gradient(gradient_params: &mut Vec<[u8; 192]>, gradient: Option<&Gradient>) -> i32 {
+    let Some(gradient) = gradient else {
+        return -1;
+    };
+    if gradient_params.len() >= MAX_GRADIENTS_COUNT {
+        error!(
+            "Max gradients count ({}) exceeded. Skipping rendering some of them.",
+            MAX_GRADIENTS_COUNT
+        );
+        return -1;
+    }
+    gradient_params.push(gradient_to_bytes(gradient));
+    gradient_params.len() as i32 - 1
+}
+
+fn gradient_to_bytes(gradient: &Gradient) -> [u8; 192] {
+    let (kind, angle_degrees, center_x, center_y) = match gradient.kind {
+        GradientKind::Linear { angle_degrees } => (0u32, angle_degrees, 0.5, 0.5),
+        GradientKind::Radial { center_x, center_y } => (1, 0.0, center_x, center_y),
+        GradientKind::Conic {
+            angle_degrees,
+            center_x,
+            center_y,
+        } => (2, angle_degrees, center_x, center_y),
+    };
+    let stops = &gradient.stops[..usize::min(gradient.stops.len(), Gradient::MAX_STOPS)];
+
+    let mut result = [0u8; 192];
+    result[0..4].copy_from_slice(&kind.to_le_bytes());
+    result[4..8].copy_from_slice(&(stops.len() as u32).to_le_bytes());
+    result[8..12].copy_from_slice(&angle_degrees.to_le_bytes());
+    result[12..16].copy_from_slice(&center_x.to_le_bytes());
+    result[16..20].copy_from_slice(&center_y.to_le_bytes());
+    for (index, stop) in stops.iter().enumerate() {
+        let offset_start = 32 + index * 4;
+        result[offset_start..offset_start + 4].copy_from_slice(&stop.offset.to_le_bytes());
+        let color_start = 64 + index * 16;
+        result[color_start..color_start + 16].copy_from_slice(&color_to_bytes(stop.color));
+    }
+    result
+}
+
 fn create_buffer(ctx: &WgpuCtx, size: usize) -> wgpu::Buffer {
     ctx.device.create_buffer_init(&BufferInitDescriptor {
         label: Some("params buffer"),
@@ -355,12 +449,3 @@ fn color_to_bytes(color: RGBAColor) -> [u8; 16] {
     result[12..16].copy_from_slice(&(a as f32 / 255.0).to_le_bytes());
     result
 }
-
-fn srgb_to_linear(color: u8) -> f32 {
-    let color = color as f32 / 255.0;
-    if color < 0.04045 {
-        color / 12.92
-    } else {
-        f32::powf((color + 0.055) / 1.055, 2.4)
-    }
-}
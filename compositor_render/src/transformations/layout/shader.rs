@@ -11,7 +11,11 @@ use crate::{
     Resolution,
 };
 
-use super::{params::ParamsBindGroups, RenderLayout};
+use super::{
+    blur::{BlurPipeline, BlurTextures},
+    params::{LayoutInfo, ParamsBindGroups},
+    RenderLayout, RenderLayoutContent,
+};
 
 #[derive(Debug)]
 pub struct LayoutShader {
@@ -19,6 +23,7 @@ pub struct LayoutShader {
     sampler: Sampler,
     texture_bgl: wgpu::BindGroupLayout,
     params_bind_groups: ParamsBindGroups,
+    blur: BlurPipeline,
 }
 
 impl LayoutShader {
@@ -40,8 +45,9 @@ impl LayoutShader {
         shader_module: wgpu::ShaderModule,
     ) -> Result<Self, CreateShaderError> {
         let sampler = Sampler::new(&wgpu_ctx.device);
-        let texture_bgl = common_pipeline::create_single_texture_bgl(&wgpu_ctx.device);
+        let texture_bgl = texture_bgl(&wgpu_ctx.device);
         let params_bind_groups = ParamsBindGroups::new(wgpu_ctx);
+        let blur = BlurPipeline::new(wgpu_ctx);
 
         let pipeline_layout =
             wgpu_ctx
@@ -56,7 +62,7 @@ impl LayoutShader {
                     ],
                     push_constant_ranges: &[wgpu::PushConstantRange {
                         stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
-                        range: 0..16,
+                        range: 0..20,
                     }],
                 });
 
@@ -71,9 +77,11 @@ impl LayoutShader {
             sampler,
             texture_bgl,
             params_bind_groups,
+            blur,
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &self,
         wgpu_ctx: &Arc<WgpuCtx>,
@@ -81,11 +89,33 @@ impl LayoutShader {
         layouts: Vec<RenderLayout>,
         textures: &[Option<&NodeTexture>],
         target: &NodeTextureState,
+        backdrop: &mut NodeTexture,
+        blur_textures: &mut BlurTextures,
     ) {
+        let backdrop_blurs: Vec<Option<f32>> = layouts
+            .iter()
+            .map(|layout| match layout.content {
+                RenderLayoutContent::BackdropBlur { blur_radius } => Some(blur_radius),
+                _ => None,
+            })
+            .collect();
         let layout_infos = self
             .params_bind_groups
             .update(wgpu_ctx, output_resolution, layouts);
-        let input_texture_bgs: Vec<wgpu::BindGroup> = self.input_textures_bg(wgpu_ctx, textures);
+
+        // Backdrop textures are only allocated if any of the layouts is blended
+        // or blurs its backdrop.
+        let has_backdrop_blur = backdrop_blurs.iter().any(Option::is_some);
+        let needs_backdrop = has_backdrop_blur
+            || layout_infos
+                .iter()
+                .any(|layout_info| layout_info.blend_mode != 0);
+        let backdrop = match needs_backdrop {
+            true => Some(backdrop.ensure_size(wgpu_ctx, target.resolution())),
+            false => None,
+        };
+        let input_texture_bgs: Vec<wgpu::BindGroup> =
+            self.input_textures_bg(wgpu_ctx, textures, &layout_infos, backdrop);
 
         if layout_infos.len() != input_texture_bgs.len() {
             error!(
@@ -95,13 +125,52 @@ impl LayoutShader {
             );
         }
 
-        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        // Blended layout needs to read everything that was rendered before it, so
+        // each of them starts a new render pass preceded by a copy of the target
+        // to the backdrop texture. Layout with a backdrop blur additionally blurs
+        // that copy.
+        let mut batches: Vec<Batch> = vec![Batch::default()];
+        for (index, ((texture_bg, layout_info), backdrop_blur)) in input_texture_bgs
+            .iter()
+            .zip(layout_infos.iter())
+            .zip(backdrop_blurs.iter())
+            .take(100)
+            .enumerate()
         {
+            if layout_info.blend_mode != 0 || backdrop_blur.is_some() {
+                batches.push(Batch {
+                    backdrop_blur: *backdrop_blur,
+                    layouts: vec![],
+                });
+            }
+            if let Some(batch) = batches.last_mut() {
+                batch.layouts.push((index, texture_bg, layout_info));
+            }
+        }
+
+        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        for (batch_index, batch) in batches.iter().enumerate() {
+            let load = match batch_index {
+                0 => wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                _ => wgpu::LoadOp::Load,
+            };
+            if let Some(backdrop) = backdrop.filter(|_| batch_index > 0) {
+                encoder.copy_texture_to_texture(
+                    target.rgba_texture().texture().texture.as_image_copy(),
+                    backdrop.rgba_texture().texture().texture.as_image_copy(),
+                    target.rgba_texture().size(),
+                );
+                if let Some(blur_radius) = batch.backdrop_blur {
+                    self.blur
+                        .encode(wgpu_ctx, &mut encoder, backdrop, blur_textures, blur_radius);
+                }
+            }
+
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: None,
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        load,
                         store: wgpu::StoreOp::Store,
                     },
                     view: &target.rgba_texture().texture().view,
@@ -113,12 +182,7 @@ impl LayoutShader {
                 occlusion_query_set: None,
             });
 
-            for (index, (texture_bg, layout_info)) in input_texture_bgs
-                .iter()
-                .zip(layout_infos.iter())
-                .take(100)
-                .enumerate()
-            {
+            for (index, texture_bg, layout_info) in batch.layouts.iter() {
                 render_pass.set_pipeline(&self.pipeline);
 
                 render_pass.set_push_constants(
@@ -129,7 +193,11 @@ impl LayoutShader {
 
                 render_pass.set_bind_group(0, texture_bg, &[]);
                 render_pass.set_bind_group(1, &self.params_bind_groups.bind_group_1, &[]);
-                render_pass.set_bind_group(2, &self.params_bind_groups.bind_groups_2[index].0, &[]);
+                render_pass.set_bind_group(
+                    2,
+                    &self.params_bind_groups.bind_groups_2[*index].0,
+                    &[],
+                );
                 render_pass.set_bind_group(3, &self.sampler.bind_group, &[]);
 
                 wgpu_ctx.plane.draw(&mut render_pass);
@@ -138,31 +206,84 @@ impl LayoutShader {
         wgpu_ctx.queue.submit(Some(encoder.finish()));
     }
 
+    /// Blurs content of the `target` in place.
+    pub fn blur(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        target: &NodeTextureState,
+        blur_textures: &mut BlurTextures,
+        blur_radius: f32,
+    ) {
+        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        self.blur
+            .encode(wgpu_ctx, &mut encoder, target, blur_textures, blur_radius);
+        wgpu_ctx.queue.submit(Some(encoder.finish()));
+    }
+
     fn input_textures_bg(
         &self,
         wgpu_ctx: &Arc<WgpuCtx>,
         textures: &[Option<&NodeTexture>],
+        layout_infos: &[LayoutInfo],
+        backdrop: Option<&NodeTextureState>,
     ) -> Vec<wgpu::BindGroup> {
         textures
             .iter()
-            .map(|texture| {
-                texture
+            .zip(layout_infos)
+            .map(|(texture, layout_info)| {
+                let texture_view = texture
                     .and_then(|texture| texture.state())
                     .map(|state| &state.rgba_texture().texture().view)
-                    .unwrap_or(&wgpu_ctx.empty_texture.view)
+                    .unwrap_or(&wgpu_ctx.empty_texture.view);
+                let backdrop_view = backdrop
+                    .filter(|_| layout_info.blend_mode != 0 || layout_info.layout_type == 2)
+                    .map(|state| &state.rgba_texture().texture().view)
+                    .unwrap_or(&wgpu_ctx.empty_texture.view);
+                (texture_view, backdrop_view)
             })
-            .map(|view| {
+            .map(|(texture_view, backdrop_view)| {
                 wgpu_ctx
                     .device
                     .create_bind_group(&wgpu::BindGroupDescriptor {
                         layout: &self.texture_bgl,
                         label: None,
-                        entries: &[wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(view),
-                        }],
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: wgpu::BindingResource::TextureView(texture_view),
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::TextureView(backdrop_view),
+                            },
+                        ],
                     })
             })
             .collect()
     }
 }
+
+/// Layouts rendered in a single render pass.
+#[derive(Default)]
+struct Batch<'a> {
+    /// Radius of the blur applied to the backdrop before this batch is rendered.
+    backdrop_blur: Option<f32>,
+    layouts: Vec<(usize, &'a wgpu::BindGroup, &'a LayoutInfo)>,
+}
+
+fn texture_bgl(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+        binding,
+        count: None,
+        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            multisampled: false,
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+        },
+    };
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("layout textures bgl"),
+        entries: &[texture_entry(0), texture_entry(1)],
+    })
+}
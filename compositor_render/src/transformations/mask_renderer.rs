@@ -0,0 +1,338 @@
+use std::{collections::HashMap, sync::Arc};
+
+use bytes::{Bytes, BytesMut};
+use resvg::{
+    tiny_skia,
+    usvg::{self, TreeParsing},
+};
+
+use crate::{
+    scene::MaskMode,
+    state::RenderCtx,
+    wgpu::{
+        common_pipeline::{self, CreateShaderError, Sampler},
+        texture::{NodeTexture, NodeTextureState, RGBATexture},
+        WgpuCtx, WgpuErrorScope,
+    },
+    Resolution,
+};
+
+use super::image_renderer::SvgError;
+
+/// Built-in pipeline that multiplies alpha of the content texture by
+/// the visibility calculated from the mask texture.
+#[derive(Debug)]
+pub struct MaskRenderer {
+    pipeline: wgpu::RenderPipeline,
+    sampler: Sampler,
+    textures_bgl: wgpu::BindGroupLayout,
+}
+
+impl MaskRenderer {
+    pub fn new(wgpu_ctx: &Arc<WgpuCtx>) -> Result<Self, CreateShaderError> {
+        let scope = WgpuErrorScope::push(&wgpu_ctx.device);
+
+        let shader_module = wgpu_ctx
+            .device
+            .create_shader_module(wgpu::include_wgsl!("./mask_renderer/apply_mask.wgsl"));
+        let sampler = Sampler::new(&wgpu_ctx.device);
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            count: None,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                view_dimension: wgpu::TextureViewDimension::D2,
+            },
+        };
+        let textures_bgl =
+            wgpu_ctx
+                .device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("mask textures bgl"),
+                    entries: &[texture_entry(0), texture_entry(1)],
+                });
+
+        let pipeline_layout =
+            wgpu_ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("mask pipeline layout"),
+                    bind_group_layouts: &[&textures_bgl, &sampler.bind_group_layout],
+                    push_constant_ranges: &[wgpu::PushConstantRange {
+                        stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        range: 0..4,
+                    }],
+                });
+
+        let pipeline = common_pipeline::create_render_pipeline(
+            &wgpu_ctx.device,
+            &pipeline_layout,
+            &shader_module,
+        );
+
+        scope.pop(&wgpu_ctx.device)?;
+
+        Ok(Self {
+            pipeline,
+            sampler,
+            textures_bgl,
+        })
+    }
+
+    fn render(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        content: &wgpu::TextureView,
+        mask: &wgpu::TextureView,
+        mode: MaskMode,
+        target: &NodeTextureState,
+    ) {
+        let textures_bg = wgpu_ctx
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("mask textures bind group"),
+                layout: &self.textures_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(content),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(mask),
+                    },
+                ],
+            });
+        let mode: u32 = match mode {
+            MaskMode::Alpha => 0,
+            MaskMode::Luminance => 1,
+        };
+
+        let mut encoder = wgpu_ctx.device.create_command_encoder(&Default::default());
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("apply mask"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                    view: &target.rgba_texture().texture().view,
+                    resolve_target: None,
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_push_constants(
+                wgpu::ShaderStages::VERTEX_FRAGMENT,
+                0,
+                &mode.to_le_bytes(),
+            );
+            render_pass.set_bind_group(0, &textures_bg, &[]);
+            render_pass.set_bind_group(1, &self.sampler.bind_group, &[]);
+
+            wgpu_ctx.plane.draw(&mut render_pass);
+        }
+        wgpu_ctx.queue.submit(Some(encoder.finish()));
+    }
+}
+
+/// SVG path rasterized to an RGBA bitmap. Path is rasterized when a scene is
+/// updated, so invalid paths are reported before anything is rendered.
+#[derive(Clone)]
+pub struct MaskPathBitmap {
+    path: Arc<str>,
+    data: Bytes,
+    resolution: Resolution,
+}
+
+impl std::fmt::Debug for MaskPathBitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaskPathBitmap")
+            .field("path", &self.path)
+            .field("resolution", &self.resolution)
+            .finish()
+    }
+}
+
+impl MaskPathBitmap {
+    /// `path` is an SVG path data in pixel coordinates of the `resolution`.
+    pub fn new(path: Arc<str>, resolution: Resolution) -> Result<Self, SvgError> {
+        let svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}"><path d="{path}" fill="white"/></svg>"#,
+            width = resolution.width,
+            height = resolution.height,
+            path = escape_xml_attribute(&path),
+        );
+        let tree = usvg::Tree::from_str(&svg, &Default::default())?;
+        let tree = resvg::Tree::from_usvg(&tree);
+
+        let mut buffer = BytesMut::zeroed(resolution.width * resolution.height * 4);
+        if let Some(mut pixmap) = tiny_skia::PixmapMut::from_bytes(
+            &mut buffer,
+            resolution.width as u32,
+            resolution.height as u32,
+        ) {
+            tree.render(tiny_skia::Transform::default(), &mut pixmap);
+        }
+
+        Ok(Self {
+            path,
+            data: buffer.freeze(),
+            resolution,
+        })
+    }
+}
+
+/// Bitmaps rasterized for the previous scene. Rasterization is expensive, so paths
+/// that did not change are reused when the scene is updated.
+#[derive(Debug, Default)]
+pub struct MaskPathCache {
+    bitmaps: HashMap<(Arc<str>, Resolution), MaskPathBitmap>,
+}
+
+impl MaskPathCache {
+    pub fn insert(&mut self, bitmap: MaskPathBitmap) {
+        self.bitmaps
+            .insert((bitmap.path.clone(), bitmap.resolution), bitmap);
+    }
+
+    /// Returns a cached bitmap if the same path was rasterized with the same resolution,
+    /// otherwise rasterizes the path.
+    pub fn bitmap(
+        &self,
+        path: Arc<str>,
+        resolution: Resolution,
+    ) -> Result<MaskPathBitmap, SvgError> {
+        match self.bitmaps.get(&(path.clone(), resolution)) {
+            Some(bitmap) => Ok(bitmap.clone()),
+            None => MaskPathBitmap::new(path, resolution),
+        }
+    }
+}
+
+fn escape_xml_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub struct MaskNode {
+    renderer: Arc<MaskRenderer>,
+    mode: MaskMode,
+    path_texture: Option<RGBATexture>,
+    resolution: Resolution,
+}
+
+impl MaskNode {
+    pub fn new(
+        ctx: &RenderCtx,
+        mode: MaskMode,
+        path: Option<MaskPathBitmap>,
+        resolution: Resolution,
+    ) -> Self {
+        let path_texture = path.map(|path| {
+            let texture = RGBATexture::new(ctx.wgpu_ctx, path.resolution);
+            texture.upload(ctx.wgpu_ctx, &path.data);
+            ctx.wgpu_ctx.queue.submit([]);
+            texture
+        });
+
+        Self {
+            renderer: ctx.renderers.mask.clone(),
+            mode,
+            path_texture,
+            resolution,
+        }
+    }
+
+    /// First source is the content, second one (if the path is not defined) is the mask.
+    pub fn render(&self, ctx: &RenderCtx, sources: &[&NodeTexture], target: &mut NodeTexture) {
+        let content = texture_view(ctx.wgpu_ctx, sources.first().copied());
+        // Rasterized path is white, so alpha and luminance produce the same result.
+        let (mask, mode) = match &self.path_texture {
+            Some(texture) => (&texture.texture().view, MaskMode::Alpha),
+            None => (
+                texture_view(ctx.wgpu_ctx, sources.get(1).copied()),
+                self.mode,
+            ),
+        };
+
+        let target = target.ensure_size(ctx.wgpu_ctx, self.resolution);
+        self.renderer
+            .render(ctx.wgpu_ctx, content, mask, mode, target);
+    }
+}
+
+fn texture_view<'a>(
+    wgpu_ctx: &'a WgpuCtx,
+    texture: Option<&'a NodeTexture>,
+) -> &'a wgpu::TextureView {
+    texture
+        .and_then(|texture| texture.state())
+        .map(|state| &state.rgba_texture().texture().view)
+        .unwrap_or(&wgpu_ctx.empty_texture.view)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Resolution;
+
+    use super::{MaskPathBitmap, MaskPathCache};
+
+    const RESOLUTION: Resolution = Resolution {
+        width: 4,
+        height: 4,
+    };
+
+    fn alpha(bitmap: &MaskPathBitmap, x: usize, y: usize) -> u8 {
+        bitmap.data[(y * bitmap.resolution.width + x) * 4 + 3]
+    }
+
+    #[test]
+    fn path_is_rasterized_in_pixel_coordinates() {
+        let bitmap = MaskPathBitmap::new(Arc::from("M0 0 H2 V4 H0 Z"), RESOLUTION).unwrap();
+
+        assert_eq!(bitmap.data.len(), 4 * 4 * 4);
+        assert_eq!(alpha(&bitmap, 0, 0), 255);
+        assert_eq!(alpha(&bitmap, 1, 3), 255);
+        assert_eq!(alpha(&bitmap, 2, 0), 0);
+        assert_eq!(alpha(&bitmap, 3, 3), 0);
+    }
+
+    #[test]
+    fn cache_reuses_bitmaps_with_the_same_path_and_resolution() {
+        let path: Arc<str> = Arc::from("M0 0 H2 V4 H0 Z");
+        let bitmap = MaskPathBitmap::new(path.clone(), RESOLUTION).unwrap();
+        let mut cache = MaskPathCache::default();
+        cache.insert(bitmap.clone());
+
+        let cached = cache
+            .bitmap(Arc::from("M0 0 H2 V4 H0 Z"), RESOLUTION)
+            .unwrap();
+        assert_eq!(cached.data.as_ptr(), bitmap.data.as_ptr());
+
+        let other_path = cache
+            .bitmap(Arc::from("M0 0 H4 V4 H0 Z"), RESOLUTION)
+            .unwrap();
+        assert_ne!(other_path.data.as_ptr(), bitmap.data.as_ptr());
+        assert_eq!(alpha(&other_path, 3, 3), 255);
+
+        let resolution = Resolution {
+            width: 8,
+            height: 8,
+        };
+        let other_resolution = cache.bitmap(path, resolution).unwrap();
+        assert_eq!(other_resolution.resolution, resolution);
+        assert_eq!(other_resolution.data.len(), 8 * 8 * 4);
+    }
+}
@@ -0,0 +1,46 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+struct MaskInfo {
+    // 0 -> Alpha, 1 -> Luminance
+    mode: u32,
+}
+
+var<push_constant> mask_info: MaskInfo;
+
+@group(0) @binding(0) var content_texture: texture_2d<f32>;
+@group(0) @binding(1) var mask_texture: texture_2d<f32>;
+@group(1) @binding(0) var sampler_: sampler;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    output.position = vec4(input.position, 1.0);
+    output.tex_coords = input.tex_coords;
+
+    return output;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let content = textureSample(content_texture, sampler_, input.tex_coords);
+    let mask = textureSample(mask_texture, sampler_, input.tex_coords);
+
+    var visibility: f32;
+    if mask_info.mode == 1u {
+        // Relative luminance (Rec. 709 coefficients), defined for linear values.
+        visibility = dot(mask.rgb, vec3<f32>(0.2126, 0.7152, 0.0722)) * mask.a;
+    } else {
+        visibility = mask.a;
+    }
+
+    return vec4<f32>(content.rgb, content.a * visibility);
+}
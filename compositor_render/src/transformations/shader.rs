@@ -13,6 +13,7 @@ mod pipeline;
 pub mod validation;
 
 const SHADER_INPUT_TEXTURES_AMOUNT: u32 = 16;
+const SHADER_AUDIO_INPUTS_AMOUNT: usize = 16;
 
 #[derive(Debug)]
 pub struct Shader {
@@ -3,17 +3,21 @@ use std::{sync::Arc, time::Duration};
 use wgpu::util::DeviceExt;
 
 use crate::{
+    audio_levels::LEVEL_WINDOW,
     scene::ShaderParam,
     state::RenderCtx,
     wgpu::{texture::NodeTexture, WgpuCtx},
-    Resolution,
+    InputId, Resolution,
 };
 
-use super::Shader;
+use super::{Shader, SHADER_AUDIO_INPUTS_AMOUNT};
 
 pub struct ShaderNode {
     params_bind_group: wgpu::BindGroup,
     _custom_params_buffer: wgpu::Buffer,
+    audio_levels_bind_group: wgpu::BindGroup,
+    audio_levels_buffer: wgpu::Buffer,
+    audio_inputs: Vec<InputId>,
     shader: Arc<Shader>,
     resolution: Resolution,
 }
@@ -23,14 +27,32 @@ impl ShaderNode {
         ctx: &RenderCtx,
         shader: Arc<Shader>,
         shader_params: &Option<ShaderParam>,
+        audio_inputs: &[InputId],
         resolution: &Resolution,
     ) -> Self {
         let custom_params_buffer = Self::new_params_buffer(ctx.wgpu_ctx, shader_params);
         let params_bind_group = Self::new_params_bind_group(ctx.wgpu_ctx, &custom_params_buffer);
 
+        // array<vec4<f32>, 16>
+        let audio_levels_buffer = ctx.wgpu_ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("shader node audio levels buffer"),
+            size: (SHADER_AUDIO_INPUTS_AMOUNT * 16) as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let audio_levels_bind_group =
+            Self::new_params_bind_group(ctx.wgpu_ctx, &audio_levels_buffer);
+
         Self {
             params_bind_group,
             _custom_params_buffer: custom_params_buffer,
+            audio_levels_bind_group,
+            audio_levels_buffer,
+            audio_inputs: audio_inputs
+                .iter()
+                .take(SHADER_AUDIO_INPUTS_AMOUNT)
+                .cloned()
+                .collect(),
             shader,
             resolution: *resolution,
         }
@@ -70,16 +92,34 @@ impl ShaderNode {
 
     pub fn render(
         &self,
-        wgpu_ctx: &Arc<WgpuCtx>,
+        ctx: &RenderCtx,
         sources: &[&NodeTexture],
         target: &mut NodeTexture,
         pts: Duration,
     ) {
+        let wgpu_ctx = ctx.wgpu_ctx;
         let target = target.ensure_size(wgpu_ctx, self.resolution);
 
+        if !self.audio_inputs.is_empty() {
+            let mut levels_bytes = [0u8; SHADER_AUDIO_INPUTS_AMOUNT * 16];
+            for (input_id, level_bytes) in self.audio_inputs.iter().zip(levels_bytes.chunks_mut(16))
+            {
+                let level = ctx
+                    .renderers
+                    .audio_levels
+                    .level(input_id, pts, LEVEL_WINDOW);
+                level_bytes[0..4].copy_from_slice(&level.rms.to_le_bytes());
+                level_bytes[4..8].copy_from_slice(&level.peak.to_le_bytes());
+            }
+            wgpu_ctx
+                .queue
+                .write_buffer(&self.audio_levels_buffer, 0, &levels_bytes);
+        }
+
         self.shader.pipeline.render(
             wgpu_ctx,
             &self.params_bind_group,
+            &self.audio_levels_bind_group,
             sources,
             target,
             pts,
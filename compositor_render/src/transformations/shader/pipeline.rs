@@ -21,6 +21,8 @@ use super::{
 
 pub(super) const USER_DEFINED_BUFFER_BINDING: u32 = 0;
 pub(super) const USER_DEFINED_BUFFER_GROUP: u32 = 1;
+/// Optional binding, shaders that do not declare it are not affected.
+pub(super) const AUDIO_LEVELS_GROUP: u32 = 3;
 
 #[derive(Debug)]
 pub(super) struct ShaderPipeline {
@@ -72,6 +74,7 @@ impl ShaderPipeline {
                         &textures_bgl,
                         &wgpu_ctx.uniform_bgl,
                         &sampler.bind_group_layout,
+                        &wgpu_ctx.uniform_bgl,
                     ],
                     push_constant_ranges: &[wgpu::PushConstantRange {
                         stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
@@ -98,6 +101,7 @@ impl ShaderPipeline {
         &self,
         wgpu_ctx: &Arc<WgpuCtx>,
         params: &wgpu::BindGroup,
+        audio_levels: &wgpu::BindGroup,
         sources: &[&NodeTexture],
         target: &NodeTextureState,
         pts: Duration,
@@ -142,6 +146,7 @@ impl ShaderPipeline {
             render_pass.set_bind_group(0, &input_textures_bg, &[]);
             render_pass.set_bind_group(USER_DEFINED_BUFFER_GROUP, params, &[]);
             render_pass.set_bind_group(2, &self.sampler.bind_group, &[]);
+            render_pass.set_bind_group(AUDIO_LEVELS_GROUP, audio_levels, &[]);
 
             wgpu_ctx.plane.draw(&mut render_pass);
         };
@@ -1,13 +1,15 @@
 use std::{
     cmp::max,
+    collections::BTreeSet,
     fmt,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use glyphon::{
-    fontdb::{Database, Source},
-    AttrsOwned, Buffer, Cache, Color, FontSystem, Metrics, Shaping, SwashCache, TextArea,
-    TextAtlas, TextBounds,
+    fontdb::{Database, Source, ID},
+    AttrsOwned, Buffer, Cache, Color, FamilyOwned, FontSystem, Metrics, Shaping, SwashCache,
+    TextArea, TextAtlas, TextBounds,
 };
 use tracing::warn;
 use wgpu::{
@@ -16,8 +18,10 @@ use wgpu::{
 };
 
 use crate::{
+    error::{RegisterFontError, UnregisterFontError},
     scene::{
-        HorizontalAlign, RGBAColor, TextComponent, TextDimensions, TextStyle, TextWeight, TextWrap,
+        DynamicTextParams, HorizontalAlign, RGBAColor, TextComponent, TextDimensions, TextShadow,
+        TextStroke, TextStyle, TextWeight, TextWrap,
     },
     state::RenderCtx,
     utils::rgba_to_wgpu_color,
@@ -25,15 +29,32 @@ use crate::{
     Resolution,
 };
 
+use self::effects::{TextEffects, TextEffectsTextures};
+
+pub(crate) mod effects;
+pub mod subtitles;
+
 #[derive(Debug, Clone)]
 pub(crate) struct TextRenderParams {
     pub(crate) buffer: TextBuffer,
     pub(crate) resolution: Resolution,
+    pub(crate) color: RGBAColor,
     pub(crate) background_color: RGBAColor,
+    pub(crate) stroke: Option<TextStroke>,
+    pub(crate) shadow: Option<TextShadow>,
+    /// Render white glyphs on a transparent background, colors are applied
+    /// by the parent layout.
+    pub(crate) gradient_fill: bool,
+    pub(crate) dynamic: Option<DynamicTextParams>,
 }
 
 #[derive(Clone)]
-pub(crate) struct TextBuffer(Arc<glyphon::Buffer>);
+pub(crate) struct TextBuffer {
+    buffer: Arc<glyphon::Buffer>,
+    /// Same text shaped without colors of the spans. Used to render a stroke,
+    /// a shadow and a text filled with a gradient.
+    plain_buffer: Arc<glyphon::Buffer>,
+}
 
 impl fmt::Debug for TextBuffer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -41,6 +62,39 @@ impl fmt::Debug for TextBuffer {
     }
 }
 
+/// Space around the text reserved for a stroke and a shadow, so they are not
+/// cut off when the texture size is fitted to the text.
+#[derive(Debug, Clone, Copy)]
+struct TextPadding {
+    top: f32,
+    right: f32,
+    bottom: f32,
+    left: f32,
+}
+
+impl TextPadding {
+    fn new(stroke: Option<&TextStroke>, shadow: Option<&TextShadow>) -> Self {
+        let stroke_width = stroke.map(clamped_stroke_width).unwrap_or(0.0);
+        let (offset_x, offset_y) = shadow
+            .map(|shadow| (shadow.offset_x, shadow.offset_y))
+            .unwrap_or((0.0, 0.0));
+        Self {
+            top: (stroke_width + f32::max(0.0, -offset_y)).ceil(),
+            right: (stroke_width + f32::max(0.0, offset_x)).ceil(),
+            bottom: (stroke_width + f32::max(0.0, offset_y)).ceil(),
+            left: (stroke_width + f32::max(0.0, -offset_x)).ceil(),
+        }
+    }
+
+    fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
 impl From<Resolution> for glyphon::Resolution {
     fn from(value: Resolution) -> Self {
         Self {
@@ -51,25 +105,102 @@ impl From<Resolution> for glyphon::Resolution {
 }
 
 pub(crate) struct TextRendererNode {
-    buffer: TextBuffer,
+    buffer: Arc<glyphon::Buffer>,
+    plain_buffer: Arc<glyphon::Buffer>,
     resolution: Resolution,
+    color: Color,
     background_color: wgpu::Color,
+    /// Top-left corner of the text, moved by the space reserved for a stroke
+    /// and a shadow.
+    text_position: (f32, f32),
+    /// Vertical offset of the dynamic text, e.g. subtitles aligned to the bottom.
+    dynamic_offset: f32,
+    /// Stroke and shadow rendered below the text.
+    effects: Option<TextEffects>,
+    effects_textures: TextEffectsTextures,
+    gradient_fill: bool,
+    dynamic: Option<DynamicTextParams>,
+    /// Content of the dynamic text from the last render.
+    rendered_text: Option<String>,
     was_rendered: bool,
 }
 
 impl TextRendererNode {
     pub(crate) fn new(params: TextRenderParams) -> Self {
-        let background_color = rgba_to_wgpu_color(&params.background_color);
+        let padding = TextPadding::new(params.stroke.as_ref(), params.shadow.as_ref());
+        if params.gradient_fill {
+            // Stroke and shadow would be filled with the gradient too.
+            return Self {
+                buffer: params.buffer.plain_buffer.clone(),
+                plain_buffer: params.buffer.plain_buffer,
+                resolution: params.resolution,
+                color: Color::rgb(255, 255, 255),
+                background_color: wgpu::Color::TRANSPARENT,
+                text_position: (padding.left, padding.top),
+                effects: None,
+                effects_textures: TextEffectsTextures::default(),
+                gradient_fill: true,
+                dynamic_offset: 0.0,
+                dynamic: params.dynamic,
+                rendered_text: None,
+                was_rendered: false,
+            };
+        }
+
+        let effects = match params.stroke.is_some() || params.shadow.is_some() {
+            true => Some(TextEffects {
+                stroke: params.stroke,
+                shadow: params.shadow,
+                background_color: params.background_color,
+            }),
+            false => None,
+        };
 
         Self {
-            buffer: params.buffer,
+            buffer: params.buffer.buffer,
+            plain_buffer: params.buffer.plain_buffer,
             resolution: params.resolution,
-            background_color,
+            color: glyphon_color(params.color),
+            background_color: rgba_to_wgpu_color(&params.background_color),
+            text_position: (padding.left, padding.top),
+            effects,
+            effects_textures: TextEffectsTextures::default(),
+            gradient_fill: false,
+            dynamic_offset: 0.0,
+            dynamic: params.dynamic,
+            rendered_text: None,
             was_rendered: false,
         }
     }
 
-    pub(crate) fn render(&mut self, renderer_ctx: &mut RenderCtx, target: &mut NodeTexture) {
+    /// Shapes the dynamic text again if its content changed since the last render.
+    fn update_dynamic_text(&mut self, renderer_ctx: &RenderCtx, pts: Duration) {
+        let Some(dynamic) = &mut self.dynamic else {
+            return;
+        };
+        let text = dynamic.text(pts);
+        if self.rendered_text.as_ref() == Some(&text) {
+            return;
+        }
+
+        let (buffer, offset) = dynamic.layout(renderer_ctx.text_renderer_ctx, &text);
+        self.buffer = match self.gradient_fill {
+            true => buffer.plain_buffer.clone(),
+            false => buffer.buffer,
+        };
+        self.plain_buffer = buffer.plain_buffer;
+        self.dynamic_offset = offset;
+        self.rendered_text = Some(text);
+        self.was_rendered = false;
+    }
+
+    pub(crate) fn render(
+        &mut self,
+        renderer_ctx: &mut RenderCtx,
+        target: &mut NodeTexture,
+        pts: Duration,
+    ) {
+        self.update_dynamic_text(renderer_ctx, pts);
         if self.was_rendered {
             return;
         }
@@ -114,6 +245,24 @@ impl TextRendererNode {
             None,
         );
 
+        let bounds = TextBounds {
+            left: 0,
+            top: 0,
+            right: self.resolution.width as i32,
+            bottom: self.resolution.height as i32,
+        };
+        let (left, top) = self.text_position;
+        let top = top + self.dynamic_offset;
+        let text_area = TextArea {
+            buffer: &self.buffer,
+            left,
+            top,
+            scale: 1.0,
+            bounds,
+            default_color: self.color,
+            custom_glyphs: &[],
+        };
+
         text_renderer
             .prepare(
                 &renderer_ctx.wgpu_ctx.device,
@@ -121,24 +270,45 @@ impl TextRendererNode {
                 font_system,
                 &mut atlas,
                 &viewport,
-                [TextArea {
-                    buffer: &self.buffer.0,
-                    left: 0 as f32,
-                    top: 0 as f32,
-                    scale: 1.0,
-                    bounds: TextBounds {
-                        left: 0,
-                        top: 0,
-                        right: self.resolution.width as i32,
-                        bottom: self.resolution.height as i32,
-                    },
-                    default_color: Color::rgb(255, 255, 255),
-                    custom_glyphs: &[],
-                }],
+                [text_area],
                 swash_cache,
             )
             .unwrap();
 
+        // Glyphs without colors of the spans, used as a mask of the stroke and the shadow.
+        let glyphs_renderer = match self.effects {
+            Some(_) => {
+                let mut glyphs_renderer = glyphon::TextRenderer::new(
+                    &mut atlas,
+                    &renderer_ctx.wgpu_ctx.device,
+                    MultisampleState::default(),
+                    None,
+                );
+                let glyphs_area = TextArea {
+                    buffer: &self.plain_buffer,
+                    left,
+                    top,
+                    scale: 1.0,
+                    bounds,
+                    default_color: Color::rgb(255, 255, 255),
+                    custom_glyphs: &[],
+                };
+                glyphs_renderer
+                    .prepare(
+                        &renderer_ctx.wgpu_ctx.device,
+                        &renderer_ctx.wgpu_ctx.queue,
+                        font_system,
+                        &mut atlas,
+                        &viewport,
+                        [glyphs_area],
+                        swash_cache,
+                    )
+                    .unwrap();
+                Some(glyphs_renderer)
+            }
+            None => None,
+        };
+
         let mut encoder =
             renderer_ctx
                 .wgpu_ctx
@@ -148,6 +318,46 @@ impl TextRendererNode {
                 });
 
         let target_state = target.ensure_size(renderer_ctx.wgpu_ctx, self.resolution);
+        let load = match (&self.effects, glyphs_renderer) {
+            (Some(effects), Some(glyphs_renderer)) => {
+                let glyphs = self
+                    .effects_textures
+                    .glyphs(renderer_ctx.wgpu_ctx, self.resolution);
+                {
+                    let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                        label: Some("Text effects glyphs"),
+                        color_attachments: &[Some(RenderPassColorAttachment {
+                            view: &glyphs.rgba_texture().texture().view,
+                            resolve_target: None,
+                            ops: Operations {
+                                load: LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                                store: wgpu::StoreOp::Store,
+                            },
+                        })],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    });
+
+                    glyphs_renderer
+                        .render(&atlas, &viewport, &mut pass)
+                        .unwrap();
+                }
+
+                // Background, shadow and stroke are rendered first, the text is
+                // blended on top of them.
+                renderer_ctx.renderers.text_effects.encode(
+                    renderer_ctx.wgpu_ctx,
+                    &mut encoder,
+                    effects,
+                    &mut self.effects_textures,
+                    target_state,
+                );
+                LoadOp::Load
+            }
+            _ => LoadOp::Clear(self.background_color),
+        };
+
         let view = &target_state.rgba_texture().texture().view;
         {
             let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
@@ -156,7 +366,7 @@ impl TextRendererNode {
                     view,
                     resolve_target: None,
                     ops: Operations {
-                        load: LoadOp::Clear(self.background_color),
+                        load,
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -174,37 +384,18 @@ impl TextRendererNode {
 }
 
 pub(crate) struct TextParams {
-    content: Arc<str>,
+    spans: Vec<(Arc<str>, AttrsOwned)>,
+    /// Used for parts of spans that do not define their own attributes.
     attributes: AttrsOwned,
     font_size: f32,
     line_height: f32,
     align: glyphon::cosmic_text::Align,
     wrap: glyphon::cosmic_text::Wrap,
+    padding: TextPadding,
 }
 
 impl From<&TextComponent> for TextParams {
     fn from(text: &TextComponent) -> Self {
-        let RGBAColor(r, g, b, a) = text.color;
-        let color = glyphon::Color::rgba(r, g, b, a);
-
-        let family = glyphon::FamilyOwned::Name(text.font_family.to_string());
-
-        let style = match text.style {
-            TextStyle::Normal => glyphon::Style::Normal,
-            TextStyle::Italic => glyphon::Style::Italic,
-            TextStyle::Oblique => glyphon::Style::Oblique,
-        };
-        let weight = match text.weight {
-            TextWeight::Thin => glyphon::Weight::THIN,
-            TextWeight::ExtraLight => glyphon::Weight::EXTRA_LIGHT,
-            TextWeight::Light => glyphon::Weight::LIGHT,
-            TextWeight::Normal => glyphon::Weight::NORMAL,
-            TextWeight::Medium => glyphon::Weight::MEDIUM,
-            TextWeight::SemiBold => glyphon::Weight::SEMIBOLD,
-            TextWeight::Bold => glyphon::Weight::BOLD,
-            TextWeight::ExtraBold => glyphon::Weight::EXTRA_BOLD,
-            TextWeight::Black => glyphon::Weight::BLACK,
-        };
         let wrap = match text.wrap {
             TextWrap::None => glyphon::cosmic_text::Wrap::None,
             TextWrap::Glyph => glyphon::cosmic_text::Wrap::Glyph,
@@ -217,71 +408,213 @@ impl From<&TextComponent> for TextParams {
             HorizontalAlign::Center => glyphon::cosmic_text::Align::Center,
         };
 
+        let attributes = glyphon::AttrsOwned {
+            // Color is defined when the buffer is rendered, so text filled
+            // with a gradient can be rendered without colors.
+            color_opt: None,
+            family_owned: FamilyOwned::Name(text.font_family.to_string()),
+            stretch: Default::default(),
+            style: glyphon_style(&text.style),
+            weight: glyphon_weight(&text.weight),
+            metadata: Default::default(),
+            cache_key_flags: glyphon::cosmic_text::CacheKeyFlags::empty(),
+            metrics_opt: None,
+        };
+        let line_height_ratio = match text.font_size > 0.0 {
+            true => text.line_height / text.font_size,
+            false => 1.0,
+        };
+        let spans = text
+            .spans
+            .iter()
+            .map(|span| {
+                let span_attributes = glyphon::AttrsOwned {
+                    color_opt: span.color.map(glyphon_color),
+                    family_owned: span
+                        .font_family
+                        .as_ref()
+                        .map(|family| FamilyOwned::Name(family.to_string()))
+                        .unwrap_or_else(|| attributes.family_owned.clone()),
+                    style: span
+                        .style
+                        .as_ref()
+                        .map(glyphon_style)
+                        .unwrap_or(attributes.style),
+                    weight: span
+                        .weight
+                        .as_ref()
+                        .map(glyphon_weight)
+                        .unwrap_or(attributes.weight),
+                    metrics_opt: span.font_size.map(|font_size| {
+                        Metrics::new(font_size, font_size * line_height_ratio).into()
+                    }),
+                    ..attributes.clone()
+                };
+                (span.text.clone(), span_attributes)
+            })
+            .collect();
+
         Self {
-            attributes: glyphon::AttrsOwned {
-                color_opt: Some(color),
-                family_owned: family,
-                stretch: Default::default(),
-                style,
-                weight,
-                metadata: Default::default(),
-                cache_key_flags: glyphon::cosmic_text::CacheKeyFlags::empty(),
-                metrics_opt: None,
-            },
-            content: text.text.clone(),
+            spans,
+            attributes,
             font_size: text.font_size,
             line_height: text.line_height,
             align,
             wrap,
+            padding: TextPadding::new(text.stroke.as_ref(), text.shadow.as_ref()),
         }
     }
 }
 
+fn glyphon_style(style: &TextStyle) -> glyphon::Style {
+    match style {
+        TextStyle::Normal => glyphon::Style::Normal,
+        TextStyle::Italic => glyphon::Style::Italic,
+        TextStyle::Oblique => glyphon::Style::Oblique,
+    }
+}
+
+fn glyphon_weight(weight: &TextWeight) -> glyphon::Weight {
+    match weight {
+        TextWeight::Thin => glyphon::Weight::THIN,
+        TextWeight::ExtraLight => glyphon::Weight::EXTRA_LIGHT,
+        TextWeight::Light => glyphon::Weight::LIGHT,
+        TextWeight::Normal => glyphon::Weight::NORMAL,
+        TextWeight::Medium => glyphon::Weight::MEDIUM,
+        TextWeight::SemiBold => glyphon::Weight::SEMIBOLD,
+        TextWeight::Bold => glyphon::Weight::BOLD,
+        TextWeight::ExtraBold => glyphon::Weight::EXTRA_BOLD,
+        TextWeight::Black => glyphon::Weight::BLACK,
+    }
+}
+
+fn glyphon_color(color: RGBAColor) -> Color {
+    let RGBAColor(r, g, b, a) = color;
+    Color::rgba(r, g, b, a)
+}
+
+fn clamped_stroke_width(stroke: &TextStroke) -> f32 {
+    stroke.width.clamp(0.0, TextStroke::MAX_WIDTH)
+}
+
 pub struct TextRendererCtx {
     font_system: Mutex<FontSystem>,
     swash_cache: Mutex<SwashCache>,
     cache: Mutex<Cache>,
+    /// Fonts added with `register_font`. Only those can be unregistered.
+    registered_fonts: Mutex<Vec<ID>>,
 }
 
 impl TextRendererCtx {
     pub(crate) fn new(device: &wgpu::Device, load_system_fonts: bool) -> Self {
-        let mut font_system = if load_system_fonts {
-            FontSystem::new()
-        } else {
-            let locale = sys_locale::get_locale().unwrap_or_else(|| {
-                warn!("failed to get system locale, falling back to en-US");
-                String::from("en-US")
-            });
-            FontSystem::new_with_locale_and_db(locale, Database::new())
-        };
-        font_system
-            .db_mut()
-            .load_font_source(Source::Binary(Arc::new(include_bytes!(
-                "../../fonts/Inter_18pt-Regular.ttf"
-            ))));
-        font_system
-            .db_mut()
-            .load_font_source(Source::Binary(Arc::new(include_bytes!(
-                "../../fonts/Inter_18pt-Italic.ttf"
-            ))));
-        font_system
-            .db_mut()
-            .load_font_source(Source::Binary(Arc::new(include_bytes!(
-                "../../fonts/Inter_18pt-Bold.ttf"
-            ))));
         Self {
-            font_system: Mutex::new(font_system),
+            font_system: Mutex::new(new_font_system(load_system_fonts)),
             swash_cache: Mutex::new(SwashCache::new()),
             cache: Mutex::new(Cache::new(device)),
+            registered_fonts: Mutex::new(Vec::new()),
         }
     }
 
-    pub fn add_font(&self, source: Source) {
+    pub(crate) fn register_font(&self, source: Source) -> Result<Vec<String>, RegisterFontError> {
         let mut font_system = self.font_system.lock().unwrap();
-        font_system.db_mut().load_font_source(source);
+        let mut registered_fonts = self.registered_fonts.lock().unwrap();
+        register_font(&mut font_system, &mut registered_fonts, source)
+    }
+
+    pub(crate) fn unregister_font(&self, font_family: &str) -> Result<(), UnregisterFontError> {
+        let mut font_system = self.font_system.lock().unwrap();
+        let mut registered_fonts = self.registered_fonts.lock().unwrap();
+        unregister_font(&mut font_system, &mut registered_fonts, font_family)
+    }
+
+    pub(crate) fn font_families(&self) -> Vec<String> {
+        font_families(&self.font_system.lock().unwrap())
     }
 }
 
+/// Font system with system fonts (if enabled) and fonts bundled with the compositor.
+fn new_font_system(load_system_fonts: bool) -> FontSystem {
+    let mut font_system = if load_system_fonts {
+        FontSystem::new()
+    } else {
+        let locale = sys_locale::get_locale().unwrap_or_else(|| {
+            warn!("failed to get system locale, falling back to en-US");
+            String::from("en-US")
+        });
+        FontSystem::new_with_locale_and_db(locale, Database::new())
+    };
+    font_system
+        .db_mut()
+        .load_font_source(Source::Binary(Arc::new(include_bytes!(
+            "../../fonts/Inter_18pt-Regular.ttf"
+        ))));
+    font_system
+        .db_mut()
+        .load_font_source(Source::Binary(Arc::new(include_bytes!(
+            "../../fonts/Inter_18pt-Italic.ttf"
+        ))));
+    font_system
+        .db_mut()
+        .load_font_source(Source::Binary(Arc::new(include_bytes!(
+            "../../fonts/Inter_18pt-Bold.ttf"
+        ))));
+    font_system
+}
+
+/// Returns families of the loaded font faces.
+fn register_font(
+    font_system: &mut FontSystem,
+    registered_fonts: &mut Vec<ID>,
+    source: Source,
+) -> Result<Vec<String>, RegisterFontError> {
+    let ids = font_system.db_mut().load_font_source(source);
+    if ids.is_empty() {
+        return Err(RegisterFontError::NoFontFaces);
+    }
+
+    let families = ids
+        .iter()
+        .filter_map(|id| font_system.db().face(*id))
+        .flat_map(|face| face.families.iter().map(|(family, _)| family.clone()))
+        .collect::<BTreeSet<_>>();
+    registered_fonts.extend(ids);
+    Ok(families.into_iter().collect())
+}
+
+/// Removes all registered faces of the family. Other fonts (e.g. system fonts) are kept.
+fn unregister_font(
+    font_system: &mut FontSystem,
+    registered_fonts: &mut Vec<ID>,
+    font_family: &str,
+) -> Result<(), UnregisterFontError> {
+    let (removed, kept): (Vec<ID>, Vec<ID>) = registered_fonts.iter().copied().partition(|id| {
+        font_system.db().face(*id).is_some_and(|face| {
+            face.families
+                .iter()
+                .any(|(family, _)| family == font_family)
+        })
+    });
+    if removed.is_empty() {
+        return Err(UnregisterFontError::NotRegistered(font_family.to_string()));
+    }
+
+    for id in removed {
+        font_system.db_mut().remove_face(id);
+    }
+    *registered_fonts = kept;
+    Ok(())
+}
+
+fn font_families(font_system: &FontSystem) -> Vec<String> {
+    font_system
+        .db()
+        .faces()
+        .flat_map(|face| face.families.iter().map(|(family, _)| family.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
 impl TextRendererCtx {
     pub(crate) fn layout_text(
         &self,
@@ -289,14 +622,49 @@ impl TextRendererCtx {
         text_resolution: TextDimensions,
     ) -> (TextBuffer, Resolution) {
         let font_system = &mut self.font_system.lock().unwrap();
+        let (buffer, texture_size) =
+            Self::shape_text(font_system, &text_params, text_resolution, true);
+        let has_span_colors = text_params
+            .spans
+            .iter()
+            .any(|(_, attributes)| attributes.color_opt.is_some());
+        let plain_buffer = match has_span_colors {
+            true => Self::shape_text(font_system, &text_params, text_resolution, false).0,
+            false => buffer.clone(),
+        };
+
+        (
+            TextBuffer {
+                buffer,
+                plain_buffer,
+            },
+            texture_size,
+        )
+    }
+
+    fn shape_text(
+        font_system: &mut FontSystem,
+        text_params: &TextParams,
+        text_resolution: TextDimensions,
+        with_span_colors: bool,
+    ) -> (Arc<Buffer>, Resolution) {
+        let padding = text_params.padding;
         let mut buffer = Buffer::new(
             font_system,
             Metrics::new(text_params.font_size, text_params.line_height),
         );
 
-        buffer.set_text(
+        let spans = text_params.spans.iter().map(|(text, attributes)| {
+            let attributes = attributes.as_attrs();
+            let attributes = glyphon::cosmic_text::Attrs {
+                color_opt: attributes.color_opt.filter(|_| with_span_colors),
+                ..attributes
+            };
+            (text.as_ref(), attributes)
+        });
+        buffer.set_rich_text(
             font_system,
-            &text_params.content,
+            spans,
             text_params.attributes.as_attrs(),
             Shaping::Advanced,
         );
@@ -311,16 +679,29 @@ impl TextRendererCtx {
                 max_width,
                 max_height,
             } => {
-                buffer.set_size(font_system, Some(max_width), Some(max_height));
+                buffer.set_size(
+                    font_system,
+                    Some(f32::max(max_width - padding.horizontal(), 0.0)),
+                    Some(f32::max(max_height - padding.vertical(), 0.0)),
+                );
                 buffer.shape_until_scroll(font_system, false);
-                Self::get_text_resolution(
+                let text_size = Self::get_text_resolution(
                     buffer.lines.iter(),
                     text_params.line_height,
                     text_params.font_size,
-                )
+                );
+
+                Resolution {
+                    width: text_size.width + padding.horizontal() as usize,
+                    height: text_size.height + padding.vertical() as usize,
+                }
             }
             TextDimensions::FittedColumn { width, max_height } => {
-                buffer.set_size(font_system, Some(width), Some(max_height));
+                buffer.set_size(
+                    font_system,
+                    Some(f32::max(width - padding.horizontal(), 0.0)),
+                    Some(f32::max(max_height - padding.vertical(), 0.0)),
+                );
                 buffer.shape_until_scroll(font_system, false);
                 let text_size = Self::get_text_resolution(
                     buffer.lines.iter(),
@@ -330,22 +711,28 @@ impl TextRendererCtx {
 
                 Resolution {
                     width: width as usize,
-                    height: text_size.height,
+                    height: text_size.height + padding.vertical() as usize,
                 }
             }
         };
 
         buffer.set_size(
             font_system,
-            Some(texture_size.width as f32),
-            Some(texture_size.height as f32 + text_params.line_height),
+            Some(f32::max(
+                texture_size.width as f32 - padding.horizontal(),
+                0.0,
+            )),
+            Some(
+                f32::max(texture_size.height as f32 - padding.vertical(), 0.0)
+                    + text_params.line_height,
+            ),
         );
         for line in &mut buffer.lines {
             line.set_align(Some(text_params.align));
         }
         buffer.shape_until_scroll(font_system, false);
 
-        (TextBuffer(buffer.into()), texture_size)
+        (buffer.into(), texture_size)
     }
 
     fn get_text_resolution<'a, I: Iterator<Item = &'a glyphon::BufferLine>>(
@@ -354,19 +741,91 @@ impl TextRendererCtx {
         font_size: f32,
     ) -> Resolution {
         let mut width = 0;
-        let mut lines_count = 0u32;
+        let mut height = 0.0;
 
         for line in lines {
             if let Some(layout) = line.layout_opt() {
                 for layout_line in layout {
-                    lines_count += 1;
+                    // Lines with spans that define a font size have their own height.
+                    height += layout_line.line_height_opt.unwrap_or(line_height).ceil();
                     width = max(width, layout_line.w.ceil() as usize);
                 }
             }
         }
 
         let last_line_padding = font_size / 5.0;
-        let height = (lines_count as f32 * line_height.ceil() + last_line_padding) as usize;
+        let height = (height + last_line_padding) as usize;
         Resolution { width, height }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use glyphon::fontdb::Source;
+
+    use crate::error::{RegisterFontError, UnregisterFontError};
+
+    use super::{font_families, new_font_system, register_font, unregister_font};
+
+    /// Family of the fonts bundled with the compositor.
+    const FAMILY: &str = "Inter 18pt";
+
+    fn bold_font() -> Source {
+        Source::Binary(Arc::new(include_bytes!("../../fonts/Inter_18pt-Bold.ttf")))
+    }
+
+    #[test]
+    fn register_and_unregister_font() {
+        let mut font_system = new_font_system(false);
+        let mut registered_fonts = Vec::new();
+        let faces_count = font_system.db().faces().count();
+
+        let families = register_font(&mut font_system, &mut registered_fonts, bold_font());
+        assert_eq!(families.unwrap(), vec![String::from(FAMILY)]);
+        assert_eq!(registered_fonts.len(), 1);
+        assert_eq!(font_system.db().faces().count(), faces_count + 1);
+        assert_eq!(font_families(&font_system), vec![String::from(FAMILY)]);
+
+        unregister_font(&mut font_system, &mut registered_fonts, FAMILY).unwrap();
+        assert!(registered_fonts.is_empty());
+        // only the registered copy is removed, bundled fonts of the same family are kept
+        assert_eq!(font_system.db().faces().count(), faces_count);
+        assert_eq!(font_families(&font_system), vec![String::from(FAMILY)]);
+    }
+
+    #[test]
+    fn unregister_font_that_was_not_registered() {
+        let mut font_system = new_font_system(false);
+        let mut registered_fonts = Vec::new();
+        let faces_count = font_system.db().faces().count();
+
+        assert!(matches!(
+            unregister_font(&mut font_system, &mut registered_fonts, FAMILY),
+            Err(UnregisterFontError::NotRegistered(family)) if family == FAMILY
+        ));
+        assert_eq!(font_system.db().faces().count(), faces_count);
+
+        register_font(&mut font_system, &mut registered_fonts, bold_font()).unwrap();
+        assert!(matches!(
+            unregister_font(&mut font_system, &mut registered_fonts, "Unknown"),
+            Err(UnregisterFontError::NotRegistered(_))
+        ));
+        assert_eq!(registered_fonts.len(), 1);
+    }
+
+    #[test]
+    fn register_invalid_font() {
+        let mut font_system = new_font_system(false);
+        let mut registered_fonts = Vec::new();
+
+        let result = register_font(
+            &mut font_system,
+            &mut registered_fonts,
+            Source::Binary(Arc::new(b"not a font".to_vec())),
+        );
+        assert!(matches!(result, Err(RegisterFontError::NoFontFaces)));
+        assert!(registered_fonts.is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+use std::sync::Arc;
+
+use crate::{
+    scene::{RGBAColor, TextShadow, TextStroke},
+    utils::srgb_to_linear,
+    wgpu::{
+        common_pipeline::{self, CreateShaderError},
+        texture::{NodeTexture, NodeTextureState},
+        WgpuCtx, WgpuErrorScope,
+    },
+    Resolution,
+};
+
+/// Stroke and shadow of the text, rendered together with the background below the glyphs.
+#[derive(Debug, Clone)]
+pub(super) struct TextEffects {
+    pub stroke: Option<TextStroke>,
+    pub shadow: Option<TextShadow>,
+    pub background_color: RGBAColor,
+}
+
+/// Built-in pipeline that renders a stroke and a shadow from a mask of the glyphs.
+///
+/// Outline of the glyphs is calculated once with a separable distance transform,
+/// so its cost does not depend on the number of overlapping copies of the text.
+/// Shadow is a shifted outline (or glyphs if there is no stroke). Both of them are
+/// composited over the background with the alpha of their colors.
+#[derive(Debug)]
+pub(crate) struct TextEffectsRenderer {
+    pipeline: wgpu::RenderPipeline,
+    textures_bgl: wgpu::BindGroupLayout,
+}
+
+/// Textures used by `TextEffectsRenderer`, kept between renders so they are not reallocated.
+#[derive(Default)]
+pub(super) struct TextEffectsTextures {
+    /// Glyphs rendered without colors, only alpha is used.
+    glyphs: NodeTexture,
+    /// Horizontal distance to the closest glyph in each row.
+    distance: NodeTexture,
+    /// Glyphs extended by the stroke width.
+    outline: NodeTexture,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EffectsPass {
+    HorizontalDistance,
+    Outline,
+    Composite,
+}
+
+impl TextEffectsRenderer {
+    pub fn new(wgpu_ctx: &Arc<WgpuCtx>) -> Result<Self, CreateShaderError> {
+        let scope = WgpuErrorScope::push(&wgpu_ctx.device);
+
+        let shader_module = wgpu_ctx
+            .device
+            .create_shader_module(wgpu::include_wgsl!("./effects.wgsl"));
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            count: None,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                view_dimension: wgpu::TextureViewDimension::D2,
+            },
+        };
+        let textures_bgl =
+            wgpu_ctx
+                .device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("text effects textures bgl"),
+                    entries: &[texture_entry(0), texture_entry(1)],
+                });
+
+        let pipeline_layout =
+            wgpu_ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: Some("text effects pipeline layout"),
+                    bind_group_layouts: &[&textures_bgl],
+                    push_constant_ranges: &[wgpu::PushConstantRange {
+                        stages: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        range: 0..64,
+                    }],
+                });
+
+        // Every pass overwrites the entire target.
+        let pipeline = common_pipeline::create_render_pipeline_with_blend(
+            &wgpu_ctx.device,
+            &pipeline_layout,
+            &shader_module,
+            None,
+        );
+
+        scope.pop(&wgpu_ctx.device)?;
+
+        Ok(Self {
+            pipeline,
+            textures_bgl,
+        })
+    }
+
+    /// Renders background, shadow and stroke to `target`. Glyphs have to be rendered
+    /// to the texture returned by `TextEffectsTextures::glyphs` first.
+    pub(super) fn encode(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        encoder: &mut wgpu::CommandEncoder,
+        effects: &TextEffects,
+        textures: &mut TextEffectsTextures,
+        target: &NodeTextureState,
+    ) {
+        let resolution = target.resolution();
+        let Some(glyphs) = textures.glyphs.state() else {
+            return;
+        };
+
+        let mask = match effects.stroke {
+            Some(_) => {
+                let distance = textures.distance.ensure_size(wgpu_ctx, resolution);
+                self.encode_pass(
+                    wgpu_ctx,
+                    encoder,
+                    effects,
+                    EffectsPass::HorizontalDistance,
+                    glyphs,
+                    None,
+                    distance,
+                );
+                let outline = textures.outline.ensure_size(wgpu_ctx, resolution);
+                self.encode_pass(
+                    wgpu_ctx,
+                    encoder,
+                    effects,
+                    EffectsPass::Outline,
+                    glyphs,
+                    Some(distance),
+                    outline,
+                );
+                outline
+            }
+            None => glyphs,
+        };
+        self.encode_pass(
+            wgpu_ctx,
+            encoder,
+            effects,
+            EffectsPass::Composite,
+            mask,
+            None,
+            target,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn encode_pass(
+        &self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        encoder: &mut wgpu::CommandEncoder,
+        effects: &TextEffects,
+        pass: EffectsPass,
+        mask: &NodeTextureState,
+        distance: Option<&NodeTextureState>,
+        target: &NodeTextureState,
+    ) {
+        let distance = distance
+            .map(|distance| &distance.rgba_texture().texture().view)
+            .unwrap_or(&wgpu_ctx.empty_texture.view);
+        let textures_bg = wgpu_ctx
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("text effects textures bind group"),
+                layout: &self.textures_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(
+                            &mask.rgba_texture().texture().view,
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(distance),
+                    },
+                ],
+            });
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("text effects"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+                view: &target.rgba_texture().texture().view,
+                resolve_target: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_push_constants(
+            wgpu::ShaderStages::VERTEX_FRAGMENT,
+            0,
+            &push_constants(effects, pass),
+        );
+        render_pass.set_bind_group(0, &textures_bg, &[]);
+
+        wgpu_ctx.plane.draw(&mut render_pass);
+    }
+}
+
+impl TextEffectsTextures {
+    /// Texture to which the glyphs are rendered before `TextEffectsRenderer::encode`.
+    pub(super) fn glyphs(
+        &mut self,
+        wgpu_ctx: &Arc<WgpuCtx>,
+        resolution: Resolution,
+    ) -> &NodeTextureState {
+        self.glyphs.ensure_size(wgpu_ctx, resolution)
+    }
+}
+
+fn push_constants(effects: &TextEffects, pass: EffectsPass) -> [u8; 64] {
+    // Missing effects are fully transparent.
+    let transparent = RGBAColor(0, 0, 0, 0);
+    let stroke_color = effects
+        .stroke
+        .as_ref()
+        .map(|stroke| stroke.color)
+        .unwrap_or(transparent);
+    let shadow_color = effects
+        .shadow
+        .as_ref()
+        .map(|shadow| shadow.color)
+        .unwrap_or(transparent);
+    let shadow_offset = effects
+        .shadow
+        .as_ref()
+        .map(|shadow| [shadow.offset_x, shadow.offset_y])
+        .unwrap_or_default();
+    let stroke_width = effects
+        .stroke
+        .as_ref()
+        .map(|stroke| stroke.width.clamp(0.0, TextStroke::MAX_WIDTH))
+        .unwrap_or(0.0);
+    let pass_type: u32 = match pass {
+        EffectsPass::HorizontalDistance => 0,
+        EffectsPass::Outline => 1,
+        EffectsPass::Composite => 2,
+    };
+
+    let mut result = [0u8; 64];
+    result[0..16].copy_from_slice(&linear_color(stroke_color));
+    result[16..32].copy_from_slice(&linear_color(shadow_color));
+    result[32..48].copy_from_slice(&background_color(effects.background_color));
+    result[48..52].copy_from_slice(&shadow_offset[0].to_le_bytes());
+    result[52..56].copy_from_slice(&shadow_offset[1].to_le_bytes());
+    result[56..60].copy_from_slice(&stroke_width.to_le_bytes());
+    result[60..64].copy_from_slice(&pass_type.to_le_bytes());
+    result
+}
+
+/// Colors of the stroke and shadow are converted the same way as the color of the glyphs.
+fn linear_color(color: RGBAColor) -> [u8; 16] {
+    let RGBAColor(r, g, b, a) = color;
+    let mut result = [0u8; 16];
+    result[0..4].copy_from_slice(&srgb_to_linear(r).to_le_bytes());
+    result[4..8].copy_from_slice(&srgb_to_linear(g).to_le_bytes());
+    result[8..12].copy_from_slice(&srgb_to_linear(b).to_le_bytes());
+    result[12..16].copy_from_slice(&(a as f32 / 255.0).to_le_bytes());
+    result
+}
+
+/// Background is written with the same values as the clear color of the text
+/// rendered without effects.
+fn background_color(color: RGBAColor) -> [u8; 16] {
+    let RGBAColor(r, g, b, a) = color;
+    let mut result = [0u8; 16];
+    for (i, component) in [r, g, b, a].into_iter().enumerate() {
+        result[i * 4..(i + 1) * 4].copy_from_slice(&(component as f32 / 255.0).to_le_bytes());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scene::{RGBAColor, TextShadow, TextStroke};
+
+    use super::{push_constants, EffectsPass, TextEffects};
+
+    fn f32_at(bytes: &[u8], offset: usize) -> f32 {
+        f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn missing_effects_are_transparent() {
+        let effects = TextEffects {
+            stroke: None,
+            shadow: None,
+            background_color: RGBAColor(255, 0, 0, 255),
+        };
+        let bytes = push_constants(&effects, EffectsPass::Composite);
+        assert_eq!(f32_at(&bytes, 12), 0.0);
+        assert_eq!(f32_at(&bytes, 28), 0.0);
+        assert_eq!(f32_at(&bytes, 32), 1.0);
+        assert_eq!(f32_at(&bytes, 56), 0.0);
+        assert_eq!(u32::from_le_bytes(bytes[60..64].try_into().unwrap()), 2);
+    }
+
+    #[test]
+    fn effects_keep_alpha_and_clamp_stroke_width() {
+        let effects = TextEffects {
+            stroke: Some(TextStroke {
+                width: 100.0,
+                color: RGBAColor(0, 0, 0, 51),
+            }),
+            shadow: Some(TextShadow {
+                offset_x: 2.5,
+                offset_y: -3.0,
+                color: RGBAColor(255, 255, 255, 102),
+            }),
+            background_color: RGBAColor(0, 0, 0, 0),
+        };
+        let bytes = push_constants(&effects, EffectsPass::Outline);
+        assert_eq!(f32_at(&bytes, 12), 0.2);
+        assert_eq!(f32_at(&bytes, 16), 1.0);
+        assert_eq!(f32_at(&bytes, 28), 0.4);
+        assert_eq!(f32_at(&bytes, 48), 2.5);
+        assert_eq!(f32_at(&bytes, 52), -3.0);
+        assert_eq!(f32_at(&bytes, 56), TextStroke::MAX_WIDTH);
+        assert_eq!(u32::from_le_bytes(bytes[60..64].try_into().unwrap()), 1);
+    }
+}
@@ -0,0 +1,141 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+struct EffectsInfo {
+    // linear RGB, alpha 0 if the text has no stroke
+    stroke_color: vec4<f32>,
+    // linear RGB, alpha 0 if the text has no shadow
+    shadow_color: vec4<f32>,
+    background_color: vec4<f32>,
+    // in pixels
+    shadow_offset: vec2<f32>,
+    // in pixels
+    stroke_width: f32,
+    // 0 -> horizontal distance, 1 -> outline, 2 -> composite
+    pass_type: u32,
+}
+
+var<push_constant> effects_info: EffectsInfo;
+
+// Glyphs in pass 0 and 1, outline (or glyphs if the text has no stroke) in pass 2.
+@group(0) @binding(0) var mask_texture: texture_2d<f32>;
+// Result of pass 0, used only by pass 1.
+@group(0) @binding(1) var distance_texture: texture_2d<f32>;
+
+// Distances are searched up to `TextStroke::MAX_WIDTH + 1` pixels.
+const MAX_RADIUS: i32 = 21;
+// Stored in the distance texture if there are no glyphs within the radius.
+const NO_DISTANCE: f32 = 255.0;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    output.position = vec4(input.position, 1.0);
+    output.tex_coords = input.tex_coords;
+
+    return output;
+}
+
+fn in_bounds(coords: vec2<i32>, texture: texture_2d<f32>) -> bool {
+    let dimensions = vec2<i32>(textureDimensions(texture));
+    return all(coords >= vec2<i32>(0)) && all(coords < dimensions);
+}
+
+fn mask(coords: vec2<i32>) -> f32 {
+    if (!in_bounds(coords, mask_texture)) {
+        return 0.0;
+    }
+    return textureLoad(mask_texture, coords, 0).a;
+}
+
+fn radius() -> i32 {
+    return min(i32(ceil(effects_info.stroke_width)) + 1, MAX_RADIUS);
+}
+
+// Distance to the closest pixel in the same row that is covered by a glyph.
+fn horizontal_distance(coords: vec2<i32>) -> f32 {
+    let radius = radius();
+    var distance = NO_DISTANCE;
+    for (var dx = -radius; dx <= radius; dx++) {
+        if (mask(coords + vec2<i32>(dx, 0)) >= 0.5) {
+            distance = min(distance, f32(abs(dx)));
+        }
+    }
+    return distance;
+}
+
+// Distance to the closest pixel covered by a glyph combined from horizontal distances
+// of the rows above and below. Pixel centers are half a pixel inside the edge of a glyph,
+// so the outline is smoothed over a pixel around `stroke_width`.
+fn outline(coords: vec2<i32>) -> f32 {
+    let radius = radius();
+    var distance_squared = NO_DISTANCE * NO_DISTANCE;
+    for (var dy = -radius; dy <= radius; dy++) {
+        let row_coords = coords + vec2<i32>(0, dy);
+        if (!in_bounds(row_coords, distance_texture)) {
+            continue;
+        }
+        let dx = round(textureLoad(distance_texture, row_coords, 0).a * 255.0);
+        if (dx < NO_DISTANCE) {
+            distance_squared = min(distance_squared, dx * dx + f32(dy * dy));
+        }
+    }
+    let coverage = clamp(effects_info.stroke_width + 0.5 - sqrt(distance_squared), 0.0, 1.0);
+    return max(coverage, mask(coords));
+}
+
+// Bilinear interpolation of the mask, shadow offset does not have to be a whole pixel.
+fn shifted_mask(position: vec2<f32>) -> f32 {
+    let shifted = position - effects_info.shadow_offset - 0.5;
+    let coords = vec2<i32>(floor(shifted));
+    let t = fract(shifted);
+    let top = mix(mask(coords), mask(coords + vec2<i32>(1, 0)), t.x);
+    let bottom = mix(mask(coords + vec2<i32>(0, 1)), mask(coords + vec2<i32>(1, 1)), t.x);
+    return mix(top, bottom, t.y);
+}
+
+fn over(color: vec3<f32>, alpha: f32, background: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(color * alpha, alpha) + background * (1.0 - alpha);
+}
+
+// Background, shadow and stroke composited with premultiplied colors. Text is rendered
+// on top of the result.
+fn composite(position: vec2<f32>) -> vec4<f32> {
+    let background = effects_info.background_color;
+    var color = vec4<f32>(background.rgb * background.a, background.a);
+
+    let shadow = effects_info.shadow_color;
+    color = over(shadow.rgb, shifted_mask(position) * shadow.a, color);
+
+    let stroke = effects_info.stroke_color;
+    color = over(stroke.rgb, mask(vec2<i32>(floor(position))) * stroke.a, color);
+
+    if (color.a < 0.0001) {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let coords = vec2<i32>(floor(input.position.xy));
+    switch (effects_info.pass_type) {
+        case 0u: {
+            return vec4<f32>(0.0, 0.0, 0.0, horizontal_distance(coords) / 255.0);
+        }
+        case 1u: {
+            return vec4<f32>(1.0, 1.0, 1.0, outline(coords));
+        }
+        default: {
+            return composite(input.position.xy);
+        }
+    }
+}
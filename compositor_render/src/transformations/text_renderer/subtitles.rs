@@ -0,0 +1,383 @@
+use std::{
+    str::Utf8Error,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::asset::{AssetLoadError, AssetSource};
+
+#[derive(Debug, Clone)]
+pub struct SubtitlesSpec {
+    /// Track without a source is empty, cues can be added with `Renderer::add_subtitle_cue`.
+    pub src: Option<AssetSource>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleCue {
+    pub start: Duration,
+    pub end: Duration,
+    pub text: Arc<str>,
+}
+
+/// Cues loaded from a SRT or WebVTT file. Cues can be added after the registration,
+/// so the same track can be used for live captions.
+#[derive(Debug)]
+pub struct Subtitles {
+    /// Cues loaded from the file, sorted by `start`.
+    cues: Vec<SubtitleCue>,
+    live_cues: Mutex<LiveCues>,
+}
+
+/// Cues added with `Subtitles::add_cue`. Cues that ended before the earliest time
+/// that can still be displayed are removed, so only current and upcoming cues are kept.
+#[derive(Debug, Default)]
+struct LiveCues {
+    /// Sorted by `start`.
+    cues: Vec<SubtitleCue>,
+    last_pts: Duration,
+    /// Largest `offset_ms` of the components that displayed the track. Component
+    /// added later with a larger offset does not display cues that were already removed.
+    largest_offset_ms: Option<i64>,
+}
+
+/// Position of a text component in the cues loaded from the file. Displayed time
+/// only moves forward, so every cue is visited once instead of on every frame.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SubtitlesCursor {
+    time: Duration,
+    /// Index of the first cue that did not start before `time`.
+    next: usize,
+    /// Indexes of the cues displayed at `time`.
+    active: Vec<usize>,
+}
+
+impl Subtitles {
+    pub fn new(spec: SubtitlesSpec) -> Result<Self, SubtitlesError> {
+        let cues = match spec.src {
+            Some(src) => {
+                let file = src.load()?;
+                let mut cues = parse_subtitles(std::str::from_utf8(&file)?)?;
+                cues.sort_by_key(|cue| cue.start);
+                cues
+            }
+            None => Vec::new(),
+        };
+        Ok(Self {
+            cues,
+            live_cues: Mutex::default(),
+        })
+    }
+
+    pub(crate) fn add_cue(&self, cue: SubtitleCue) {
+        let mut live_cues = self.live_cues.lock().unwrap();
+        let index = live_cues
+            .cues
+            .partition_point(|existing| existing.start <= cue.start);
+        live_cues.cues.insert(index, cue);
+    }
+
+    /// Text of all cues displayed at `pts` shifted by `offset_ms`, each in a separate line.
+    pub(crate) fn text(
+        &self,
+        pts: Duration,
+        offset_ms: i64,
+        cursor: &mut SubtitlesCursor,
+    ) -> String {
+        let time = cue_time(pts, offset_ms);
+        let live_cues: Vec<SubtitleCue> = {
+            let mut live_cues = self.live_cues.lock().unwrap();
+            live_cues.prune(pts, offset_ms);
+            match time {
+                Some(time) => live_cues
+                    .cues
+                    .iter()
+                    .take_while(|cue| cue.start <= time)
+                    .filter(|cue| cue.end > time)
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            }
+        };
+        let Some(time) = time else {
+            return String::new();
+        };
+
+        cursor.seek(&self.cues, time);
+        let mut cues = cursor
+            .active
+            .iter()
+            .map(|index| &self.cues[*index])
+            .chain(&live_cues)
+            .collect::<Vec<_>>();
+        // stable sort, cues from the file are displayed first if they start at the same time
+        cues.sort_by_key(|cue| cue.start);
+        cues.iter()
+            .map(|cue| cue.text.as_ref())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl LiveCues {
+    fn prune(&mut self, pts: Duration, offset_ms: i64) {
+        self.last_pts = Duration::max(self.last_pts, pts);
+        let largest_offset_ms = i64::max(self.largest_offset_ms.unwrap_or(offset_ms), offset_ms);
+        self.largest_offset_ms = Some(largest_offset_ms);
+        if let Some(earliest_time) = cue_time(self.last_pts, largest_offset_ms) {
+            self.cues.retain(|cue| cue.end > earliest_time);
+        }
+    }
+}
+
+impl SubtitlesCursor {
+    fn seek(&mut self, cues: &[SubtitleCue], time: Duration) {
+        if time < self.time {
+            *self = Self::default();
+        }
+        while let Some(cue) = cues.get(self.next).filter(|cue| cue.start <= time) {
+            if cue.end > time {
+                self.active.push(self.next);
+            }
+            self.next += 1;
+        }
+        self.active.retain(|index| cues[*index].end > time);
+        self.time = time;
+    }
+}
+
+/// Time of the cues displayed at `pts`. Positive offset delays the subtitles,
+/// nothing is displayed before the offset.
+fn cue_time(pts: Duration, offset_ms: i64) -> Option<Duration> {
+    let offset = Duration::from_millis(offset_ms.unsigned_abs());
+    match offset_ms >= 0 {
+        true => pts.checked_sub(offset),
+        false => pts.checked_add(offset),
+    }
+}
+
+/// Parses both SRT and WebVTT files. Blocks without a timing line (e.g. the `WEBVTT`
+/// header, `NOTE` and `STYLE` blocks) are skipped. Formatting tags are removed from
+/// the text of the cues.
+fn parse_subtitles(content: &str) -> Result<Vec<SubtitleCue>, SubtitlesParseError> {
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut cues = Vec::new();
+    let mut line_number = 1;
+
+    for block in content.split("\n\n") {
+        let block_line_number = line_number;
+        line_number += block.lines().count() + 1;
+
+        let mut lines = block.lines().skip_while(|line| line.trim().is_empty());
+        let Some(first_line) = lines.next() else {
+            continue;
+        };
+        if ["NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|keyword| first_line.starts_with(keyword))
+        {
+            continue;
+        }
+        // Cue number (SRT) or cue identifier (WebVTT) before the timing line.
+        let timing_line = match first_line.contains("-->") {
+            true => first_line,
+            false => match lines.next() {
+                Some(line) if line.contains("-->") => line,
+                _ => continue,
+            },
+        };
+
+        let (start, end) = timing_line
+            .split_once("-->")
+            .ok_or(SubtitlesParseError::InvalidTiming(block_line_number))?;
+        // WebVTT cue settings are placed after the end timestamp.
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let start = parse_timestamp(start.trim())
+            .ok_or(SubtitlesParseError::InvalidTiming(block_line_number))?;
+        let end =
+            parse_timestamp(end).ok_or(SubtitlesParseError::InvalidTiming(block_line_number))?;
+
+        let text = lines.map(strip_tags).collect::<Vec<_>>().join("\n");
+        cues.push(SubtitleCue {
+            start,
+            end,
+            text: text.into(),
+        })
+    }
+
+    Ok(cues)
+}
+
+/// Parses `HH:MM:SS,mmm` (SRT), `HH:MM:SS.mmm` or `MM:SS.mmm` (WebVTT).
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (time, millis) = timestamp.split_once([',', '.'])?;
+    let millis: u64 = millis.parse().ok()?;
+
+    let mut parts = time.rsplit(':');
+    let seconds: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let hours: u64 = match parts.next() {
+        Some(hours) => hours.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() || seconds > 59 || minutes > 59 || millis > 999 {
+        return None;
+    }
+
+    Some(Duration::from_millis(
+        ((hours * 60 + minutes) * 60 + seconds) * 1000 + millis,
+    ))
+}
+
+/// Removes HTML-like tags (e.g. `<i>`, `<c.yellow>`, `<00:01.000>`) and SSA overrides
+/// (e.g. `{\an8}`).
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut closing_char = None;
+    for char in line.chars() {
+        match (closing_char, char) {
+            (None, '<') => closing_char = Some('>'),
+            (None, '{') if line.contains("{\\") => closing_char = Some('}'),
+            (None, char) => text.push(char),
+            (Some(closing), char) if closing == char => closing_char = None,
+            (Some(_), _) => (),
+        }
+    }
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{a0}")
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubtitlesError {
+    #[error("Failed to load subtitles file: {0}")]
+    SubtitlesLoad(#[from] AssetLoadError),
+
+    #[error("Invalid utf-8 content inside subtitles file: {0}")]
+    InvalidUtf8Content(#[from] Utf8Error),
+
+    #[error(transparent)]
+    ParsingSubtitlesFailed(#[from] SubtitlesParseError),
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum SubtitlesParseError {
+    #[error("Invalid cue timing in a block starting at line {0}.")]
+    InvalidTiming(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(start_ms: u64, end_ms: u64, text: &str) -> SubtitleCue {
+        SubtitleCue {
+            start: Duration::from_millis(start_ms),
+            end: Duration::from_millis(end_ms),
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn test_parse_srt() {
+        let content = "1\r\n00:00:01,000 --> 00:00:02,500\r\nFirst line\r\n<i>second</i> line\r\n\r\n2\r\n01:00:00,000 --> 01:00:01,000\r\n{\\an8}Top\r\n";
+        assert_eq!(
+            parse_subtitles(content),
+            Ok(vec![
+                cue(1000, 2500, "First line\nsecond line"),
+                cue(3_600_000, 3_601_000, "Top"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_webvtt() {
+        let content = "WEBVTT - title\n\nNOTE comment\n\nintro\n00:01.000 --> 00:02.000 align:start line:0\n<v Speaker>Hello &amp; welcome\n\n00:00:03.000 --> 00:00:04.000\n<c.yellow>Bye</c>\n";
+        assert_eq!(
+            parse_subtitles(content),
+            Ok(vec![
+                cue(1000, 2000, "Hello & welcome"),
+                cue(3000, 4000, "Bye"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_timing() {
+        let content = "1\n00:00:01 --> 00:00:02,000\ntext\n";
+        assert_eq!(
+            parse_subtitles(content),
+            Err(SubtitlesParseError::InvalidTiming(1))
+        );
+    }
+
+    fn text_at(subtitles: &Subtitles, cursor: &mut SubtitlesCursor, ms: u64) -> String {
+        subtitles.text(Duration::from_millis(ms), 0, cursor)
+    }
+
+    #[test]
+    fn test_overlapping_cues() {
+        let subtitles = Subtitles {
+            cues: vec![cue(1000, 3000, "first")],
+            live_cues: Mutex::default(),
+        };
+        subtitles.add_cue(cue(2000, 4000, "second"));
+        let cursor = &mut SubtitlesCursor::default();
+        assert_eq!(text_at(&subtitles, cursor, 500), "");
+        assert_eq!(text_at(&subtitles, cursor, 1000), "first");
+        assert_eq!(text_at(&subtitles, cursor, 2500), "first\nsecond");
+        assert_eq!(text_at(&subtitles, cursor, 3000), "second");
+        assert_eq!(text_at(&subtitles, cursor, 4000), "");
+    }
+
+    #[test]
+    fn test_cursor() {
+        let subtitles = Subtitles {
+            cues: vec![
+                cue(0, 10_000, "long"),
+                cue(1000, 2000, "first"),
+                cue(3000, 4000, "second"),
+            ],
+            live_cues: Mutex::default(),
+        };
+        let cursor = &mut SubtitlesCursor::default();
+        assert_eq!(text_at(&subtitles, cursor, 1500), "long\nfirst");
+        assert_eq!(text_at(&subtitles, cursor, 3500), "long\nsecond");
+        assert_eq!(cursor.next, 3);
+        assert_eq!(cursor.active, vec![0, 2]);
+        assert_eq!(text_at(&subtitles, cursor, 5000), "long");
+        assert_eq!(cursor.active, vec![0]);
+
+        // moving back in time starts from the first cue
+        assert_eq!(text_at(&subtitles, cursor, 1500), "long\nfirst");
+    }
+
+    #[test]
+    fn test_prune_live_cues() {
+        let subtitles = Subtitles {
+            cues: Vec::new(),
+            live_cues: Mutex::default(),
+        };
+        subtitles.add_cue(cue(1000, 2000, "first"));
+        subtitles.add_cue(cue(3000, 4000, "second"));
+        let cursor = &mut SubtitlesCursor::default();
+
+        // displayed with a 1 second offset, so the first cue is still needed at 2.5 s
+        assert_eq!(
+            subtitles.text(Duration::from_millis(2500), 1000, cursor),
+            "first"
+        );
+        assert_eq!(subtitles.live_cues.lock().unwrap().cues.len(), 2);
+
+        assert_eq!(
+            text_at(&subtitles, &mut SubtitlesCursor::default(), 3500),
+            "second"
+        );
+        assert_eq!(subtitles.live_cues.lock().unwrap().cues.len(), 1);
+        assert_eq!(
+            subtitles.text(Duration::from_millis(5500), 1000, cursor),
+            ""
+        );
+        assert!(subtitles.live_cues.lock().unwrap().cues.is_empty());
+    }
+}
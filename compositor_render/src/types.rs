@@ -10,6 +10,10 @@ pub struct Frame {
     pub data: FrameData,
     pub resolution: Resolution,
     pub pts: Duration,
+    /// Closed captions associated with this frame, represented as CEA-708 `cc_data`
+    /// (a sequence of 3 byte `cc_data_pkt` structures). Renderer does not use this
+    /// value, it is only passed through.
+    pub captions: Option<bytes::Bytes>,
 }
 
 #[derive(Debug, Clone)]
@@ -12,6 +12,15 @@ pub(crate) fn rgba_to_wgpu_color(rgba_color: &RGBAColor) -> wgpu::Color {
     }
 }
 
+pub(crate) fn srgb_to_linear(color: u8) -> f32 {
+    let color = color as f32 / 255.0;
+    if color < 0.04045 {
+        color / 12.92
+    } else {
+        f32::powf((color + 0.055) / 1.055, 2.4)
+    }
+}
+
 pub(crate) fn random_string(length: usize) -> String {
     thread_rng()
         .sample_iter(&Alphanumeric)
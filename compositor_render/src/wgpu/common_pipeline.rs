@@ -95,6 +95,22 @@ pub fn create_render_pipeline(
     device: &wgpu::Device,
     pipeline_layout: &wgpu::PipelineLayout,
     shader_module: &wgpu::ShaderModule,
+) -> wgpu::RenderPipeline {
+    create_render_pipeline_with_blend(
+        device,
+        pipeline_layout,
+        shader_module,
+        Some(wgpu::BlendState::ALPHA_BLENDING),
+    )
+}
+
+/// Same as `create_render_pipeline`, but output of the fragment shader is combined
+/// with the target using the provided blend state (`None` replaces the target).
+pub fn create_render_pipeline_with_blend(
+    device: &wgpu::Device,
+    pipeline_layout: &wgpu::PipelineLayout,
+    shader_module: &wgpu::ShaderModule,
+    blend: Option<wgpu::BlendState>,
 ) -> wgpu::RenderPipeline {
     device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: None,
@@ -120,7 +136,7 @@ pub fn create_render_pipeline(
             targets: &[Some(wgpu::ColorTargetState {
                 format: wgpu::TextureFormat::Rgba8UnormSrgb,
                 write_mask: wgpu::ColorWrites::all(),
-                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                blend,
             })],
             compilation_options: wgpu::PipelineCompilationOptions::default(),
         }),
@@ -18,6 +18,8 @@ impl RGBATexture {
                 height: resolution.height as u32,
                 depth_or_array_layers: 1,
             },
+            // Sampling an sRGB texture decodes values to linear space and writing to it
+            // encodes them back, so shaders always operate on linear values.
             wgpu::TextureFormat::Rgba8UnormSrgb,
             wgpu::TextureUsages::RENDER_ATTACHMENT
                 | wgpu::TextureUsages::COPY_DST
@@ -1,12 +1,9 @@
-use std::sync::Arc;
-
 use bytes::Bytes;
 use compositor_api::types as api;
 use compositor_render::{
-    image::{ImageSource, ImageType},
-    InputId, OutputFrameFormat, OutputId, RegistryType, Renderer, RendererId, RendererSpec,
+    asset::AssetSource, image::ImageType, InputId, OutputFrameFormat, OutputId, RegistryType,
+    Renderer, RendererId, RendererSpec,
 };
-use glyphon::fontdb::Source;
 use input_uploader::InputUploader;
 use output_downloader::OutputDownloader;
 use types::to_js_error;
@@ -113,7 +110,7 @@ impl LiveCompositorRenderer {
 
         let bytes = download(&url).await?;
         let renderer_spec = RendererSpec::Image(compositor_render::image::ImageSpec {
-            src: ImageSource::Bytes { bytes },
+            src: AssetSource::Bytes { bytes },
             image_type,
         });
         self.renderer
@@ -124,7 +121,8 @@ impl LiveCompositorRenderer {
     pub async fn register_font(&mut self, font_url: String) -> Result<(), JsValue> {
         let bytes = download(&font_url).await?;
         self.renderer
-            .register_font(Source::Binary(Arc::new(bytes.to_vec())));
+            .register_font(AssetSource::Bytes { bytes })
+            .map_err(to_js_error)?;
         Ok(())
     }
 
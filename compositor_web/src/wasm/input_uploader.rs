@@ -39,6 +39,7 @@ impl InputUploader {
                     data,
                     resolution: frame.resolution,
                     pts,
+                    captions: None,
                 },
             );
         }
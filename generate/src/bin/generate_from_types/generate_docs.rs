@@ -2,8 +2,9 @@ use super::docs_config::DocsConfig;
 use super::document::generate;
 use super::markdown::overrides;
 use compositor_api::types::{
-    DeckLink, Image, ImageSpec, InputStream, Mp4Input, Mp4Output, Rescaler, RtpInput, RtpOutput,
-    Shader, ShaderSpec, Text, Tiles, View, WebRendererSpec, WebView,
+    AudioMeter, ChromaKey, ColorCorrection, DeckLink, FontSpec, Grid, Image, ImageSpec,
+    InputStream, LutSpec, Mask, Mp4Input, Mp4Output, Rescaler, RtpInput, RtpOutput, Shader,
+    ShaderSpec, Subtitles, SubtitlesSpec, Text, Ticker, Tiles, View, WebRendererSpec, WebView,
 };
 use std::{fs, path::PathBuf};
 
@@ -24,6 +25,9 @@ pub fn generate_docs() {
     let renderer_pages = [
         generate::<ShaderSpec>("Shader", &config),
         generate::<ImageSpec>("Image", &config),
+        generate::<LutSpec>("Lut", &config),
+        generate::<SubtitlesSpec>("Subtitles", &config),
+        generate::<FontSpec>("Font", &config),
         generate::<WebRendererSpec>("WebRenderer", &config),
         generate::<RtpInput>("RtpInputStream", &config),
         generate::<Mp4Input>("Mp4Input", &config),
@@ -39,6 +43,13 @@ pub fn generate_docs() {
         generate::<Text>("Text", &config),
         generate::<Tiles>("Tiles", &config),
         generate::<Rescaler>("Rescaler", &config),
+        generate::<Grid>("Grid", &config),
+        generate::<Mask>("Mask", &config),
+        generate::<ChromaKey>("ChromaKey", &config),
+        generate::<ColorCorrection>("ColorCorrection", &config),
+        generate::<Ticker>("Ticker", &config),
+        generate::<Subtitles>("Subtitles", &config),
+        generate::<AudioMeter>("AudioMeter", &config),
     ];
 
     let output_pages = [
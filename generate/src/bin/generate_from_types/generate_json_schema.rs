@@ -19,6 +19,9 @@ enum ApiTypes {
     RegisterInput(routes::RegisterInput),
     RegisterOutput(routes::RegisterOutput),
     RegisterImage(types::ImageSpec),
+    RegisterLut(types::LutSpec),
+    RegisterSubtitles(types::SubtitlesSpec),
+    RegisterFont(types::FontSpec),
     RegisterWebRenderer(types::WebRendererSpec),
     RegisterShader(types::ShaderSpec),
     UpdateOutput(types::UpdateOutputRequest),
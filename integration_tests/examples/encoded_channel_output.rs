@@ -58,6 +58,13 @@ fn main() {
                     width: 1280,
                     height: 720,
                 },
+                rate_control: Default::default(),
+                gop_size: None,
+                keyframe_interval: None,
+                b_frames: None,
+                profile: None,
+                level: None,
+                tune: None,
                 raw_options: vec![],
             })),
             audio: Some(AudioEncoderOptions::Opus(
@@ -73,6 +80,7 @@ fn main() {
                 input_id: input_id.clone(),
             }),
             end_condition: PipelineOutputEndCondition::Never,
+            captions_source: None,
         }),
         audio: Some(compositor_pipeline::pipeline::OutputAudioOptions {
             initial: AudioMixingParams {
@@ -79,6 +79,13 @@ fn main() {
                     width: 1280,
                     height: 720,
                 },
+                rate_control: Default::default(),
+                gop_size: None,
+                keyframe_interval: None,
+                b_frames: None,
+                profile: None,
+                level: None,
+                tune: None,
                 raw_options: vec![],
             })),
             audio: None,
@@ -89,6 +96,7 @@ fn main() {
                 input_id: input_id.clone(),
             }),
             end_condition: PipelineOutputEndCondition::Never,
+            captions_source: None,
         }),
         audio: None, // TODO: add audio example
     };
@@ -142,6 +150,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_a.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -150,6 +159,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_b.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -158,6 +168,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_c.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -166,6 +177,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_a.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -174,6 +186,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_b.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -182,6 +195,7 @@ fn generate_frames(device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<Frame> {
             data: FrameData::Rgba8UnormWgpuTexture(texture_c.clone()),
             resolution,
             pts: Duration::from_millis(i * 20),
+            captions: None,
         })
     }
 
@@ -95,6 +95,7 @@ fn main() {
                 input_id: input_id.clone(),
             }),
             end_condition: PipelineOutputEndCondition::Never,
+            captions_source: None,
         }),
         audio: Some(compositor_pipeline::pipeline::OutputAudioOptions {
             initial: AudioMixingParams {
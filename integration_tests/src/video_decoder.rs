@@ -88,6 +88,7 @@ impl VideoDecoder {
                 data,
                 resolution,
                 pts: Duration::from_micros(pts as u64),
+                captions: None,
             });
         }
 
@@ -15,12 +15,13 @@ use crate::state::{ApiState, Response};
 use compositor_api::error::ApiError;
 
 use self::{
-    update_output::handle_keyframe_request, update_output::handle_output_update,
-    ws::handle_ws_upgrade,
+    update_output::handle_encoder_update, update_output::handle_keyframe_request,
+    update_output::handle_output_update, ws::handle_ws_upgrade,
 };
 use crate::middleware::body_logger_middleware;
 
 mod register_request;
+mod subtitles;
 mod unregister_request;
 mod update_output;
 mod ws;
@@ -39,12 +40,30 @@ pub fn routes(state: ApiState) -> Router {
         .route("/:id/register", post(register_request::handle_output))
         .route("/:id/unregister", post(unregister_request::handle_output))
         .route("/:id/update", post(handle_output_update))
-        .route("/:id/request_keyframe", post(handle_keyframe_request));
+        .route("/:id/request_keyframe", post(handle_keyframe_request))
+        .route("/:id/update_encoder", post(handle_encoder_update));
 
     let image = Router::new()
         .route("/:id/register", post(register_request::handle_image))
         .route("/:id/unregister", post(unregister_request::handle_image));
 
+    let lut = Router::new()
+        .route("/:id/register", post(register_request::handle_lut))
+        .route("/:id/unregister", post(unregister_request::handle_lut));
+
+    let subtitles = Router::new()
+        .route("/:id/register", post(register_request::handle_subtitles))
+        .route(
+            "/:id/unregister",
+            post(unregister_request::handle_subtitles),
+        )
+        .route("/:id/add_cue", post(subtitles::handle_add_cue));
+
+    let font = Router::new()
+        .route("/register", post(register_request::handle_font))
+        .route("/unregister", post(unregister_request::handle_font))
+        .route("/families", get(handle_font_families));
+
     let web = Router::new()
         .route("/:id/register", post(register_request::handle_web_renderer))
         .route(
@@ -61,10 +80,19 @@ pub fn routes(state: ApiState) -> Router {
         Ok(Response::Ok {})
     }
 
+    async fn handle_font_families(State(state): State<ApiState>) -> Response {
+        Response::FontFamilies {
+            families: state.pipeline().font_families(),
+        }
+    }
+
     Router::new()
         .nest("/api/input", inputs)
         .nest("/api/output", outputs)
         .nest("/api/image", image)
+        .nest("/api/lut", lut)
+        .nest("/api/subtitles", subtitles)
+        .nest("/api/font", font)
         .nest("/api/web-renderer", web)
         .nest("/api/shader", shader)
         // Start request
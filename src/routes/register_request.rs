@@ -10,8 +10,8 @@ use crate::{
 use compositor_api::{
     error::ApiError,
     types::{
-        DeckLink, ImageSpec, InputId, Mp4Input, Mp4Output, OutputId, RendererId, RtpInput,
-        RtpOutput, ShaderSpec, WebRendererSpec,
+        DeckLink, FontSpec, ImageSpec, InputId, LutSpec, Mp4Input, Mp4Output, OutputId, RendererId,
+        RtpInput, RtpOutput, ShaderSpec, SubtitlesSpec, WebRendererSpec,
     },
 };
 
@@ -126,3 +126,44 @@ pub(super) async fn handle_image(
     .await
     .unwrap()
 }
+
+pub(super) async fn handle_lut(
+    State(api): State<ApiState>,
+    Path(lut_id): Path<RendererId>,
+    Json(request): Json<LutSpec>,
+) -> Result<Response, ApiError> {
+    let api = api.clone();
+    tokio::task::spawn_blocking(move || {
+        Pipeline::register_renderer(&api.pipeline, lut_id.into(), request.try_into()?)?;
+        Ok(Response::Ok {})
+    })
+    .await
+    .unwrap()
+}
+
+pub(super) async fn handle_subtitles(
+    State(api): State<ApiState>,
+    Path(subtitles_id): Path<RendererId>,
+    Json(request): Json<SubtitlesSpec>,
+) -> Result<Response, ApiError> {
+    let api = api.clone();
+    tokio::task::spawn_blocking(move || {
+        Pipeline::register_renderer(&api.pipeline, subtitles_id.into(), request.try_into()?)?;
+        Ok(Response::Ok {})
+    })
+    .await
+    .unwrap()
+}
+
+pub(super) async fn handle_font(
+    State(api): State<ApiState>,
+    Json(request): Json<FontSpec>,
+) -> Result<Response, ApiError> {
+    let api = api.clone();
+    tokio::task::spawn_blocking(move || {
+        let families = Pipeline::register_font(&api.pipeline, request.try_into()?)?;
+        Ok(Response::FontFamilies { families })
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use axum::extract::{Path, State};
+use serde::{Deserialize, Serialize};
+
+use crate::state::{ApiState, Response};
+
+use compositor_api::{error::ApiError, types::RendererId, types::TypeError};
+
+use super::Json;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AddSubtitleCue {
+    /// Text of the cue, lines are separated with `\n`.
+    text: String,
+    /// Time in milliseconds when the cue should be displayed. Value `0` represents
+    /// time of the start request. Defaults to the timestamp of the last rendered frame.
+    start_ms: Option<f64>,
+    /// How long the cue is displayed in milliseconds.
+    duration_ms: f64,
+}
+
+pub(super) async fn handle_add_cue(
+    State(api): State<ApiState>,
+    Path(subtitles_id): Path<RendererId>,
+    Json(request): Json<AddSubtitleCue>,
+) -> Result<Response, ApiError> {
+    let start = request
+        .start_ms
+        .map(|start_ms| parse_duration_ms(start_ms, "start_ms"))
+        .transpose()?;
+    let duration = parse_duration_ms(request.duration_ms, "duration_ms")?;
+    api.pipeline()
+        .add_subtitle_cue(&subtitles_id.into(), start, duration, request.text)?;
+    Ok(Response::Ok {})
+}
+
+fn parse_duration_ms(value: f64, field: &str) -> Result<Duration, TypeError> {
+    Duration::try_from_secs_f64(value / 1000.0).map_err(|_| {
+        TypeError::new(format!(
+            "\"{field}\" field has to be a non-negative number."
+        ))
+    })
+}
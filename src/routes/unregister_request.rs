@@ -28,6 +28,12 @@ pub struct UnregisterOutput {
     schedule_time_ms: Option<f64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnregisterFont {
+    /// Font family of the fonts that should be removed.
+    font_family: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum UnregisterRenderer {
@@ -124,3 +130,29 @@ pub(super) async fn handle_image(
         .unregister_renderer(&image_id.into(), RegistryType::Image)?;
     Ok(Response::Ok {})
 }
+
+pub(super) async fn handle_lut(
+    State(api): State<ApiState>,
+    Path(lut_id): Path<RendererId>,
+) -> Result<Response, ApiError> {
+    api.pipeline()
+        .unregister_renderer(&lut_id.into(), RegistryType::Lut)?;
+    Ok(Response::Ok {})
+}
+
+pub(super) async fn handle_subtitles(
+    State(api): State<ApiState>,
+    Path(subtitles_id): Path<RendererId>,
+) -> Result<Response, ApiError> {
+    api.pipeline()
+        .unregister_renderer(&subtitles_id.into(), RegistryType::Subtitles)?;
+    Ok(Response::Ok {})
+}
+
+pub(super) async fn handle_font(
+    State(api): State<ApiState>,
+    Json(request): Json<UnregisterFont>,
+) -> Result<Response, ApiError> {
+    api.pipeline().unregister_font(&request.font_family)?;
+    Ok(Response::Ok {})
+}
@@ -8,7 +8,7 @@ use crate::state::{ApiState, Response};
 
 use compositor_api::{
     error::ApiError,
-    types::{OutputId, UpdateOutputRequest},
+    types::{OutputId, UpdateEncoderRequest, UpdateOutputRequest},
 };
 
 use super::Json;
@@ -59,3 +59,14 @@ pub(super) async fn handle_keyframe_request(
 
     Ok(Response::Ok {})
 }
+
+pub(super) async fn handle_encoder_update(
+    State(api): State<ApiState>,
+    Path(output_id): Path<OutputId>,
+    Json(request): Json<UpdateEncoderRequest>,
+) -> Result<Response, ApiError> {
+    let update = request.try_into()?;
+    api.pipeline().update_encoder(output_id.into(), update)?;
+
+    Ok(Response::Ok {})
+}
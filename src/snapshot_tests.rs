@@ -15,11 +15,20 @@ mod snapshot;
 mod test_case;
 mod utils;
 
+mod audio_meter_tests;
+mod blend_mode_tests;
+mod blur_tests;
+mod chroma_key_tests;
+mod color_correction_tests;
+mod gradient_tests;
 mod image_tests;
+mod mask_tests;
 mod rescaler_tests;
 mod shader_tests;
 mod simple_tests;
+mod subtitles_tests;
 mod text_tests;
+mod ticker_tests;
 mod tiles_tests;
 mod tiles_transitions_tests;
 mod transition_tests;
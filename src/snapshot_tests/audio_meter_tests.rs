@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use compositor_render::{AudioLevel, InputId};
+
+use super::{scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn audio_meter_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("audio_meter"));
+    let default = TestCase {
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(1000),
+            Duration::from_millis(2500),
+            Duration::from_millis(4000),
+        ],
+        audio_levels: audio_levels(),
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "audio_meter/bar",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/audio_meter/bar.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "audio_meter/segmented",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/audio_meter/segmented.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "audio_meter/waveform",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/audio_meter/waveform.scene.json"
+        )),
+        ..default
+    });
+
+    runner.run()
+}
+
+/// Level that rises from silence to full scale over 4 seconds with a faster
+/// oscillation on top, in 20ms batches.
+fn audio_levels() -> Vec<(Duration, Duration, InputId, AudioLevel)> {
+    let batch = Duration::from_millis(20);
+    (0..200)
+        .map(|index| {
+            let start_pts = batch * index;
+            let time = start_pts.as_secs_f32();
+            let peak = (time / 4.0 * (0.75 + 0.25 * (time * 8.0).sin())).min(1.0);
+            let level = AudioLevel {
+                rms: peak * 0.5,
+                peak,
+            };
+            (
+                start_pts,
+                start_pts + batch,
+                InputId("input_1".into()),
+                level,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,62 @@
+use super::{input::TestInput, scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn blend_mode_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("blend_mode"));
+    let default = TestCase {
+        inputs: vec![TestInput::new(1)],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "blend_mode/view_multiply",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/view_multiply.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/view_screen",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/view_screen.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/view_overlay",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/view_overlay.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/view_add",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/view_add.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/view_difference",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/view_difference.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/rescaler_multiply",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/rescaler_multiply.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blend_mode/inherited_by_children",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blend_mode/inherited_by_children.scene.json"
+        )),
+        ..Default::default()
+    });
+
+    runner.run()
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use super::{input::TestInput, scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn blur_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("blur"));
+    let default = TestCase {
+        inputs: vec![TestInput::new(1)],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "blur/view_blur_radius",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blur/view_blur_radius.scene.json"
+        )),
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "blur/rescaler_background_fill",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blur/rescaler_background_fill.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blur/border_radius_and_box_shadow",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blur/border_radius_and_box_shadow.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blur/backdrop_blur",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blur/backdrop_blur.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "blur/animated_blur_radius",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/blur/animated_blur_radius.scene.json"
+        )),
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(500),
+            Duration::from_millis(1000),
+        ],
+        ..default.clone()
+    });
+
+    runner.run()
+}
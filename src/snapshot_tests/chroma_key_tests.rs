@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use super::{input::TestInput, scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn chroma_key_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("chroma_key"));
+    let default = TestCase {
+        inputs: vec![TestInput::new(0), TestInput::new(1)],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "chroma_key/default",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/default.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "chroma_key/similarity_smoothness",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/similarity_smoothness.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "chroma_key/spill",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/spill.scene.json"
+        )),
+        inputs: vec![TestInput::new(1), TestInput::new(11)],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "chroma_key/no_spill",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/no_spill.scene.json"
+        )),
+        inputs: vec![TestInput::new(1), TestInput::new(11)],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "chroma_key/blue_key_color",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/blue_key_color.scene.json"
+        )),
+        inputs: vec![TestInput::new(0), TestInput::new(4)],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "chroma_key/animated_similarity",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/chroma_key/animated_similarity.scene.json"
+        )),
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(500),
+            Duration::from_millis(1000),
+        ],
+        ..default.clone()
+    });
+
+    runner.run()
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use compositor_render::{asset::AssetSource, lut::LutSpec, RendererId, RendererSpec};
+
+use super::{input::TestInput, scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn color_correction_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("color_correction"));
+    let default = TestCase {
+        inputs: vec![
+            TestInput::new(0),
+            TestInput::new(2),
+            TestInput::new(4),
+            TestInput::new(8),
+        ],
+        ..Default::default()
+    };
+    let invert_lut = (
+        RendererId("invert_lut".into()),
+        RendererSpec::Lut(LutSpec {
+            src: AssetSource::Bytes {
+                bytes: Bytes::from_static(include_bytes!(
+                    "../../snapshot_tests/color_correction/invert.cube"
+                )),
+            },
+        }),
+    );
+
+    runner.add(TestCase {
+        name: "color_correction/brightness_contrast",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/brightness_contrast.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/saturation",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/saturation.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/gamma",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/gamma.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/white_balance",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/white_balance.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/lift_gamma_gain",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/lift_gamma_gain.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/lut",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/lut.scene.json"
+        )),
+        renderers: vec![invert_lut.clone()],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/lut_half_strength",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/lut_half_strength.scene.json"
+        )),
+        renderers: vec![invert_lut.clone()],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "color_correction/animated_saturation",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/color_correction/animated_saturation.scene.json"
+        )),
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(500),
+            Duration::from_millis(1000),
+        ],
+        ..default.clone()
+    });
+
+    runner.run()
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use super::{
+    input::TestInput, scene_from_json, scenes_from_json, snapshots_path, test_case::TestCase,
+    TestRunner,
+};
+
+#[test]
+fn gradient_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("gradient"));
+
+    runner.add(TestCase {
+        name: "gradient/linear_background",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/gradient/linear_background.scene.json"
+        )),
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "gradient/radial_and_conic",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/gradient/radial_and_conic.scene.json"
+        )),
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "gradient/border_gradient",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/gradient/border_gradient.scene.json"
+        )),
+        inputs: vec![TestInput::new(1)],
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "gradient/text_gradient",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/gradient/text_gradient.scene.json"
+        )),
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "gradient/transition",
+        scene_updates: scenes_from_json(&[
+            include_str!("../../snapshot_tests/gradient/transition_start.scene.json"),
+            include_str!("../../snapshot_tests/gradient/transition_end.scene.json"),
+        ]),
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(500),
+            Duration::from_millis(1000),
+        ],
+        ..Default::default()
+    });
+
+    runner.run()
+}
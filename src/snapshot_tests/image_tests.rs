@@ -1,5 +1,6 @@
 use compositor_render::{
-    image::{ImageSource, ImageSpec, ImageType},
+    asset::AssetSource,
+    image::{ImageSpec, ImageType},
     RendererId, RendererSpec,
 };
 
@@ -15,7 +16,7 @@ fn image_tests() {
     let image_renderer = (
         RendererId("image_jpeg".into()),
         RendererSpec::Image(ImageSpec {
-            src: ImageSource::Url {
+            src: AssetSource::Url {
                 url: "https://www.rust-lang.org/static/images/rust-social.jpg".to_string(),
             },
             image_type: ImageType::Jpeg,
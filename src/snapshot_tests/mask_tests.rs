@@ -0,0 +1,37 @@
+use super::{input::TestInput, scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn mask_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("mask"));
+    let default = TestCase {
+        inputs: vec![TestInput::new(0)],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "mask/alpha",
+        scene_updates: scene_from_json(include_str!("../../snapshot_tests/mask/alpha.scene.json")),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "mask/luminance",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/mask/luminance.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "mask/path",
+        scene_updates: scene_from_json(include_str!("../../snapshot_tests/mask/path.scene.json")),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "mask/path_and_component",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/mask/path_and_component.scene.json"
+        )),
+        ..default.clone()
+    });
+
+    runner.run()
+}
@@ -226,6 +226,7 @@ fn user_params_snapshot_tests(runner: &mut TestRunner) {
             layout3.shader_param(),
             layout4.shader_param(),
         ])),
+        audio_inputs: vec![],
         size: DEFAULT_RESOLUTION.into(),
         children: vec![
             Component::InputStream(InputStreamComponent {
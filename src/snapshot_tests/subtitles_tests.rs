@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use compositor_render::{asset::AssetSource, subtitles::SubtitlesSpec, RendererId, RendererSpec};
+
+use super::{scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn subtitles_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("subtitles"));
+    let subtitles = |bytes: &'static [u8]| {
+        (
+            RendererId("example".into()),
+            RendererSpec::Subtitles(SubtitlesSpec {
+                src: Some(AssetSource::Bytes {
+                    bytes: Bytes::from_static(bytes),
+                }),
+            }),
+        )
+    };
+    let srt = subtitles(include_bytes!("../../snapshot_tests/subtitles/example.srt"));
+    let vtt = subtitles(include_bytes!("../../snapshot_tests/subtitles/example.vtt"));
+    let default = TestCase {
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(1000),
+            Duration::from_millis(1800),
+            Duration::from_millis(3000),
+        ],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "subtitles/srt_bottom",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/subtitles/bottom.scene.json"
+        )),
+        renderers: vec![srt.clone()],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "subtitles/vtt_bottom",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/subtitles/bottom.scene.json"
+        )),
+        renderers: vec![vtt],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "subtitles/srt_center",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/subtitles/center.scene.json"
+        )),
+        renderers: vec![srt.clone()],
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "subtitles/srt_offset",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/subtitles/offset.scene.json"
+        )),
+        renderers: vec![srt],
+        ..default
+    });
+
+    runner.run()
+}
@@ -9,8 +9,8 @@ use super::{
 
 use anyhow::Result;
 use compositor_render::{
-    scene::Component, Frame, FrameSet, InputId, OutputFrameFormat, OutputId, Renderer, RendererId,
-    RendererSpec, Resolution,
+    scene::Component, AudioLevel, Frame, FrameSet, InputId, OutputFrameFormat, OutputId, Renderer,
+    RendererId, RendererSpec, Resolution,
 };
 
 pub(super) const OUTPUT_ID: &str = "output_1";
@@ -22,6 +22,12 @@ pub(super) struct TestCase {
     pub renderers: Vec<(RendererId, RendererSpec)>,
     pub timestamps: Vec<Duration>,
     pub scene_updates: Vec<Component>,
+    /// Scene updates sent after some frames were already rendered. Each update is
+    /// applied before rendering the first timestamp that is not earlier than its pts.
+    pub delayed_scene_updates: Vec<(Duration, Component)>,
+    /// Levels passed to the renderer as if they were calculated by the audio mixer,
+    /// each entry covers samples between `start_pts` and `end_pts`.
+    pub audio_levels: Vec<(Duration, Duration, InputId, AudioLevel)>,
     pub only: bool,
     pub allowed_error: f32,
     pub resolution: Resolution,
@@ -36,6 +42,8 @@ impl Default for TestCase {
             renderers: Vec::new(),
             timestamps: vec![Duration::from_secs(0)],
             scene_updates: vec![],
+            delayed_scene_updates: vec![],
+            audio_levels: vec![],
             only: false,
             allowed_error: 1.0,
             resolution: Resolution {
@@ -65,28 +73,43 @@ impl TestCase {
             renderer.register_input(InputId(format!("input_{}", index + 1).into()))
         }
 
+        let audio_levels = renderer.audio_levels();
+        for (start_pts, end_pts, input_id, level) in self.audio_levels.iter() {
+            audio_levels.push(*start_pts, *end_pts, [(input_id.clone(), *level)]);
+        }
+
         for update in &self.scene_updates {
-            renderer
-                .update_scene(
-                    OutputId(OUTPUT_ID.into()),
-                    self.resolution,
-                    self.output_format,
-                    update.clone(),
-                )
-                .unwrap();
+            self.update_scene(&mut renderer, update);
         }
 
         renderer
     }
 
+    fn update_scene(&self, renderer: &mut Renderer, update: &Component) {
+        renderer
+            .update_scene(
+                OutputId(OUTPUT_ID.into()),
+                self.resolution,
+                self.output_format,
+                update.clone(),
+            )
+            .unwrap();
+    }
+
     pub(super) fn run(&self) -> TestResult {
         if self.name.is_empty() {
             panic!("Snapshot test name has to be provided");
         }
         let mut renderer = self.renderer();
         let mut result = TestResult::Success;
+        let mut delayed_updates = self.delayed_scene_updates.iter().peekable();
 
         for pts in self.timestamps.iter().copied() {
+            while let Some((_, update)) =
+                delayed_updates.next_if(|(update_pts, _)| *update_pts <= pts)
+            {
+                self.update_scene(&mut renderer, update);
+            }
             if let TestResult::Failure = self.test_snapshots_for_pts(&mut renderer, pts) {
                 result = TestResult::Failure;
             }
@@ -136,6 +159,7 @@ impl TestCase {
                 data: input.data.clone(),
                 resolution: input.resolution,
                 pts,
+                captions: None,
             };
             frame_set.frames.insert(input_id, frame);
         }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use super::{scene_from_json, scenes_from_json, snapshots_path, test_case::TestCase, TestRunner};
 
 #[test]
@@ -60,6 +62,18 @@ fn text_tests() {
         )),
         ..Default::default()
     });
+    runner.add(TestCase {
+        name: "text/dynamic_timecode_and_counter",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/text/dynamic_timecode_and_counter.scene.json"
+        )),
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(1500),
+            Duration::from_secs(65),
+        ],
+        ..Default::default()
+    });
     runner.add(TestCase {
         name: "text/red_text_on_blue_background",
         scene_updates: scene_from_json(include_str!(
@@ -67,6 +81,20 @@ fn text_tests() {
         )),
         ..Default::default()
     });
+    runner.add(TestCase {
+        name: "text/rich_text_spans",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/text/rich_text_spans.scene.json"
+        )),
+        ..Default::default()
+    });
+    runner.add(TestCase {
+        name: "text/stroke_and_shadow",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/text/stroke_and_shadow.scene.json"
+        )),
+        ..Default::default()
+    });
     runner.add(TestCase {
         name: "text/wrap_glyph",
         scene_updates: scene_from_json(include_str!(
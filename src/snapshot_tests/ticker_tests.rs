@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use super::{scene_from_json, snapshots_path, test_case::TestCase, TestRunner};
+
+#[test]
+fn ticker_tests() {
+    let mut runner = TestRunner::new(snapshots_path().join("ticker"));
+    let default = TestCase {
+        timestamps: vec![
+            Duration::from_millis(0),
+            Duration::from_millis(1000),
+            Duration::from_millis(3000),
+            Duration::from_millis(8000),
+        ],
+        ..Default::default()
+    };
+
+    runner.add(TestCase {
+        name: "ticker/scroll_left",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/ticker/scroll_left.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "ticker/scroll_right",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/ticker/scroll_right.scene.json"
+        )),
+        ..default.clone()
+    });
+    runner.add(TestCase {
+        name: "ticker/update_content",
+        scene_updates: scene_from_json(include_str!(
+            "../../snapshot_tests/ticker/update_content_start.scene.json"
+        )),
+        // sent after the first text scrolled into the component, so the new text
+        // is appended after the current iteration of the old one
+        delayed_scene_updates: vec![(
+            Duration::from_millis(3000),
+            scene_from_json(include_str!(
+                "../../snapshot_tests/ticker/update_content_end.scene.json"
+            ))
+            .remove(0),
+        )],
+        ..default
+    });
+
+    runner.run()
+}
@@ -3,8 +3,8 @@ use std::{sync::Arc, time::Duration};
 
 use compositor_render::{
     scene::{
-        BorderRadius, Component, Overflow, Position, RGBAColor, ShaderComponent, Size,
-        ViewChildrenDirection, ViewComponent,
+        AlignItems, BlendMode, BorderRadius, Component, JustifyContent, Overflow, Padding,
+        Position, RGBAColor, ShaderComponent, Size, ViewChildrenDirection, ViewComponent,
     },
     shader::ShaderSpec,
     OutputFrameFormat, RendererId, RendererSpec, Resolution,
@@ -40,6 +40,7 @@ fn yuv_test_gradient() {
             children: vec![],
             shader_id: shader_id.clone(),
             shader_param: None,
+            audio_inputs: vec![],
             size: Size {
                 width: width as f32,
                 height: height as f32,
@@ -92,12 +93,29 @@ fn yuv_test_uniform_color() {
                 height: None,
             },
             transition: None,
+            enter_transition: None,
+            exit_transition: None,
+            animations: vec![],
             overflow: Overflow::Hidden,
             background_color: RGBAColor(50, 0, 0, 255),
+            background_gradient: None,
             border_radius: BorderRadius::ZERO,
             border_width: 0.0,
             border_color: RGBAColor(0, 0, 0, 0),
+            border_gradient: None,
             box_shadow: vec![],
+            opacity: 1.0,
+            blend_mode: BlendMode::Normal,
+            blur_radius: 0.0,
+            backdrop_blur: 0.0,
+            padding: Padding::ZERO,
+            gap: 0.0,
+            justify_content: JustifyContent::Start,
+            align_items: AlignItems::Stretch,
+            flex_grow: 0.0,
+            flex_shrink: 0.0,
+            grid_placement: Default::default(),
+            pinned: false,
         })],
         resolution: Resolution { width, height },
         ..Default::default()
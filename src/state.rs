@@ -18,6 +18,7 @@ pub type Pipeline = compositor_pipeline::Pipeline;
 pub enum Response {
     Ok {},
     RegisteredPort { port: u16 },
+    FontFamilies { families: Vec<String> },
 }
 
 impl IntoResponse for Response {
@@ -52,6 +52,7 @@ function parseEvent(event: any): CompositorEvent | null {
       CompositorEventType.AUDIO_INPUT_PLAYING,
       CompositorEventType.VIDEO_INPUT_EOS,
       CompositorEventType.AUDIO_INPUT_EOS,
+      CompositorEventType.VIDEO_INPUT_DECODE_ERROR,
     ].includes(event.type)
   ) {
     return { type: event.type, inputId: event.input_id };
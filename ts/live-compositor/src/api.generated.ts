@@ -9,7 +9,16 @@
  * This enum is used to generate JSON schema for all API types.
  * This prevents repeating types in generated schema.
  */
-export type ApiTypes = RegisterInput | RegisterOutput | ImageSpec | WebRendererSpec | ShaderSpec | UpdateOutputRequest;
+export type ApiTypes =
+  | RegisterInput
+  | RegisterOutput
+  | ImageSpec
+  | LutSpec
+  | SubtitlesSpec
+  | FontSpec
+  | WebRendererSpec
+  | ShaderSpec
+  | UpdateOutputRequest;
 export type RegisterInput =
   | {
       type: "rtp_stream";
@@ -65,6 +74,10 @@ export type RegisterInput =
        * not defined then stream is synchronized based on the first frames delivery time.
        */
       offset_ms?: number | null;
+      /**
+       * (**default=`ffmpeg_h264`**) The decoder to use for decoding video.
+       */
+      video_decoder?: VideoDecoder | null;
     }
   | {
       type: "decklink";
@@ -106,9 +119,7 @@ export type RegisterInput =
     };
 export type PortOrPortRange = string | number;
 export type TransportProtocol = "udp" | "tcp_server";
-export type InputRtpVideoOptions = {
-  decoder: "ffmpeg_h264";
-};
+export type VideoDecoder = "ffmpeg_h264" | "vulkan_video" | "auto";
 export type InputRtpAudioOptions =
   | {
       decoder: "opus";
@@ -187,19 +198,94 @@ export type RegisterOutput =
       audio?: OutputMp4AudioOptions | null;
     };
 export type InputId = string;
-export type VideoEncoderOptions = {
-  type: "ffmpeg_h264";
-  /**
-   * (**default=`"fast"`**) Preset for an encoder. See `FFmpeg` [docs](https://trac.ffmpeg.org/wiki/Encode/H.264#Preset) to learn more.
-   */
-  preset?: H264EncoderPreset | null;
-  /**
-   * Raw FFmpeg encoder options. See [docs](https://ffmpeg.org/ffmpeg-codecs.html) for more.
-   */
-  ffmpeg_options?: {
-    [k: string]: string;
-  } | null;
-};
+export type VideoEncoderOptions =
+  | {
+      type: "ffmpeg_h264";
+      /**
+       * (**default=`"fast"`**) Preset for an encoder. See `FFmpeg` [docs](https://trac.ffmpeg.org/wiki/Encode/H.264#Preset) to learn more.
+       */
+      preset?: H264EncoderPreset | null;
+      /**
+       * (**default=`{ "type": "crf", "crf": 23 }`**) Rate control mode of the encoder.
+       */
+      rate_control?: H264RateControl | null;
+      /**
+       * Number of frames between keyframes (GOP length). When specified, scene-cut detection
+       * is disabled and keyframes are produced at a fixed interval.
+       */
+      gop_size?: number | null;
+      /**
+       * Maximal interval between keyframes in milliseconds. Keyframes are forced based on the
+       * output PTS, so this value does not depend on the framerate.
+       */
+      keyframe_interval_ms?: number | null;
+      /**
+       * Maximal number of consecutive B-frames. Use `0` to disable B-frames.
+       */
+      b_frames?: number | null;
+      /**
+       * H264 profile. If not specified, the encoder picks the profile based on other options.
+       */
+      profile?: H264Profile | null;
+      /**
+       * H264 level, e.g. `"3.1"`, `"4.1"` or `"5.2"`.
+       */
+      level?: string | null;
+      /**
+       * Tune encoder settings for a specific type of content or use case.
+       */
+      tune?: H264EncoderTune | null;
+      /**
+       * Raw FFmpeg encoder options. See [docs](https://ffmpeg.org/ffmpeg-codecs.html) for more.
+       * Those options take precedence over values derived from other fields.
+       */
+      ffmpeg_options?: {
+        [k: string]: string;
+      } | null;
+    }
+  | {
+      type: "vaapi_h264";
+      /**
+       * Path to a DRM render node, e.g. `"/dev/dri/renderD128"`. If not specified, all render
+       * nodes are checked and the first one that supports H264 encoding is used.
+       */
+      device?: string | null;
+      /**
+       * (**default=`true`**) Use the `ffmpeg_h264` encoder if VA-API encoding is not available.
+       */
+      software_fallback?: boolean | null;
+      /**
+       * (**default=`{ "type": "crf", "crf": 23 }`**) Rate control mode of the encoder. The
+       * `crf` mode is mapped to a constant QP mode.
+       */
+      rate_control?: H264RateControl | null;
+      /**
+       * Number of frames between keyframes (GOP length).
+       */
+      gop_size?: number | null;
+      /**
+       * Maximal interval between keyframes in milliseconds.
+       */
+      keyframe_interval_ms?: number | null;
+      /**
+       * Maximal number of consecutive B-frames. Use `0` to disable B-frames.
+       */
+      b_frames?: number | null;
+      /**
+       * H264 profile. `baseline` is mapped to the constrained baseline profile.
+       */
+      profile?: H264Profile | null;
+      /**
+       * H264 level, e.g. `"3.1"`, `"4.1"` or `"5.2"`.
+       */
+      level?: string | null;
+      /**
+       * Raw FFmpeg options of the `h264_vaapi` encoder. They are not used by the software fallback.
+       */
+      ffmpeg_options?: {
+        [k: string]: string;
+      } | null;
+    };
 export type H264EncoderPreset =
   | "ultrafast"
   | "superfast"
@@ -211,6 +297,50 @@ export type H264EncoderPreset =
   | "slower"
   | "veryslow"
   | "placebo";
+export type H264RateControl =
+  | {
+      type: "crf";
+      /**
+       * (**default=`23`**) Constant rate factor (0-51). Lower values mean better quality.
+       */
+      crf?: number | null;
+      /**
+       * Upper limit of the bitrate in bits per second. Requires `buffer_size`.
+       */
+      max_bitrate?: number | null;
+      /**
+       * Size of the VBV buffer in bits.
+       */
+      buffer_size?: number | null;
+    }
+  | {
+      type: "vbr";
+      /**
+       * Average bitrate in bits per second.
+       */
+      bitrate: number;
+      /**
+       * Upper limit of the bitrate in bits per second. Requires `buffer_size`.
+       */
+      max_bitrate?: number | null;
+      /**
+       * Size of the VBV buffer in bits.
+       */
+      buffer_size?: number | null;
+    }
+  | {
+      type: "cbr";
+      /**
+       * Bitrate in bits per second.
+       */
+      bitrate: number;
+      /**
+       * (**default=`bitrate`**) Size of the VBV buffer in bits.
+       */
+      buffer_size?: number | null;
+    };
+export type H264Profile = "baseline" | "main" | "high";
+export type H264EncoderTune = "film" | "animation" | "grain" | "still_image" | "fast_decode" | "zero_latency";
 export type Component =
   | {
       type: "input_stream";
@@ -234,16 +364,16 @@ export type Component =
        */
       children?: Component[] | null;
       /**
-       * Width of a component in pixels. Exact behavior might be different based on the parent
-       * component:
+       * Width of a component in pixels (without a border). Exact behavior might be different
+       * based on the parent component:
        * - If the parent component is a layout, check sections "Absolute positioning" and "Static
        * positioning" of that component.
        * - If the parent component is not a layout, then this field is required.
        */
       width?: number | null;
       /**
-       * Height of a component in pixels. Exact behavior might be different based on the parent
-       * component:
+       * Height of a component in pixels (without a border). Exact behavior might be different
+       * based on the parent component:
        * - If the parent component is a layout, check sections "Absolute positioning" and "Static
        * positioning" of that component.
        * - If the parent component is not a layout, then this field is required.
@@ -254,20 +384,20 @@ export type Component =
        */
       direction?: ViewDirection | null;
       /**
-       * Distance in pixels between this component's top edge and its parent's top edge.
+       * Distance in pixels between this component's top edge and its parent's top edge (including a border).
        * If this field is defined, then the component will ignore a layout defined by its parent.
        */
       top?: number | null;
       /**
-       * Distance in pixels between this component's left edge and its parent's left edge.
+       * Distance in pixels between this component's left edge and its parent's left edge (including a border).
        * If this field is defined, this element will be absolutely positioned, instead of being
        * laid out by its parent.
        */
       left?: number | null;
       /**
-       * Distance in pixels between the bottom edge of this component and the bottom edge of its parent.
-       * If this field is defined, this element will be absolutely positioned, instead of being
-       * laid out by its parent.
+       * Distance in pixels between the bottom edge of this component and the bottom edge of its
+       * parent (including a border). If this field is defined, this element will be absolutely
+       * positioned, instead of being laid out by its parent.
        */
       bottom?: number | null;
       /**
@@ -286,6 +416,24 @@ export type Component =
        * effect if the previous scene already contained a `View` component with the same id.
        */
       transition?: Transition | null;
+      /**
+       * Animation used when this component is added to the scene. It is applied if the
+       * previous scene did not contain a component with the same `id`. Requires `id`.
+       */
+      enter_transition?: EnterExitTransition | null;
+      /**
+       * Animation used when this component is removed from the scene. Component is rendered
+       * until the animation finishes. It is only applied if its parent is a `View`, `Tiles`
+       * or `Grid` component that is present in both scenes (with the same `id`). The removed
+       * component keeps its space in the parent until the next scene update. Requires `id`.
+       */
+      exit_transition?: EnterExitTransition | null;
+      /**
+       * List of keyframe animations. Animations start when the component is added to the
+       * scene. If a scene update contains the same animation (with exactly the same parameters)
+       * for a component with the same `id`, the animation continues instead of restarting.
+       */
+      animations?: KeyframeAnimation[] | null;
       /**
        * (**default=`"hidden"`**) Controls what happens to content that is too big to fit into an area.
        */
@@ -294,6 +442,113 @@ export type Component =
        * (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
        */
       background_color_rgba?: RGBAColor | null;
+      /**
+       * Gradient used as a background. If defined, `background_color_rgba` is ignored.
+       */
+      background_gradient?: Gradient | null;
+      /**
+       * (**default=`0.0`**) Radius of a rounded corner.
+       */
+      border_radius?: number | null;
+      /**
+       * (**default=`0.0`**) Border width.
+       */
+      border_width?: number | null;
+      /**
+       * (**default=`"#00000000"`**) Border color in a `"#RRGGBBAA"` format.
+       */
+      border_color_rgba?: RGBAColor | null;
+      /**
+       * Gradient used to fill a border. If defined, `border_color_rgba` is ignored. Positions
+       * are relative to the whole component, including the border.
+       */
+      border_gradient?: Gradient | null;
+      /**
+       * List of box shadows.
+       */
+      box_shadow?: BoxShadow[] | null;
+      /**
+       * (**default=`1.0`**) Opacity of the component and all of its children. Value
+       * between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+       * together first, so overlapping children are not visible through each other. If the
+       * value is lower than `1.0`, content is clipped to the component's bounds.
+       */
+      opacity?: number | null;
+      /**
+       * (**default=`"normal"`**) Defines how colors of the component are combined with
+       * everything rendered below it. The component is composed with its children first
+       * and the result is blended as a whole. Children with their own blend mode are
+       * blended with the content of this component rendered below them.
+       */
+      blend_mode?: BlendMode | null;
+      /**
+       * (**default=`0.0`**) Radius of a Gaussian blur applied to the component and all
+       * of its children. The blurred content is clipped to the component's bounds.
+       */
+      blur_radius?: number | null;
+      /**
+       * (**default=`0.0`**) Radius of a Gaussian blur applied to everything rendered
+       * behind the component (a frosted-glass effect). Only visible through transparent
+       * parts of the component, e.g. a semi-transparent `background_color_rgba`.
+       */
+      backdrop_blur?: number | null;
+      /**
+       * (**default=`0.0`**) Padding on all sides, in pixels. Padding is added to the size of
+       * the component (similarly to a border) and only affects statically positioned children.
+       */
+      padding?: number | null;
+      /**
+       * Padding on the top side, in pixels. Overrides `padding`.
+       */
+      padding_top?: number | null;
+      /**
+       * Padding on the right side, in pixels. Overrides `padding`.
+       */
+      padding_right?: number | null;
+      /**
+       * Padding on the bottom side, in pixels. Overrides `padding`.
+       */
+      padding_bottom?: number | null;
+      /**
+       * Padding on the left side, in pixels. Overrides `padding`.
+       */
+      padding_left?: number | null;
+      /**
+       * (**default=`0.0`**) Space in pixels between statically positioned children.
+       */
+      gap?: number | null;
+      /**
+       * (**default=`"start"`**) Alignment of statically positioned children along the `direction`
+       * axis. Only has an effect if children do not fill the entire space.
+       */
+      justify_content?: JustifyContent | null;
+      /**
+       * (**default=`"stretch"`**) Alignment of statically positioned children along the axis
+       * perpendicular to the `direction`.
+       */
+      align_items?: AlignItems | null;
+      /**
+       * (**default=`0.0`**) Defines how much of the free space in the parent `View` is added
+       * to this component. Only used if the component is statically positioned inside a `View`.
+       */
+      flex_grow?: number | null;
+      /**
+       * (**default=`0.0`**) Defines how much this component shrinks if the statically positioned
+       * children of the parent `View` do not fit. Shrinking is weighted by the component size.
+       * Only used if the component is statically positioned inside a `View`.
+       */
+      flex_shrink?: number | null;
+      /**
+       * Position of the component inside of a parent `Grid` component. The scene update fails
+       * if it is defined and the parent is not a `Grid`.
+       */
+      grid_placement?: GridPlacement | null;
+      /**
+       * (**default=`false`**) Pins the component if it is a child of a `Tiles` component.
+       * In spotlight modes, pinned children are placed in the spotlight area, in other modes
+       * they are placed first.
+       */
+      pinned?: boolean | null;
     }
   | {
       type: "web_view";
@@ -306,7 +561,8 @@ export type Component =
        */
       children?: Component[] | null;
       /**
-       * Id of a web renderer instance. It identifies an instance registered using a [`register web renderer`](../routes.md#register-web-renderer-instance) request.
+       * Id of a web renderer instance. It identifies an instance registered using a
+       * [`register web renderer`](../routes.md#register-web-renderer-instance) request.
        *
        * :::warning
        * You can only refer to specific instances in one Component at a time.
@@ -328,6 +584,16 @@ export type Component =
        * Id of a shader. It identifies a shader registered using a [`register shader`](../routes.md#register-shader) request.
        */
       shader_id: RendererId;
+      /**
+       * Ids of inputs which audio levels are passed inside the shader as:
+       *
+       * ```wgsl
+       * @group(3) @binding(0) var<uniform> audio_levels: array<vec4<f32>, 16>;
+       * ```
+       * Levels are linear values in the order of this list, `x` is the RMS level and `y` is the peak
+       * level. Up to 16 inputs are supported.
+       */
+      audio_inputs?: InputId[] | null;
       /**
        * Object that will be serialized into a `struct` and passed inside the shader as:
        *
@@ -335,8 +601,10 @@ export type Component =
        * @group(1) @binding(0) var<uniform>
        * ```
        * :::note
-       * This object's structure must match the structure defined in a shader source code. Currently, we do not handle memory layout automatically.
-       * To achieve the correct memory alignment, you might need to pad your data with additional fields. See [WGSL documentation](https://www.w3.org/TR/WGSL/#alignment-and-size) for more details.
+       * This object's structure must match the structure defined in a shader source code.
+       * Currently, we do not handle memory layout automatically. To achieve the correct memory
+       * alignment, you might need to pad your data with additional fields. See
+       * [WGSL documentation](https://www.w3.org/TR/WGSL/#alignment-and-size) for more details.
        * :::
        */
       shader_param?: ShaderParam | null;
@@ -363,9 +631,20 @@ export type Component =
        */
       id?: ComponentId | null;
       /**
-       * Text that will be rendered.
+       * Text that will be rendered. Exactly one of `text`, `spans` or `dynamic` needs to be defined.
+       */
+      text?: string | null;
+      /**
+       * Fragments of the text that will be rendered, each with its own styling. Properties
+       * not defined on a span are inherited from the `Text` component. Exactly one of `text`,
+       * `spans` or `dynamic` needs to be defined.
        */
-      text: string;
+      spans?: TextSpan[] | null;
+      /**
+       * Text generated for every frame, e.g. a clock or a timecode. Exactly one of `text`,
+       * `spans` or `dynamic` needs to be defined.
+       */
+      dynamic?: DynamicText | null;
       /**
        * Width of a texture that text will be rendered on. If not provided, the resulting texture
        * will be sized based on the defined text but limited to `max_width` value.
@@ -399,6 +678,12 @@ export type Component =
        * (**default=`"#FFFFFFFF"`**) Font color in `#RRGGBBAA` format.
        */
       color_rgba?: RGBAColor | null;
+      /**
+       * Gradient used to fill the text. If defined, `color_rgba` is ignored. The gradient
+       * is applied by the parent component, so the `Text` has to be a child of a layout
+       * component (e.g. `View`).
+       */
+      color_gradient?: Gradient | null;
       /**
        * (**default=`"#00000000"`**) Background color in `#RRGGBBAA` format.
        */
@@ -424,6 +709,14 @@ export type Component =
        * (**default=`"normal"`**) Font weight. The selected font needs to support the specified weight.
        */
       weight?: TextWeight | null;
+      /**
+       * Outline drawn around the glyphs.
+       */
+      stroke?: TextStroke | null;
+      /**
+       * Copy of the text (including a stroke) drawn below it.
+       */
+      shadow?: TextShadow | null;
     }
   | {
       type: "tiles";
@@ -475,11 +768,29 @@ export type Component =
        * (**default=`"center"`**) Vertical alignment of tiles.
        */
       vertical_align?: VerticalAlign | null;
+      /**
+       * (**default=`1.0`**) Opacity of the component and all of its children. Value
+       * between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+       * together first, so overlapping children are not visible through each other. If the
+       * value is lower than `1.0`, content is clipped to the component's bounds.
+       */
+      opacity?: number | null;
+      /**
+       * (**default=`"grid"`**) Defines how tiles are arranged.
+       */
+      layout_mode?: TilesLayoutMode | null;
+      /**
+       * Ids of child components that define the order of tiles. Listed children are placed
+       * first (in the listed order), remaining children keep the order in which they are defined.
+       * It allows keeping a stable order of tiles independently of the order of children.
+       */
+      order?: ComponentId[] | null;
       /**
        * Defines how this component will behave during a scene update. This will only have an
        * effect if the previous scene already contained a `Tiles` component with the same id.
        */
       transition?: Transition | null;
+      border_radius?: number | null;
     }
   | {
       type: "rescaler";
@@ -504,36 +815,36 @@ export type Component =
        */
       vertical_align?: VerticalAlign | null;
       /**
-       * Width of a component in pixels. Exact behavior might be different based on the parent
-       * component:
+       * Width of a component in pixels (without a border). Exact behavior might be different
+       * based on the parent component:
        * - If the parent component is a layout, check sections "Absolute positioning" and "Static
        * positioning" of that component.
        * - If the parent component is not a layout, then this field is required.
        */
       width?: number | null;
       /**
-       * Height of a component in pixels. Exact behavior might be different based on the parent
-       * component:
+       * Height of a component in pixels (without a border). Exact behavior might be different
+       * based on the parent component:
        * - If the parent component is a layout, check sections "Absolute positioning" and "Static
        * positioning" of that component.
        * - If the parent component is not a layout, then this field is required.
        */
       height?: number | null;
       /**
-       * Distance in pixels between this component's top edge and its parent's top edge.
+       * Distance in pixels between this component's top edge and its parent's top edge (including a border).
        * If this field is defined, then the component will ignore a layout defined by its parent.
        */
       top?: number | null;
       /**
-       * Distance in pixels between this component's left edge and its parent's left edge.
+       * Distance in pixels between this component's left edge and its parent's left edge (including a border).
        * If this field is defined, this element will be absolutely positioned, instead of being
        * laid out by its parent.
        */
       left?: number | null;
       /**
-       * Distance in pixels between this component's bottom edge and its parent's bottom edge.
-       * If this field is defined, this element will be absolutely positioned, instead of being
-       * laid out by its parent.
+       * Distance in pixels between the bottom edge of this component and the bottom edge of its
+       * parent (including a border). If this field is defined, this element will be absolutely
+       * positioned, instead of being laid out by its parent.
        */
       bottom?: number | null;
       /**
@@ -552,41 +863,692 @@ export type Component =
        * effect if the previous scene already contained a `Rescaler` component with the same id.
        */
       transition?: Transition | null;
-    };
-export type ComponentId = string;
-export type ViewDirection = "row" | "column";
-/**
- * Easing functions are used to interpolate between two values over time.
- *
- * Custom easing functions can be implemented with cubic Bézier.
- * The control points are defined with `points` field by providing four numerical values: `x1`, `y1`, `x2` and `y2`. The `x1` and `x2` values have to be in the range `[0; 1]`. The cubic Bézier result is clamped to the range `[0; 1]`.
- * You can find example control point configurations [here](https://easings.net/).
- */
-export type EasingFunction =
-  | {
-      function_name: "linear";
-    }
-  | {
-      function_name: "bounce";
+      /**
+       * Animation used when this component is added to the scene. It is applied if the
+       * previous scene did not contain a component with the same `id`. Requires `id`.
+       */
+      enter_transition?: EnterExitTransition | null;
+      /**
+       * Animation used when this component is removed from the scene. Component is rendered
+       * until the animation finishes. It is only applied if its parent is a `View`, `Tiles`
+       * or `Grid` component that is present in both scenes (with the same `id`). The removed
+       * component keeps its space in the parent until the next scene update. Requires `id`.
+       */
+      exit_transition?: EnterExitTransition | null;
+      /**
+       * List of keyframe animations. Animations start when the component is added to the
+       * scene. If a scene update contains the same animation (with exactly the same parameters)
+       * for a component with the same `id`, the animation continues instead of restarting.
+       */
+      animations?: KeyframeAnimation[] | null;
+      /**
+       * (**default=`0.0`**) Radius of a rounded corner.
+       */
+      border_radius?: number | null;
+      /**
+       * (**default=`0.0`**) Border width.
+       */
+      border_width?: number | null;
+      /**
+       * (**default=`"#00000000"`**) Border color in a `"#RRGGBBAA"` format.
+       */
+      border_color_rgba?: RGBAColor | null;
+      /**
+       * List of box shadows.
+       */
+      box_shadow?: BoxShadow[] | null;
+      /**
+       * (**default=`1.0`**) Opacity of the component and all of its children. Value
+       * between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+       * together first, so overlapping children are not visible through each other. If the
+       * value is lower than `1.0`, content is clipped to the component's bounds.
+       */
+      opacity?: number | null;
+      /**
+       * (**default=`"normal"`**) Defines how colors of the component are combined with
+       * everything rendered below it. The component is composed with its children first
+       * and the result is blended as a whole. Children with their own blend mode are
+       * blended with the content of this component rendered below them.
+       */
+      blend_mode?: BlendMode | null;
+      /**
+       * (**default=`0.0`**) Radius of a Gaussian blur applied to the component and its
+       * child. The blurred content is clipped to the component's bounds.
+       */
+      blur_radius?: number | null;
+      /**
+       * (**default=`0.0`**) Defines how much of the free space in the parent `View` is added
+       * to this component. Only used if the component is statically positioned inside a `View`.
+       */
+      flex_grow?: number | null;
+      /**
+       * (**default=`0.0`**) Defines how much this component shrinks if the statically positioned
+       * children of the parent `View` do not fit. Shrinking is weighted by the component size.
+       * Only used if the component is statically positioned inside a `View`.
+       */
+      flex_shrink?: number | null;
+      /**
+       * Position of the component inside of a parent `Grid` component. The scene update fails
+       * if it is defined and the parent is not a `Grid`.
+       */
+      grid_placement?: GridPlacement | null;
+      /**
+       * (**default=`false`**) Pins the component if it is a child of a `Tiles` component.
+       * In spotlight modes, pinned children are placed in the spotlight area, in other modes
+       * they are placed first.
+       */
+      pinned?: boolean | null;
+      /**
+       * Part of the child that should be rendered. The selected rectangle is rescaled
+       * instead of the entire child.
+       */
+      crop?: RescalerCrop | null;
     }
   | {
-      function_name: "cubic_bezier";
+      type: "grid";
       /**
-       * @minItems 4
-       * @maxItems 4
+       * Id of a component.
        */
-      points: [number, number, number, number];
-    };
-export type Overflow = "visible" | "hidden" | "fit";
-export type RGBAColor = string;
-export type RendererId = string;
-export type ShaderParam =
-  | {
-      type: "f32";
-      value: number;
+      id?: ComponentId | null;
+      /**
+       * List of component's children. Children are placed in cells based on their
+       * `grid_placement` field.
+       */
+      children?: Component[] | null;
+      /**
+       * Width of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      width?: number | null;
+      /**
+       * Height of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      height?: number | null;
+      /**
+       * (**default=`["1fr"]`**) Sizes of columns.
+       */
+      columns?: GridTrack[] | null;
+      /**
+       * (**default=`[]`**) Sizes of rows. If children do not fit into defined rows, additional
+       * `"auto"` rows are added.
+       */
+      rows?: GridTrack[] | null;
+      /**
+       * (**default=`0`**) Space between columns in pixels.
+       */
+      column_gap?: number | null;
+      /**
+       * (**default=`0`**) Space between rows in pixels.
+       */
+      row_gap?: number | null;
+      /**
+       * (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+       */
+      background_color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`1.0`**) Opacity of the component and all of its children. Value
+       * between `0.0` (fully transparent) and `1.0` (fully opaque). Children are composed
+       * together first, so overlapping children are not visible through each other. If the
+       * value is lower than `1.0`, content is clipped to the component's bounds.
+       */
+      opacity?: number | null;
+      /**
+       * Defines how this component will behave during a scene update. This will only have an
+       * effect if the previous scene already contained a `Grid` component with the same id.
+       */
+      transition?: Transition | null;
     }
   | {
-      type: "u32";
+      type: "mask";
+      /**
+       * Id of a component.
+       */
+      id?: ComponentId | null;
+      /**
+       * Component that will be clipped by the mask.
+       */
+      child: Component;
+      /**
+       * Component used as a mask, e.g. an `Image` or a `Shader`. Its output is stretched
+       * to the resolution of the `Mask` component. Mutually exclusive with `path`.
+       */
+      source?: Component | null;
+      /**
+       * SVG path data (in a format of the `d` attribute) in pixel coordinates of the `Mask`
+       * component. Area inside the path is visible. Mutually exclusive with `source`.
+       */
+      path?: string | null;
+      /**
+       * (**default=`"alpha"`**) Defines how visibility is calculated from the `source`
+       * component. Ignored if `path` is used.
+       */
+      mode?: MaskMode | null;
+      /**
+       * Resolution of the component. Both the child and the mask are stretched to this resolution.
+       */
+      resolution: Resolution;
+    }
+  | {
+      type: "chroma_key";
+      /**
+       * Id of a component.
+       */
+      id?: ComponentId | null;
+      /**
+       * Component that will be keyed.
+       */
+      child: Component;
+      /**
+       * (**default=`"#00FF00FF"`**) Color that will be removed in a `"#RRGGBBAA"` format.
+       * Alpha channel is ignored.
+       */
+      key_color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`0.4`**) Pixels with a color closer to the key color than this value
+       * are fully transparent. The distance is calculated between chroma components of
+       * colors in linear RGB.
+       */
+      similarity?: number | null;
+      /**
+       * (**default=`0.08`**) Width of the range above `similarity` in which pixels are
+       * partially transparent. Higher values produce softer edges.
+       */
+      smoothness?: number | null;
+      /**
+       * (**default=`0.1`**) Value between `0.0` and `1.0` that defines how strongly the key
+       * color reflected on the remaining pixels is suppressed.
+       */
+      spill?: number | null;
+      /**
+       * Resolution of the component. The child is stretched to this resolution.
+       */
+      resolution: Resolution;
+      /**
+       * Defines how this component will behave during a scene update. This will only have an
+       * effect if the previous scene already contained a `ChromaKey` component with the same id.
+       * All of the keying parameters are animated.
+       */
+      transition?: Transition | null;
+      /**
+       * List of keyframe animations of `key_color_rgba`, `similarity`, `smoothness` and `spill`.
+       * Animations start when the component is added to the scene.
+       */
+      animations?: KeyframeAnimation[] | null;
+    }
+  | {
+      type: "color_correction";
+      /**
+       * Id of a component.
+       */
+      id?: ComponentId | null;
+      /**
+       * Component which colors will be adjusted.
+       */
+      child: Component;
+      /**
+       * (**default=`0.0`**) Value added to all color channels. Positive values brighten
+       * the image, negative values darken it.
+       */
+      brightness?: number | null;
+      /**
+       * (**default=`1.0`**) Multiplier of the distance from mid-gray. Values above `1.0`
+       * increase contrast, values below `1.0` decrease it.
+       */
+      contrast?: number | null;
+      /**
+       * (**default=`1.0`**) `0.0` produces a grayscale image, values above `1.0` make
+       * colors more vivid.
+       */
+      saturation?: number | null;
+      /**
+       * (**default=`1.0`**) Gamma correction applied to all channels. Values above `1.0`
+       * brighten midtones, values below `1.0` darken them.
+       */
+      gamma?: number | null;
+      /**
+       * (**default=`0.0`**) White balance temperature, between `-1.0` (cooler, blue) and
+       * `1.0` (warmer, orange).
+       */
+      temperature?: number | null;
+      /**
+       * (**default=`0.0`**) White balance tint, between `-1.0` (green) and `1.0` (magenta).
+       */
+      tint?: number | null;
+      /**
+       * Per-channel adjustment of shadows, midtones and highlights.
+       */
+      lift_gamma_gain?: LiftGammaGain | null;
+      /**
+       * Id of a LUT. It identifies a LUT registered using a [`register LUT`](../routes.md#register-lut)
+       * request. The LUT is applied after all other adjustments.
+       */
+      lut_id?: RendererId | null;
+      /**
+       * (**default=`1.0`**) Value between `0.0` and `1.0` that defines how strongly the LUT is
+       * applied. `0.0` means that the LUT has no effect.
+       */
+      lut_strength?: number | null;
+      /**
+       * Resolution of the component. The child is stretched to this resolution.
+       */
+      resolution: Resolution;
+      /**
+       * Defines how this component will behave during a scene update. This will only have an
+       * effect if the previous scene already contained a `ColorCorrection` component with the
+       * same id. All of the numeric parameters are animated.
+       */
+      transition?: Transition | null;
+      /**
+       * List of keyframe animations of `brightness`, `contrast`, `saturation`, `gamma`,
+       * `temperature`, `tint` and `lut_strength`. Animations start when the component is added
+       * to the scene.
+       */
+      animations?: KeyframeAnimation[] | null;
+    }
+  | {
+      type: "ticker";
+      /**
+       * Id of a component. Required to preserve the scroll position and to append a new
+       * content at the end of the current loop when the scene is updated.
+       */
+      id?: ComponentId | null;
+      /**
+       * Text rendered in a loop. The `id` of this component is ignored.
+       */
+      text: Text;
+      /**
+       * (**default=`" • "`**) Text rendered after each iteration of the loop. It uses
+       * the same style as the `text`.
+       */
+      separator?: string | null;
+      /**
+       * (**default=`100`**) Scroll speed in pixels per second.
+       */
+      speed?: number | null;
+      /**
+       * (**default=`"left"`**) Direction in which the text moves.
+       */
+      direction?: TickerDirection | null;
+      /**
+       * Width of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      width?: number | null;
+      /**
+       * Height of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      height?: number | null;
+      /**
+       * Distance in pixels between this component's top edge and its parent's top edge.
+       * If this field is defined, then the component will ignore a layout defined by its parent.
+       */
+      top?: number | null;
+      /**
+       * Distance in pixels between this component's left edge and its parent's left edge.
+       * If this field is defined, this element will be absolutely positioned, instead of being
+       * laid out by its parent.
+       */
+      left?: number | null;
+      /**
+       * Distance in pixels between the bottom edge of this component and the bottom edge of its
+       * parent. If this field is defined, this element will be absolutely positioned, instead of
+       * being laid out by its parent.
+       */
+      bottom?: number | null;
+      /**
+       * Distance in pixels between this component's right edge and its parent's right edge.
+       * If this field is defined, this element will be absolutely positioned, instead of being
+       * laid out by its parent.
+       */
+      right?: number | null;
+      /**
+       * Rotation of a component in degrees. If this field is defined, this element will be
+       * absolutely positioned, instead of being laid out by its parent.
+       */
+      rotation?: number | null;
+      /**
+       * (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+       */
+      background_color_rgba?: RGBAColor | null;
+    }
+  | {
+      type: "subtitles";
+      /**
+       * Id of a component.
+       */
+      id?: ComponentId | null;
+      /**
+       * Id of subtitles registered using a [`RegisterSubtitles`](../routes.md#register-subtitles)
+       * request.
+       */
+      subtitles_id: RendererId;
+      /**
+       * (**default=`0`**) Offset in milliseconds added to the timestamps of the cues. Positive
+       * values display the cues later.
+       */
+      offset_ms?: number | null;
+      /**
+       * Width of the component in pixels.
+       */
+      width: number;
+      /**
+       * Height of the component in pixels.
+       */
+      height: number;
+      /**
+       * (**default=`"bottom"`**) Vertical position of the cue text inside the component.
+       */
+      vertical_align?: VerticalAlign | null;
+      /**
+       * Font size in pixels.
+       */
+      font_size: number;
+      /**
+       * Distance between lines in pixels. Defaults to the value of the `font_size` property.
+       */
+      line_height?: number | null;
+      /**
+       * (**default=`"#FFFFFFFF"`**) Font color in `#RRGGBBAA` format.
+       */
+      color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`"#00000000"`**) Background color in `#RRGGBBAA` format.
+       */
+      background_color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`"Verdana"`**) Font family. Provide [family-name](https://www.w3.org/TR/2018/REC-css-fonts-3-20180920/#family-name-value)
+       * for a specific font. "generic-family" values like e.g. "sans-serif" will not work.
+       */
+      font_family?: string | null;
+      /**
+       * (**default=`"normal"`**) Font style. The selected font needs to support the specified style.
+       */
+      style?: TextStyle | null;
+      /**
+       * (**default=`"center"`**) Text align.
+       */
+      align?: HorizontalAlign | null;
+      /**
+       * (**default=`"word"`**) Text wrapping options.
+       */
+      wrap?: TextWrapMode | null;
+      /**
+       * (**default=`"normal"`**) Font weight. The selected font needs to support the specified weight.
+       */
+      weight?: TextWeight | null;
+      /**
+       * Outline drawn around the glyphs.
+       */
+      stroke?: TextStroke | null;
+      /**
+       * Copy of the text (including a stroke) drawn below it.
+       */
+      shadow?: TextShadow | null;
+    }
+  | {
+      type: "audio_meter";
+      /**
+       * Id of a component.
+       */
+      id?: ComponentId | null;
+      /**
+       * Id of an input which audio level is displayed. It identifies a stream registered
+       * using a [`RegisterInputStream`](../routes.md#register-input) request.
+       */
+      input_id: InputId;
+      /**
+       * (**default=`{ "type": "bar" }`**) Visual style of the meter.
+       */
+      style?: AudioMeterStyle | null;
+      /**
+       * (**default=`"vertical"`**) Direction in which the meter fills.
+       */
+      orientation?: AudioMeterOrientation | null;
+      /**
+       * (**default=`-60`**) Level in dBFS displayed at the start of the meter. Quieter
+       * signal is displayed as silence.
+       */
+      min_db?: number | null;
+      /**
+       * (**default=`-18`**) Level in dBFS above which `warning_color_rgba` is used.
+       */
+      warning_db?: number | null;
+      /**
+       * (**default=`-6`**) Level in dBFS above which `clip_color_rgba` is used.
+       */
+      clip_db?: number | null;
+      /**
+       * (**default=`"#22C55EFF"`**) Color of the levels below `warning_db` in a `"#RRGGBBAA"` format.
+       */
+      color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`"#EAB308FF"`**) Color of the levels between `warning_db` and `clip_db`
+       * in a `"#RRGGBBAA"` format.
+       */
+      warning_color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`"#EF4444FF"`**) Color of the levels above `clip_db` in a `"#RRGGBBAA"` format.
+       */
+      clip_color_rgba?: RGBAColor | null;
+      /**
+       * (**default=`"#00000000"`**) Background color in a `"#RRGGBBAA"` format.
+       */
+      background_color_rgba?: RGBAColor | null;
+      /**
+       * Width of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      width?: number | null;
+      /**
+       * Height of a component in pixels. Exact behavior might be different based on the parent
+       * component:
+       * - If the parent component is a layout, check sections "Absolute positioning" and "Static
+       * positioning" of that component.
+       * - If the parent component is not a layout, then this field is required.
+       */
+      height?: number | null;
+      /**
+       * Distance in pixels between this component's top edge and its parent's top edge.
+       * If this field is defined, then the component will ignore a layout defined by its parent.
+       */
+      top?: number | null;
+      /**
+       * Distance in pixels between this component's left edge and its parent's left edge.
+       * If this field is defined, this element will be absolutely positioned, instead of being
+       * laid out by its parent.
+       */
+      left?: number | null;
+      /**
+       * Distance in pixels between the bottom edge of this component and the bottom edge of its
+       * parent. If this field is defined, this element will be absolutely positioned, instead of
+       * being laid out by its parent.
+       */
+      bottom?: number | null;
+      /**
+       * Distance in pixels between this component's right edge and its parent's right edge.
+       * If this field is defined, this element will be absolutely positioned, instead of being
+       * laid out by its parent.
+       */
+      right?: number | null;
+      /**
+       * Rotation of a component in degrees. If this field is defined, this element will be
+       * absolutely positioned, instead of being laid out by its parent.
+       */
+      rotation?: number | null;
+    };
+export type ComponentId = string;
+export type ViewDirection = "row" | "column";
+/**
+ * Easing functions are used to interpolate between two values over time.
+ *
+ * Custom easing functions can be implemented with cubic Bézier.
+ * The control points are defined with `points` field by providing four numerical values: `x1`, `y1`, `x2` and `y2`. The `x1` and `x2` values have to be in the range `[0; 1]`. The cubic Bézier result is clamped to the range `[0; 1]`.
+ * You can find example control point configurations [here](https://easings.net/).
+ *
+ * Spring easing simulates a physical spring. If the spring does not settle before
+ * the end of the transition, the final value is applied at the end. When a transition
+ * that uses spring easing interrupts another transition, it preserves the current velocity.
+ */
+export type EasingFunction =
+  | {
+      function_name: "linear";
+    }
+  | {
+      function_name: "bounce";
+    }
+  | {
+      function_name: "cubic_bezier";
+      /**
+       * @minItems 4
+       * @maxItems 4
+       */
+      points: [number, number, number, number];
+    }
+  | {
+      function_name: "spring";
+      /**
+       * (**default=`100`**) Stiffness of the spring. Has to be larger than 0.
+       */
+      stiffness?: number | null;
+      /**
+       * (**default=`10`**) Damping of the spring. Has to be larger or equal to 0.
+       */
+      damping?: number | null;
+      /**
+       * (**default=`1`**) Mass attached to the spring. Has to be larger than 0.
+       */
+      mass?: number | null;
+      /**
+       * (**default=`0`**) Initial velocity, in units of the transition distance per second.
+       */
+      initial_velocity?: number | null;
+    };
+export type EnterExitEffect =
+  | {
+      type: "fade";
+    }
+  | {
+      type: "slide";
+      from: SlideEdge;
+    }
+  | {
+      type: "scale";
+    };
+export type SlideEdge = "top" | "bottom" | "left" | "right";
+export type AnimatedProperty =
+  | "width"
+  | "height"
+  | "opacity"
+  | "border_width"
+  | "border_radius"
+  | "border_color_rgba"
+  | "top"
+  | "left"
+  | "bottom"
+  | "right"
+  | "rotation"
+  | "background_color_rgba"
+  | "crop_top"
+  | "crop_left"
+  | "crop_width"
+  | "crop_height"
+  | "key_color_rgba"
+  | "similarity"
+  | "smoothness"
+  | "spill"
+  | "brightness"
+  | "contrast"
+  | "saturation"
+  | "gamma"
+  | "temperature"
+  | "tint"
+  | "lut_strength"
+  | "blur_radius"
+  | "backdrop_blur";
+export type KeyframeValue = number | RGBAColor;
+export type RGBAColor = string;
+export type AnimationIterations = number | string;
+export type AnimationDirection = "normal" | "reverse" | "alternate" | "alternate_reverse";
+export type Overflow = "visible" | "hidden" | "fit";
+/**
+ * Fill that smoothly changes between colors of its stops. Positions are relative to
+ * the filled area.
+ */
+export type Gradient =
+  | {
+      type: "linear";
+      /**
+       * (**default=`180.0`**) Direction of the gradient line in degrees. `0.0` points
+       * up and `90.0` points right.
+       */
+      angle?: number | null;
+      /**
+       * List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+       */
+      stops: GradientStop[];
+    }
+  | {
+      type: "radial";
+      /**
+       * (**default=`0.5`**) Horizontal position of the center as a fraction of the width.
+       */
+      center_x?: number | null;
+      /**
+       * (**default=`0.5`**) Vertical position of the center as a fraction of the height.
+       */
+      center_y?: number | null;
+      /**
+       * List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+       */
+      stops: GradientStop[];
+    }
+  | {
+      type: "conic";
+      /**
+       * (**default=`0.0`**) Angle in degrees where the gradient starts. `0.0` points up.
+       */
+      angle?: number | null;
+      /**
+       * (**default=`0.5`**) Horizontal position of the center as a fraction of the width.
+       */
+      center_x?: number | null;
+      /**
+       * (**default=`0.5`**) Vertical position of the center as a fraction of the height.
+       */
+      center_y?: number | null;
+      /**
+       * List of color stops sorted by `offset`. Between 2 and 8 stops are supported.
+       */
+      stops: GradientStop[];
+    };
+export type BlendMode = "normal" | "multiply" | "screen" | "overlay" | "add" | "difference";
+export type JustifyContent = "start" | "end" | "center" | "space_between" | "space_around" | "space_evenly";
+export type AlignItems = "start" | "end" | "center" | "stretch";
+export type RendererId = string;
+export type ShaderParam =
+  | {
+      type: "f32";
+      value: number;
+    }
+  | {
+      type: "u32";
       value: number;
     }
   | {
@@ -631,8 +1593,6 @@ export type ShaderParamStructField1 =
       field_name?: string;
     };
 export type TextStyle = "normal" | "italic" | "oblique";
-export type HorizontalAlign = "left" | "right" | "justified" | "center";
-export type TextWrapMode = "none" | "glyph" | "word";
 /**
  * Font weight, based on the [OpenType specification](https://learn.microsoft.com/en-gb/typography/opentype/spec/os2#usweightclass).
  */
@@ -646,9 +1606,99 @@ export type TextWeight =
   | "bold"
   | "extra_bold"
   | "black";
+export type DynamicText =
+  | {
+      type: "clock";
+      /**
+       * (**default=`"%H:%M:%S"`**) Format of the time, see [chrono documentation](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
+       * for supported specifiers.
+       */
+      format?: string | null;
+      /**
+       * (**default=`"utc"`**) Timezone of the clock:
+       * - `"utc"`
+       * - `"local"` - timezone of the machine running the compositor.
+       * - IANA timezone name, e.g. `"Europe/Warsaw"`. Daylight saving time changes are applied.
+       * - Fixed offset from UTC in `"+HH:MM"` or `"-HH:MM"` format, e.g. `"+02:00"`.
+       */
+      timezone?: string | null;
+    }
+  | {
+      type: "timecode";
+      /**
+       * Framerate used to calculate frame numbers. It should match the framerate of the output.
+       */
+      framerate: Framerate;
+      /**
+       * Use drop-frame timecode (`HH:MM:SS;FF`). Only supported for 29.97 (`"30000/1001"`)
+       * and 59.94 (`"60000/1001"`) framerates. Defaults to `true` for those framerates
+       * and to `false` otherwise.
+       */
+      drop_frame?: boolean | null;
+    }
+  | {
+      type: "countdown";
+      /**
+       * Unix timestamp in milliseconds. Remaining time is calculated based on the
+       * system clock.
+       */
+      end_timestamp_ms: number;
+    }
+  | {
+      type: "counter";
+      /**
+       * (**default=`0`**) Initial value.
+       */
+      start?: number | null;
+      /**
+       * (**default=`1`**) Value added every interval, can be negative.
+       */
+      step?: number | null;
+      /**
+       * (**default=`1000`**) Interval in milliseconds.
+       */
+      interval_ms?: number | null;
+    };
+export type Framerate = string | number;
+export type HorizontalAlign = "left" | "right" | "justified" | "center";
+export type TextWrapMode = "none" | "glyph" | "word";
 export type AspectRatio = string;
 export type VerticalAlign = "top" | "center" | "bottom" | "justified";
+export type TilesLayoutMode = "grid" | "spotlight_left" | "spotlight_top" | "filmstrip";
 export type RescaleMode = "fit" | "fill";
+export type CropUnit = "pixels" | "normalized";
+/**
+ * Size of a grid row or column in one of the formats:
+ * - `"<N>px"` - size in pixels, e.g. `"200px"`.
+ * - `"<N>fr"` - share of the space left after sizing all other tracks, e.g. `"1fr"`.
+ * - `"auto"` - size of the largest child placed only in this track (if its size is known).
+ */
+export type GridTrack = string;
+export type MaskMode = "alpha" | "luminance";
+export type TickerDirection = "left" | "right";
+export type AudioMeterStyle =
+  | {
+      type: "bar";
+    }
+  | {
+      type: "segmented";
+      /**
+       * (**default=`12`**) Number of segments, between 1 and 32.
+       */
+      segments?: number | null;
+    }
+  | {
+      type: "waveform";
+      /**
+       * (**default=`5000`**) Time range displayed by the waveform in milliseconds.
+       */
+      duration_ms?: number | null;
+      /**
+       * (**default=`24`**) Number of bars, between 1 and 48.
+       */
+      bars?: number | null;
+    };
+export type AudioMeterOrientation = "vertical" | "horizontal";
 export type MixingStrategy = "sum_clip" | "sum_scale";
 export type RtpAudioEncoderOptions = {
   type: "opus";
@@ -694,6 +1744,9 @@ export type WebEmbeddingMethod =
   | "native_embedding_over_content"
   | "native_embedding_under_content";
 
+export interface InputRtpVideoOptions {
+  decoder: VideoDecoder;
+}
 export interface OutputVideoOptions {
   /**
    * Output resolution in pixels.
@@ -711,6 +1764,10 @@ export interface OutputVideoOptions {
    * Root of a component tree/scene that should be rendered for the output. Use [`update_output` request](../routes.md#update-output) to update this value after registration. [Learn more](../../concept/component.md).
    */
   initial: Video;
+  /**
+   * ID of an input from which closed captions (CEA-608/708 carried in H.264 SEI messages) should be passed to this output. Captions are re-inserted into the output stream with timestamps of the output frames. Supported only by the `ffmpeg_h264` encoder.
+   */
+  captions_input_id?: InputId | null;
 }
 export interface Resolution {
   /**
@@ -761,6 +1818,289 @@ export interface Transition {
    */
   easing_function?: EasingFunction | null;
 }
+export interface EnterExitTransition {
+  /**
+   * Animation that is applied to the component.
+   */
+  effect: EnterExitEffect;
+  /**
+   * Duration of a transition in milliseconds.
+   */
+  duration_ms: number;
+  /**
+   * (**default=`"linear"`**) Easing function to be used for the transition.
+   */
+  easing_function?: EasingFunction | null;
+}
+/**
+ * Animation of a single numeric or color property of a component.
+ */
+export interface KeyframeAnimation {
+  /**
+   * Animated property.
+   */
+  property: AnimatedProperty;
+  /**
+   * List of keyframes sorted by `offset`. At least one keyframe is required.
+   */
+  keyframes: Keyframe[];
+  /**
+   * Duration of a single iteration in milliseconds.
+   */
+  duration_ms: number;
+  /**
+   * (**default=`0`**) Delay in milliseconds before the first iteration starts.
+   */
+  delay_ms?: number | null;
+  /**
+   * (**default=`1`**) Number of iterations. Use `"infinite"` to repeat the animation forever.
+   */
+  iterations?: AnimationIterations | null;
+  /**
+   * (**default=`"normal"`**) Direction in which keyframes are played.
+   */
+  direction?: AnimationDirection | null;
+}
+export interface Keyframe {
+  /**
+   * Position of the keyframe within a single iteration, in the range `[0, 1]`.
+   */
+  offset: number;
+  /**
+   * Value of the property. A number for numeric properties or a color in a `"#RRGGBBAA"`
+   * format for color properties.
+   */
+  value: KeyframeValue;
+  /**
+   * (**default=`"linear"`**) Easing function used between this keyframe and the next one.
+   */
+  easing_function?: EasingFunction | null;
+}
+export interface GradientStop {
+  /**
+   * Position of the stop on the gradient, between `0.0` (start) and `1.0` (end).
+   */
+  offset: number;
+  /**
+   * Color in a `"#RRGGBBAA"` format.
+   */
+  color_rgba: RGBAColor;
+}
+export interface BoxShadow {
+  offset_x?: number | null;
+  offset_y?: number | null;
+  color_rgba?: RGBAColor | null;
+  blur_radius?: number | null;
+}
+export interface GridPlacement {
+  /**
+   * Index of the first row occupied by the component (starting from 0). Maximum
+   * value is `1000`.
+   */
+  row?: number | null;
+  /**
+   * Index of the first column occupied by the component (starting from 0). Maximum
+   * value is `1000`.
+   */
+  column?: number | null;
+  /**
+   * (**default=`1`**) Number of rows occupied by the component. Maximum value is `1000`.
+   */
+  row_span?: number | null;
+  /**
+   * (**default=`1`**) Number of columns occupied by the component. Maximum value
+   * is `1000`.
+   */
+  column_span?: number | null;
+}
+export interface TextSpan {
+  /**
+   * Text of the span.
+   */
+  text: string;
+  /**
+   * Font size in pixels. Defaults to the `font_size` of the `Text` component.
+   */
+  font_size?: number | null;
+  /**
+   * Font color in `#RRGGBBAA` format. Defaults to the `color_rgba` of the `Text` component.
+   */
+  color_rgba?: RGBAColor | null;
+  /**
+   * Font family. Defaults to the `font_family` of the `Text` component.
+   */
+  font_family?: string | null;
+  /**
+   * Font style. Defaults to the `style` of the `Text` component.
+   */
+  style?: TextStyle | null;
+  /**
+   * Font weight. Defaults to the `weight` of the `Text` component.
+   */
+  weight?: TextWeight | null;
+}
+export interface TextStroke {
+  /**
+   * Width of the outline in pixels, between `0` and `20`.
+   */
+  width: number;
+  /**
+   * (**default=`"#000000FF"`**) Color of the outline in `#RRGGBBAA` format.
+   */
+  color_rgba?: RGBAColor | null;
+}
+export interface TextShadow {
+  /**
+   * (**default=`0.0`**) Horizontal offset of the shadow in pixels.
+   */
+  offset_x?: number | null;
+  /**
+   * (**default=`0.0`**) Vertical offset of the shadow in pixels.
+   */
+  offset_y?: number | null;
+  /**
+   * (**default=`"#000000FF"`**) Color of the shadow in `#RRGGBBAA` format.
+   */
+  color_rgba?: RGBAColor | null;
+}
+export interface RescalerCrop {
+  /**
+   * (**default=`0.0`**) Distance between the top edge of the child and the top edge
+   * of the cropped rectangle.
+   */
+  top?: number | null;
+  /**
+   * (**default=`0.0`**) Distance between the left edge of the child and the left edge
+   * of the cropped rectangle.
+   */
+  left?: number | null;
+  /**
+   * Width of the cropped rectangle.
+   */
+  width: number;
+  /**
+   * Height of the cropped rectangle.
+   */
+  height: number;
+  /**
+   * (**default=`"pixels"`**) Unit of the `top`, `left`, `width` and `height` fields.
+   */
+  unit?: CropUnit | null;
+}
+export interface LiftGammaGain {
+  /**
+   * (**default=`{ red: 0.0, green: 0.0, blue: 0.0 }`**) Value added to shadows. White is
+   * not affected.
+   */
+  lift?: ColorChannels | null;
+  /**
+   * (**default=`{ red: 1.0, green: 1.0, blue: 1.0 }`**) Gamma correction of midtones.
+   */
+  gamma?: ColorChannels | null;
+  /**
+   * (**default=`{ red: 1.0, green: 1.0, blue: 1.0 }`**) Multiplier of highlights. Black is
+   * not affected.
+   */
+  gain?: ColorChannels | null;
+}
+export interface ColorChannels {
+  red: number;
+  green: number;
+  blue: number;
+}
+export interface Text {
+  /**
+   * Id of a component.
+   */
+  id?: ComponentId | null;
+  /**
+   * Text that will be rendered. Exactly one of `text`, `spans` or `dynamic` needs to be defined.
+   */
+  text?: string | null;
+  /**
+   * Fragments of the text that will be rendered, each with its own styling. Properties
+   * not defined on a span are inherited from the `Text` component. Exactly one of `text`,
+   * `spans` or `dynamic` needs to be defined.
+   */
+  spans?: TextSpan[] | null;
+  /**
+   * Text generated for every frame, e.g. a clock or a timecode. Exactly one of `text`,
+   * `spans` or `dynamic` needs to be defined.
+   */
+  dynamic?: DynamicText | null;
+  /**
+   * Width of a texture that text will be rendered on. If not provided, the resulting texture
+   * will be sized based on the defined text but limited to `max_width` value.
+   */
+  width?: number | null;
+  /**
+   * Height of a texture that text will be rendered on. If not provided, the resulting texture
+   * will be sized based on the defined text but limited to `max_height` value.
+   * It's an error to provide `height` if `width` is not defined.
+   */
+  height?: number | null;
+  /**
+   * (**default=`7682`**) Maximal `width`. Limits the width of the texture that the text will be rendered on.
+   * Value is ignored if `width` is defined.
+   */
+  max_width?: number | null;
+  /**
+   * (**default=`4320`**) Maximal `height`. Limits the height of the texture that the text will be rendered on.
+   * Value is ignored if height is defined.
+   */
+  max_height?: number | null;
+  /**
+   * Font size in pixels.
+   */
+  font_size: number;
+  /**
+   * Distance between lines in pixels. Defaults to the value of the `font_size` property.
+   */
+  line_height?: number | null;
+  /**
+   * (**default=`"#FFFFFFFF"`**) Font color in `#RRGGBBAA` format.
+   */
+  color_rgba?: RGBAColor | null;
+  /**
+   * Gradient used to fill the text. If defined, `color_rgba` is ignored. The gradient
+   * is applied by the parent component, so the `Text` has to be a child of a layout
+   * component (e.g. `View`).
+   */
+  color_gradient?: Gradient | null;
+  /**
+   * (**default=`"#00000000"`**) Background color in `#RRGGBBAA` format.
+   */
+  background_color_rgba?: RGBAColor | null;
+  /**
+   * (**default=`"Verdana"`**) Font family. Provide [family-name](https://www.w3.org/TR/2018/REC-css-fonts-3-20180920/#family-name-value)
+   * for a specific font. "generic-family" values like e.g. "sans-serif" will not work.
+   */
+  font_family?: string | null;
+  /**
+   * (**default=`"normal"`**) Font style. The selected font needs to support the specified style.
+   */
+  style?: TextStyle | null;
+  /**
+   * (**default=`"left"`**) Text align.
+   */
+  align?: HorizontalAlign | null;
+  /**
+   * (**default=`"none"`**) Text wrapping options.
+   */
+  wrap?: TextWrapMode | null;
+  /**
+   * (**default=`"normal"`**) Font weight. The selected font needs to support the specified weight.
+   */
+  weight?: TextWeight | null;
+  /**
+   * Outline drawn around the glyphs.
+   */
+  stroke?: TextStroke | null;
+  /**
+   * Copy of the text (including a stroke) drawn below it.
+   */
+  shadow?: TextShadow | null;
+}
 export interface OutputRtpAudioOptions {
   /**
    * (**default="sum_clip"**) Specifies how audio should be mixed.
@@ -807,6 +2147,43 @@ export interface OutputMp4AudioOptions {
    */
   initial: Audio;
 }
+export interface LutSpec {
+  /**
+   * Url to download a `.cube` file. This field is mutually exclusive with the `path` field.
+   */
+  url?: string | null;
+  /**
+   * Path to a `.cube` file. This field is mutually exclusive with the `url` field.
+   */
+  path?: string | null;
+}
+export interface SubtitlesSpec {
+  /**
+   * Url to download a `.srt` or `.vtt` file. This field is mutually exclusive with
+   * the `path` field.
+   */
+  url?: string | null;
+  /**
+   * Path to a `.srt` or `.vtt` file. This field is mutually exclusive with the `url` field.
+   */
+  path?: string | null;
+}
+export interface FontSpec {
+  /**
+   * Url to download a font file (`.ttf`, `.otf` or `.ttc`). This field is mutually exclusive
+   * with the `path` and `base64` fields.
+   */
+  url?: string | null;
+  /**
+   * Path to a font file. This field is mutually exclusive with the `url` and `base64` fields.
+   */
+  path?: string | null;
+  /**
+   * Base64 encoded content of a font file. This field is mutually exclusive with the `url`
+   * and `path` fields.
+   */
+  base64?: string | null;
+}
 export interface WebRendererSpec {
   /**
    * Url of a website that you want to render.
@@ -28,6 +28,13 @@ export type EasingFunction =
   | {
       functionName: 'cubic_bezier';
       points: [number, number, number, number];
+    }
+  | {
+      functionName: 'spring';
+      stiffness?: number;
+      damping?: number;
+      mass?: number;
+      initialVelocity?: number;
     };
 
 export function intoApiEasingFunction(easing: EasingFunction): Api.EasingFunction {
@@ -43,6 +50,14 @@ export function intoApiEasingFunction(easing: EasingFunction): Api.EasingFunctio
       function_name: 'cubic_bezier',
       points: easing.points,
     };
+  } else if (typeof easing === 'object' && easing.functionName === 'spring') {
+    return {
+      function_name: 'spring',
+      stiffness: easing.stiffness,
+      damping: easing.damping,
+      mass: easing.mass,
+      initial_velocity: easing.initialVelocity,
+    };
   } else {
     throw new Error(`Invalid LiveCompositor.EasingFunction ${easing}`);
   }
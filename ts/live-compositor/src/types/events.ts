@@ -5,6 +5,7 @@ export enum CompositorEventType {
   VIDEO_INPUT_PLAYING = 'VIDEO_INPUT_PLAYING',
   AUDIO_INPUT_EOS = 'AUDIO_INPUT_EOS',
   VIDEO_INPUT_EOS = 'VIDEO_INPUT_EOS',
+  VIDEO_INPUT_DECODE_ERROR = 'VIDEO_INPUT_DECODE_ERROR',
   OUTPUT_DONE = 'OUTPUT_DONE',
 }
 
@@ -15,4 +16,5 @@ export type CompositorEvent =
   | { type: CompositorEventType.VIDEO_INPUT_PLAYING; inputId: string }
   | { type: CompositorEventType.AUDIO_INPUT_EOS; inputId: string }
   | { type: CompositorEventType.VIDEO_INPUT_EOS; inputId: string }
+  | { type: CompositorEventType.VIDEO_INPUT_DECODE_ERROR; inputId: string }
   | { type: CompositorEventType.OUTPUT_DONE; outputId: string };